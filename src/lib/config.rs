@@ -11,7 +11,13 @@ pub(crate) const DEFAULT_CONFIG: LibConfig = LibConfig {
         toys_version: None,
         toys_hard_mode_version: None,
         filename: None,
+        in_memory: false,
+        pool_size: None,
+        busy_timeout_ms: None,
+        journal_mode: None,
         update_on_startup: true,
+        cache_dir: None,
+        force_refresh: false,
     },
     general: GeneralConfig { build_graph: true },
 };
@@ -32,7 +38,24 @@ pub struct DatabaseConfig {
     pub toys_hard_mode_version: Option<u16>,
     pub names_version: Option<u16>,
     pub filename: Option<String>,
+    /// Store records in memory instead of a `sqlite` file.
+    #[serde(default)]
+    pub in_memory: bool,
+    /// Maximum number of pooled `sqlite` connections.
+    #[serde(default)]
+    pub pool_size: Option<u32>,
+    /// Time in milliseconds to wait for a locked `sqlite` database.
+    #[serde(default)]
+    pub busy_timeout_ms: Option<u64>,
+    /// Journal mode of the `sqlite` database. ex. `wal` or `delete`
+    #[serde(default)]
+    pub journal_mode: Option<String>,
     pub update_on_startup: bool,
+    /// Directory of cached wiki pages.
+    pub cache_dir: Option<String>,
+    /// Ignore cached wiki pages and download every page again.
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 #[derive(Deserialize)]
@@ -0,0 +1,266 @@
+use std::fmt::Display;
+
+use crate::Entity;
+
+/// A column of a table in [`SAPDB`](struct@crate::SAPDB).
+/// * Used to build a [`SAPQuery`](crate::SAPQuery) so column names are checked at compile time.
+pub trait Column: Display + Copy {
+    /// Table the column belongs to.
+    fn table() -> Entity;
+}
+
+/// Columns of the `pets` table.
+/// * See [here](crate::db) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PetColumn {
+    /// Name of pet.
+    Name,
+    /// Tier of pet.
+    Tier,
+    /// Attack of pet.
+    Attack,
+    /// Health of pet.
+    Health,
+    /// Pack of pet.
+    Pack,
+    /// Effect trigger of pet.
+    EffectTrigger,
+    /// Effect description of pet.
+    Effect,
+    /// Effect attack of pet.
+    EffectAtk,
+    /// Effect health of pet.
+    EffectHealth,
+    /// Number of triggers of pet's effect.
+    NTriggers,
+    /// If pet's effect is temporary.
+    TempEffect,
+    /// Level of pet.
+    Lvl,
+    /// Cost of pet.
+    Cost,
+    /// Image url of pet.
+    ImgUrl,
+    /// If pet is a token.
+    IsToken,
+    /// If pet can be summoned by generic summon queries.
+    Summonable,
+}
+
+impl PetColumn {
+    /// All columns of the table.
+    pub const ALL: [PetColumn; 16] = [
+        PetColumn::Name,
+        PetColumn::Tier,
+        PetColumn::Attack,
+        PetColumn::Health,
+        PetColumn::Pack,
+        PetColumn::EffectTrigger,
+        PetColumn::Effect,
+        PetColumn::EffectAtk,
+        PetColumn::EffectHealth,
+        PetColumn::NTriggers,
+        PetColumn::TempEffect,
+        PetColumn::Lvl,
+        PetColumn::Cost,
+        PetColumn::ImgUrl,
+        PetColumn::IsToken,
+        PetColumn::Summonable,
+    ];
+}
+
+impl Display for PetColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PetColumn::Name => write!(f, "name"),
+            PetColumn::Tier => write!(f, "tier"),
+            PetColumn::Attack => write!(f, "attack"),
+            PetColumn::Health => write!(f, "health"),
+            PetColumn::Pack => write!(f, "pack"),
+            PetColumn::EffectTrigger => write!(f, "effect_trigger"),
+            PetColumn::Effect => write!(f, "effect"),
+            PetColumn::EffectAtk => write!(f, "effect_atk"),
+            PetColumn::EffectHealth => write!(f, "effect_health"),
+            PetColumn::NTriggers => write!(f, "n_triggers"),
+            PetColumn::TempEffect => write!(f, "temp_effect"),
+            PetColumn::Lvl => write!(f, "lvl"),
+            PetColumn::Cost => write!(f, "cost"),
+            PetColumn::ImgUrl => write!(f, "img_url"),
+            PetColumn::IsToken => write!(f, "is_token"),
+            PetColumn::Summonable => write!(f, "summonable"),
+        }
+    }
+}
+
+impl Column for PetColumn {
+    fn table() -> Entity {
+        Entity::Pet
+    }
+}
+
+/// Columns of the `foods` table.
+/// * See [here](crate::db) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FoodColumn {
+    /// Name of food.
+    Name,
+    /// Tier of food.
+    Tier,
+    /// Effect description of food.
+    Effect,
+    /// Pack of food.
+    Pack,
+    /// If food can be held.
+    Holdable,
+    /// If food is single use.
+    SingleUse,
+    /// If food's effect lasts until the end of battle.
+    EndOfBattle,
+    /// If food's effect is random.
+    Random,
+    /// Number of targets of food's effect.
+    NTargets,
+    /// Effect attack of food.
+    EffectAtk,
+    /// Effect health of food.
+    EffectHealth,
+    /// If food's effect is turn-based.
+    TurnEffect,
+    /// Cost of food.
+    Cost,
+    /// Image url of food.
+    ImgUrl,
+    /// If food is an ailment.
+    IsAilment,
+}
+
+impl FoodColumn {
+    /// All columns of the table.
+    pub const ALL: [FoodColumn; 15] = [
+        FoodColumn::Name,
+        FoodColumn::Tier,
+        FoodColumn::Effect,
+        FoodColumn::Pack,
+        FoodColumn::Holdable,
+        FoodColumn::SingleUse,
+        FoodColumn::EndOfBattle,
+        FoodColumn::Random,
+        FoodColumn::NTargets,
+        FoodColumn::EffectAtk,
+        FoodColumn::EffectHealth,
+        FoodColumn::TurnEffect,
+        FoodColumn::Cost,
+        FoodColumn::ImgUrl,
+        FoodColumn::IsAilment,
+    ];
+}
+
+impl Display for FoodColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FoodColumn::Name => write!(f, "name"),
+            FoodColumn::Tier => write!(f, "tier"),
+            FoodColumn::Effect => write!(f, "effect"),
+            FoodColumn::Pack => write!(f, "pack"),
+            FoodColumn::Holdable => write!(f, "holdable"),
+            FoodColumn::SingleUse => write!(f, "single_use"),
+            FoodColumn::EndOfBattle => write!(f, "end_of_battle"),
+            FoodColumn::Random => write!(f, "random"),
+            FoodColumn::NTargets => write!(f, "n_targets"),
+            FoodColumn::EffectAtk => write!(f, "effect_atk"),
+            FoodColumn::EffectHealth => write!(f, "effect_health"),
+            FoodColumn::TurnEffect => write!(f, "turn_effect"),
+            FoodColumn::Cost => write!(f, "cost"),
+            FoodColumn::ImgUrl => write!(f, "img_url"),
+            FoodColumn::IsAilment => write!(f, "is_ailment"),
+        }
+    }
+}
+
+impl Column for FoodColumn {
+    fn table() -> Entity {
+        Entity::Food
+    }
+}
+
+/// Columns of the `toys` table.
+/// * See [here](crate::db) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToyColumn {
+    /// Name of toy.
+    Name,
+    /// Tier of toy.
+    Tier,
+    /// Effect trigger of toy.
+    EffectTrigger,
+    /// Effect description of toy.
+    Effect,
+    /// Effect attack of toy.
+    EffectAtk,
+    /// Effect health of toy.
+    EffectHealth,
+    /// Number of triggers of toy's effect.
+    NTriggers,
+    /// If toy's effect is temporary.
+    TempEffect,
+    /// Level of toy.
+    Lvl,
+    /// Source of toy.
+    Source,
+    /// Image url of toy.
+    ImgUrl,
+    /// If toy is a hard mode toy.
+    HardMode,
+}
+
+impl ToyColumn {
+    /// All columns of the table.
+    pub const ALL: [ToyColumn; 12] = [
+        ToyColumn::Name,
+        ToyColumn::Tier,
+        ToyColumn::EffectTrigger,
+        ToyColumn::Effect,
+        ToyColumn::EffectAtk,
+        ToyColumn::EffectHealth,
+        ToyColumn::NTriggers,
+        ToyColumn::TempEffect,
+        ToyColumn::Lvl,
+        ToyColumn::Source,
+        ToyColumn::ImgUrl,
+        ToyColumn::HardMode,
+    ];
+}
+
+impl Display for ToyColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToyColumn::Name => write!(f, "name"),
+            ToyColumn::Tier => write!(f, "tier"),
+            ToyColumn::EffectTrigger => write!(f, "effect_trigger"),
+            ToyColumn::Effect => write!(f, "effect"),
+            ToyColumn::EffectAtk => write!(f, "effect_atk"),
+            ToyColumn::EffectHealth => write!(f, "effect_health"),
+            ToyColumn::NTriggers => write!(f, "n_triggers"),
+            ToyColumn::TempEffect => write!(f, "temp_effect"),
+            ToyColumn::Lvl => write!(f, "lvl"),
+            ToyColumn::Source => write!(f, "source"),
+            ToyColumn::ImgUrl => write!(f, "img_url"),
+            ToyColumn::HardMode => write!(f, "hard_mode"),
+        }
+    }
+}
+
+impl Column for ToyColumn {
+    fn table() -> Entity {
+        Entity::Toy
+    }
+}
+
+/// Get the names of all columns of a table.
+pub(crate) fn column_names(table: Entity) -> Vec<String> {
+    match table {
+        Entity::Pet => PetColumn::ALL.iter().map(ToString::to_string).collect(),
+        Entity::Food => FoodColumn::ALL.iter().map(ToString::to_string).collect(),
+        Entity::Toy => ToyColumn::ALL.iter().map(ToString::to_string).collect(),
+    }
+}
@@ -0,0 +1,301 @@
+use std::{fs, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    db::snapshot::{DataSnapshot, SNAPSHOT_VERSION},
+    error::SAPTestError,
+};
+
+/// Files of each table in a [`Format::Csv`] export.
+const CSV_PETS: &str = "pets.csv";
+const CSV_FOODS: &str = "foods.csv";
+const CSV_TOYS: &str = "toys.csv";
+const CSV_NAMES: &str = "names.csv";
+
+/// File format of exported game data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Single JSON file of a [`DataSnapshot`].
+    Json,
+    /// Directory with one CSV file per table.
+    /// * `pets.csv`, `foods.csv`, `toys.csv`, and `names.csv`
+    /// * Each file has a header row of field names.
+    Csv,
+}
+
+impl DataSnapshot {
+    /// Write the snapshot to a file or directory in a given [`Format`].
+    /// * Directories for [`Format::Csv`] are created if they don't exist.
+    /// # Example
+    /// ```
+    /// use saptest::db::{export::Format, snapshot::DataSnapshot};
+    ///
+    /// let snapshot = DataSnapshot::embedded().unwrap();
+    /// let dir = std::env::temp_dir().join("saptest_snapshot_csv");
+    /// snapshot.write(&dir, Format::Csv).unwrap();
+    ///
+    /// assert_eq!(DataSnapshot::read(&dir, Format::Csv).unwrap(), snapshot);
+    /// ```
+    pub fn write(&self, path: impl AsRef<Path>, format: Format) -> Result<(), SAPTestError> {
+        let path = path.as_ref();
+        match format {
+            Format::Json => write_file(path, &self.to_json()?),
+            Format::Csv => {
+                fs::create_dir_all(path).map_err(|err| io_failure("Export", path, err))?;
+                write_file(&path.join(CSV_PETS), &to_csv(&self.pets)?)?;
+                write_file(&path.join(CSV_FOODS), &to_csv(&self.foods)?)?;
+                write_file(&path.join(CSV_TOYS), &to_csv(&self.toys)?)?;
+                write_file(&path.join(CSV_NAMES), &to_csv(&self.names)?)
+            }
+        }
+    }
+
+    /// Read a snapshot from a file or directory in a given [`Format`].
+    /// * Missing tables in a [`Format::Csv`] directory have no records.
+    /// * See [`DataSnapshot::write`].
+    pub fn read(path: impl AsRef<Path>, format: Format) -> Result<Self, SAPTestError> {
+        let path = path.as_ref();
+        match format {
+            Format::Json => DataSnapshot::from_json(&read_file(path)?),
+            Format::Csv => {
+                let read_table = |file: &str| {
+                    let table_path = path.join(file);
+                    if table_path.exists() {
+                        read_file(&table_path)
+                    } else {
+                        Ok(String::new())
+                    }
+                };
+                Ok(DataSnapshot {
+                    version: SNAPSHOT_VERSION,
+                    pets: from_csv(&read_table(CSV_PETS)?)?,
+                    foods: from_csv(&read_table(CSV_FOODS)?)?,
+                    toys: from_csv(&read_table(CSV_TOYS)?)?,
+                    names: from_csv(&read_table(CSV_NAMES)?)?,
+                })
+            }
+        }
+    }
+}
+
+fn io_failure(subject: &str, path: &Path, err: std::io::Error) -> SAPTestError {
+    SAPTestError::QueryFailure {
+        subject: subject.to_string(),
+        reason: format!("{}: {err}", path.display()),
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), SAPTestError> {
+    fs::write(path, contents).map_err(|err| io_failure("Export", path, err))
+}
+
+fn read_file(path: &Path) -> Result<String, SAPTestError> {
+    fs::read_to_string(path).map_err(|err| io_failure("Import", path, err))
+}
+
+/// Write records as CSV with a header row of field names.
+/// * Missing values are written as empty cells and any non-string value as JSON.
+/// * Strings are quoted if they're empty or could be read as a number or boolean.
+fn to_csv<T: Serialize>(records: &[T]) -> Result<String, SAPTestError> {
+    let mut rows: Vec<Vec<String>> = vec![];
+    for record in records.iter() {
+        let Value::Object(fields) = serde_json::to_value(record)? else {
+            return Err(SAPTestError::QueryFailure {
+                subject: "Export".to_string(),
+                reason: "Only records with named fields can be written as CSV.".to_string(),
+            });
+        };
+        if rows.is_empty() {
+            rows.push(fields.keys().map(|key| escape_cell(key)).collect());
+        }
+        rows.push(
+            fields
+                .into_iter()
+                .map(|(_, value)| match value {
+                    Value::String(value) if value_from_cell(&value, false).is_string() => {
+                        escape_cell(&value)
+                    }
+                    Value::String(value) => quote_cell(&value),
+                    Value::Null => String::new(),
+                    value => escape_cell(&value.to_string()),
+                })
+                .collect(),
+        );
+    }
+    Ok(rows
+        .iter()
+        .map(|row| row.join(","))
+        .map(|line| line + "\n")
+        .collect())
+}
+
+/// Read records from CSV with a header row of field names.
+/// * See [`value_from_cell`].
+fn from_csv<T: DeserializeOwned>(csv: &str) -> Result<Vec<T>, SAPTestError> {
+    let mut rows = parse_csv(csv)?.into_iter();
+    let Some(header) = rows.next() else {
+        return Ok(vec![]);
+    };
+    rows.map(|row| {
+        if row.len() != header.len() {
+            return Err(SAPTestError::QueryFailure {
+                subject: "Import".to_string(),
+                reason: format!(
+                    "Row {row:?} has {} cells but the header has {}.",
+                    row.len(),
+                    header.len()
+                ),
+            });
+        }
+        let fields = header
+            .iter()
+            .map(|(key, _)| key.clone())
+            .zip(
+                row.into_iter()
+                    .map(|(cell, quoted)| value_from_cell(&cell, quoted)),
+            )
+            .collect::<Map<String, Value>>();
+        Ok(serde_json::from_value(Value::Object(fields))?)
+    })
+    .collect()
+}
+
+/// Get the value of a cell.
+/// * Cells that are objects or arrays in JSON are read as JSON.
+/// * Unquoted cells that are empty are missing values.
+/// * Unquoted cells that are numbers or booleans in JSON are read as JSON.
+/// * Any other cell is a string.
+fn value_from_cell(cell: &str, quoted: bool) -> Value {
+    if cell.is_empty() && !quoted {
+        return Value::Null;
+    }
+    match serde_json::from_str::<Value>(cell) {
+        Ok(value @ (Value::Object(_) | Value::Array(_))) => value,
+        Ok(value @ (Value::Number(_) | Value::Bool(_))) if !quoted => value,
+        _ => Value::String(cell.to_owned()),
+    }
+}
+
+fn quote_cell(cell: &str) -> String {
+    format!("\"{}\"", cell.replace('"', "\"\""))
+}
+
+/// Quote a cell if it contains a delimiter, quote, or line break.
+fn escape_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        quote_cell(cell)
+    } else {
+        cell.to_owned()
+    }
+}
+
+/// Parse rows of cells from CSV.
+/// * Each cell is paired with whether it was quoted.
+/// * Quoted cells can contain delimiters, escaped quotes (`""`), and line breaks.
+fn parse_csv(csv: &str) -> Result<Vec<Vec<(String, bool)>>, SAPTestError> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if cell.is_empty() && !quoted => {
+                in_quotes = true;
+                quoted = true;
+            }
+            (',', false) => row.push((std::mem::take(&mut cell), std::mem::take(&mut quoted))),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push((std::mem::take(&mut cell), std::mem::take(&mut quoted)));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => cell.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(SAPTestError::QueryFailure {
+            subject: "Import".to_string(),
+            reason: "CSV has an unterminated quoted cell.".to_string(),
+        });
+    }
+    // Last row without a trailing line break.
+    if !cell.is_empty() || quoted || !row.is_empty() {
+        row.push((cell, quoted));
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Format;
+    use crate::{
+        db::{
+            pack::Pack,
+            record::{PetRecord, TeamNameRecord},
+            snapshot::{DataSnapshot, SNAPSHOT_VERSION},
+        },
+        PetName,
+    };
+
+    fn snapshot() -> DataSnapshot {
+        let pet = PetRecord {
+            name: PetName::Ant,
+            tier: 1,
+            attack: 2,
+            health: 1,
+            pack: Pack::Turtle,
+            effect_trigger: Some("Faint".to_string()),
+            effect: Some("Give a random friend \"+2/+1\",\nonce.".to_string()),
+            effect_atk: 2,
+            effect_health: 1,
+            n_triggers: 1,
+            temp_effect: false,
+            lvl: 1,
+            cost: 3,
+            img_url: String::new(),
+            is_token: false,
+            summonable: true,
+        };
+        let custom_pet = PetRecord {
+            name: PetName::Custom("Bear".to_string()),
+            effect_trigger: None,
+            effect: Some("1".to_string()),
+            ..pet.clone()
+        };
+        DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            pets: vec![pet, custom_pet],
+            names: vec![TeamNameRecord {
+                category: "Prefix".to_string(),
+                word: "true".to_string(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_snapshot_csv_roundtrip() {
+        let dir = std::env::temp_dir().join("saptest_export_csv_test");
+        let snapshot = snapshot();
+        snapshot.write(&dir, Format::Csv).unwrap();
+        assert_eq!(DataSnapshot::read(&dir, Format::Csv).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_snapshot_json_roundtrip() {
+        let file = std::env::temp_dir().join("saptest_export_json_test.json");
+        let snapshot = snapshot();
+        snapshot.write(&file, Format::Json).unwrap();
+        assert_eq!(DataSnapshot::read(&file, Format::Json).unwrap(), snapshot);
+    }
+}
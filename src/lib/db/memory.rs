@@ -0,0 +1,560 @@
+use indexmap::IndexMap;
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use crate::{
+    db::{
+        query::{Comparison, Order, SAPQuery},
+        record::{FoodRecord, PetRecord, SAPRecord, ScrapeStatusRecord, TeamNameRecord, ToyRecord},
+        snapshot::DataSnapshot,
+        storage::{search_words, Storage},
+    },
+    error::SAPTestError,
+    Entity,
+};
+
+/// Records of each table.
+/// * Keyed by the unique columns of the table. Records stay in the order they were first inserted.
+#[derive(Debug, Default)]
+struct Tables {
+    pets: IndexMap<(String, String, usize), PetRecord>,
+    foods: IndexMap<(String, String), FoodRecord>,
+    toys: IndexMap<(String, usize), ToyRecord>,
+    names: BTreeMap<(String, String), TeamNameRecord>,
+    scrape_status: BTreeMap<String, ScrapeStatusRecord>,
+}
+
+/// [`Storage`] of records in memory.
+/// * Queries are evaluated like they would be in `sqlite`.
+/// * Records are lost when dropped.
+/// # Example
+/// ```
+/// use saptest::{SapDB, db::memory::MemoryStorage};
+///
+/// let db = SapDB::with_storage(MemoryStorage::default());
+/// assert!(db.snapshot().unwrap().pets.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    tables: RwLock<Tables>,
+}
+
+impl MemoryStorage {
+    fn read(&self) -> Result<RwLockReadGuard<'_, Tables>, SAPTestError> {
+        self.tables
+            .read()
+            .map_err(|err| SAPTestError::QueryFailure {
+                subject: "Memory Storage".to_string(),
+                reason: err.to_string(),
+            })
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, Tables>, SAPTestError> {
+        self.tables
+            .write()
+            .map_err(|err| SAPTestError::QueryFailure {
+                subject: "Memory Storage".to_string(),
+                reason: err.to_string(),
+            })
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn load(
+        &self,
+        snapshot: &DataSnapshot,
+        statuses: &[ScrapeStatusRecord],
+    ) -> Result<(), SAPTestError> {
+        let mut tables = self.write()?;
+        for pet in snapshot.pets.iter() {
+            let key = (pet.name.to_string(), pet.pack.to_string(), pet.lvl);
+            tables.pets.insert(key, pet.clone());
+        }
+        for food in snapshot.foods.iter() {
+            let key = (food.name.to_string(), food.pack.to_string());
+            tables.foods.insert(key, food.clone());
+        }
+        for toy in snapshot.toys.iter() {
+            let key = (toy.name.to_string(), toy.lvl);
+            tables.toys.insert(key, toy.clone());
+        }
+        for name in snapshot.names.iter() {
+            let key = (name.category.clone(), name.word.clone());
+            tables.names.entry(key).or_insert_with(|| name.clone());
+        }
+        for status in statuses.iter() {
+            tables
+                .scrape_status
+                .insert(status.page.clone(), status.clone());
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> Result<bool, SAPTestError> {
+        let tables = self.read()?;
+        Ok(tables.pets.is_empty() && tables.foods.is_empty() && tables.toys.is_empty())
+    }
+
+    fn execute_query(&self, query: &SAPQuery) -> Result<Vec<SAPRecord>, SAPTestError> {
+        let table = query.checked_table()?;
+        let mut records: Vec<SAPRecord> = {
+            let tables = self.read()?;
+            let records: Box<dyn Iterator<Item = SAPRecord>> = match table {
+                Entity::Pet => Box::new(tables.pets.values().cloned().map(SAPRecord::Pet)),
+                Entity::Food => Box::new(tables.foods.values().cloned().map(SAPRecord::Food)),
+                Entity::Toy => Box::new(tables.toys.values().cloned().map(SAPRecord::Toy)),
+            };
+            records.filter(|record| is_match(query, record)).collect()
+        };
+
+        // Stable sort so ties keep their order of insertion.
+        records.sort_by(|record, other_record| {
+            query
+                .order_by
+                .iter()
+                .map(|(column, order)| {
+                    let ordering = compare_values(
+                        &column_value(record, column).unwrap_or_default(),
+                        &column_value(other_record, column).unwrap_or_default(),
+                    );
+                    match order {
+                        Order::Asc => ordering,
+                        Order::Desc => ordering.reverse(),
+                    }
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+
+        // Filter by roles if any.
+        if !query.roles.is_empty() {
+            let mut role_records = vec![];
+            for record in records.into_iter() {
+                if let SAPRecord::Pet(pet) = &record {
+                    if !pet.roles()?.iter().any(|role| query.roles.contains(role)) {
+                        continue;
+                    }
+                }
+                role_records.push(record);
+            }
+            records = role_records;
+        }
+
+        Ok(records
+            .into_iter()
+            .skip(query.offset.unwrap_or(0))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .collect())
+    }
+
+    fn names(&self) -> Result<Vec<TeamNameRecord>, SAPTestError> {
+        Ok(self.read()?.names.values().cloned().collect())
+    }
+
+    fn scrape_status(&self) -> Result<Vec<ScrapeStatusRecord>, SAPTestError> {
+        Ok(self.read()?.scrape_status.values().cloned().collect())
+    }
+
+    fn search_effects(&self, text: &str) -> Result<Vec<SAPRecord>, SAPTestError> {
+        let words: Vec<String> = search_words(text)
+            .into_iter()
+            .map(|word| word.to_lowercase())
+            .collect();
+        if words.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let tables = self.read()?;
+        let records = tables
+            .pets
+            .values()
+            .map(|pet| {
+                (
+                    [pet.effect_trigger.as_deref(), pet.effect.as_deref()],
+                    SAPRecord::Pet(pet.clone()),
+                )
+            })
+            .chain(tables.foods.values().map(|food| {
+                (
+                    [None, Some(food.effect.as_str())],
+                    SAPRecord::Food(food.clone()),
+                )
+            }))
+            .chain(tables.toys.values().map(|toy| {
+                (
+                    [toy.effect_trigger.as_deref(), toy.effect.as_deref()],
+                    SAPRecord::Toy(toy.clone()),
+                )
+            }));
+
+        // Rank by the number of words in the effect matching the search.
+        let mut ranked_records = vec![];
+        for (effect_text, record) in records {
+            let effect_words: Vec<String> = effect_text
+                .iter()
+                .flatten()
+                .flat_map(|text| search_words(text))
+                .map(|word| word.to_lowercase())
+                .collect();
+            // Every word must match the start of a word in the effect.
+            if !words.iter().all(|word| {
+                effect_words
+                    .iter()
+                    .any(|effect_word| effect_word.starts_with(word))
+            }) {
+                continue;
+            }
+            let n_matches = effect_words
+                .iter()
+                .filter(|effect_word| words.iter().any(|word| effect_word.starts_with(word)))
+                .count();
+            ranked_records.push((n_matches, record));
+        }
+        ranked_records
+            .sort_by(|(n_matches, _), (other_n_matches, _)| other_n_matches.cmp(n_matches));
+        Ok(ranked_records
+            .into_iter()
+            .map(|(_, record)| record)
+            .collect())
+    }
+}
+
+/// Get the value of a column of a record as it's stored in `sqlite`.
+/// * Missing values are stored as `None`.
+fn column_value(record: &SAPRecord, column: &str) -> Option<String> {
+    let missing = || "None".to_string();
+    let value = match record {
+        SAPRecord::Pet(pet) => match column {
+            "name" => pet.name.to_string(),
+            "tier" => pet.tier.to_string(),
+            "attack" => pet.attack.to_string(),
+            "health" => pet.health.to_string(),
+            "pack" => pet.pack.to_string(),
+            "effect_trigger" => pet.effect_trigger.clone().unwrap_or_else(missing),
+            "effect" => pet.effect.clone().unwrap_or_else(missing),
+            "effect_atk" => pet.effect_atk.to_string(),
+            "effect_health" => pet.effect_health.to_string(),
+            "n_triggers" => pet.n_triggers.to_string(),
+            "temp_effect" => pet.temp_effect.to_string(),
+            "lvl" => pet.lvl.to_string(),
+            "cost" => pet.cost.to_string(),
+            "img_url" => pet.img_url.clone(),
+            "is_token" => pet.is_token.to_string(),
+            "summonable" => pet.summonable.to_string(),
+            _ => return None,
+        },
+        SAPRecord::Food(food) => match column {
+            "name" => food.name.to_string(),
+            "tier" => food.tier.to_string(),
+            "effect" => food.effect.clone(),
+            "pack" => food.pack.to_string(),
+            "holdable" => food.holdable.to_string(),
+            "single_use" => food.single_use.to_string(),
+            "end_of_battle" => food.end_of_battle.to_string(),
+            "random" => food.random.to_string(),
+            "n_targets" => food.n_targets.to_string(),
+            "effect_atk" => food.effect_atk.to_string(),
+            "effect_health" => food.effect_health.to_string(),
+            "turn_effect" => food.turn_effect.to_string(),
+            "cost" => food.cost.to_string(),
+            "img_url" => food.img_url.clone(),
+            "is_ailment" => food.is_ailment.to_string(),
+            _ => return None,
+        },
+        SAPRecord::Toy(toy) => match column {
+            "name" => toy.name.to_string(),
+            "tier" => toy.tier.to_string(),
+            "effect_trigger" => toy.effect_trigger.clone().unwrap_or_else(missing),
+            "effect" => toy.effect.clone().unwrap_or_else(missing),
+            "effect_atk" => toy.effect_atk.to_string(),
+            "effect_health" => toy.effect_health.to_string(),
+            "n_triggers" => toy.n_triggers.to_string(),
+            "temp_effect" => toy.temp_effect.to_string(),
+            "lvl" => toy.lvl.to_string(),
+            "source" => toy.source.clone().unwrap_or_else(missing),
+            "img_url" => toy.img_url.clone(),
+            "hard_mode" => toy.hard_mode.to_string(),
+            _ => return None,
+        },
+    };
+    Some(value)
+}
+
+/// Compare values numerically if both are numbers. Otherwise, compare them as text.
+fn compare_values(value: &str, other_value: &str) -> Ordering {
+    match (value.parse::<f64>(), other_value.parse::<f64>()) {
+        (Ok(num), Ok(other_num)) => num.partial_cmp(&other_num).unwrap_or(Ordering::Equal),
+        _ => value.cmp(other_value),
+    }
+}
+
+/// Check if a value matches a `SQL` `LIKE` pattern escaped with `\`.
+/// * Matching is case-insensitive for ASCII characters.
+fn is_like(value: &str, pattern: &str) -> bool {
+    enum Token {
+        Any,
+        One,
+        Char(char),
+    }
+    let mut tokens = vec![];
+    let mut pattern_chars = pattern.chars();
+    while let Some(c) = pattern_chars.next() {
+        tokens.push(match c {
+            '\\' => Token::Char(pattern_chars.next().unwrap_or('\\').to_ascii_lowercase()),
+            '%' => Token::Any,
+            '_' => Token::One,
+            c => Token::Char(c.to_ascii_lowercase()),
+        })
+    }
+
+    // Which prefixes of the value match the tokens so far.
+    let chars: Vec<char> = value.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let mut matched = vec![false; chars.len() + 1];
+    matched[0] = true;
+    for token in tokens.iter() {
+        let mut next_matched = vec![false; chars.len() + 1];
+        match token {
+            Token::Any => {
+                let mut any_matched = false;
+                for (i, is_matched) in matched.iter().enumerate() {
+                    any_matched |= is_matched;
+                    next_matched[i] = any_matched;
+                }
+            }
+            Token::One => {
+                next_matched[1..].copy_from_slice(&matched[..chars.len()]);
+            }
+            Token::Char(token_char) => {
+                for (i, c) in chars.iter().enumerate() {
+                    next_matched[i + 1] = matched[i] && c == token_char;
+                }
+            }
+        }
+        matched = next_matched;
+    }
+    matched[chars.len()]
+}
+
+/// Check if a record matches the params and comparisons of a query.
+fn is_match(query: &SAPQuery, record: &SAPRecord) -> bool {
+    let params_match = query.params.iter().all(|(name, values)| {
+        let (column, is_in) = match name.strip_prefix('-') {
+            Some(column) => (column, false),
+            None => (name.as_str(), true),
+        };
+        let Some(value) = column_value(record, column) else {
+            return false;
+        };
+        let in_values = values
+            .iter()
+            .any(|param| compare_values(&value, param).is_eq());
+        in_values == is_in
+    });
+    params_match
+        && query.comparisons.iter().all(|(column, comparison, param)| {
+            let Some(value) = column_value(record, column) else {
+                return false;
+            };
+            let ordering = compare_values(&value, param);
+            match comparison {
+                Comparison::Lt => ordering.is_lt(),
+                Comparison::Le => ordering.is_le(),
+                Comparison::Gt => ordering.is_gt(),
+                Comparison::Ge => ordering.is_ge(),
+                Comparison::Like => is_like(&value, param),
+            }
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{is_like, MemoryStorage};
+    use crate::{
+        db::{
+            pack::Pack,
+            query::{Order, SAPQuery},
+            record::{PetRecord, SAPRecord, TeamNameRecord},
+            snapshot::{DataSnapshot, SNAPSHOT_VERSION},
+            storage::Storage,
+        },
+        Entity, PetColumn, PetName,
+    };
+
+    fn pets() -> Vec<PetRecord> {
+        let ant = PetRecord {
+            name: PetName::Ant,
+            tier: 1,
+            attack: 2,
+            health: 1,
+            pack: Pack::Turtle,
+            effect_trigger: Some("Faint".to_string()),
+            effect: Some("Give one random friend +2 attack and +1 health.".to_string()),
+            effect_atk: 2,
+            effect_health: 1,
+            n_triggers: 1,
+            temp_effect: false,
+            lvl: 1,
+            cost: 3,
+            img_url: String::new(),
+            is_token: false,
+            summonable: true,
+        };
+        let beetle = PetRecord {
+            name: PetName::Beetle,
+            tier: 1,
+            attack: 2,
+            health: 3,
+            effect_trigger: Some("Start of turn".to_string()),
+            effect: Some("Gain Melon.".to_string()),
+            ..ant.clone()
+        };
+        let tiger = PetRecord {
+            name: PetName::Tiger,
+            tier: 6,
+            attack: 4,
+            health: 3,
+            effect_trigger: None,
+            effect: None,
+            ..ant.clone()
+        };
+        vec![ant, beetle, tiger]
+    }
+
+    fn storage() -> MemoryStorage {
+        let storage = MemoryStorage::default();
+        storage
+            .load(
+                &DataSnapshot {
+                    version: SNAPSHOT_VERSION,
+                    pets: pets(),
+                    names: vec![
+                        TeamNameRecord {
+                            category: "Prefix".to_string(),
+                            word: "Super".to_string(),
+                        },
+                        TeamNameRecord {
+                            category: "Prefix".to_string(),
+                            word: "Auto".to_string(),
+                        },
+                    ],
+                    ..Default::default()
+                },
+                &[],
+            )
+            .unwrap();
+        storage
+    }
+
+    fn pet_names(records: Vec<SAPRecord>) -> Vec<PetName> {
+        records
+            .into_iter()
+            .filter_map(|record| PetRecord::try_from(record).ok())
+            .map(|record| record.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_memory_load() {
+        let storage = storage();
+        assert!(!storage.is_empty().unwrap());
+        // Records are updated in place.
+        storage
+            .load(
+                &DataSnapshot {
+                    version: SNAPSHOT_VERSION,
+                    pets: vec![PetRecord {
+                        attack: 10,
+                        ..pets()[0].clone()
+                    }],
+                    ..Default::default()
+                },
+                &[],
+            )
+            .unwrap();
+        let pets = pet_names(
+            storage
+                .execute_query(&SAPQuery::builder().set_table(Entity::Pet))
+                .unwrap(),
+        );
+        assert_eq!(pets, [PetName::Ant, PetName::Beetle, PetName::Tiger]);
+        // Names are sorted.
+        let names = storage.names().unwrap();
+        assert_eq!(names[0].word, "Auto");
+    }
+
+    #[test]
+    fn test_memory_query() {
+        let storage = storage();
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_not_param(PetColumn::Name, vec![PetName::Ant]);
+        assert_eq!(
+            pet_names(storage.execute_query(&query).unwrap()),
+            [PetName::Beetle, PetName::Tiger]
+        );
+
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_range(PetColumn::Tier, ..=1)
+            .set_order_by(PetColumn::Health, Order::Desc);
+        assert_eq!(
+            pet_names(storage.execute_query(&query).unwrap()),
+            [PetName::Beetle, PetName::Ant]
+        );
+
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_contains(PetColumn::Effect, "melon");
+        assert_eq!(
+            pet_names(storage.execute_query(&query).unwrap()),
+            [PetName::Beetle]
+        );
+
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_param(PetColumn::Effect, vec!["None"])
+            .set_limit(1);
+        assert_eq!(
+            pet_names(storage.execute_query(&query).unwrap()),
+            [PetName::Tiger]
+        );
+
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_order_by(PetColumn::Name, Order::Asc)
+            .set_offset(1);
+        assert_eq!(
+            pet_names(storage.execute_query(&query).unwrap()),
+            [PetName::Beetle, PetName::Tiger]
+        );
+
+        // Invalid queries fail like in sqlite.
+        assert!(storage.execute_query(&SAPQuery::builder()).is_err());
+    }
+
+    #[test]
+    fn test_memory_search_effects() {
+        let storage = storage();
+        assert_eq!(
+            pet_names(storage.search_effects("gain mel").unwrap()),
+            [PetName::Beetle]
+        );
+        assert_eq!(
+            pet_names(storage.search_effects("FAINT").unwrap()),
+            [PetName::Ant]
+        );
+        assert!(storage.search_effects("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_is_like() {
+        assert!(is_like("Gain Melon.", "%melon%"));
+        assert!(is_like("Gain Melon.", "G_in%"));
+        assert!(!is_like("Gain Melon.", "Melon%"));
+        assert!(is_like("100%", "100\\%"));
+        assert!(!is_like("1000", "100\\%"));
+    }
+}
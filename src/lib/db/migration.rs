@@ -0,0 +1,371 @@
+use log::info;
+use rusqlite::{Connection, Transaction};
+
+use crate::error::SAPTestError;
+
+/// Schema version of a database with all [`MIGRATIONS`] applied.
+pub const SCHEMA_VERSION: usize = MIGRATIONS.len();
+
+/// Ordered schema migrations.
+/// * Migration `i` upgrades a database from schema version `i` to `i + 1`.
+/// * Only append new migrations. Applied migrations must never change.
+pub const MIGRATIONS: [Migration; 5] = [
+    Migration {
+        description: "Create names, pets, foods, and toys tables.",
+        apply: create_tables,
+    },
+    Migration {
+        description: "Add columns missing from databases created before versioned migrations.",
+        apply: add_legacy_columns,
+    },
+    Migration {
+        description: "Create scrape_status table.",
+        apply: create_scrape_status_table,
+    },
+    Migration {
+        description: "Create effects_fts full-text index of effects.",
+        apply: create_effects_fts_table,
+    },
+    Migration {
+        description: "Add summonable column to pets table.",
+        apply: add_summonable_column,
+    },
+];
+
+/// A step upgrading the database schema by one version.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Description of changes.
+    pub description: &'static str,
+    /// Apply the migration.
+    pub apply: fn(&Transaction) -> Result<(), SAPTestError>,
+}
+
+/// Get the schema version of a database.
+/// * Databases without a `schema_version` table are at version `0`.
+pub fn schema_version(conn: &Connection) -> Result<usize, SAPTestError> {
+    conn.execute_batch("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER PRIMARY KEY);")?;
+    let version: usize = conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(version)
+}
+
+/// Apply all pending [`MIGRATIONS`] to a database in a single transaction.
+/// * Returns the schema version after migrating.
+/// * Errors if the database has a newer schema version than this crate.
+pub fn migrate(conn: &mut Connection) -> Result<usize, SAPTestError> {
+    let tx = conn.transaction()?;
+    let version = schema_version(&tx)?;
+    if version > SCHEMA_VERSION {
+        return Err(SAPTestError::QueryFailure {
+            subject: "Schema Migration".to_string(),
+            reason: format!(
+                "Database schema version {version} is newer than supported version {SCHEMA_VERSION}."
+            ),
+        });
+    }
+    for (i, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        info!(target: "db", "Applying migration {}: {}", i + 1, migration.description);
+        (migration.apply)(&tx)?;
+        tx.execute("INSERT INTO schema_version (version) VALUES (?1)", [i + 1])?;
+    }
+    tx.commit()?;
+    Ok(SCHEMA_VERSION)
+}
+
+/// Add a column to a table if it doesn't exist.
+fn add_column_if_missing(
+    tx: &Transaction,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), SAPTestError> {
+    let mut stmt = tx.prepare(&format!("PRAGMA table_info({table})"))?;
+    let has_column = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<Result<Vec<String>, _>>()?
+        .iter()
+        .any(|name| name == column);
+    if !has_column {
+        tx.execute_batch(&format!(
+            "ALTER TABLE {table} ADD COLUMN {column} {definition};"
+        ))?;
+    }
+    Ok(())
+}
+
+fn create_tables(tx: &Transaction) -> Result<(), SAPTestError> {
+    tx.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS names (
+            id INTEGER PRIMARY KEY,
+            word_category TEXT NOT NULL,
+            word TEXT NOT NULL,
+            CONSTRAINT unq UNIQUE (word_category, word)
+        );
+        CREATE TABLE IF NOT EXISTS pets (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            tier INTEGER NOT NULL,
+            attack INTEGER NOT NULL,
+            health INTEGER NOT NULL,
+            pack TEXT NOT NULL,
+            effect_trigger TEXT NOT NULL,
+            effect TEXT NOT NULL,
+            effect_atk INTEGER NOT NULL,
+            effect_health INTEGER NOT NULL,
+            n_triggers INTEGER NOT NULL,
+            temp_effect BOOLEAN NOT NULL,
+            lvl INTEGER NOT NULL,
+            cost INTEGER NOT NULL,
+            img_url TEXT,
+            is_token BOOLEAN NOT NULL,
+            CONSTRAINT unq UNIQUE (name, pack, lvl)
+        );
+        CREATE TABLE IF NOT EXISTS foods (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            tier INTEGER NOT NULL,
+            effect TEXT NOT NULL,
+            pack TEXT NOT NULL,
+            holdable BOOLEAN NOT NULL,
+            single_use BOOLEAN NOT NULL,
+            end_of_battle BOOLEAN NOT NULL,
+            random BOOLEAN NOT NULL,
+            n_targets INTEGER NOT NULL,
+            effect_atk INTEGER NOT NULL,
+            effect_health INTEGER NOT NULL,
+            turn_effect BOOLEAN NOT NULL,
+            cost INTEGER NOT NULL,
+            img_url TEXT,
+            is_ailment BOOLEAN NOT NULL,
+            CONSTRAINT unq UNIQUE (name, pack)
+        );
+        CREATE TABLE IF NOT EXISTS toys (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            tier INTEGER NOT NULL,
+            effect_trigger TEXT NOT NULL,
+            effect TEXT NOT NULL,
+            effect_atk INTEGER NOT NULL,
+            effect_health INTEGER NOT NULL,
+            n_triggers INTEGER NOT NULL,
+            temp_effect BOOLEAN NOT NULL,
+            lvl INTEGER NOT NULL,
+            source TEXT NOT NULL,
+            img_url TEXT,
+            hard_mode BOOLEAN NOT NULL,
+            CONSTRAINT unq UNIQUE (name, lvl)
+        );",
+    )?;
+    Ok(())
+}
+
+fn add_legacy_columns(tx: &Transaction) -> Result<(), SAPTestError> {
+    for (table, column, definition) in [
+        // Booleans are stored as 'true' or 'false' like all other records.
+        ("pets", "img_url", "TEXT DEFAULT ''"),
+        ("pets", "is_token", "BOOLEAN NOT NULL DEFAULT 'false'"),
+        ("foods", "img_url", "TEXT DEFAULT ''"),
+        ("foods", "is_ailment", "BOOLEAN NOT NULL DEFAULT 'false'"),
+        ("toys", "img_url", "TEXT DEFAULT ''"),
+        ("toys", "hard_mode", "BOOLEAN NOT NULL DEFAULT 'false'"),
+    ] {
+        add_column_if_missing(tx, table, column, definition)?;
+    }
+    Ok(())
+}
+
+fn create_scrape_status_table(tx: &Transaction) -> Result<(), SAPTestError> {
+    tx.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS scrape_status (
+            page TEXT PRIMARY KEY,
+            url TEXT NOT NULL,
+            succeeded BOOLEAN NOT NULL,
+            error TEXT,
+            updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        );",
+    )?;
+    Ok(())
+}
+
+fn create_effects_fts_table(tx: &Transaction) -> Result<(), SAPTestError> {
+    tx.execute_batch(
+        "
+        CREATE VIRTUAL TABLE IF NOT EXISTS effects_fts USING fts5(
+            entity UNINDEXED,
+            record_id UNINDEXED,
+            effect_trigger,
+            effect
+        );",
+    )?;
+    // Keep index in sync with each table.
+    for (entity, table, effect_trigger) in [
+        ("Pet", "pets", "effect_trigger"),
+        ("Food", "foods", "NULL"),
+        ("Toy", "toys", "effect_trigger"),
+    ] {
+        let new_trigger = effect_trigger.replace("effect_trigger", "new.effect_trigger");
+        tx.execute_batch(&format!(
+            "
+            INSERT INTO effects_fts (entity, record_id, effect_trigger, effect)
+                SELECT '{entity}', id, {effect_trigger}, effect FROM {table};
+            CREATE TRIGGER IF NOT EXISTS {table}_fts_insert AFTER INSERT ON {table} BEGIN
+                INSERT INTO effects_fts (entity, record_id, effect_trigger, effect)
+                    VALUES ('{entity}', new.id, {new_trigger}, new.effect);
+            END;
+            CREATE TRIGGER IF NOT EXISTS {table}_fts_update AFTER UPDATE ON {table} BEGIN
+                DELETE FROM effects_fts WHERE entity = '{entity}' AND record_id = old.id;
+                INSERT INTO effects_fts (entity, record_id, effect_trigger, effect)
+                    VALUES ('{entity}', new.id, {new_trigger}, new.effect);
+            END;
+            CREATE TRIGGER IF NOT EXISTS {table}_fts_delete AFTER DELETE ON {table} BEGIN
+                DELETE FROM effects_fts WHERE entity = '{entity}' AND record_id = old.id;
+            END;
+            "
+        ))?;
+    }
+    Ok(())
+}
+
+fn add_summonable_column(tx: &Transaction) -> Result<(), SAPTestError> {
+    add_column_if_missing(tx, "pets", "summonable", "BOOLEAN NOT NULL DEFAULT 'true'")?;
+    // Tokens aren't summonable.
+    tx.execute_batch("UPDATE pets SET summonable = 'false' WHERE is_token = 'true';")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use rusqlite::Connection;
+
+    use super::{migrate, schema_version, SCHEMA_VERSION};
+    use crate::db::record::{FoodRecord, PetRecord, ToyRecord};
+
+    #[test]
+    fn test_migrate_legacy_db() {
+        // Database created before images, tokens, ailments, and hard mode toys were tracked.
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "
+            CREATE TABLE pets (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                tier INTEGER NOT NULL,
+                attack INTEGER NOT NULL,
+                health INTEGER NOT NULL,
+                pack TEXT NOT NULL,
+                effect_trigger TEXT NOT NULL,
+                effect TEXT NOT NULL,
+                effect_atk INTEGER NOT NULL,
+                effect_health INTEGER NOT NULL,
+                n_triggers INTEGER NOT NULL,
+                temp_effect BOOLEAN NOT NULL,
+                lvl INTEGER NOT NULL,
+                cost INTEGER NOT NULL,
+                CONSTRAINT unq UNIQUE (name, pack, lvl)
+            );
+            CREATE TABLE foods (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                tier INTEGER NOT NULL,
+                effect TEXT NOT NULL,
+                pack TEXT NOT NULL,
+                holdable BOOLEAN NOT NULL,
+                single_use BOOLEAN NOT NULL,
+                end_of_battle BOOLEAN NOT NULL,
+                random BOOLEAN NOT NULL,
+                n_targets INTEGER NOT NULL,
+                effect_atk INTEGER NOT NULL,
+                effect_health INTEGER NOT NULL,
+                turn_effect BOOLEAN NOT NULL,
+                cost INTEGER NOT NULL,
+                CONSTRAINT unq UNIQUE (name, pack)
+            );
+            CREATE TABLE toys (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                tier INTEGER NOT NULL,
+                effect_trigger TEXT NOT NULL,
+                effect TEXT NOT NULL,
+                effect_atk INTEGER NOT NULL,
+                effect_health INTEGER NOT NULL,
+                n_triggers INTEGER NOT NULL,
+                temp_effect BOOLEAN NOT NULL,
+                lvl INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                CONSTRAINT unq UNIQUE (name, lvl)
+            );
+            INSERT INTO pets VALUES (
+                1, 'Ant', 1, 2, 2, 'Turtle', 'Faint', 'Give one random friend +2 attack and +1 health.',
+                2, 1, 1, 'false', 1, 3
+            );
+            INSERT INTO foods VALUES (
+                1, 'Apple', 1, 'Give one pet +1 attack and +1 health.', 'Turtle',
+                'false', 'true', 'false', 'false', 1, 1, 1, 'false', 3
+            );
+            INSERT INTO toys VALUES (
+                1, 'Balloon', 1, 'Faint', 'Give the right-most friend +1 attack and +1 health.',
+                1, 1, 1, 'false', 1, 'Balloon'
+            );
+            ",
+        )
+        .unwrap();
+        assert_eq!(schema_version(&conn).unwrap(), 0);
+
+        assert_eq!(migrate(&mut conn).unwrap(), SCHEMA_VERSION);
+        assert_eq!(schema_version(&conn).unwrap(), SCHEMA_VERSION);
+
+        // Existing records can be read with added columns.
+        let pet = conn
+            .query_row("SELECT * FROM pets WHERE name = 'Ant'", [], |row| {
+                Ok(PetRecord::try_from(row))
+            })
+            .unwrap()
+            .unwrap();
+        assert!(!pet.is_token && pet.summonable && pet.img_url.is_empty());
+        let food = conn
+            .query_row("SELECT * FROM foods WHERE name = 'Apple'", [], |row| {
+                Ok(FoodRecord::try_from(row))
+            })
+            .unwrap()
+            .unwrap();
+        assert!(!food.is_ailment && food.img_url.is_empty());
+        let toy = conn
+            .query_row("SELECT * FROM toys WHERE name = 'Balloon'", [], |row| {
+                Ok(ToyRecord::try_from(row))
+            })
+            .unwrap()
+            .unwrap();
+        assert!(!toy.hard_mode && toy.img_url.is_empty());
+
+        // Existing effects are indexed.
+        let n_indexed: usize = conn
+            .query_row(
+                "SELECT COUNT(*) FROM effects_fts WHERE effects_fts MATCH 'random'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(n_indexed, 1);
+
+        // Migrating again does nothing.
+        assert_eq!(migrate(&mut conn).unwrap(), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_newer_db() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+        conn.execute(
+            "INSERT INTO schema_version (version) VALUES (?1)",
+            [SCHEMA_VERSION + 1],
+        )
+        .unwrap();
+        assert!(migrate(&mut conn).is_err());
+    }
+}
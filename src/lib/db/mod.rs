@@ -53,6 +53,7 @@
 //!     cost INTEGER NOT NULL,
 //!     img_url TEXT,
 //!     is_token BOOLEAN NOT NULL,
+//!     summonable BOOLEAN NOT NULL,
 //!     CONSTRAINT unq UNIQUE (name, pack, lvl)
 //! );
 //! ```
@@ -90,6 +91,9 @@
 //!     * Current image url displayed on page.
 //! * `is_token`
 //!     * Is current pet a [token](https://superautopets.wiki.gg/wiki/Tokens)?
+//! * `summonable`
+//!     * Can pet be summoned by generic summon queries?
+//!     * Tokens aren't summonable.
 //!
 //! #### Foods
 //! Food records.
@@ -201,6 +205,32 @@
 //! * `hard_mode`
 //!     * Is this a hard mode toy?
 //!
+//! ### Storage
+//! * Records of a [`SapDB`](crate::SapDB) are stored in a [`Storage`](storage::Storage) backend.
+//!     * [`SqliteStorage`](sqlite::SqliteStorage) stores records in a `sqlite` file. Requires the default `sqlite` feature.
+//!     * [`MemoryStorage`](memory::MemoryStorage) stores records in memory. Doesn't require `rusqlite` so works on `wasm32`.
+//! * Without the `sqlite` feature, the global [`SAPDB`](struct@crate::SAPDB) is always stored in memory.
+//!     ```toml
+//!     saptest = { version = "*", default-features = false }
+//!     ```
+//!
+//! ### Migrations
+//! * The schema version of a database is stored in the `schema_version` table.
+//! * Pending [`migration::MIGRATIONS`] are applied when a [`SapDB`](crate::SapDB) is created.
+//!     * Databases from older versions of this crate are upgraded in place and don't need to be deleted.
+//!
+//! ### Search
+//! * Effect descriptions and triggers are indexed in the `effects_fts` [FTS5](https://www.sqlite.org/fts5.html) table.
+//! * Search them with [`SapDB::search_effects`](crate::SapDB::search_effects).
+//!
+//! ### Custom Records
+//! * User-defined pets and foods can be inserted with [`SapDB::insert_custom_pet`](crate::SapDB::insert_custom_pet) and [`SapDB::insert_custom_food`](crate::SapDB::insert_custom_food).
+//!     * They're placed in the reserved [`Pack::Custom`](pack::Pack::Custom) and used by queries, summons, and shops like scraped records.
+//!
+//! ### Export
+//! * Game data can be exported to and imported from JSON or CSV with [`SapDB::export`](crate::SapDB::export) and [`SapDB::import`](crate::SapDB::import).
+//!     * Useful to inspect, diff, or version data outside of SQLite and to build test fixtures from plain files.
+//!
 //! ### Conversion
 //! * Any record can be converted into [`Food`](crate::Food)s or [Pet](crate::Pet)s.
 //! ```rust compile_fail
@@ -217,6 +247,10 @@
 //!     ```rust no_run
 //!     let db = saptest::SapDB::new("./test_sap.db");
 //!     ```
+//! * Or create one in memory with the bundled [`DataSnapshot`](snapshot::DataSnapshot):
+//!     ```rust no_run
+//!     let db = saptest::SapDB::in_memory();
+//!     ```
 //!
 //! ### Configuration
 //! To modify [`SapDB`](struct@crate::SapDB) behavior, create a `.saptest.toml`.
@@ -229,7 +263,13 @@
 //!         * ex. <https://superautopets.wiki.gg/wiki/Pets>
 //! * Toggle recurring updates on startup.
 //!     * By default, the database is updated on startup.
+//!     * If disabled with an empty database or the wiki can't be reached, the [`DataSnapshot`](snapshot::DataSnapshot) bundled with the crate is loaded.
 //! * Database filename.
+//! * Toggle storing records in memory instead of a `sqlite` file.
+//! * Tune the `sqlite` connection pool. See [`SqliteOptions`](sqlite::SqliteOptions).
+//!     * Maximum number of pooled connections. Increase for heavily parallel simulations.
+//!     * Time to wait for a locked database.
+//!     * Journal mode. Defaults to `wal` so reads don't wait on writes.
 //!
 //! ```toml
 //! [database]
@@ -255,9 +295,23 @@
 //! # toys_hard_mode_version = ?
 //!
 //! filename = "./sap.db"
+//! in_memory = false
 //! update_on_startup = false
+//!
+//! pool_size = 10
+//! busy_timeout_ms = 5000
+//! journal_mode = "wal"
 //! ```
 
+/// Typed query columns.
+pub mod column;
+/// Export and import of game data.
+pub mod export;
+/// In memory storage.
+pub mod memory;
+/// Database schema migrations.
+#[cfg(feature = "sqlite")]
+pub mod migration;
 /// Game packs.
 pub mod pack;
 /// Database query.
@@ -266,5 +320,13 @@ pub mod query;
 pub mod record;
 /// Database setup functions.
 pub mod setup;
+/// Offline data snapshots.
+pub mod snapshot;
+/// SQLite storage.
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+/// Storage backends.
+pub mod storage;
 /// Database helper functions.
+#[cfg(feature = "sqlite")]
 pub mod utils;
@@ -12,10 +12,13 @@ pub enum Pack {
     Puppy,
     /// The [Star pack](https://superautopets.fandom.com/wiki/Star_Pack).
     Star,
-    /// The [Weekly pack](https://superautopets.fandom.com/wiki/Weekly_Pack)
+    /// The [Weekly pack](https://superautopets.fandom.com/wiki/Weekly_Pack).
     Weekly,
     /// The [Golden pack]().
     Golden,
+    /// Reserved pack of user-defined records.
+    /// * See [`SapDB::insert_custom_pet`](crate::SapDB::insert_custom_pet) and [`SapDB::insert_custom_food`](crate::SapDB::insert_custom_food).
+    Custom,
     #[default]
     /// An unknown pack. Indicates a typo or a new update.
     Unknown,
@@ -31,6 +34,7 @@ impl FromStr for Pack {
             "star" => Ok(Pack::Star),
             "weekly" => Ok(Pack::Weekly),
             "golden" => Ok(Pack::Golden),
+            "custom" => Ok(Pack::Custom),
             _ => Ok(Pack::Unknown),
         }
     }
@@ -45,6 +49,7 @@ impl fmt::Display for Pack {
             Pack::Star => write!(f, "Star"),
             Pack::Weekly => write!(f, "Weekly"),
             Pack::Golden => write!(f, "Golden"),
+            Pack::Custom => write!(f, "Custom"),
             Pack::Unknown => write!(f, "Unknown"),
         }
     }
@@ -59,6 +64,8 @@ mod test {
     fn test_str_to_pack() {
         assert_eq!(Pack::Turtle, Pack::from_str("Turtle").unwrap());
         assert_eq!(Pack::Turtle, Pack::from_str("TURTLE").unwrap());
+        assert_eq!(Pack::Weekly, Pack::from_str("weekly").unwrap());
+        assert_eq!(Pack::Custom, Pack::from_str("Custom").unwrap());
         assert_eq!(Pack::Unknown, Pack::from_str("camel").unwrap());
     }
 }
@@ -1,14 +1,68 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-use std::fmt::Write;
+use std::{
+    fmt::Write,
+    ops::{Bound, RangeBounds},
+};
 
-use crate::{error::SAPTestError, Entity};
+use crate::{
+    db::column::{column_names, Column},
+    error::SAPTestError,
+    pets::role::PetRole,
+    Entity,
+};
+
+/// Sort order of a [`SAPQuery`] column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Order {
+    /// Smallest to largest.
+    Asc,
+    /// Largest to smallest.
+    Desc,
+}
+
+/// Comparison of a column to a value in a [`SAPQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Like,
+}
+
+impl Comparison {
+    fn as_sql(&self) -> &'static str {
+        match self {
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+            Comparison::Like => "LIKE",
+        }
+    }
+}
 
 /// Query constructor for [`SapDB::execute_query`](crate::SapDB::execute_query).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SAPQuery {
     pub(crate) table: Option<Entity>,
     pub(crate) params: IndexMap<String, Vec<String>>,
+    /// Pet roles records must have one of.
+    #[serde(default)]
+    pub(crate) roles: Vec<PetRole>,
+    /// Column comparisons records must satisfy.
+    #[serde(default)]
+    pub(crate) comparisons: Vec<(String, Comparison, String)>,
+    /// Columns to sort records by.
+    #[serde(default)]
+    pub(crate) order_by: Vec<(String, Order)>,
+    /// Maximum number of records.
+    #[serde(default)]
+    pub(crate) limit: Option<usize>,
+    /// Number of records to skip.
+    #[serde(default)]
+    pub(crate) offset: Option<usize>,
 }
 
 impl std::fmt::Display for SAPQuery {
@@ -22,15 +76,17 @@ impl std::fmt::Display for SAPQuery {
     }
 }
 
-impl<N, P> FromIterator<(N, Vec<P>)> for SAPQuery
+impl<C, P> FromIterator<(C, Vec<P>)> for SAPQuery
 where
-    N: ToString,
+    C: Column,
     P: ToString,
 {
-    fn from_iter<T: IntoIterator<Item = (N, Vec<P>)>>(iter: T) -> Self {
-        let mut query = SAPQuery::builder();
-        for (param, value) in iter.into_iter() {
-            query = query.set_param(param, value);
+    /// Build a query from [`Column`]s and their values.
+    /// * The table is set to the table of the columns.
+    fn from_iter<T: IntoIterator<Item = (C, Vec<P>)>>(iter: T) -> Self {
+        let mut query = SAPQuery::builder().set_table(C::table());
+        for (column, value) in iter.into_iter() {
+            query = query.set_param(column, value);
         }
         query
     }
@@ -48,6 +104,11 @@ impl SAPQuery {
         SAPQuery {
             table: None,
             params: IndexMap::new(),
+            roles: vec![],
+            comparisons: vec![],
+            order_by: vec![],
+            limit: None,
+            offset: None,
         }
     }
 
@@ -64,44 +125,215 @@ impl SAPQuery {
     }
 
     /// Set params in [`SAPDB`](struct@crate::SAPDB) to query. See [here](crate::db) for more details.
-    /// * Prefixing the param name with `-` will select all values not in the given params.
+    /// * Records with any of the given values in the [`Column`] are kept.
     /// ---
     /// Ex. Query [`FoodRecord`](crate::db::record::FoodRecord)s where `name` is [`FoodName::Apple`](crate::FoodName::Apple) or [`FoodName::Coconut`](crate::FoodName::Coconut).
     /// ```rust no_run
-    /// use saptest::{Entity, SAPQuery, FoodName};
+    /// use saptest::{Entity, SAPQuery, FoodName, db::column::FoodColumn};
     ///
     /// // Construct a query set to the "foods" table.
     /// let query = SAPQuery::builder()
-    ///     .set_param("name", vec![FoodName::Apple, FoodName::Coconut])
+    ///     .set_param(FoodColumn::Name, vec![FoodName::Apple, FoodName::Coconut])
     ///     .set_table(Entity::Food);
     /// ```
+    pub fn set_param<C: Column, V: ToString>(self, column: C, value: Vec<V>) -> Self {
+        self.insert_param(column.to_string(), value)
+    }
+
+    /// Set params in [`SAPDB`](struct@crate::SAPDB) to exclude from the query. See [here](crate::db) for more details.
+    /// * Records with any of the given values in the [`Column`] are removed.
     /// ---
     /// Ex. Query [`PetRecord`](crate::db::record::PetRecord)s where name is **not** [`PetName::Ant`](crate::PetName::Ant) and [`PetName::Dog`](crate::PetName::Dog).
     /// ```rust no_run
-    /// use saptest::{Entity, SAPQuery, PetName};
+    /// use saptest::{Entity, SAPQuery, PetName, db::column::PetColumn};
     ///
     /// // Construct a query set to the "pets" table.
     /// let query = SAPQuery::builder()
-    ///     .set_param("-name", vec![PetName::Ant, PetName::Dog])
+    ///     .set_not_param(PetColumn::Name, vec![PetName::Ant, PetName::Dog])
     ///     .set_table(Entity::Pet);
     /// ```
-    pub fn set_param<N: ToString, V: ToString>(mut self, name: N, value: Vec<V>) -> Self {
+    pub fn set_not_param<C: Column, V: ToString>(self, column: C, value: Vec<V>) -> Self {
+        self.insert_param(format!("-{column}"), value)
+    }
+
+    /// Add values to a param.
+    /// * Param names prefixed with `-` select all values not in the given params.
+    fn insert_param<V: ToString>(mut self, name: String, value: Vec<V>) -> Self {
         self.params
-            .entry(name.to_string())
+            .entry(name)
             .and_modify(|e| e.extend(value.iter().map(|val| val.to_string())))
             .or_insert(value.into_iter().map(|value| value.to_string()).collect());
         self
     }
 
+    /// Check if a [`Column`] is a param of the query.
+    /// * Includes params set with [`SAPQuery::set_not_param`].
+    pub fn has_param<C: Column>(&self, column: C) -> bool {
+        self.params.contains_key(&column.to_string())
+            || self.params.contains_key(&format!("-{column}"))
+    }
+
+    /// Set [`PetRole`]s to filter [`PetRecord`](crate::db::record::PetRecord)s by.
+    /// * Records with any of the given roles are kept.
+    /// * Roles are derived from effects so are ignored for other tables.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, pets::role::PetRole, PetColumn};
+    ///
+    /// // Construct a query for tier 1 pets that summon or snipe.
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_param(PetColumn::Tier, vec![1])
+    ///     .set_roles(vec![PetRole::Summoner, PetRole::Sniper]);
+    /// ```
+    pub fn set_roles(mut self, roles: Vec<PetRole>) -> Self {
+        self.roles.extend(roles);
+        self
+    }
+
+    /// Set a range of values in [`SAPDB`](struct@crate::SAPDB) to query.
+    /// * Useful for numeric columns like `tier`, `cost`, or `attack`.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, PetColumn};
+    ///
+    /// // Construct a query for pets from tier 2 up to and including tier 3.
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_range(PetColumn::Tier, 2..=3);
+    /// ```
+    pub fn set_range<C: Column, V: ToString>(
+        mut self,
+        column: C,
+        range: impl RangeBounds<V>,
+    ) -> Self {
+        let name = column.to_string();
+        match range.start_bound() {
+            Bound::Included(value) => {
+                self.comparisons
+                    .push((name.clone(), Comparison::Ge, value.to_string()))
+            }
+            Bound::Excluded(value) => {
+                self.comparisons
+                    .push((name.clone(), Comparison::Gt, value.to_string()))
+            }
+            Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            Bound::Included(value) => {
+                self.comparisons
+                    .push((name, Comparison::Le, value.to_string()))
+            }
+            Bound::Excluded(value) => {
+                self.comparisons
+                    .push((name, Comparison::Lt, value.to_string()))
+            }
+            Bound::Unbounded => {}
+        }
+        self
+    }
+
+    /// Set a `SQL` `LIKE` pattern that values in [`SAPDB`](struct@crate::SAPDB) must match.
+    /// * `%` matches any number of characters and `_` matches a single character.
+    /// * Matching is case-insensitive for ASCII characters.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, PetColumn};
+    ///
+    /// // Construct a query for pets with an effect that starts with "Give".
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_like(PetColumn::Effect, "Give%");
+    /// ```
+    pub fn set_like<C: Column, P: ToString>(mut self, column: C, pattern: P) -> Self {
+        self.comparisons
+            .push((column.to_string(), Comparison::Like, pattern.to_string()));
+        self
+    }
+
+    /// Set text that values in [`SAPDB`](struct@crate::SAPDB) must contain.
+    /// * Matching is case-insensitive for ASCII characters.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, PetColumn};
+    ///
+    /// // Construct a query for pets with an effect that deals damage.
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_contains(PetColumn::Effect, "damage");
+    /// ```
+    pub fn set_contains<C: Column, T: AsRef<str>>(self, column: C, text: T) -> Self {
+        let escaped = text
+            .as_ref()
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        self.set_like(column, format!("%{escaped}%"))
+    }
+
+    /// Set a column to sort records by.
+    /// * Columns are sorted in the order they're set.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, db::query::Order, PetColumn};
+    ///
+    /// // Construct a query for the cheapest and then strongest pets.
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_order_by(PetColumn::Cost, Order::Asc)
+    ///     .set_order_by(PetColumn::Attack, Order::Desc);
+    /// ```
+    pub fn set_order_by<C: Column>(mut self, column: C, order: Order) -> Self {
+        self.order_by.push((column.to_string(), order));
+        self
+    }
+
+    /// Set the maximum number of records to get.
+    /// * Applied after filtering by [`PetRole`]s.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, db::query::Order, PetColumn};
+    ///
+    /// // Construct a query for the 5 cheapest faint pets in tier 3 or below.
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_range(PetColumn::Tier, ..=3)
+    ///     .set_contains(PetColumn::EffectTrigger, "Faint")
+    ///     .set_order_by(PetColumn::Cost, Order::Asc)
+    ///     .set_limit(5);
+    /// ```
+    pub fn set_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the number of records to skip.
+    /// * Applied after filtering by [`PetRole`]s.
+    /// ```rust no_run
+    /// use saptest::{Entity, SAPQuery, db::query::Order, PetColumn};
+    ///
+    /// // Construct a query for the second page of 10 pets by name.
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_order_by(PetColumn::Name, Order::Asc)
+    ///     .set_limit(10)
+    ///     .set_offset(10);
+    /// ```
+    pub fn set_offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Check if the limit and offset are applied in `SQL`.
+    /// * [`PetRole`]s are filtered after the query so the limit and offset must be applied afterwards.
+    pub(crate) fn sql_paginated(&self) -> bool {
+        self.roles.is_empty()
+    }
+
     /// Get a flattened list of params in the order of insertion.
+    /// * Values of [`SAPQuery::set_range`], [`SAPQuery::set_like`], and [`SAPQuery::set_contains`] follow in the order of insertion.
     /// ```
-    /// use saptest::SAPQuery;
+    /// use saptest::{SAPQuery, db::column::PetColumn};
     ///
     /// let params = [
-    ///     ("name", vec!["Turtle"]),
-    ///     ("pack", vec!["Turtle", "Star"]),
-    ///     ("tier", vec!["1", "2", "3"]),
-    ///     ("lvl", vec!["1"]),
+    ///     (PetColumn::Name, vec!["Turtle"]),
+    ///     (PetColumn::Pack, vec!["Turtle", "Star"]),
+    ///     (PetColumn::Tier, vec!["1", "2", "3"]),
+    ///     (PetColumn::Lvl, vec!["1"]),
     /// ];
     /// let query = SAPQuery::from_iter(params);
     /// let params = query.flat_params();
@@ -112,42 +344,74 @@ impl SAPQuery {
     /// )
     /// ```
     pub fn flat_params(&self) -> Vec<&String> {
-        self.params.iter().flat_map(|(_, params)| params).collect()
+        self.params
+            .iter()
+            .flat_map(|(_, params)| params)
+            .chain(self.comparisons.iter().map(|(_, _, value)| value))
+            .collect()
+    }
+
+    /// Get the table of the query.
+    /// * Raises [`SAPTestError::QueryFailure`] when:
+    ///     * No table is set.
+    ///     * A column isn't a column of the table.
+    pub(crate) fn checked_table(&self) -> Result<Entity, SAPTestError> {
+        let Some(table) = self.table else {
+            return Err(SAPTestError::QueryFailure {
+                subject: "No Table".to_string(),
+                reason: "Query requires a table.".to_string(),
+            });
+        };
+        // Column names are inserted so must be columns of the table.
+        // * Queries deserialized from older versions may have invalid columns.
+        let table_name = format!("{}s", table.to_string().to_lowercase());
+        let table_columns = column_names(table);
+        let columns = self
+            .params
+            .keys()
+            .map(|name| name.strip_prefix('-').unwrap_or(name))
+            .chain(self.comparisons.iter().map(|(name, _, _)| name.as_str()))
+            .chain(self.order_by.iter().map(|(name, _)| name.as_str()));
+        for name in columns {
+            if !table_columns.iter().any(|column| column == name) {
+                return Err(SAPTestError::QueryFailure {
+                    subject: "Invalid Column".to_string(),
+                    reason: format!("{name:?} is not a column of the {table_name} table."),
+                });
+            }
+        }
+        Ok(table)
     }
 
     /// Generate a `SQL` string from the query.
-    /// * Raises [`SAPTestError::QueryFailure`] when no table is set.
+    /// * Raises [`SAPTestError::QueryFailure`] when:
+    ///     * No table is set.
+    ///     * A column isn't a column of the table. ex. A [`FoodColumn`](crate::db::column::FoodColumn) in a pet query.
     /// ```
-    /// use saptest::{Entity, SAPQuery, FoodName};
+    /// use saptest::{Entity, SAPQuery, FoodName, FoodColumn};
     ///
     /// let stmt = SAPQuery::builder()
     ///     .set_table(Entity::Food)
-    ///     .set_param("name", vec![FoodName::Apple, FoodName::Coconut])
+    ///     .set_param(FoodColumn::Name, vec![FoodName::Apple, FoodName::Coconut])
     ///     .as_sql()
     ///     .unwrap();
     ///
     /// assert_eq!("SELECT * FROM foods WHERE name IN (?, ?)", &stmt)
     /// ```
     pub fn as_sql(&self) -> Result<String, SAPTestError> {
-        let Some(table) = self.table.map(|table| {
-            let mut table_name = table.to_string().to_lowercase();
-            table_name.push('s');
-            table_name
-        }) else {
-            return Err(SAPTestError::QueryFailure {
-                subject: "No Table".to_string(),
-                reason: "Query requires a table.".to_string(),
-            });
-        };
+        let mut table = self.checked_table()?.to_string().to_lowercase();
+        table.push('s');
+
         let mut sql_stmt = format!("SELECT * FROM {}", table);
         // If params.
-        if !self.params.is_empty() {
+        if !self.params.is_empty() || !self.comparisons.is_empty() {
             sql_stmt.push_str(" WHERE ")
         }
 
         // Iterate through params and set up SQL statement.
         // No user param values are inserted.
-        for (i, (param_name, param_value)) in self.params.iter().enumerate() {
+        let mut conditions = vec![];
+        for (param_name, param_value) in self.params.iter() {
             // If param_name starts with '-', use NOT IN to get all other params.
             let mut param_name = &param_name[..];
             let sql_in = if let Some(neg_param_name) = param_name.strip_prefix('-') {
@@ -160,11 +424,43 @@ impl SAPQuery {
             let n_elems = param_value.len();
             let params_string = vec!["?"; n_elems].join(", ");
 
-            // If at end of params, don't include AND.
-            if i + 1 == self.params.len() {
-                let _ = write!(sql_stmt, "{param_name} {sql_in} ({params_string})");
+            conditions.push(format!("{param_name} {sql_in} ({params_string})"));
+        }
+        for (name, comparison, _) in self.comparisons.iter() {
+            let escape = if *comparison == Comparison::Like {
+                " ESCAPE '\\'"
             } else {
-                let _ = write!(sql_stmt, "{param_name} {sql_in} ({params_string}) AND ",);
+                ""
+            };
+            conditions.push(format!("{name} {} ?{escape}", comparison.as_sql()));
+        }
+        sql_stmt.push_str(&conditions.join(" AND "));
+
+        if !self.order_by.is_empty() {
+            let order_by = self
+                .order_by
+                .iter()
+                .map(|(name, order)| match order {
+                    Order::Asc => format!("{name} ASC"),
+                    Order::Desc => format!("{name} DESC"),
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            let _ = write!(sql_stmt, " ORDER BY {order_by}");
+        }
+        if self.sql_paginated() {
+            match (self.limit, self.offset) {
+                (Some(limit), Some(offset)) => {
+                    let _ = write!(sql_stmt, " LIMIT {limit} OFFSET {offset}");
+                }
+                (Some(limit), None) => {
+                    let _ = write!(sql_stmt, " LIMIT {limit}");
+                }
+                // SQLite requires a limit with an offset.
+                (None, Some(offset)) => {
+                    let _ = write!(sql_stmt, " LIMIT -1 OFFSET {offset}");
+                }
+                (None, None) => {}
             }
         }
         Ok(sql_stmt)
@@ -175,25 +471,31 @@ impl SAPQuery {
 mod test {
     use itertools::Itertools;
 
-    use super::SAPQuery;
-    use crate::{db::pack::Pack, Entity};
+    use super::{Order, SAPQuery};
+    use crate::{
+        db::{
+            column::{FoodColumn, PetColumn},
+            pack::Pack,
+        },
+        Entity,
+    };
 
     #[test]
     fn test_build_query_from_iter() {
         let params = [
-            ("name", vec!["Turtle"]),
-            ("pack", vec!["Turtle", "Star"]),
-            ("tier", vec!["1", "2", "3"]),
-            ("lvl", vec!["1"]),
+            (PetColumn::Name, vec!["Turtle"]),
+            (PetColumn::Pack, vec!["Turtle", "Star"]),
+            (PetColumn::Tier, vec!["1", "2", "3"]),
+            (PetColumn::Lvl, vec!["1"]),
         ];
         let query = SAPQuery::from_iter(params.clone());
-        assert!(query.table.is_none());
+        assert_eq!(query.table, Some(Entity::Pet));
         assert_eq!(
             query.params.clone().into_iter().collect_vec(),
             params
                 .iter()
                 .map(|(param, values)| (
-                    String::from(*param),
+                    param.to_string(),
                     values.iter().map(|val| String::from(*val)).collect_vec()
                 ))
                 .collect_vec()
@@ -203,10 +505,10 @@ mod test {
     #[test]
     fn test_query_flat_params() {
         let params = [
-            ("name", vec!["Turtle"]),
-            ("pack", vec!["Turtle", "Star"]),
-            ("tier", vec!["1", "2", "3"]),
-            ("lvl", vec!["1"]),
+            (PetColumn::Name, vec!["Turtle"]),
+            (PetColumn::Pack, vec!["Turtle", "Star"]),
+            (PetColumn::Tier, vec!["1", "2", "3"]),
+            (PetColumn::Lvl, vec!["1"]),
         ];
         let query = SAPQuery::from_iter(params);
         let params = query.flat_params();
@@ -218,7 +520,7 @@ mod test {
     fn test_build_param_query() {
         let stmt = SAPQuery::builder()
             .set_table(Entity::Food)
-            .set_param("name", vec!["apple", "coconut"])
+            .set_param(FoodColumn::Name, vec!["apple", "coconut"])
             .as_sql()
             .unwrap();
 
@@ -229,8 +531,8 @@ mod test {
     fn test_build_neg_param_query() {
         let stmt = SAPQuery::builder()
             .set_table(Entity::Food)
-            .set_param("name", vec!["apple", "coconut"])
-            .set_param("-pack", vec![Pack::Turtle])
+            .set_param(FoodColumn::Name, vec!["apple", "coconut"])
+            .set_not_param(FoodColumn::Pack, vec![Pack::Turtle])
             .as_sql()
             .unwrap();
         assert_eq!(
@@ -238,4 +540,54 @@ mod test {
             &stmt
         )
     }
+
+    #[test]
+    fn test_build_range_like_order_query() {
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_param(PetColumn::Pack, vec![Pack::Turtle])
+            .set_range(PetColumn::Tier, 2..=3)
+            .set_contains(PetColumn::EffectTrigger, "100%_Faint")
+            .set_order_by(PetColumn::Cost, Order::Asc)
+            .set_order_by(PetColumn::Attack, Order::Desc)
+            .set_limit(5);
+        assert_eq!(
+            "SELECT * FROM pets WHERE pack IN (?) AND tier >= ? AND tier <= ? AND effect_trigger LIKE ? ESCAPE '\\' ORDER BY cost ASC, attack DESC LIMIT 5",
+            &query.as_sql().unwrap()
+        );
+        assert_eq!(
+            query.flat_params(),
+            vec!["Turtle", "2", "3", "%100\\%\\_Faint%"]
+        );
+    }
+
+    #[test]
+    fn test_build_offset_query() {
+        let stmt = SAPQuery::builder()
+            .set_table(Entity::Food)
+            .set_range(FoodColumn::Cost, ..3)
+            .set_offset(2)
+            .as_sql()
+            .unwrap();
+        assert_eq!(
+            "SELECT * FROM foods WHERE cost < ? LIMIT -1 OFFSET 2",
+            &stmt
+        );
+    }
+
+    #[test]
+    fn test_build_invalid_column_query() {
+        // Pet column in a food query.
+        let query = SAPQuery::builder()
+            .set_table(Entity::Food)
+            .set_param(PetColumn::Lvl, vec![1]);
+        assert!(query.as_sql().is_err());
+
+        // Deserialized query with an unknown column.
+        let mut query = SAPQuery::builder().set_table(Entity::Food);
+        query
+            .params
+            .insert("cost; DROP TABLE foods".to_string(), vec!["1".to_string()]);
+        assert!(query.as_sql().is_err());
+    }
 }
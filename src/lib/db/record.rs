@@ -1,4 +1,12 @@
-use crate::{db::pack::Pack, error::SAPTestError, toys::names::ToyName, Effect, FoodName, PetName};
+use crate::{
+    db::pack::Pack,
+    effects::effect::{LimitScope, UsesRefresh},
+    error::SAPTestError,
+    pets::role::PetRole,
+    regex_patterns::{RGX_PER_BATTLE, RGX_PER_TURN},
+    toys::names::ToyName,
+    Effect, FoodName, PetName,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone)]
@@ -157,6 +165,41 @@ pub struct PetRecord {
     pub img_url: String,
     /// Is pet a token?
     pub is_token: bool,
+    /// Can pet be summoned by generic summon queries?
+    pub summonable: bool,
+}
+
+impl PetRecord {
+    /// Check if the pet can be summoned by generic summon queries.
+    /// * Unsummonable pets are only summoned if explicitly requested by name, `is_token`, or `summonable`.
+    pub fn is_summonable(&self) -> bool {
+        self.summonable
+    }
+
+    /// Get how the uses of the pet's effect are restored from its description.
+    /// * ex. `Works 2 times per turn.` restores two uses at the start of each turn.
+    /// * `None` if uses aren't restored within a turn or battle.
+    pub fn uses_refresh(&self) -> Option<UsesRefresh> {
+        let effect = self.effect.as_deref()?;
+        let scope = if RGX_PER_TURN.is_match(effect) {
+            LimitScope::Turn
+        } else if RGX_PER_BATTLE.is_match(effect) {
+            LimitScope::Battle
+        } else {
+            return None;
+        };
+        Some(UsesRefresh {
+            scope,
+            uses: self.n_triggers,
+        })
+    }
+
+    /// Get the [`PetRole`]s of the pet at this record's level.
+    /// * See [`PetRole::from_effect`].
+    pub fn roles(&self) -> Result<Vec<PetRole>, SAPTestError> {
+        let effects: Vec<Effect> = self.clone().try_into()?;
+        Ok(PetRole::from_effects(&effects))
+    }
 }
 
 /// A record with information about Toys from Super Auto Pets.
@@ -190,3 +233,30 @@ pub struct ToyRecord {
     /// Is hard mode toy?
     pub hard_mode: bool,
 }
+
+/// A word used to build team names.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TeamNameRecord {
+    /// Category of word. ex. `Prefix` or `Noun`
+    pub category: String,
+    /// Word.
+    pub word: String,
+}
+
+/// Status of the last attempt to scrape a wiki page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScrapeStatusRecord {
+    /// Name of page. ex. `Pets`
+    pub page: String,
+    /// Url of page.
+    pub url: String,
+    /// Reason the page failed to be scraped.
+    pub error: Option<String>,
+}
+
+impl ScrapeStatusRecord {
+    /// Check if the page was scraped successfully.
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
@@ -1,37 +1,37 @@
+#[cfg(feature = "sqlite")]
+use crate::{db::sqlite::SqliteStorage, wiki_scraper::scrape_snapshot, CONFIG};
 use crate::{
-    db::{query::SAPQuery, record::SAPRecord},
-    error::SAPTestError,
-    wiki_scraper::{
-        parse_ailment::parse_ailment_info, parse_food::parse_food_info,
-        parse_hard_mode_toys::parse_hard_mode_toy_info, parse_names::parse_names_info,
-        parse_pet::parse_pet_info, parse_tokens::parse_token_info, parse_toy::parse_toy_info,
+    db::{
+        export::Format,
+        memory::MemoryStorage,
+        pack::Pack,
+        query::SAPQuery,
+        record::{FoodRecord, PetRecord, SAPRecord, ScrapeStatusRecord},
+        snapshot::{DataSnapshot, SNAPSHOT_VERSION},
+        storage::Storage,
     },
-    Entity, CONFIG,
+    error::SAPTestError,
+    Entity, FoodName, PetName,
 };
-use log::info;
-use r2d2_sqlite::SqliteConnectionManager;
+#[cfg(feature = "sqlite")]
+use log::warn;
 use std::path::Path;
 
-const PET_URL: &str = "https://superautopets.wiki.gg/wiki/Pets?action=raw";
-const FOOD_URL: &str = "https://superautopets.wiki.gg/wiki/Food?action=raw";
-const AILMENTS_URL: &str = "https://superautopets.wiki.gg/wiki/Ailments?action=raw";
-const TOKEN_URL: &str = "https://superautopets.wiki.gg/wiki/Tokens?action=raw";
-const TOYS_URL: &str = "https://superautopets.wiki.gg/wiki/Toys?action=raw";
-const TOYS_HARD_MODE_URL: &str = "https://superautopets.wiki.gg/wiki/Hard_Mode_Toys?action=raw";
-const NAMES_URL: &str = "https://superautopets.wiki.gg/wiki/Team_Names?action=raw";
-
 /// A Super Auto Pets database.
+/// * Records are stored in a [`Storage`] backend.
 pub struct SapDB {
-    /// Database file.
-    pub file: String,
-    /// Database pooled connection.
-    pub pool: r2d2::Pool<SqliteConnectionManager>,
+    storage: Box<dyn Storage>,
 }
 
 impl SapDB {
-    /// Initialize database.
+    /// Initialize database in a `sqlite` file.
     /// * Creates a `sqlite` file at the specified `file` path with the `pets` and `foods` tables.
+    ///     * See [`SqliteStorage`]. Requires the `sqlite` feature.
     /// * Updates all tables with the most recent information from the SAP wiki.
+    ///     * Pages that fail to be scraped keep their previous records and are recorded in the `scrape_status` table.
+    ///     * See [`SapDB::scrape_status`].
+    /// * Tables without records are filled from the [`DataSnapshot`] bundled with the crate.
+    ///     * Tables with records are never overwritten by the snapshot.
     /// # Example
     /// ```
     /// use std::path::Path;
@@ -43,360 +43,345 @@ impl SapDB {
     /// assert!(db.is_ok());
     /// assert!(Path::new(db_path).exists());
     /// ```
+    #[cfg(feature = "sqlite")]
     pub fn new<P>(file: P) -> Result<Self, SAPTestError>
     where
         P: AsRef<Path> + Into<String>,
     {
-        let pool = SapDB::create_conn_pool(&file)?;
-        let db = SapDB {
-            file: file.into(),
-            pool,
-        };
-
+        let db = SapDB::with_storage(SqliteStorage::new(file)?);
         // Update on startup if enabled.
         if CONFIG.database.update_on_startup {
-            db.create_tables()?
-                .update_food_info()?
-                .update_pet_info()?
-                .update_toy_info()?
-                .update_name_info()?;
+            db.update_all_info()?;
         }
+        db.load_missing_tables()?;
 
         Ok(db)
     }
 
-    /// Create `rusqlite` connection pool.
-    fn create_conn_pool<P>(file: P) -> Result<r2d2::Pool<SqliteConnectionManager>, SAPTestError>
-    where
-        P: AsRef<Path>,
-    {
-        let manager = SqliteConnectionManager::file(file.as_ref());
-        let pool = r2d2::Pool::new(manager)?;
-        Ok(pool)
+    /// Initialize database in memory with the [`DataSnapshot`] bundled with the crate.
+    /// * Never touches the filesystem or the SAP wiki.
+    ///     * Useful for fast tests and for platforms without `sqlite` like `wasm32`.
+    /// * See [`MemoryStorage`].
+    /// # Example
+    /// ```
+    /// use saptest::{SapDB, SAPQuery, Entity, PetName, PetColumn};
+    ///
+    /// let db = SapDB::in_memory().unwrap();
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_param(PetColumn::Name, vec![PetName::Ant]);
+    ///
+    /// assert!(!db.execute_query(query).unwrap().is_empty());
+    /// ```
+    pub fn in_memory() -> Result<Self, SAPTestError> {
+        let db = SapDB::with_storage(MemoryStorage::default());
+        db.load_snapshot(&DataSnapshot::embedded()?)?;
+        Ok(db)
     }
 
-    /// Create tables if they don't exist.
-    fn create_tables(&self) -> Result<&Self, SAPTestError> {
-        self.pool.get()?.execute_batch(
-            "
-            CREATE TABLE IF NOT EXISTS names (
-                id INTEGER PRIMARY KEY,
-                word_category TEXT NOT NULL,
-                word TEXT NOT NULL,
-                CONSTRAINT unq UNIQUE (word_category, word)
-            );
-            CREATE TABLE IF NOT EXISTS pets (
-                id INTEGER PRIMARY KEY,
-                name TEXT NOT NULL,
-                tier INTEGER NOT NULL,
-                attack INTEGER NOT NULL,
-                health INTEGER NOT NULL,
-                pack TEXT NOT NULL,
-                effect_trigger TEXT NOT NULL,
-                effect TEXT NOT NULL,
-                effect_atk INTEGER NOT NULL,
-                effect_health INTEGER NOT NULL,
-                n_triggers INTEGER NOT NULL,
-                temp_effect BOOLEAN NOT NULL,
-                lvl INTEGER NOT NULL,
-                cost INTEGER NOT NULL,
-                img_url TEXT,
-                is_token BOOLEAN NOT NULL,
-                CONSTRAINT unq UNIQUE (name, pack, lvl)
-            );
-            CREATE TABLE IF NOT EXISTS foods (
-                id INTEGER PRIMARY KEY,
-                name TEXT NOT NULL,
-                tier INTEGER NOT NULL,
-                effect TEXT NOT NULL,
-                pack TEXT NOT NULL,
-                holdable BOOLEAN NOT NULL,
-                single_use BOOLEAN NOT NULL,
-                end_of_battle BOOLEAN NOT NULL,
-                random BOOLEAN NOT NULL,
-                n_targets INTEGER NOT NULL,
-                effect_atk INTEGER NOT NULL,
-                effect_health INTEGER NOT NULL,
-                turn_effect BOOLEAN NOT NULL,
-                cost INTEGER NOT NULL,
-                img_url TEXT,
-                is_ailment BOOLEAN NOT NULL,
-                CONSTRAINT unq UNIQUE (name, pack)
+    /// Initialize database with a [`Storage`] backend.
+    /// * Records in the storage are used as is. Nothing is scraped or loaded.
+    pub fn with_storage(storage: impl Storage + 'static) -> Self {
+        SapDB {
+            storage: Box::new(storage),
+        }
+    }
+
+    /// Initialize the global [`SAPDB`](struct@crate::SAPDB) from the config.
+    /// * Stored in memory if `in_memory` is set or the `sqlite` feature is disabled.
+    pub(crate) fn from_config() -> Result<Self, SAPTestError> {
+        #[cfg(feature = "sqlite")]
+        if !CONFIG.database.in_memory {
+            return SapDB::new(
+                CONFIG
+                    .database
+                    .filename
+                    .as_deref()
+                    .unwrap_or(crate::DB_FNAME),
             );
-            CREATE TABLE IF NOT EXISTS toys (
-                id INTEGER PRIMARY KEY,
-                name TEXT NOT NULL,
-                tier INTEGER NOT NULL,
-                effect_trigger TEXT NOT NULL,
-                effect TEXT NOT NULL,
-                effect_atk INTEGER NOT NULL,
-                effect_health INTEGER NOT NULL,
-                n_triggers INTEGER NOT NULL,
-                temp_effect BOOLEAN NOT NULL,
-                lvl INTEGER NOT NULL,
-                source TEXT NOT NULL,
-                img_url TEXT,
-                hard_mode BOOLEAN NOT NULL,
-                CONSTRAINT unq UNIQUE (name, lvl)
-            );",
-        )?;
+        }
+        SapDB::in_memory()
+    }
 
-        Ok(self)
+    /// Get the [`Storage`] backend of the database.
+    pub fn storage(&self) -> &dyn Storage {
+        self.storage.as_ref()
     }
 
-    /// Update food information in the database.
-    /// * Inserts a new record for each food by `pack`.
-    /// * Changes in any field aside from `name` and `pack` will update an entry.
-    fn update_food_info(&self) -> Result<&Self, SAPTestError> {
-        // Read in insert or replace SQL.
-        let sql_insert_food = "
-            INSERT INTO foods (
-                name, tier, effect, pack,
-                holdable, single_use, end_of_battle,
-                random, n_targets,
-                effect_atk, effect_health,
-                turn_effect, cost, img_url,
-                is_ailment
-            )
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
-            ON CONFLICT(name, pack) DO UPDATE SET
-                tier = ?2,
-                effect = ?3,
-                pack = ?4,
-                holdable = ?5,
-                single_use = ?6,
-                end_of_battle = ?7,
-                random = ?8,
-                n_targets = ?9,
-                effect_atk = ?10,
-                effect_health = ?11,
-                turn_effect = ?12,
-                cost = ?13,
-                img_url = ?14,
-                is_ailment = ?15
-            WHERE
-                tier != ?2 OR
-                effect != ?3
-            ;
-        ";
-        let conn = self.pool.get()?;
-        let mut n_rows_updated: usize = 0;
-
-        let food_url = CONFIG.database.foods_version.map_or_else(
-            || FOOD_URL.to_owned(),
-            |id| format!("{FOOD_URL}&oldid={id}"),
-        );
-        let ailments_url = CONFIG.database.ailments_version.map_or_else(
-            || AILMENTS_URL.to_owned(),
-            |id| format!("{AILMENTS_URL}&oldid={id}"),
-        );
-        let foods = parse_food_info(&food_url)?;
-        let ailments = parse_ailment_info(&ailments_url)?;
-        for food in foods.iter().chain(ailments.iter()) {
-            let n_rows = conn.execute(
-                sql_insert_food,
-                [
-                    &food.name.to_string(),
-                    &food.tier.to_string(),
-                    &food.effect,
-                    &food.pack.to_string(),
-                    &food.holdable.to_string(),
-                    &food.single_use.to_string(),
-                    &food.end_of_battle.to_string(),
-                    &food.random.to_string(),
-                    &food.n_targets.to_string(),
-                    &food.effect_atk.to_string(),
-                    &food.effect_health.to_string(),
-                    &food.turn_effect.to_string(),
-                    &food.cost.to_string(),
-                    &food.img_url.to_string(),
-                    &food.is_ailment.to_string(),
-                ],
-            )?;
-            n_rows_updated += n_rows;
+    /// Load a [`DataSnapshot`] into the database.
+    /// * Existing records are updated with the snapshot's records.
+    /// * All records are inserted in a single transaction.
+    /// # Example
+    /// ```
+    /// use saptest::{SapDB, db::snapshot::DataSnapshot};
+    ///
+    /// let db = SapDB::in_memory().unwrap();
+    /// let snapshot = db.snapshot().unwrap();
+    /// db.load_snapshot(&snapshot).unwrap();
+    /// assert_eq!(db.snapshot().unwrap(), snapshot);
+    /// ```
+    pub fn load_snapshot(&self, snapshot: &DataSnapshot) -> Result<&Self, SAPTestError> {
+        self.load_scraped(snapshot, &[])
+    }
+
+    /// Insert or update a user-defined [`PetRecord`].
+    /// * The pet must have a [`PetName::Custom`] name and is placed in the reserved [`Pack::Custom`].
+    ///     * Insert one record for each level of the pet.
+    /// * Custom records are used like any other record by queries, [`SummonType::QueryPet`](crate::effects::actions::SummonType::QueryPet), and [`Shop`](crate::Shop)s with [`Pack::Custom`].
+    ///     * Pets built from records have no [`Effect`](crate::Effect)s. Use the pet [registry](crate::pets::registry::register_custom_pet) to add them.
+    /// # Example
+    /// ```
+    /// use saptest::{
+    ///     SapDB, SAPQuery, Entity, PetName, PetColumn,
+    ///     db::{pack::Pack, record::PetRecord}
+    /// };
+    ///
+    /// let db = SapDB::in_memory().unwrap();
+    /// let bear = PetRecord {
+    ///     name: PetName::Custom("Bear".to_string()),
+    ///     tier: 1,
+    ///     attack: 3,
+    ///     health: 4,
+    ///     pack: Pack::Custom,
+    ///     effect_trigger: None,
+    ///     effect: None,
+    ///     effect_atk: 0,
+    ///     effect_health: 0,
+    ///     n_triggers: 0,
+    ///     temp_effect: false,
+    ///     lvl: 1,
+    ///     cost: 3,
+    ///     img_url: String::new(),
+    ///     is_token: false,
+    ///     summonable: true,
+    /// };
+    /// db.insert_custom_pet(bear).unwrap();
+    ///
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_param(PetColumn::Pack, vec![Pack::Custom]);
+    /// assert_eq!(db.execute_query(query).unwrap().len(), 1);
+    /// ```
+    pub fn insert_custom_pet(&self, mut record: PetRecord) -> Result<&Self, SAPTestError> {
+        if !matches!(record.name, PetName::Custom(_)) {
+            return Err(SAPTestError::QueryFailure {
+                subject: "Insert Custom Pet".to_string(),
+                reason: format!("{} is not a custom pet.", record.name),
+            });
+        }
+        record.pack = Pack::Custom;
+        self.load_snapshot(&DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            pets: vec![record],
+            ..Default::default()
+        })
+    }
+
+    /// Insert or update a user-defined [`FoodRecord`].
+    /// * The food must have a [`FoodName::Custom`] name and is placed in the reserved [`Pack::Custom`].
+    /// * Custom records are used like any other record by queries and [`Shop`](crate::Shop)s with [`Pack::Custom`].
+    /// # Example
+    /// ```
+    /// use saptest::{SapDB, FoodName, db::record::FoodRecord};
+    ///
+    /// let db = SapDB::in_memory().unwrap();
+    /// let cake = FoodRecord {
+    ///     name: FoodName::Custom("Cake".to_string()),
+    ///     tier: 1,
+    ///     effect: "Give one pet +2 health.".to_string(),
+    ///     effect_health: 2,
+    ///     n_targets: 1,
+    ///     cost: 3,
+    ///     ..Default::default()
+    /// };
+    /// db.insert_custom_food(cake).unwrap();
+    ///
+    /// let apple = FoodRecord {
+    ///     name: FoodName::Apple,
+    ///     ..Default::default()
+    /// };
+    /// assert!(db.insert_custom_food(apple).is_err());
+    /// ```
+    pub fn insert_custom_food(&self, mut record: FoodRecord) -> Result<&Self, SAPTestError> {
+        if !matches!(record.name, FoodName::Custom(_)) {
+            return Err(SAPTestError::QueryFailure {
+                subject: "Insert Custom Food".to_string(),
+                reason: format!("{} is not a custom food.", record.name),
+            });
         }
-        info!(target: "db", "{} rows updated in \"food\" table.", n_rows_updated);
+        record.pack = Pack::Custom;
+        self.load_snapshot(&DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            foods: vec![record],
+            ..Default::default()
+        })
+    }
+
+    /// Load scraped records and the status of each scraped page in a single transaction.
+    fn load_scraped(
+        &self,
+        snapshot: &DataSnapshot,
+        statuses: &[ScrapeStatusRecord],
+    ) -> Result<&Self, SAPTestError> {
+        self.storage.load(snapshot, statuses)?;
         Ok(self)
     }
 
-    /// Update pet information in the database.
-    /// * Scrapes pet and token information from the Fandom wiki.
-    /// * Inserts a new record for each pet by `level` and `pack`.
-    /// * Changes in any field aside from `name`, `pack`, and `level` will update an entry.
-    fn update_pet_info(&self) -> Result<&Self, SAPTestError> {
-        let conn = self.pool.get()?;
-        // Read in insert or replace SQL.
-        let sql_insert_pet = "
-            INSERT INTO pets (
-                name, tier, attack, health, pack,
-                effect_trigger, effect, effect_atk, effect_health, n_triggers, temp_effect,
-                lvl, cost, img_url, is_token
-            )
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
-            ON CONFLICT(name, pack, lvl) DO UPDATE SET
-                tier = ?2,
-                attack = ?3,
-                health = ?4,
-                effect_trigger = ?6,
-                effect = ?7,
-                effect_atk = ?8,
-                effect_health = ?9,
-                n_triggers = ?10,
-                temp_effect = ?11,
-                img_url = ?14,
-                is_token = ?15
-            WHERE
-                tier != ?2 OR
-                attack != ?3 OR
-                health != ?4 OR
-                effect_trigger != ?6 OR
-                effect != ?7
-            ;
-        ";
-        let mut n_rows_updated: usize = 0;
-
-        // Use older version if available.
-        let pet_url = CONFIG
-            .database
-            .pets_version
-            .map_or_else(|| PET_URL.to_owned(), |id| format!("{PET_URL}&oldid={id}"));
-        let token_url = CONFIG.database.tokens_version.map_or_else(
-            || TOKEN_URL.to_owned(),
-            |id| format!("{TOKEN_URL}&oldid={id}"),
-        );
+    /// Get the status of the last attempt to scrape each wiki page.
+    /// * Pages that failed keep the records from their last successful scrape.
+    /// # Example
+    /// ```
+    /// use saptest::SAPDB;
+    ///
+    /// for status in SAPDB.scrape_status().unwrap() {
+    ///     if !status.succeeded() {
+    ///         println!("{} failed: {:?}", status.page, status.error);
+    ///     }
+    /// }
+    /// ```
+    pub fn scrape_status(&self) -> Result<Vec<ScrapeStatusRecord>, SAPTestError> {
+        self.storage.scrape_status()
+    }
 
-        let mut pets = parse_pet_info(&pet_url)?;
-        let tokens = parse_token_info(&token_url)?;
-        pets.extend(tokens);
-
-        // Add each pet.
-        for pet in pets.iter() {
-            // Creating a new row for each pack and level a pet belongs to.
-            // Each pet constrained by name and pack so will replace if already exists.
-            let n_rows = conn.execute(
-                sql_insert_pet,
-                [
-                    &pet.name.to_string(),
-                    &pet.tier.to_string(),
-                    &pet.attack.to_string(),
-                    &pet.health.to_string(),
-                    &pet.pack.to_string(),
-                    &pet.effect_trigger
-                        .clone()
-                        .unwrap_or_else(|| "None".to_string()),
-                    &pet.effect.clone().unwrap_or_else(|| "None".to_string()),
-                    &pet.effect_atk.to_string(),
-                    &pet.effect_health.to_string(),
-                    &pet.n_triggers.to_string(),
-                    &pet.temp_effect.to_string(),
-                    &pet.lvl.to_string(),
-                    &pet.cost.to_string(),
-                    &pet.img_url.to_string(),
-                    &pet.is_token.to_string(),
-                ],
-            )?;
-            n_rows_updated += n_rows;
-        }
-        info!(target: "db", "{} rows updated in \"pet\" table.", n_rows_updated);
+    /// Export all tables in the database to a file or directory in a given [`Format`].
+    /// * See [`DataSnapshot::write`].
+    /// # Example
+    /// ```
+    /// use saptest::{SapDB, db::export::Format};
+    ///
+    /// let db = SapDB::in_memory().unwrap();
+    /// let path = std::env::temp_dir().join("saptest_export.json");
+    /// db.export(&path, Format::Json).unwrap();
+    /// db.import(&path, Format::Json).unwrap();
+    /// ```
+    pub fn export(&self, path: impl AsRef<Path>, format: Format) -> Result<&Self, SAPTestError> {
+        self.snapshot()?.write(path, format)?;
         Ok(self)
     }
 
-    /// Update toy information in the database.
-    /// * Scrapes toy (hard and normal) information from the Fandom wiki.
-    /// * Inserts a new record for each pet by `level`
-    /// * Changes in any field aside from `name` and `level` will update an entry.
-    fn update_toy_info(&self) -> Result<&Self, SAPTestError> {
-        let conn = self.pool.get()?;
-        // Read in insert or replace SQL.
-        let sql_insert_pet = "
-            INSERT INTO toys (
-                name, tier, effect_trigger, effect, effect_atk, effect_health,
-                n_triggers, temp_effect,
-                lvl, source, img_url, hard_mode
-            )
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
-            ON CONFLICT(name, lvl) DO UPDATE SET
-                tier = ?2,
-                effect_trigger = ?3,
-                effect = ?4,
-                effect_atk = ?5,
-                effect_health = ?6,
-                n_triggers = ?7,
-                temp_effect = ?8,
-                source = ?10,
-                img_url = ?11,
-                hard_mode = ?12
-            WHERE
-                tier != ?2 OR
-                effect_atk != ?5 OR
-                effect_health != ?6 OR
-                effect_trigger != ?3 OR
-                effect != ?4
-            ;
-        ";
-        let mut n_rows_updated: usize = 0;
-
-        // Use older version if available.
-        let toys_url = CONFIG.database.toys_version.map_or_else(
-            || TOYS_URL.to_owned(),
-            |id| format!("{TOYS_URL}&oldid={id}"),
-        );
-        let hard_mode_toys_url = CONFIG.database.toys_version.map_or_else(
-            || TOYS_HARD_MODE_URL.to_owned(),
-            |id| format!("{TOYS_HARD_MODE_URL}&oldid={id}"),
-        );
+    /// Import records from a file or directory in a given [`Format`].
+    /// * Existing records are updated with the imported records.
+    /// * See [`DataSnapshot::read`] and [`SapDB::load_snapshot`].
+    pub fn import(&self, path: impl AsRef<Path>, format: Format) -> Result<&Self, SAPTestError> {
+        self.load_snapshot(&DataSnapshot::read(path, format)?)
+    }
 
-        let mut toys = parse_toy_info(&toys_url)?;
-        let hard_mode_toys = parse_hard_mode_toy_info(&hard_mode_toys_url)?;
-        toys.extend(hard_mode_toys);
-
-        // Add each toy.
-        for toy in toys.iter() {
-            // Creating a new row for each pack and level a pet belongs to.
-            // Each pet constrained by name and pack so will replace if already exists.
-            let n_rows = conn.execute(
-                sql_insert_pet,
-                [
-                    &toy.name.to_string(),
-                    &toy.tier.to_string(),
-                    &toy.effect_trigger
-                        .clone()
-                        .unwrap_or_else(|| "None".to_string()),
-                    &toy.effect.clone().unwrap_or_else(|| "None".to_string()),
-                    &toy.effect_atk.to_string(),
-                    &toy.effect_health.to_string(),
-                    &toy.n_triggers.to_string(),
-                    &toy.temp_effect.to_string(),
-                    &toy.lvl.to_string(),
-                    &toy.source.clone().unwrap_or_else(|| "None".to_string()),
-                    &toy.img_url.to_string(),
-                    &toy.hard_mode.to_string(),
-                ],
-            )?;
-            n_rows_updated += n_rows;
+    /// Get a [`DataSnapshot`] of all tables in the database.
+    /// * Write it with [`DataSnapshot::to_json`] to bundle or share the current data.
+    /// # Example
+    /// ```
+    /// use saptest::{SAPDB, db::snapshot::SNAPSHOT_VERSION};
+    ///
+    /// let snapshot = SAPDB.snapshot().unwrap();
+    /// assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+    /// ```
+    pub fn snapshot(&self) -> Result<DataSnapshot, SAPTestError> {
+        let mut snapshot = DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            ..Default::default()
+        };
+        for entity in [Entity::Pet, Entity::Food, Entity::Toy] {
+            for record in self.execute_query(SAPQuery::builder().set_table(entity))? {
+                match record {
+                    SAPRecord::Pet(record) => snapshot.pets.push(record),
+                    SAPRecord::Food(record) => snapshot.foods.push(record),
+                    SAPRecord::Toy(record) => snapshot.toys.push(record),
+                }
+            }
         }
-        info!(target: "db", "{} rows updated in \"pet\" table.", n_rows_updated);
-        Ok(self)
+        snapshot.names = self.storage.names()?;
+        Ok(snapshot)
     }
 
-    fn update_name_info(&self) -> Result<&Self, SAPTestError> {
-        let conn = self.pool.get()?;
-        // Read in insert or replace SQL.
-        let sql_insert_names = "
-            INSERT OR IGNORE INTO names (word_category, word) VALUES (?1, ?2);
-        ";
+    /// Search the effect descriptions and triggers of all pets, foods, and toys.
+    /// * Records are ranked by relevance with the best match first.
+    /// * Records must contain every word in the text.
+    ///     * Words match the start of words in effects. ex. `melon` matches `Melon` and `Melons`.
+    ///     * Matching is case-insensitive.
+    /// # Example
+    /// ```
+    /// use saptest::{SAPDB, db::record::SAPRecord};
+    ///
+    /// for record in SAPDB.search_effects("gain melon").unwrap() {
+    ///     if let SAPRecord::Pet(pet) = record {
+    ///         println!("{} (Level {}): {:?}", pet.name, pet.lvl, pet.effect);
+    ///     }
+    /// }
+    /// ```
+    pub fn search_effects(&self, text: &str) -> Result<Vec<SAPRecord>, SAPTestError> {
+        self.storage.search_effects(text)
+    }
 
-        let names_url = CONFIG.database.names_version.map_or_else(
-            || NAMES_URL.to_owned(),
-            |id| format!("{NAMES_URL}&oldid={id}"),
-        );
-        let words = parse_names_info(&names_url)?;
-        let n_words = words.len();
-        for word in words.into_iter() {
-            conn.execute(sql_insert_names, [word.word_type.to_string(), word.word])?;
+    /// Get the schema version of the database.
+    /// * This is the number of [`migration`](crate::db::migration)s applied.
+    /// * Storage without a versioned schema, like [`MemoryStorage`], has no version.
+    /// # Example
+    /// ```
+    /// use saptest::{SAPDB, db::migration::SCHEMA_VERSION};
+    ///
+    /// assert_eq!(SAPDB.schema_version().unwrap(), Some(SCHEMA_VERSION));
+    /// ```
+    pub fn schema_version(&self) -> Result<Option<usize>, SAPTestError> {
+        self.storage.schema_version()
+    }
+
+    /// Fill tables without records with the records of the [`DataSnapshot`] bundled with the crate.
+    /// * Tables with records are kept as is so partially scraped data isn't overwritten.
+    /// * Pages that failed to be scraped are logged from [`SapDB::scrape_status`].
+    #[cfg(feature = "sqlite")]
+    fn load_missing_tables(&self) -> Result<&Self, SAPTestError> {
+        let current = self.snapshot()?;
+        let embedded = DataSnapshot::embedded()?;
+        let missing = DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            pets: if current.pets.is_empty() {
+                embedded.pets
+            } else {
+                vec![]
+            },
+            foods: if current.foods.is_empty() {
+                embedded.foods
+            } else {
+                vec![]
+            },
+            toys: if current.toys.is_empty() {
+                embedded.toys
+            } else {
+                vec![]
+            },
+            names: if current.names.is_empty() {
+                embedded.names
+            } else {
+                vec![]
+            },
+        };
+        if missing.is_empty() {
+            return Ok(self);
         }
-        info!(target: "db", "{} rows updated in \"names\" table.", n_words);
-        Ok(self)
+
+        let failed_pages = self
+            .scrape_status()?
+            .into_iter()
+            .filter_map(|status| (!status.succeeded()).then_some(status.page))
+            .collect::<Vec<String>>();
+        warn!(
+            target: "db",
+            "Tables without records. Using bundled snapshot for pets: {}, foods: {}, toys: {}, names: {}. Failed pages: {failed_pages:?}",
+            !missing.pets.is_empty(),
+            !missing.foods.is_empty(),
+            !missing.toys.is_empty(),
+            !missing.names.is_empty(),
+        );
+        self.load_snapshot(&missing)
+    }
+
+    /// Update all tables with the most recent information from the SAP wiki.
+    /// * All wiki pages are fetched concurrently and inserted in a single transaction.
+    /// * Only errors on database failures. Pages that fail to be scraped are skipped.
+    #[cfg(feature = "sqlite")]
+    fn update_all_info(&self) -> Result<&Self, SAPTestError> {
+        let (snapshot, statuses) = scrape_snapshot();
+        self.load_scraped(&snapshot, &statuses)
     }
 
     /// Execute `SELECT` query in the Super Auto Pets database with a [`SAPQuery`].
@@ -405,13 +390,13 @@ impl SapDB {
     /// ---
     /// Pet Query
     /// ```
-    /// use saptest::{SAPDB, SAPQuery, Entity, PetName, db::{pack::Pack, record::SAPRecord}};
+    /// use saptest::{SAPDB, SAPQuery, Entity, PetName, db::{pack::Pack, record::SAPRecord}, PetColumn};
     ///
     /// let query = SAPQuery::builder()
     ///     .set_table(Entity::Pet)
-    ///     .set_param("name", vec![PetName::Tiger])
-    ///     .set_param("lvl", vec![2])
-    ///     .set_param("pack", vec![Pack::Turtle]);
+    ///     .set_param(PetColumn::Name, vec![PetName::Tiger])
+    ///     .set_param(PetColumn::Lvl, vec![2])
+    ///     .set_param(PetColumn::Pack, vec![Pack::Turtle]);
     ///
     /// let pets = SAPDB.execute_query(query).unwrap();
     /// let Some(SAPRecord::Pet(record)) = pets.first() else { panic!("No Record found.")};
@@ -420,12 +405,12 @@ impl SapDB {
     /// ---
     /// Food Query
     /// ```
-    /// use saptest::{SAPDB, SAPQuery, Entity, FoodName, db::{pack::Pack, record::SAPRecord}};
+    /// use saptest::{SAPDB, SAPQuery, Entity, FoodName, db::{pack::Pack, record::SAPRecord}, FoodColumn};
     ///
     /// let query = SAPQuery::builder()
     ///     .set_table(Entity::Food)
-    ///     .set_param("name", vec![FoodName::Apple])
-    ///     .set_param("pack", vec![Pack::Turtle]);
+    ///     .set_param(FoodColumn::Name, vec![FoodName::Apple])
+    ///     .set_param(FoodColumn::Pack, vec![Pack::Turtle]);
     ///
     /// let foods = SAPDB.execute_query(query).unwrap();
     ///
@@ -435,11 +420,11 @@ impl SapDB {
     /// ---
     /// Toy Query
     /// ```
-    /// use saptest::{SAPDB, SAPQuery, Entity, ToyName, db::record::SAPRecord};
+    /// use saptest::{SAPDB, SAPQuery, Entity, ToyName, db::record::SAPRecord, ToyColumn};
     /// let mut query = SAPQuery::builder()
     ///     .set_table(Entity::Toy)
-    ///     .set_param("name", vec![ToyName::Balloon])
-    ///     .set_param("lvl", vec![2]);
+    ///     .set_param(ToyColumn::Name, vec![ToyName::Balloon])
+    ///     .set_param(ToyColumn::Lvl, vec![2]);
     ///
     /// let toys = SAPDB.execute_query(query).unwrap();
     ///
@@ -447,34 +432,233 @@ impl SapDB {
     /// assert!(record.name == ToyName::Balloon && record.lvl == 2)
     /// ```
     pub fn execute_query(&self, sap_query: SAPQuery) -> Result<Vec<SAPRecord>, SAPTestError> {
-        let conn = self.pool.get()?;
-        let mut records: Vec<SAPRecord> = vec![];
-
-        let mut stmt = conn.prepare(&sap_query.as_sql()?)?;
-        // ^ Requires a table. Safe to unwrap.
-        let table = sap_query.table.unwrap();
-
-        let mut query = stmt.query(rusqlite::params_from_iter(sap_query.flat_params()))?;
-        while let Some(row) = query.next()? {
-            let record = match table {
-                Entity::Pet => SAPRecord::Pet(row.try_into()?),
-                Entity::Food => SAPRecord::Food(row.try_into()?),
-                Entity::Toy => SAPRecord::Toy(row.try_into()?),
-            };
-            records.push(record);
-        }
-        Ok(records)
+        self.storage.execute_query(&sap_query)
     }
 }
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "sqlite")]
+    use crate::db::{record::ScrapeStatusRecord, sqlite::SqliteStorage};
     use crate::{
-        db::{pack::Pack, query::SAPQuery, record::SAPRecord},
+        db::{
+            memory::MemoryStorage,
+            pack::Pack,
+            query::{Order, SAPQuery},
+            record::{FoodRecord, PetRecord, SAPRecord},
+            setup::SapDB,
+            snapshot::{DataSnapshot, SNAPSHOT_VERSION},
+        },
+        pets::role::PetRole,
         toys::names::ToyName,
-        Entity, FoodName, PetName, SAPDB,
+        Entity, FoodColumn, FoodName, PetColumn, PetName, ToyColumn, SAPDB,
     };
 
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let snapshot = SAPDB.snapshot().unwrap();
+        let json = snapshot.to_json().unwrap();
+        assert_eq!(DataSnapshot::from_json(&json).unwrap(), snapshot);
+
+        // Snapshots from other versions are rejected.
+        let other_version = DataSnapshot {
+            version: SNAPSHOT_VERSION + 1,
+            ..Default::default()
+        };
+        assert!(DataSnapshot::from_json(&other_version.to_json().unwrap()).is_err());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_scrape_status() {
+        let file = std::env::temp_dir().join("saptest_scrape_status.db");
+        let db =
+            SapDB::with_storage(SqliteStorage::new(file.to_string_lossy().to_string()).unwrap());
+        let mut status = ScrapeStatusRecord {
+            page: "Tokens".to_string(),
+            url: "https://superautopets.wiki.gg/wiki/Tokens?action=raw".to_string(),
+            error: Some("Missing column.".to_string()),
+        };
+        db.load_scraped(&DataSnapshot::default(), &[status.clone()])
+            .unwrap();
+        assert_eq!(db.scrape_status().unwrap(), [status.clone()]);
+        assert!(!db.scrape_status().unwrap()[0].succeeded());
+
+        // Later scrapes replace the page's status.
+        status.error = None;
+        db.load_scraped(&DataSnapshot::default(), &[status.clone()])
+            .unwrap();
+        assert_eq!(db.scrape_status().unwrap(), [status]);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_load_missing_tables() {
+        let db = SapDB::with_storage(MemoryStorage::default());
+        let embedded = DataSnapshot::embedded().unwrap();
+        let SAPRecord::Pet(ant) = SAPDB
+            .execute_query(
+                SAPQuery::builder()
+                    .set_table(Entity::Pet)
+                    .set_param(PetColumn::Name, vec![PetName::Ant])
+                    .set_param(PetColumn::Lvl, vec![1])
+                    .set_param(PetColumn::Pack, vec![Pack::Turtle]),
+            )
+            .unwrap()
+            .remove(0)
+        else {
+            panic!("Not a pet record.")
+        };
+        // Only the pets page was scraped.
+        let failed_status = ScrapeStatusRecord {
+            page: "Food".to_string(),
+            url: "https://superautopets.wiki.gg/wiki/Food?action=raw".to_string(),
+            error: Some("Missing column.".to_string()),
+        };
+        db.load_scraped(
+            &DataSnapshot {
+                version: SNAPSHOT_VERSION,
+                pets: vec![ant.clone()],
+                ..Default::default()
+            },
+            &[failed_status],
+        )
+        .unwrap();
+        db.load_missing_tables().unwrap();
+
+        // Scraped pets are kept and empty tables are filled from the snapshot.
+        let snapshot = db.snapshot().unwrap();
+        assert_eq!(snapshot.pets, [ant]);
+        assert_eq!(snapshot.foods.len(), embedded.foods.len());
+        assert_eq!(snapshot.toys.len(), embedded.toys.len());
+        assert_eq!(snapshot.names.len(), embedded.names.len());
+
+        // Nothing is loaded once all tables have records.
+        db.load_missing_tables().unwrap();
+        assert_eq!(db.snapshot().unwrap(), snapshot);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_search_effects() {
+        let file = std::env::temp_dir().join("saptest_search_effects.db");
+        let db =
+            SapDB::with_storage(SqliteStorage::new(file.to_string_lossy().to_string()).unwrap());
+        let pet = PetRecord {
+            name: PetName::Ant,
+            tier: 1,
+            attack: 2,
+            health: 1,
+            pack: Pack::Turtle,
+            effect_trigger: Some("Faint".to_string()),
+            effect: Some("Give one random friend +2 attack and +1 health.".to_string()),
+            effect_atk: 2,
+            effect_health: 1,
+            n_triggers: 1,
+            temp_effect: false,
+            lvl: 1,
+            cost: 3,
+            img_url: String::new(),
+            is_token: false,
+            summonable: true,
+        };
+        let melon_pet = PetRecord {
+            name: PetName::Beetle,
+            effect_trigger: Some("Start of turn".to_string()),
+            effect: Some("Gain Melon.".to_string()),
+            ..pet.clone()
+        };
+        db.load_snapshot(&DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            pets: vec![pet.clone(), melon_pet.clone()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        let SAPRecord::Pet(record) = &db.search_effects("gain melon").unwrap()[0] else {
+            panic!("Not a pet record.")
+        };
+        assert_eq!(record, &melon_pet);
+        assert_eq!(db.search_effects("faint random").unwrap().len(), 1);
+        assert!(db.search_effects("\"").unwrap().is_empty());
+
+        // Updated records are reindexed.
+        db.load_snapshot(&DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            pets: vec![PetRecord {
+                effect: Some("Gain Honey.".to_string()),
+                ..melon_pet
+            }],
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(db.search_effects("melon").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_insert_custom_records() {
+        let db = SapDB::with_storage(MemoryStorage::default());
+        let bear = PetRecord {
+            name: PetName::Custom("Bear".to_string()),
+            tier: 1,
+            attack: 3,
+            health: 4,
+            pack: Pack::Turtle,
+            effect_trigger: None,
+            effect: None,
+            effect_atk: 0,
+            effect_health: 0,
+            n_triggers: 0,
+            temp_effect: false,
+            lvl: 1,
+            cost: 3,
+            img_url: String::new(),
+            is_token: false,
+            summonable: true,
+        };
+        db.insert_custom_pet(bear.clone()).unwrap();
+        db.insert_custom_food(FoodRecord {
+            name: FoodName::Custom("Cake".to_string()),
+            tier: 1,
+            ..Default::default()
+        })
+        .unwrap();
+
+        // Custom records are placed in the custom pack.
+        let query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_param(PetColumn::Pack, vec![Pack::Custom])
+            .set_param(PetColumn::Tier, vec![1]);
+        let Some(SAPRecord::Pet(record)) = db.execute_query(query).unwrap().pop() else {
+            panic!("No custom pet found.")
+        };
+        assert_eq!(
+            record,
+            PetRecord {
+                pack: Pack::Custom,
+                ..bear.clone()
+            }
+        );
+        let query = SAPQuery::builder()
+            .set_table(Entity::Food)
+            .set_param(FoodColumn::Pack, vec![Pack::Custom]);
+        assert_eq!(db.execute_query(query).unwrap().len(), 1);
+
+        // Scraped records can't be overwritten.
+        assert!(db
+            .insert_custom_pet(PetRecord {
+                name: PetName::Ant,
+                ..bear
+            })
+            .is_err());
+        assert!(db
+            .insert_custom_food(FoodRecord {
+                name: FoodName::Apple,
+                ..Default::default()
+            })
+            .is_err());
+    }
+
     #[test]
     fn test_query_no_params() {
         let food_query = SAPQuery::builder().set_table(Entity::Food);
@@ -491,8 +675,8 @@ mod test {
     fn test_query_params_food() {
         let food_query = SAPQuery::builder()
             .set_table(Entity::Food)
-            .set_param("name", vec![FoodName::Apple])
-            .set_param("pack", vec![Pack::Turtle]);
+            .set_param(FoodColumn::Name, vec![FoodName::Apple])
+            .set_param(FoodColumn::Pack, vec![Pack::Turtle]);
 
         let foods = SAPDB.execute_query(food_query).unwrap();
 
@@ -506,9 +690,9 @@ mod test {
     fn test_query_params_pets() {
         let pet_query = SAPQuery::builder()
             .set_table(Entity::Pet)
-            .set_param("name", vec![PetName::Tiger])
-            .set_param("lvl", vec![2])
-            .set_param("pack", vec![Pack::Turtle]);
+            .set_param(PetColumn::Name, vec![PetName::Tiger])
+            .set_param(PetColumn::Lvl, vec![2])
+            .set_param(PetColumn::Pack, vec![Pack::Turtle]);
 
         let pets = SAPDB.execute_query(pet_query).unwrap();
         let SAPRecord::Pet(record) = pets.first().unwrap() else {
@@ -518,36 +702,57 @@ mod test {
     }
 
     #[test]
-    fn test_query_params_toys() {
-        let toy_query = SAPQuery::builder()
-            .set_table(Entity::Toy)
-            .set_param("name", vec![ToyName::Balloon])
-            .set_param("lvl", vec![1]);
+    fn test_query_pet_roles() {
+        let pet_query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_param(PetColumn::Tier, vec![1])
+            .set_param(PetColumn::Lvl, vec![1])
+            .set_roles(vec![PetRole::Summoner]);
 
-        let toys = SAPDB.execute_query(toy_query).unwrap();
-        let SAPRecord::Toy(record) = toys.first().unwrap() else {
-            panic!("No Record found.")
-        };
-        assert!(record.name == ToyName::Balloon && record.lvl == 1)
+        let pets = SAPDB.execute_query(pet_query).unwrap();
+        assert!(!pets.is_empty());
+        for record in pets {
+            let SAPRecord::Pet(record) = record else {
+                panic!("Not a pet record.")
+            };
+            assert!(record.roles().unwrap().contains(&PetRole::Summoner))
+        }
     }
 
     #[test]
-    fn test_update_foods() {
-        assert!(SAPDB.update_pet_info().is_ok())
-    }
+    fn test_query_order_limit() {
+        let pet_query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_range(PetColumn::Tier, ..=3)
+            .set_contains(PetColumn::EffectTrigger, "faint")
+            .set_order_by(PetColumn::Cost, Order::Asc)
+            .set_order_by(PetColumn::Tier, Order::Desc)
+            .set_limit(5);
 
-    #[test]
-    fn test_update_pets() {
-        assert!(SAPDB.update_food_info().is_ok())
+        let pets = SAPDB.execute_query(pet_query).unwrap();
+        assert_eq!(pets.len(), 5);
+        let mut prev_tier = usize::MAX;
+        for record in pets {
+            let SAPRecord::Pet(record) = record else {
+                panic!("Not a pet record.")
+            };
+            assert!(record.tier <= 3 && record.tier <= prev_tier);
+            assert!(record.effect_trigger.unwrap().contains("Faint"));
+            prev_tier = record.tier;
+        }
     }
 
     #[test]
-    fn test_update_names() {
-        assert!(SAPDB.update_name_info().is_ok())
-    }
+    fn test_query_params_toys() {
+        let toy_query = SAPQuery::builder()
+            .set_table(Entity::Toy)
+            .set_param(ToyColumn::Name, vec![ToyName::Balloon])
+            .set_param(ToyColumn::Lvl, vec![1]);
 
-    #[test]
-    fn test_update_toys() {
-        assert!(SAPDB.update_toy_info().is_ok())
+        let toys = SAPDB.execute_query(toy_query).unwrap();
+        let SAPRecord::Toy(record) = toys.first().unwrap() else {
+            panic!("No Record found.")
+        };
+        assert!(record.name == ToyName::Balloon && record.lvl == 1)
     }
 }
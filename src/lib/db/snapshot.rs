@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db::record::{FoodRecord, PetRecord, TeamNameRecord, ToyRecord},
+    error::SAPTestError,
+};
+
+/// Version of the [`DataSnapshot`] format.
+pub const SNAPSHOT_VERSION: usize = 1;
+
+/// Snapshot bundled with the crate.
+/// * Regenerate from an updated database with [`SapDB::snapshot`](crate::SapDB::snapshot).
+const EMBEDDED_SNAPSHOT: &str = include_str!("snapshot.json");
+
+/// Snapshot of all tables in a [`SapDB`](crate::SapDB).
+/// * Allows using the database without scraping the wiki. ex. In CI or air-gapped environments.
+/// * Load with [`SapDB::load_snapshot`](crate::SapDB::load_snapshot).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DataSnapshot {
+    /// Version of the snapshot format.
+    pub version: usize,
+    /// Records of the `pets` table.
+    pub pets: Vec<PetRecord>,
+    /// Records of the `foods` table.
+    pub foods: Vec<FoodRecord>,
+    /// Records of the `toys` table.
+    pub toys: Vec<ToyRecord>,
+    /// Records of the `names` table.
+    pub names: Vec<TeamNameRecord>,
+}
+
+impl DataSnapshot {
+    /// Get the snapshot bundled with the crate.
+    /// # Example
+    /// ```
+    /// use saptest::db::snapshot::{DataSnapshot, SNAPSHOT_VERSION};
+    ///
+    /// let snapshot = DataSnapshot::embedded().unwrap();
+    /// assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+    /// ```
+    pub fn embedded() -> Result<Self, SAPTestError> {
+        DataSnapshot::from_json(EMBEDDED_SNAPSHOT)
+    }
+
+    /// Read a snapshot from JSON.
+    /// * Errors if the snapshot's version doesn't match [`SNAPSHOT_VERSION`].
+    pub fn from_json(json: &str) -> Result<Self, SAPTestError> {
+        let snapshot: DataSnapshot = serde_json::from_str(json)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(SAPTestError::QueryFailure {
+                subject: "Snapshot Version".to_string(),
+                reason: format!(
+                    "Snapshot version {} doesn't match supported version {SNAPSHOT_VERSION}.",
+                    snapshot.version
+                ),
+            });
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot to JSON.
+    /// # Example
+    /// ```
+    /// use saptest::db::snapshot::DataSnapshot;
+    ///
+    /// let snapshot = DataSnapshot::embedded().unwrap();
+    /// let json = snapshot.to_json().unwrap();
+    /// assert_eq!(DataSnapshot::from_json(&json).unwrap(), snapshot);
+    /// ```
+    pub fn to_json(&self) -> Result<String, SAPTestError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Check if the snapshot has no records.
+    pub fn is_empty(&self) -> bool {
+        self.pets.is_empty()
+            && self.foods.is_empty()
+            && self.toys.is_empty()
+            && self.names.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        db::{pack::Pack, snapshot::DataSnapshot},
+        FoodName, PetName, ToyName,
+    };
+
+    #[test]
+    fn test_embedded_snapshot() {
+        let snapshot = DataSnapshot::embedded().unwrap();
+        let ant = snapshot
+            .pets
+            .iter()
+            .find(|pet| pet.name == PetName::Ant && pet.lvl == 1 && pet.pack == Pack::Turtle)
+            .unwrap();
+        assert_eq!((ant.attack, ant.health, ant.tier), (2, 2, 1));
+
+        assert!(snapshot.pets.iter().any(|pet| pet.is_token));
+        // Tokens are never summoned by generic queries.
+        assert!(snapshot
+            .pets
+            .iter()
+            .all(|pet| pet.summonable != pet.is_token));
+        assert!(snapshot
+            .foods
+            .iter()
+            .any(|food| food.name == FoodName::Apple && food.pack == Pack::Turtle));
+        assert!(snapshot
+            .toys
+            .iter()
+            .any(|toy| toy.name == ToyName::Balloon && toy.lvl == 1));
+        assert!(!snapshot.names.is_empty());
+    }
+}
@@ -0,0 +1,605 @@
+use log::{info, warn};
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::Connection;
+use std::{fmt, path::Path, str::FromStr, time::Duration};
+
+use crate::{
+    db::{
+        migration,
+        query::SAPQuery,
+        record::{FoodRecord, PetRecord, SAPRecord, ScrapeStatusRecord, TeamNameRecord, ToyRecord},
+        snapshot::DataSnapshot,
+        storage::{search_words, Storage},
+    },
+    error::SAPTestError,
+    Entity, CONFIG,
+};
+
+/// Default maximum number of pooled connections.
+pub const DEFAULT_POOL_SIZE: u32 = 10;
+/// Default time in milliseconds to wait for a locked database.
+pub const DEFAULT_BUSY_TIMEOUT_MS: u64 = 5000;
+/// Number of prepared statements cached by each connection.
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
+/// Journal mode of a `sqlite` database.
+/// * See <https://www.sqlite.org/pragma.html#pragma_journal_mode>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JournalMode {
+    /// Delete the rollback journal after each transaction. Default of `sqlite`.
+    Delete,
+    /// Truncate the rollback journal after each transaction.
+    Truncate,
+    /// Keep the rollback journal and overwrite its header after each transaction.
+    Persist,
+    /// Keep the rollback journal in memory.
+    Memory,
+    /// Write-ahead log. Readers don't block writers and writers don't block readers.
+    #[default]
+    Wal,
+    /// No rollback journal.
+    Off,
+}
+
+impl FromStr for JournalMode {
+    type Err = SAPTestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "delete" => Ok(JournalMode::Delete),
+            "truncate" => Ok(JournalMode::Truncate),
+            "persist" => Ok(JournalMode::Persist),
+            "memory" => Ok(JournalMode::Memory),
+            "wal" => Ok(JournalMode::Wal),
+            "off" => Ok(JournalMode::Off),
+            _ => Err(SAPTestError::QueryFailure {
+                subject: "Invalid Journal Mode".to_string(),
+                reason: format!("{s:?} is not a sqlite journal mode."),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for JournalMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JournalMode::Delete => write!(f, "DELETE"),
+            JournalMode::Truncate => write!(f, "TRUNCATE"),
+            JournalMode::Persist => write!(f, "PERSIST"),
+            JournalMode::Memory => write!(f, "MEMORY"),
+            JournalMode::Wal => write!(f, "WAL"),
+            JournalMode::Off => write!(f, "OFF"),
+        }
+    }
+}
+
+/// Options of the connection pool of a [`SqliteStorage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteOptions {
+    /// Maximum number of pooled connections. At least one connection is pooled.
+    pub pool_size: u32,
+    /// Time to wait for a locked database before failing.
+    pub busy_timeout: Duration,
+    /// Journal mode of the database.
+    pub journal_mode: JournalMode,
+}
+
+impl Default for SqliteOptions {
+    /// Options set in `.saptest.toml`.
+    /// * `pool_size` defaults to [`DEFAULT_POOL_SIZE`].
+    /// * `busy_timeout_ms` defaults to [`DEFAULT_BUSY_TIMEOUT_MS`].
+    /// * `journal_mode` defaults to [`JournalMode::Wal`].
+    fn default() -> Self {
+        let journal_mode = CONFIG
+            .database
+            .journal_mode
+            .as_deref()
+            .map_or(Ok(JournalMode::default()), JournalMode::from_str)
+            .unwrap_or_else(|err| {
+                warn!(target: "db", "{err}. Using default journal mode.");
+                JournalMode::default()
+            });
+        SqliteOptions {
+            pool_size: CONFIG.database.pool_size.unwrap_or(DEFAULT_POOL_SIZE),
+            busy_timeout: Duration::from_millis(
+                CONFIG
+                    .database
+                    .busy_timeout_ms
+                    .unwrap_or(DEFAULT_BUSY_TIMEOUT_MS),
+            ),
+            journal_mode,
+        }
+    }
+}
+
+/// [`Storage`] of records in a `sqlite` file.
+/// * Tables are created and pending schema [`migration`]s are applied when created.
+/// # Example
+/// ```
+/// use saptest::{SapDB, db::sqlite::SqliteStorage};
+///
+/// let storage = SqliteStorage::new("./sap.db").unwrap();
+/// let db = SapDB::with_storage(storage);
+/// ```
+pub struct SqliteStorage {
+    /// Database file.
+    pub file: String,
+    /// Database pooled connection.
+    pub pool: r2d2::Pool<SqliteConnectionManager>,
+}
+
+impl SqliteStorage {
+    /// Open or create a `sqlite` file at the specified `file` path.
+    /// * The connection pool uses the [`SqliteOptions`] set in `.saptest.toml`.
+    pub fn new<P>(file: P) -> Result<Self, SAPTestError>
+    where
+        P: AsRef<Path> + Into<String>,
+    {
+        SqliteStorage::with_options(file, &SqliteOptions::default())
+    }
+
+    /// Open or create a `sqlite` file at the specified `file` path with given [`SqliteOptions`].
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use saptest::db::sqlite::{JournalMode, SqliteOptions, SqliteStorage};
+    ///
+    /// let options = SqliteOptions {
+    ///     pool_size: 4,
+    ///     busy_timeout: Duration::from_secs(10),
+    ///     journal_mode: JournalMode::Wal,
+    /// };
+    /// let storage = SqliteStorage::with_options("./sap.db", &options).unwrap();
+    /// assert_eq!(storage.pool.max_size(), 4);
+    /// ```
+    pub fn with_options<P>(file: P, options: &SqliteOptions) -> Result<Self, SAPTestError>
+    where
+        P: AsRef<Path> + Into<String>,
+    {
+        let pool = SqliteStorage::create_conn_pool(&file, options)?;
+        let storage = SqliteStorage {
+            file: file.into(),
+            pool,
+        };
+        storage.migrate()?;
+        Ok(storage)
+    }
+
+    /// Create `rusqlite` connection pool.
+    /// * Each connection is configured with the [`SqliteOptions`] when opened.
+    fn create_conn_pool<P>(
+        file: P,
+        options: &SqliteOptions,
+    ) -> Result<r2d2::Pool<SqliteConnectionManager>, SAPTestError>
+    where
+        P: AsRef<Path>,
+    {
+        let (busy_timeout, journal_mode) = (options.busy_timeout, options.journal_mode);
+        let manager = SqliteConnectionManager::file(file.as_ref()).with_init(move |conn| {
+            conn.busy_timeout(busy_timeout)?;
+            conn.pragma_update_and_check(None, "journal_mode", journal_mode.to_string(), |_| {
+                Ok(())
+            })?;
+            conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+            Ok(())
+        });
+        let pool = r2d2::Pool::builder()
+            .max_size(options.pool_size.max(1))
+            .build(manager)?;
+        Ok(pool)
+    }
+
+    /// Create tables and apply any pending schema [`migration`]s.
+    fn migrate(&self) -> Result<&Self, SAPTestError> {
+        let mut conn = self.pool.get()?;
+        let version = migration::migrate(&mut conn)?;
+        info!(target: "db", "Database schema at version {version}.");
+        Ok(self)
+    }
+
+    /// Insert or update food records.
+    fn insert_foods(conn: &Connection, foods: &[FoodRecord]) -> Result<(), SAPTestError> {
+        // Read in insert or replace SQL.
+        let sql_insert_food = "
+            INSERT INTO foods (
+                name, tier, effect, pack,
+                holdable, single_use, end_of_battle,
+                random, n_targets,
+                effect_atk, effect_health,
+                turn_effect, cost, img_url,
+                is_ailment
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            ON CONFLICT(name, pack) DO UPDATE SET
+                tier = ?2,
+                effect = ?3,
+                pack = ?4,
+                holdable = ?5,
+                single_use = ?6,
+                end_of_battle = ?7,
+                random = ?8,
+                n_targets = ?9,
+                effect_atk = ?10,
+                effect_health = ?11,
+                turn_effect = ?12,
+                cost = ?13,
+                img_url = ?14,
+                is_ailment = ?15
+            WHERE
+                tier != ?2 OR
+                effect != ?3
+            ;
+        ";
+        let mut n_rows_updated: usize = 0;
+        for food in foods.iter() {
+            let n_rows = conn.prepare_cached(sql_insert_food)?.execute([
+                &food.name.to_string(),
+                &food.tier.to_string(),
+                &food.effect,
+                &food.pack.to_string(),
+                &food.holdable.to_string(),
+                &food.single_use.to_string(),
+                &food.end_of_battle.to_string(),
+                &food.random.to_string(),
+                &food.n_targets.to_string(),
+                &food.effect_atk.to_string(),
+                &food.effect_health.to_string(),
+                &food.turn_effect.to_string(),
+                &food.cost.to_string(),
+                &food.img_url.to_string(),
+                &food.is_ailment.to_string(),
+            ])?;
+            n_rows_updated += n_rows;
+        }
+        info!(target: "db", "{} rows updated in \"food\" table.", n_rows_updated);
+        Ok(())
+    }
+
+    /// Insert or update pet records.
+    fn insert_pets(conn: &Connection, pets: &[PetRecord]) -> Result<(), SAPTestError> {
+        // Read in insert or replace SQL.
+        let sql_insert_pet = "
+            INSERT INTO pets (
+                name, tier, attack, health, pack,
+                effect_trigger, effect, effect_atk, effect_health, n_triggers, temp_effect,
+                lvl, cost, img_url, is_token, summonable
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+            ON CONFLICT(name, pack, lvl) DO UPDATE SET
+                tier = ?2,
+                attack = ?3,
+                health = ?4,
+                effect_trigger = ?6,
+                effect = ?7,
+                effect_atk = ?8,
+                effect_health = ?9,
+                n_triggers = ?10,
+                temp_effect = ?11,
+                img_url = ?14,
+                is_token = ?15,
+                summonable = ?16
+            WHERE
+                tier != ?2 OR
+                attack != ?3 OR
+                health != ?4 OR
+                effect_trigger != ?6 OR
+                effect != ?7
+            ;
+        ";
+        let mut n_rows_updated: usize = 0;
+
+        // Add each pet.
+        for pet in pets.iter() {
+            // Creating a new row for each pack and level a pet belongs to.
+            // Each pet constrained by name and pack so will replace if already exists.
+            let n_rows = conn.prepare_cached(sql_insert_pet)?.execute([
+                &pet.name.to_string(),
+                &pet.tier.to_string(),
+                &pet.attack.to_string(),
+                &pet.health.to_string(),
+                &pet.pack.to_string(),
+                &pet.effect_trigger
+                    .clone()
+                    .unwrap_or_else(|| "None".to_string()),
+                &pet.effect.clone().unwrap_or_else(|| "None".to_string()),
+                &pet.effect_atk.to_string(),
+                &pet.effect_health.to_string(),
+                &pet.n_triggers.to_string(),
+                &pet.temp_effect.to_string(),
+                &pet.lvl.to_string(),
+                &pet.cost.to_string(),
+                &pet.img_url.to_string(),
+                &pet.is_token.to_string(),
+                &pet.summonable.to_string(),
+            ])?;
+            n_rows_updated += n_rows;
+        }
+        info!(target: "db", "{} rows updated in \"pet\" table.", n_rows_updated);
+        Ok(())
+    }
+
+    /// Insert or update toy records.
+    fn insert_toys(conn: &Connection, toys: &[ToyRecord]) -> Result<(), SAPTestError> {
+        // Read in insert or replace SQL.
+        let sql_insert_pet = "
+            INSERT INTO toys (
+                name, tier, effect_trigger, effect, effect_atk, effect_health,
+                n_triggers, temp_effect,
+                lvl, source, img_url, hard_mode
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+            ON CONFLICT(name, lvl) DO UPDATE SET
+                tier = ?2,
+                effect_trigger = ?3,
+                effect = ?4,
+                effect_atk = ?5,
+                effect_health = ?6,
+                n_triggers = ?7,
+                temp_effect = ?8,
+                source = ?10,
+                img_url = ?11,
+                hard_mode = ?12
+            WHERE
+                tier != ?2 OR
+                effect_atk != ?5 OR
+                effect_health != ?6 OR
+                effect_trigger != ?3 OR
+                effect != ?4
+            ;
+        ";
+        let mut n_rows_updated: usize = 0;
+
+        // Add each toy.
+        for toy in toys.iter() {
+            // Creating a new row for each pack and level a pet belongs to.
+            // Each pet constrained by name and pack so will replace if already exists.
+            let n_rows = conn.prepare_cached(sql_insert_pet)?.execute([
+                &toy.name.to_string(),
+                &toy.tier.to_string(),
+                &toy.effect_trigger
+                    .clone()
+                    .unwrap_or_else(|| "None".to_string()),
+                &toy.effect.clone().unwrap_or_else(|| "None".to_string()),
+                &toy.effect_atk.to_string(),
+                &toy.effect_health.to_string(),
+                &toy.n_triggers.to_string(),
+                &toy.temp_effect.to_string(),
+                &toy.lvl.to_string(),
+                &toy.source.clone().unwrap_or_else(|| "None".to_string()),
+                &toy.img_url.to_string(),
+                &toy.hard_mode.to_string(),
+            ])?;
+            n_rows_updated += n_rows;
+        }
+        info!(target: "db", "{} rows updated in \"toy\" table.", n_rows_updated);
+        Ok(())
+    }
+
+    /// Insert or update the status of scraped wiki pages.
+    fn insert_scrape_status(
+        conn: &Connection,
+        statuses: &[ScrapeStatusRecord],
+    ) -> Result<(), SAPTestError> {
+        let sql_insert_status = "
+            INSERT INTO scrape_status (page, url, succeeded, error)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(page) DO UPDATE SET
+                url = ?2,
+                succeeded = ?3,
+                error = ?4,
+                updated_at = CURRENT_TIMESTAMP
+            ;
+        ";
+        for status in statuses.iter() {
+            conn.prepare_cached(sql_insert_status)?
+                .execute(rusqlite::params![
+                    status.page,
+                    status.url,
+                    status.succeeded(),
+                    status.error
+                ])?;
+        }
+        Ok(())
+    }
+
+    /// Insert team name words.
+    fn insert_names(conn: &Connection, names: &[TeamNameRecord]) -> Result<(), SAPTestError> {
+        // Read in insert or replace SQL.
+        let sql_insert_names = "
+            INSERT OR IGNORE INTO names (word_category, word) VALUES (?1, ?2);
+        ";
+        let mut n_words: usize = 0;
+        for name in names.iter() {
+            n_words += conn
+                .prepare_cached(sql_insert_names)?
+                .execute([&name.category, &name.word])?;
+        }
+        info!(target: "db", "{} rows updated in \"names\" table.", n_words);
+        Ok(())
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn load(
+        &self,
+        snapshot: &DataSnapshot,
+        statuses: &[ScrapeStatusRecord],
+    ) -> Result<(), SAPTestError> {
+        let mut conn = self.pool.get()?;
+        let tx = conn.transaction()?;
+        SqliteStorage::insert_foods(&tx, &snapshot.foods)?;
+        SqliteStorage::insert_pets(&tx, &snapshot.pets)?;
+        SqliteStorage::insert_toys(&tx, &snapshot.toys)?;
+        SqliteStorage::insert_names(&tx, &snapshot.names)?;
+        SqliteStorage::insert_scrape_status(&tx, statuses)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn is_empty(&self) -> Result<bool, SAPTestError> {
+        let conn = self.pool.get()?;
+        let n_records: usize = conn.query_row(
+            "SELECT (SELECT COUNT(*) FROM pets) + (SELECT COUNT(*) FROM foods) + (SELECT COUNT(*) FROM toys)",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(n_records == 0)
+    }
+
+    fn execute_query(&self, sap_query: &SAPQuery) -> Result<Vec<SAPRecord>, SAPTestError> {
+        let conn = self.pool.get()?;
+        let mut records: Vec<SAPRecord> = vec![];
+
+        let mut stmt = conn.prepare_cached(&sap_query.as_sql()?)?;
+        // ^ Requires a table. Safe to unwrap.
+        let table = sap_query.table.unwrap();
+
+        let mut query = stmt.query(rusqlite::params_from_iter(sap_query.flat_params()))?;
+        while let Some(row) = query.next()? {
+            let record = match table {
+                Entity::Pet => {
+                    let record: PetRecord = row.try_into()?;
+                    // Filter by roles if any.
+                    if !sap_query.roles.is_empty()
+                        && !record
+                            .roles()?
+                            .iter()
+                            .any(|role| sap_query.roles.contains(role))
+                    {
+                        continue;
+                    }
+                    SAPRecord::Pet(record)
+                }
+                Entity::Food => SAPRecord::Food(row.try_into()?),
+                Entity::Toy => SAPRecord::Toy(row.try_into()?),
+            };
+            records.push(record);
+        }
+        // Paginate after filtering by roles.
+        if !sap_query.sql_paginated() {
+            records = records
+                .into_iter()
+                .skip(sap_query.offset.unwrap_or(0))
+                .take(sap_query.limit.unwrap_or(usize::MAX))
+                .collect();
+        }
+        Ok(records)
+    }
+
+    fn names(&self) -> Result<Vec<TeamNameRecord>, SAPTestError> {
+        let conn = self.pool.get()?;
+        let mut stmt = conn
+            .prepare_cached("SELECT word_category, word FROM names ORDER BY word_category, word")?;
+        let names = stmt
+            .query_map([], |row| {
+                Ok(TeamNameRecord {
+                    category: row.get(0)?,
+                    word: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<TeamNameRecord>, _>>()?;
+        Ok(names)
+    }
+
+    fn scrape_status(&self) -> Result<Vec<ScrapeStatusRecord>, SAPTestError> {
+        let conn = self.pool.get()?;
+        let mut stmt =
+            conn.prepare_cached("SELECT page, url, error FROM scrape_status ORDER BY page")?;
+        let statuses = stmt
+            .query_map([], |row| {
+                Ok(ScrapeStatusRecord {
+                    page: row.get(0)?,
+                    url: row.get(1)?,
+                    error: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<ScrapeStatusRecord>, _>>()?;
+        Ok(statuses)
+    }
+
+    fn search_effects(&self, text: &str) -> Result<Vec<SAPRecord>, SAPTestError> {
+        // Match each word as a prefix. Quoting words escapes FTS5 syntax.
+        let fts_query = search_words(text)
+            .into_iter()
+            .map(|word| format!("\"{word}\"*"))
+            .collect::<Vec<String>>()
+            .join(" ");
+        if fts_query.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let conn = self.pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT entity, record_id FROM effects_fts WHERE effects_fts MATCH ?1 ORDER BY rank",
+        )?;
+        let matches = stmt
+            .query_map([fts_query], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<Result<Vec<(String, i64)>, _>>()?;
+
+        let mut records = vec![];
+        for (entity, id) in matches {
+            let (table, entity) = match entity.as_str() {
+                "Pet" => ("pets", Entity::Pet),
+                "Food" => ("foods", Entity::Food),
+                _ => ("toys", Entity::Toy),
+            };
+            let mut stmt = conn.prepare_cached(&format!("SELECT * FROM {table} WHERE id = ?1"))?;
+            let mut rows = stmt.query([id])?;
+            if let Some(row) = rows.next()? {
+                records.push(match entity {
+                    Entity::Pet => SAPRecord::Pet(row.try_into()?),
+                    Entity::Food => SAPRecord::Food(row.try_into()?),
+                    Entity::Toy => SAPRecord::Toy(row.try_into()?),
+                })
+            }
+        }
+        Ok(records)
+    }
+
+    fn schema_version(&self) -> Result<Option<usize>, SAPTestError> {
+        let conn = self.pool.get()?;
+        Ok(Some(migration::schema_version(&conn)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{str::FromStr, time::Duration};
+
+    use super::{JournalMode, SqliteOptions, SqliteStorage};
+
+    #[test]
+    fn test_sqlite_options() {
+        let file = std::env::temp_dir().join("saptest_sqlite_options.db");
+        let options = SqliteOptions {
+            pool_size: 2,
+            busy_timeout: Duration::from_millis(250),
+            journal_mode: JournalMode::Wal,
+        };
+        let storage =
+            SqliteStorage::with_options(file.to_string_lossy().to_string(), &options).unwrap();
+        assert_eq!(storage.pool.max_size(), 2);
+
+        let conn = storage.pool.get().unwrap();
+        let journal_mode: String = conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(journal_mode, "wal");
+        let busy_timeout: u64 = conn
+            .query_row("PRAGMA busy_timeout", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(busy_timeout, 250);
+    }
+
+    #[test]
+    fn test_str_to_journal_mode() {
+        assert_eq!(JournalMode::from_str("WAL").unwrap(), JournalMode::Wal);
+        assert_eq!(
+            JournalMode::from_str("delete").unwrap(),
+            JournalMode::Delete
+        );
+        assert!(JournalMode::from_str("journal").is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    db::{
+        query::SAPQuery,
+        record::{SAPRecord, ScrapeStatusRecord, TeamNameRecord},
+        snapshot::DataSnapshot,
+    },
+    error::SAPTestError,
+};
+
+/// Backend storing the records of a [`SapDB`](crate::SapDB).
+/// * [`SqliteStorage`](crate::db::sqlite::SqliteStorage) stores records in a `sqlite` file.
+///     * Requires the `sqlite` feature, enabled by default.
+/// * [`MemoryStorage`](crate::db::memory::MemoryStorage) stores records in memory.
+///     * Doesn't touch the filesystem so works without `rusqlite` or on `wasm32`.
+pub trait Storage: Send + Sync {
+    /// Insert or update records and the status of each scraped wiki page.
+    /// * Records are unique by:
+    ///     * Pets: `name`, `pack`, and `lvl`
+    ///     * Foods: `name` and `pack`
+    ///     * Toys: `name` and `lvl`
+    ///     * Names: `category` and `word`
+    /// * Either all records are inserted or none are.
+    fn load(
+        &self,
+        snapshot: &DataSnapshot,
+        statuses: &[ScrapeStatusRecord],
+    ) -> Result<(), SAPTestError>;
+
+    /// Check if there are no pets, foods, or toys.
+    fn is_empty(&self) -> Result<bool, SAPTestError>;
+
+    /// Get records matching a [`SAPQuery`].
+    /// * See [`SapDB::execute_query`](crate::SapDB::execute_query).
+    fn execute_query(&self, query: &SAPQuery) -> Result<Vec<SAPRecord>, SAPTestError>;
+
+    /// Get all team name words sorted by category and then word.
+    fn names(&self) -> Result<Vec<TeamNameRecord>, SAPTestError>;
+
+    /// Get the status of the last attempt to scrape each wiki page sorted by page.
+    fn scrape_status(&self) -> Result<Vec<ScrapeStatusRecord>, SAPTestError>;
+
+    /// Search the effect descriptions and triggers of all pets, foods, and toys.
+    /// * See [`SapDB::search_effects`](crate::SapDB::search_effects).
+    fn search_effects(&self, text: &str) -> Result<Vec<SAPRecord>, SAPTestError>;
+
+    /// Get the schema version of the storage.
+    /// * Storage without a versioned schema has no version.
+    fn schema_version(&self) -> Result<Option<usize>, SAPTestError> {
+        Ok(None)
+    }
+}
+
+/// Split search text into words.
+/// * Words are separated by any character that isn't alphanumeric.
+pub(crate) fn search_words(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
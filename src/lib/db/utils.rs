@@ -20,6 +20,7 @@ impl TryFrom<&Row<'_>> for PetRecord {
         let pack: String = pet_row.get(5)?;
         let is_temp_effect_str: String = pet_row.get(11)?;
         let is_token: String = pet_row.get(15)?;
+        let summonable: String = pet_row.get(16)?;
         Ok(PetRecord {
             name: PetName::from_str(&pet_name)?,
             tier: pet_row.get(2)?,
@@ -36,6 +37,7 @@ impl TryFrom<&Row<'_>> for PetRecord {
             cost: pet_row.get(13)?,
             img_url: pet_row.get(14)?,
             is_token: is_token == *"true",
+            summonable: summonable == *"true",
         })
     }
 }
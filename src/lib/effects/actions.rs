@@ -9,12 +9,19 @@ use crate::{
         stats::Statistics,
     },
     error::SAPTestError,
-    foods::{food::Food, names::FoodName},
-    pets::pet::{Pet, MAX_PET_STATS},
-    shop::store::{MAX_SHOP_TIER, MIN_SHOP_TIER},
-    teams::effect_helpers::EffectApplyHelpers,
-    Entity, PetName, SAPQuery, Team, Toy, ToyName, SAPDB,
+    foods::{
+        food::{Attachments, Food},
+        names::FoodName,
+    },
+    pets::pet::Pet,
+    shop::{
+        store::{MAX_SHOP_TIER, MIN_SHOP_TIER},
+        viewer::ShopItemViewer,
+    },
+    teams::{counters::CounterKey, effect_helpers::EffectApplyHelpers},
+    Entity, PetColumn, PetName, SAPQuery, Team, Toy, ToyColumn, ToyName, SAPDB,
 };
+use itertools::Itertools;
 use rand::{
     random,
     seq::{IteratorRandom, SliceRandom},
@@ -22,10 +29,11 @@ use rand::{
 };
 use rand_chacha::ChaCha12Rng;
 use serde::{Deserialize, Serialize};
-use std::{
-    collections::HashMap,
-    sync::{Arc, RwLock},
-};
+use std::sync::{Arc, RwLock};
+
+/// [`SAPQuery`] columns that explicitly allow summoning tokens.
+const TOKEN_QUERY_COLUMNS: [PetColumn; 3] =
+    [PetColumn::Name, PetColumn::IsToken, PetColumn::Summonable];
 
 /// [`Pet`] attribute used for [`Action::Copy`].
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -58,18 +66,94 @@ pub enum StatChangeType {
     /// Change attack to current health.
     CurrentHealth,
     /// Set statistics based on a given team counter.
-    TeamCounter(String),
+    TeamCounter(CounterKey),
+    /// Change by [`Statistics`] multiplied by the team's [`Shop`](crate::Shop) tier.
+    ShopTierMultiplier(Statistics),
+    /// Change by [`Statistics`] multiplied by the team's current turn.
+    TurnMultiplier(Statistics),
+    /// Change by a percentage of the [`Statistics`] of the trigger's affected pet at the time of the trigger.
+    /// * See [`Outcome::affected_snapshot`].
+    TriggerAffectedStats(Statistics),
+    /// Change by a percentage of the [`Statistics`] of the trigger's afflicting pet at the time of the trigger.
+    /// * See [`Outcome::afflicting_snapshot`].
+    TriggerAfflictingStats(Statistics),
+    /// Change by [`Statistics`] multiplied by the number of [`Shop`](crate::Shop) pets of a given tier.
+    /// 1. [`Statistics`] per pet.
+    /// 2. Shop pet tier.
+    ShopTierPetsMultiplier(Statistics, usize),
+    /// Change by [`Statistics`] multiplied by the number of [`Shop`](crate::Shop) foods.
+    ShopFoodsMultiplier(Statistics),
+}
+
+/// Types of [`Shop`](crate::Shop) prices for [`Action::Markup`].
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub enum PriceType {
+    /// Gold needed to roll the [`Shop`](crate::Shop).
+    Roll,
+    /// Gold needed to buy a [`ShopItem`](crate::ShopItem).
+    Item,
+}
+
+/// Types of gold changes for [`Action::AlterGold`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub enum GoldChangeType {
+    /// Change gold by a static amount.
+    Static(isize),
+    /// Change gold by a percentage of current gold.
+    /// * Rounded towards zero.
+    Percent(isize),
+    /// Change gold without going below a minimum.
+    /// * Gold already below the minimum is never reduced further.
+    /// 1. Gold change.
+    /// 2. Minimum gold.
+    Clamped(Box<GoldChangeType>, usize),
+}
+
+impl GoldChangeType {
+    /// Get the gold left after applying this change to some amount of gold.
+    /// * Gold never goes below zero.
+    /// ```
+    /// use saptest::effects::actions::GoldChangeType;
+    ///
+    /// assert_eq!(GoldChangeType::Static(-3).apply(10), 7);
+    /// assert_eq!(GoldChangeType::Static(-3).apply(2), 0);
+    /// assert_eq!(GoldChangeType::Percent(-50).apply(5), 3);
+    /// assert_eq!(GoldChangeType::Percent(100).apply(5), 10);
+    ///
+    /// let clamped_loss = GoldChangeType::Clamped(Box::new(GoldChangeType::Percent(-100)), 2);
+    /// assert_eq!(clamped_loss.apply(10), 2);
+    /// assert_eq!(clamped_loss.apply(1), 1);
+    /// ```
+    pub fn apply(&self, coins: usize) -> usize {
+        match self {
+            GoldChangeType::Static(change) => coins.saturating_add_signed(*change),
+            GoldChangeType::Percent(percent) => {
+                let change = (coins as isize).saturating_mul(*percent) / 100;
+                coins.saturating_add_signed(change)
+            }
+            GoldChangeType::Clamped(change, min_coins) => {
+                let new_coins = change.apply(coins);
+                if new_coins < coins {
+                    new_coins.max(coins.min(*min_coins))
+                } else {
+                    new_coins
+                }
+            }
+        }
+    }
 }
 
 impl StatChangeType {
     /// Convert [`StatChangeType`] into [`Statistics`].
     /// * `pet_stats`: Pet [`Statistics`].
-    /// * `team_counter`: `Team.counters` reference.
+    /// * `team`: [`Team`] providing counters, [`Shop`](crate::Shop) tier, and turn.
+    /// * `trigger`: [`Outcome`] providing trigger pet snapshots.
     /// * `is_set_action`: If used with [`Action::Set`], keeps original stats.
     pub(crate) fn to_stats(
         &self,
         pet_stats: Option<Statistics>,
-        team_counters: Option<&HashMap<String, usize>>,
+        team: Option<&Team>,
+        trigger: Option<&Outcome>,
         is_set_action: bool,
     ) -> Result<Statistics, SAPTestError> {
         Ok(match self {
@@ -117,21 +201,72 @@ impl StatChangeType {
                     reason: "Needs pet stats current health.".to_owned(),
                 })?,
             StatChangeType::TeamCounter(counter_key) => {
-                let counter_value = team_counters
-                    .and_then(|counters| counters.get(counter_key))
-                    .ok_or(SAPTestError::InvalidTeamAction {
+                let counter_value = team.and_then(|team| team.counters.get(counter_key)).ok_or(
+                    SAPTestError::InvalidTeamAction {
                         subject: "Invalid Stat Change".to_owned(),
                         reason: format!("No such counter key: {counter_key}"),
-                    })?;
+                    },
+                )?;
 
-                let counter_value = TryInto::<isize>::try_into(*counter_value)?;
+                let counter_value = TryInto::<isize>::try_into(counter_value)?;
                 Statistics {
                     attack: counter_value,
                     health: counter_value,
                 }
             }
+            StatChangeType::ShopTierMultiplier(stats) => {
+                let tier = StatChangeType::team_value(team, "shop tier", |team| team.shop.tier())?;
+                *stats * Statistics::new(tier, tier)?
+            }
+            StatChangeType::TurnMultiplier(stats) => {
+                let turn = StatChangeType::team_value(team, "turn", |team| team.history.curr_turn)?;
+                *stats * Statistics::new(turn, turn)?
+            }
+            StatChangeType::TriggerAffectedStats(multiplier)
+            | StatChangeType::TriggerAfflictingStats(multiplier) => {
+                let snapshot = trigger.and_then(|trigger| {
+                    if let StatChangeType::TriggerAffectedStats(_) = self {
+                        trigger.affected_snapshot()
+                    } else {
+                        trigger.afflicting_snapshot()
+                    }
+                });
+                snapshot
+                    .map(|snapshot| snapshot.stats.mult_perc(multiplier))
+                    .ok_or(SAPTestError::InvalidTeamAction {
+                        subject: "No Trigger Pet Stats".to_owned(),
+                        reason: format!("{self:?} needs a trigger pet snapshot."),
+                    })?
+            }
+            StatChangeType::ShopTierPetsMultiplier(stats, tier) => {
+                let num_tier = StatChangeType::team_value(team, "shop pets", |team| {
+                    team.shop
+                        .pets
+                        .iter()
+                        .filter(|pet| pet.tier() == *tier)
+                        .count()
+                })?;
+                *stats * Statistics::new(num_tier, num_tier)?
+            }
+            StatChangeType::ShopFoodsMultiplier(stats) => {
+                let num_foods =
+                    StatChangeType::team_value(team, "shop foods", |team| team.shop.foods.len())?;
+                *stats * Statistics::new(num_foods, num_foods)?
+            }
         })
     }
+
+    fn team_value(
+        team: Option<&Team>,
+        value: &str,
+        get_value: impl Fn(&Team) -> usize,
+    ) -> Result<usize, SAPTestError> {
+        team.map(get_value)
+            .ok_or_else(|| SAPTestError::InvalidTeamAction {
+                subject: "No Team".to_owned(),
+                reason: format!("Stat change needs team {value}."),
+            })
+    }
 }
 
 /// Types of summons for [`Action::Summon`].
@@ -141,14 +276,16 @@ pub enum SummonType {
     /// 1. [`SAPQuery`] that queries a [`Pet`].
     /// 3. [`Statistics`] if provided.
     ///
+    /// Unsummonable pets are excluded unless the query sets a `name`, `is_token`, or `summonable` param.
+    ///
     /// # Example
     /// ```rust no_run
-    /// use saptest::{effects::actions::SummonType, SAPQuery, PetName, Entity};
+    /// use saptest::{effects::actions::SummonType, SAPQuery, PetName, Entity, PetColumn};
     /// // Summon a dog at default stats.
     /// let summon_type = SummonType::QueryPet(
     ///     SAPQuery::builder()
     ///         .set_table(Entity::Pet)
-    ///         .set_param("name", vec![PetName::Dog]),
+    ///         .set_param(PetColumn::Name, vec![PetName::Dog]),
     ///     None
     /// );
     /// ```
@@ -188,6 +325,11 @@ pub enum SummonType {
         /// * `-1` indicates the previous shop tier of the current team.
         tier_diff: Option<isize>,
     },
+    /// Summon the most recently fainted [`Pet`] on the same [`Team`]. Used for [`Pteranodon`](crate::PetName::Pteranodon).
+    /// * The owner of the [`Effect`] is never summoned.
+    /// 1. Pet [`Statistics`]. Defaults to the fainted [`Pet`]'s base stats if omitted.
+    /// 2. Pet `level`.
+    FaintedPet(Option<Statistics>, usize),
 }
 
 impl SummonType {
@@ -198,10 +340,15 @@ impl SummonType {
     ) -> Result<Pet, SAPTestError> {
         let mut new_pet = match self {
             SummonType::QueryPet(query, stats) => {
+                // Exclude tokens unless explicitly requested.
+                let tokens_requested = TOKEN_QUERY_COLUMNS
+                    .iter()
+                    .any(|column| query.has_param(*column));
                 let pet_records: Vec<PetRecord> = SAPDB
                     .execute_query(query.clone())?
                     .into_iter()
                     .filter_map(|record| record.try_into().ok())
+                    .filter(|record: &PetRecord| tokens_requested || record.is_summonable())
                     .collect();
                 let mut rng = ChaCha12Rng::seed_from_u64(
                     target_pet.read().unwrap().seed.unwrap_or_else(random),
@@ -228,22 +375,21 @@ impl SummonType {
             SummonType::CustomPet(name, stat_types, lvl) => {
                 let mut stats = stat_types.to_stats(
                     Some(target_pet.read().unwrap().stats),
-                    Some(&team.counters),
+                    Some(team),
+                    None,
                     false,
                 )?;
-                Pet::new(
-                    name.clone(),
-                    Some(stats.clamp(1, MAX_PET_STATS).to_owned()),
-                    *lvl,
-                )?
+                let mut pet = Pet::new(name.clone(), None, *lvl)?;
+                pet.stats = *stats.clamp(1, team.battle_rules.stat_cap.max());
+                pet
             }
             SummonType::SelfPet(new_stats, new_level, keep_item) => {
                 // Current pet. Remove item
                 let mut pet = target_pet.read().unwrap().clone();
-                pet.item = if *keep_item {
-                    target_pet.read().unwrap().item.clone()
+                pet.items = if *keep_item {
+                    target_pet.read().unwrap().items.clone()
                 } else {
-                    None
+                    Attachments::default()
                 };
                 pet.stats = new_stats
                     .map_or_else(|| target_pet.read().unwrap().stats, |set_stats| set_stats);
@@ -256,8 +402,8 @@ impl SummonType {
                 let summon_query_type = SummonType::QueryPet(
                     SAPQuery::builder()
                         .set_table(Entity::Pet)
-                        .set_param("tier", vec![target_pet.read().unwrap().tier])
-                        .set_param("lvl", vec![level.unwrap_or(1)]),
+                        .set_param(PetColumn::Tier, vec![target_pet.read().unwrap().tier])
+                        .set_param(PetColumn::Lvl, vec![level.unwrap_or(1)]),
                     *stats,
                 );
                 summon_query_type.to_pet(team, target_pet)?
@@ -294,18 +440,33 @@ impl SummonType {
                     SAPQuery::builder()
                         .set_table(Entity::Pet)
                         .set_param(
-                            "tier",
+                            PetColumn::Tier,
                             vec![
                                 // Restrict to min and max shop tier.
                                 calculated_tier
                                     .clamp(MIN_SHOP_TIER as isize, MAX_SHOP_TIER as isize),
                             ],
                         )
-                        .set_param("lvl", vec![lvl.unwrap_or(1)]),
+                        .set_param(PetColumn::Lvl, vec![lvl.unwrap_or(1)]),
                     *stats,
                 );
                 summon_query_type.to_pet(team, target_pet)?
             }
+            SummonType::FaintedPet(stats, lvl) => {
+                let fainted_pet_name = team
+                    .fainted
+                    .iter()
+                    .rev()
+                    .flatten()
+                    .find(|pet| !Arc::ptr_eq(pet, target_pet))
+                    .map(|pet| pet.read().unwrap().name.clone());
+                // NOTE: Allow to fail silently if no fainted pet.
+                if let Some(fainted_pet_name) = fainted_pet_name {
+                    Pet::new(fainted_pet_name, *stats, *lvl)?
+                } else {
+                    return Err(SAPTestError::FallibleAction);
+                }
+            }
         };
 
         new_pet.id = Some(team.history.pet_count + 1);
@@ -323,12 +484,12 @@ pub enum GainType {
     /// Gain a [`Food`] item from a [`SAPQuery`].
     /// # Example
     /// ```rust no_run
-    /// use saptest::{effects::actions::GainType, SAPQuery, Entity};
+    /// use saptest::{effects::actions::GainType, SAPQuery, Entity, FoodColumn};
     ///
     /// // Gain Garlic.
     /// let mut query = SAPQuery::builder()
     ///     .set_table(Entity::Food)
-    ///     .set_param("name", vec!["Garlic"]);
+    ///     .set_param(FoodColumn::Name, vec!["Garlic"]);
     ///
     /// let summon_type = GainType::QueryItem(query);
     /// ```
@@ -337,8 +498,10 @@ pub enum GainType {
     RandomShopItem,
     /// Gain the stored [`Food`].
     StoredItem(Box<Food>),
-    /// Remove [`Food`].
+    /// Remove held perk [`Food`].
     NoItem,
+    /// Remove held ailment [`Food`].
+    NoAilment,
 }
 
 impl GainType {
@@ -348,7 +511,7 @@ impl GainType {
         target_pet: &Arc<RwLock<Pet>>,
     ) -> Result<Option<Food>, SAPTestError> {
         Ok(match self {
-            GainType::SelfItem => target_pet.read().unwrap().item.clone(),
+            GainType::SelfItem => target_pet.read().unwrap().items.perk.as_deref().cloned(),
             GainType::DefaultItem(food_name) => Some(Food::try_from(food_name)?),
             GainType::StoredItem(food) => Some(*food.clone()),
             GainType::RandomShopItem => {
@@ -372,11 +535,20 @@ impl GainType {
                         })?;
                 Some(Food::try_from(food_record.name.clone())?)
             }
-            GainType::NoItem => None,
+            GainType::NoItem | GainType::NoAilment => None,
         })
     }
 }
 
+/// Types of resources taken by [`Action::Steal`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub enum StealType {
+    /// Held [`Food`] of the target [`Pet`].
+    Item,
+    /// Amount of gold from the target [`Team`]'s [`Shop`](crate::Shop).
+    Gold(usize),
+}
+
 /// Types of ways to get a [`Toy`].
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum ToyType {
@@ -391,7 +563,28 @@ pub enum ToyType {
         lvl: Option<usize>,
     },
     /// Query toy based on given SQL.
-    QueryOneToy(SAPQuery),
+    /// * A random toy is chosen if multiple toys match.
+    QueryOneToy {
+        /// Toy query.
+        query: SAPQuery,
+        /// Behavior if no toy matches the query.
+        #[serde(default)]
+        fallback: ToyFallback,
+    },
+}
+
+/// Behavior of [`ToyType::QueryOneToy`] when no [`Toy`] matches its query.
+/// * Ex. A toy-granting pet like [`Ferret`](crate::PetName::Ferret) at a level its source toy doesn't have.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ToyFallback {
+    /// Fail with an error.
+    #[default]
+    Error,
+    /// Get no toy.
+    NoToy,
+    /// Get the highest level toy matching the query below the queried `lvl`.
+    /// * Gets no toy if none exist.
+    LowerLevel,
 }
 
 impl ToyType {
@@ -402,7 +595,7 @@ impl ToyType {
                 let mut rng = ChaCha12Rng::seed_from_u64(team.seed.unwrap_or_else(random));
                 let mut query = SAPQuery::builder().set_table(Entity::Toy);
                 if let Some(lvl) = lvl {
-                    query = query.set_param("lvl", vec![lvl.to_string()]);
+                    query = query.set_param(ToyColumn::Lvl, vec![lvl.to_string()]);
                 };
                 let rec: ToyRecord = SAPDB
                     .execute_query(query)?
@@ -415,20 +608,47 @@ impl ToyType {
                     })?;
                 Some(rec.try_into()?)
             }
-            ToyType::QueryOneToy(sap_query) => {
+            ToyType::QueryOneToy { query, fallback } => {
                 let mut rng = ChaCha12Rng::seed_from_u64(team.seed.unwrap_or_else(random));
+                let query_toys = |query: SAPQuery| -> Result<Vec<ToyRecord>, SAPTestError> {
+                    Ok(SAPDB
+                        .execute_query(query)?
+                        .into_iter()
+                        .filter_map(|record| record.try_into().ok())
+                        .collect_vec())
+                };
 
-                let rec: ToyRecord = SAPDB
-                    .execute_query(sap_query.clone())?
-                    .into_iter()
-                    .filter_map(|record| record.try_into().ok())
-                    .choose(&mut rng)
-                    .ok_or(SAPTestError::QueryFailure {
-                        subject: String::from("No Toy Found"),
-                        reason: format!("No toy found for random toy query {self:?}"),
-                    })?;
+                let mut records = query_toys(query.clone())?;
+                if records.is_empty() && *fallback == ToyFallback::LowerLevel {
+                    // Requery at any level and keep the highest level below the queried level.
+                    let max_lvl = query
+                        .params
+                        .get(&ToyColumn::Lvl.to_string())
+                        .and_then(|lvls| lvls.iter().filter_map(|lvl| lvl.parse().ok()).max())
+                        .unwrap_or(usize::MAX);
+                    let mut lower_query = query.clone();
+                    lower_query.params.shift_remove(&ToyColumn::Lvl.to_string());
+                    let lower_records = query_toys(lower_query)?
+                        .into_iter()
+                        .filter(|record| record.lvl < max_lvl)
+                        .collect_vec();
+                    let highest_lvl = lower_records.iter().map(|record| record.lvl).max();
+                    records = lower_records
+                        .into_iter()
+                        .filter(|record| Some(record.lvl) == highest_lvl)
+                        .collect_vec();
+                }
 
-                Some(rec.try_into()?)
+                match records.into_iter().choose(&mut rng) {
+                    Some(rec) => Some(rec.try_into()?),
+                    None if *fallback == ToyFallback::Error => {
+                        return Err(SAPTestError::QueryFailure {
+                            subject: String::from("No Toy Found"),
+                            reason: format!("No toy found for random toy query {self:?}"),
+                        })
+                    }
+                    None => None,
+                }
             }
         })
     }
@@ -443,6 +663,15 @@ pub enum RandomizeType {
     Stats,
 }
 
+/// Sources of repeated [`Effect`](crate::Effect)s for [`Action::Repeat`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RepeatSource {
+    /// Effects of the friend directly ahead of the effect owner.
+    /// * Only repeated in battle.
+    /// * Repeated effects are at the level of the effect owner.
+    FriendAhead,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 /// Conditions for [`LogicType`].
 pub enum ConditionType {
@@ -504,7 +733,12 @@ impl ConditionType {
                             .map(|food| cond.matches_food(&food.read().unwrap()))
                             .unwrap_or_default(),
                     ),
-                    Entity::Toy => todo!(),
+                    Entity::Toy => usize::from(
+                        trigger
+                            .afflicting_toy
+                            .as_ref()
+                            .is_some_and(|toy| cond.matches_toy(toy)),
+                    ),
                 };
                 Ok(res)
             }
@@ -527,6 +761,127 @@ pub enum LogicType {
     IfNot(ConditionType),
     /// If any [`ConditionType`] met.
     IfAny(ConditionType),
+    /// If all [`ConditionType`]s met.
+    All(Vec<ConditionType>),
+    /// If at least one [`ConditionType`] met.
+    Any(Vec<ConditionType>),
+}
+
+/// User-defined behavior for an [`Action::Custom`].
+/// * Allows prototyping pets whose behavior isn't covered by existing [`Action`]s.
+///
+/// # Example
+/// ```
+/// use std::sync::{Arc, RwLock};
+/// use saptest::{
+///     Pet, Team,
+///     effects::actions::{Action, CustomAction},
+///     error::SAPTestError,
+/// };
+///
+/// /// Swap attack between the affected and afflicting pets.
+/// struct SwapAttack;
+///
+/// impl CustomAction for SwapAttack {
+///     fn name(&self) -> String {
+///         "Swap Attack".to_string()
+///     }
+///
+///     fn apply(
+///         &self,
+///         affected_pet: &Arc<RwLock<Pet>>,
+///         afflicting_pet: &Arc<RwLock<Pet>>,
+///         _team: &mut Team,
+///         _opponent: Option<&mut Team>,
+///     ) -> Result<(), SAPTestError> {
+///         if !Arc::ptr_eq(affected_pet, afflicting_pet) {
+///             let mut affected = affected_pet.write().unwrap();
+///             let mut afflicting = afflicting_pet.write().unwrap();
+///             std::mem::swap(&mut affected.stats.attack, &mut afflicting.stats.attack);
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let action = Action::Custom(Arc::new(SwapAttack));
+/// assert_eq!(action.to_string(), "Custom Swap Attack");
+/// ```
+pub trait CustomAction: Send + Sync {
+    /// Name of the action.
+    /// * Used to display and serialize the action.
+    fn name(&self) -> String;
+
+    /// Apply the action.
+    /// * `affected_pet` is the [`Pet`] targeted by the [`Effect`].
+    /// * `afflicting_pet` is the [`Pet`] owning the [`Effect`].
+    /// * `team` is the [`Team`] of the `affected_pet`.
+    /// * `opponent` is the other [`Team`], if any.
+    fn apply(
+        &self,
+        affected_pet: &Arc<RwLock<Pet>>,
+        afflicting_pet: &Arc<RwLock<Pet>>,
+        team: &mut Team,
+        opponent: Option<&mut Team>,
+    ) -> Result<(), SAPTestError>;
+}
+
+impl std::fmt::Debug for dyn CustomAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CustomAction({})", self.name())
+    }
+}
+
+impl PartialEq for dyn CustomAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+}
+
+/// Placeholder for a deserialized [`Action::Custom`].
+/// * Only the name of a [`CustomAction`] is serialized so it can't be applied.
+struct OpaqueCustomAction(String);
+
+impl CustomAction for OpaqueCustomAction {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+
+    fn apply(
+        &self,
+        _affected_pet: &Arc<RwLock<Pet>>,
+        _afflicting_pet: &Arc<RwLock<Pet>>,
+        _team: &mut Team,
+        _opponent: Option<&mut Team>,
+    ) -> Result<(), SAPTestError> {
+        Err(SAPTestError::InvalidPetAction {
+            subject: "Custom Action".to_string(),
+            reason: format!(
+                "Deserialized custom action {} has no behavior. Replace it with its implementation.",
+                self.0
+            ),
+        })
+    }
+}
+
+/// Serialize a [`CustomAction`] as an opaque marker of its name.
+mod custom_action_serde {
+    use super::{CustomAction, OpaqueCustomAction};
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::sync::Arc;
+
+    pub fn serialize<S: Serializer>(
+        action: &Arc<dyn CustomAction>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&action.name())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Arc<dyn CustomAction>, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Arc::new(OpaqueCustomAction(name)))
+    }
 }
 
 /// Pet actions.
@@ -552,12 +907,40 @@ pub enum Action {
     /// * [`Position::First`]
     /// * [`Position::Last`]
     Push(Position),
+    /// Swap positions of a [`Pet`] with an enemy [`Pet`] at some [`Position`].
+    /// * Both pets stay on their team and take the position of the other.
+    /// * Used by [`Eggplant`](crate::FoodName::Eggplant).
+    SwapEnemy(Position),
     /// Copy some attribute from [`CopyType`] to the owner from the [`Pet`] at a given [`Position`].
     /// 1. Attribute to copy.
     /// 2. [`Target`] [`Team`] to copy from.
     /// 3. Position of copy from.
     ///     * If multiple pets are targeted, only the first is taken.
     Copy(CopyType, Target, Position),
+    /// Steal some resource from a [`Pet`] or [`Team`] and give it to the owner.
+    /// * Stolen items are held by the owner and stolen gold is added to the owner's [`Shop`](crate::Shop).
+    /// ```
+    /// use saptest::{
+    ///     Effect, Position,
+    ///     effects::{trigger::*, state::Target, actions::{Action, StealType}},
+    /// };
+    /// // Steal the held item of the first enemy before attacking. ex. Raccoon
+    /// let steal_item_effect = Effect::new(
+    ///     TRIGGER_SELF_BEFORE_ATTACK,
+    ///     Target::Enemy,
+    ///     Position::First,
+    ///     Action::Steal { what: StealType::Item, from: Target::Enemy },
+    ///     Some(1),
+    ///     false,
+    /// );
+    /// ```
+    Steal {
+        /// Resource to steal.
+        what: StealType,
+        /// [`Target`] team to steal from.
+        /// * Should match the [`Target`] of the effect when stealing items.
+        from: Target,
+    },
     /// Negate some amount of [`Statistics`] damage.
     /// * An item-only [`Action`].
     /// * Used for [`Garlic`](crate::FoodName::Garlic) and [`Melon`](crate::FoodName::Melon)
@@ -567,17 +950,27 @@ pub enum Action {
     /// * An item-only [`Action`].
     /// * Used by [`Cheese`](crate::FoodName::Cheese) and [`Fortune Cookie`](crate::FoodName::FortuneCookie)
     Critical(usize),
+    /// Deal extra damage to [`Pet`]s of a lower tier than the attacker.
+    /// * An item-only [`Action`].
+    /// * Used by [`Salt`](crate::FoodName::Salt).
+    /// 1. Percent of attack dealt as extra damage.
+    LowerTierDamage(usize),
     /// Swallow a [`Pet`], summon it, and level it on faint.
     /// 1. Specified `level` after spawning.
+    ///     * The summoned pet has its base stats plus the experience needed to reach this level.
     /// 2. Pet [`Position`] of the pet to swallow.
     ///     * If this targets multiple [`Pet`]s, the first is taken.
+    ///
+    /// The swallowed pet keeps its held items and can be inspected with [`Pet::swallowed_pet`].
     Whale(usize, Position),
     /// Transform owner into another [`Pet`].
     /// 1. [`PetName`] to summon as.
     /// 2. [`Statistics`] of transformed pet.
     /// 3. Level after transformation.
     ///
-    /// **Note: This does not emit a summon trigger or faint trigger.**
+    /// The transformed pet keeps the owner's position and held items. See [`Team::transform_pet`](crate::Team::transform_pet).
+    ///
+    /// **Note: This emits a transform trigger but not a summon trigger or faint trigger.**
     ///
     /// <https://superautopets.fandom.com/wiki/Whale>
     Transform(PetName, Option<Statistics>, usize),
@@ -598,20 +991,41 @@ pub enum Action {
     AddShopStats(Statistics),
     /// Add a [`Shop`](crate::Shop) food as a [`ShopItem`](crate::ShopItem).
     AddShopFood(GainType),
+    /// Add [`Statistics`] to [`Shop`](crate::Shop) foods at the [`Effect`](crate::Effect)'s position.
+    /// * Only foods that add static stats are buffed.
+    AddShopFoodStats(Statistics),
     /// Add a [`Shop`](crate::Shop) pet as a [`ShopItem`](crate::ShopItem).
     AddShopPet(SummonType),
     /// Clear [`Shop`](crate::Shop) items of a specified [type](crate::effects::effect::Entity).
     ClearShop(Entity),
     /// Alter gold for the [`Shop`](crate::Shop).
-    AlterGold(isize),
-    /// Alter cost for a [`Pet`] when sold.
+    AlterGold(GoldChangeType),
+    /// Alter gold gained for a [`Pet`] when sold.
+    /// * See [`Pet::sell_value`].
     AlterCost(isize),
     /// Reduce cost of [`Shop`](crate::Shop) a [`ShopItem`](crate::ShopItem).
     /// 1. Item type to discount.
     /// 2. Gold to discount by.
     Discount(Entity, usize),
+    /// Increase a [`Shop`](crate::Shop) price for the rest of the turn.
+    /// * Items stocked later in the turn also have the increased price.
+    /// * Action of the hard mode [`Dice`](crate::ToyName::Dice) and [`Open Piggy Bank`](crate::ToyName::OpenPiggyBank).
+    /// 1. Price to increase.
+    /// 2. Gold to increase by.
+    Markup(PriceType, usize),
     /// Free roll(s) for the [`Shop`](crate::Shop).
     FreeRoll(usize),
+    /// Unfreeze [`Shop`](crate::Shop) items of a specified [type](crate::effects::effect::Entity).
+    /// * Reserved items stay frozen.
+    Unfreeze(Entity),
+    /// Multiply the stats a bought [`Food`](crate::Food) adds by some amount.
+    /// * Applied when food is bought rather than through a trigger. Each food bought uses the effect once.
+    /// * The action of the [`Cat`](crate::PetName::Cat).
+    MultiplyFoodStats(usize),
+    /// Repeat the [`Effect`](crate::Effect)s of other [`Pet`]s given a [`RepeatSource`].
+    /// * Applied when the repeated effects activate rather than through a trigger.
+    /// * The action of the [`Tiger`](crate::PetName::Tiger).
+    Repeat(RepeatSource),
     /// Save remaining gold up to a given limit. This gold is then available on the next turn.
     SaveGold {
         /// Gold limit.
@@ -663,27 +1077,38 @@ pub enum Action {
     /// );
     /// ```
     Conditional(LogicType, Box<Action>, Box<Action>),
+    /// Perform the [`Action`] of the first branch with its [`LogicType`] condition met, defaulting to the second [`Action`] if none are met.
+    /// * Equivalent to else-if branches of nested [`Action::Conditional`]s.
+    /// 1. Branches of [`LogicType`] conditions and their [`Action`].
+    /// 2. `Else` [`Action`]
+    ///
+    /// # Examples
+    /// ---
+    /// **End turn** → Gain +2 attack if the shop is tier 6. Otherwise, gain +1 attack if the shop is tier 5.
+    /// ```rust compile_fail
+    /// let action = Action::ConditionalChain(
+    ///     vec![
+    ///         (
+    ///             LogicType::If(ConditionType::Shop(ShopCondition::Tier(Some(CondOrdering::Equal(6))))),
+    ///             Action::Add(StatChangeType::StaticAttack(2)),
+    ///         ),
+    ///         (
+    ///             LogicType::If(ConditionType::Shop(ShopCondition::Tier(Some(CondOrdering::Equal(5))))),
+    ///             Action::Add(StatChangeType::StaticAttack(1)),
+    ///         ),
+    ///     ],
+    ///     Box::new(Action::None),
+    /// );
+    /// ```
+    ConditionalChain(Vec<(LogicType, Action)>, Box<Action>),
     /// Hardcoded Lynx ability.
     ///
     /// <https://superautopets.fandom.com/wiki/Lynx>
     Lynx,
-    /// Hardcoded Stegosaurus ability
-    /// 1. [`Statistics`] buff.
-    ///
-    /// <https://superautopets.fandom.com/wiki/Stegosaurus>
-    Stegosaurus(Statistics),
     /// Hardcoded Cockroach ability.
     ///
     /// <https://superautopets.fandom.com/wiki/Cockroach>
     Cockroach,
-    /// Hardcoded moose ability
-    /// <https://superautopets.fandom.com/wiki/Moose>
-    Moose {
-        /// [`Statistics`] buff.
-        stats: Statistics,
-        /// Shop pet `tier` to trigger buff.
-        tier: usize,
-    },
     /// Hardcoded Fox ability.
     /// 1. Item [type](crate::Entity) to steal.
     /// 2. Buff multiplier.
@@ -693,14 +1118,18 @@ pub enum Action {
     Fox(Entity, usize),
     /// Gain experiences point.
     /// 1. Number of points to gain.
+    ///
+    /// Each point gives `+1/+1`. Pets that level up change their effects and emit level up triggers.
+    /// * Experience past the max level is lost.
     Experience(usize),
     /// Endure damage so health doesn't go below one.
     /// * An item-only [`Action`].
     /// * Used for the [`Pepper`](crate::FoodName::Pepper).
     Endure,
     /// Adjust counter for a team.
-    /// 1. Counter name to modify.
+    /// 1. [`CounterKey`] of counter to modify.
     ///     * If this counter does not exist, a new entry is created.
+    ///     * The count is clamped to the counter's bounds.
     /// 2. Amount to modify counter by.
     ///     * Positive values increment, while negative values decrement the count.
     ///
@@ -709,20 +1138,126 @@ pub enum Action {
     /// ```
     /// use saptest::{
     ///     Effect, Position,
-    ///     effects::{trigger::*, state::Target, actions::Action}
+    ///     effects::{trigger::*, state::Target, actions::Action},
+    ///     teams::counters::CounterKey,
     /// };
     /// let add_trumpets_effect = Effect::new(
     ///     TRIGGER_SELF_FAINT,
     ///     Target::Friend,
     ///     // Doesn't target pet but is required.
     ///     Position::TriggerAffected(None),
-    ///     Action::AddToCounter(String::from("Trumpets"), 2),
+    ///     Action::AddToCounter(CounterKey::Trumpets, 2),
     ///     Some(1),
     ///     true,
     /// );
     /// ```
-    AddToCounter(String, isize),
+    AddToCounter(CounterKey, isize),
+    /// Add an [`Outcome`] trigger to the affected pet's [`Team`].
+    /// * The trigger's affected pet is set to the affected pet and its afflicting pet to the [`Effect`] owner.
+    /// * Used for follow-up triggers. ex. Counts as a knockout.
+    /// * Avoid pushing a trigger that activates the same effect as this never stops.
+    /// ```
+    /// use saptest::effects::{actions::Action, trigger::TRIGGER_KNOCKOUT};
+    ///
+    /// let action = Action::PushTrigger(TRIGGER_KNOCKOUT);
+    /// assert_eq!(action.tags(), ["trigger"]);
+    /// ```
+    PushTrigger(Outcome),
+    /// User-defined [`CustomAction`].
+    /// * Serializes as an opaque marker containing the action's name.
+    ///     * A deserialized custom action raises an error when applied.
+    Custom(#[serde(with = "custom_action_serde")] Arc<dyn CustomAction>),
     #[default]
     /// No action to take.
     None,
 }
+
+impl Action {
+    /// Built-in tags classifying an [`Action`].
+    /// * `"buff"`: Adds stats or experience.
+    /// * `"damage"`: Removes stats or faints pets.
+    /// * `"debuff"`: Reduces stats by a percentage.
+    /// * `"stats"`: Sets stats.
+    /// * `"summon"`: Summons pets.
+    /// * `"item"`: Gains, steals, or negates items.
+    /// * `"toy"`: Gains toys.
+    /// * `"shop"`: Modifies the [`Shop`](crate::Shop).
+    /// * `"position"`: Moves pets.
+    /// * `"copy"`: Copies or repeats effects, stats, or pets.
+    /// * `"counter"`: Modifies team counters.
+    /// * `"trigger"`: Adds triggers.
+    /// * `"custom"`: User-defined [`CustomAction`]s.
+    ///
+    /// Nested actions in [`Action::Multiple`] and conditional actions include the tags of all their actions.
+    /// ```
+    /// use saptest::effects::actions::{Action, GoldChangeType, StatChangeType};
+    ///
+    /// let action = Action::Multiple(vec![
+    ///     Action::Add(StatChangeType::StaticAttack(1)),
+    ///     Action::AlterGold(GoldChangeType::Static(1)),
+    /// ]);
+    /// assert_eq!(action.tags(), ["buff", "shop"]);
+    /// ```
+    pub fn tags(&self) -> Vec<&'static str> {
+        let tag = match self {
+            Action::Add(_) | Action::Experience(_) | Action::Invincible | Action::Endure => "buff",
+            Action::Remove(_)
+            | Action::Kill
+            | Action::Lynx
+            | Action::Critical(_)
+            | Action::LowerTierDamage(_) => "damage",
+            Action::Debuff(_) => "debuff",
+            Action::Set(_) | Action::Cockroach => "stats",
+            Action::Summon(_) | Action::Whale(_, _) => "summon",
+            Action::Gain(_) | Action::Steal { .. } | Action::Negate(_) | Action::Fox(_, _) => {
+                "item"
+            }
+            Action::GetToy(_) => "toy",
+            Action::AddShopStats(_)
+            | Action::AddShopFood(_)
+            | Action::AddShopFoodStats(_)
+            | Action::AddShopPet(_)
+            | Action::ClearShop(_)
+            | Action::AlterGold(_)
+            | Action::AlterCost(_)
+            | Action::Discount(_, _)
+            | Action::Markup(_, _)
+            | Action::FreeRoll(_)
+            | Action::Unfreeze(_)
+            | Action::MultiplyFoodStats(_)
+            | Action::SaveGold { .. } => "shop",
+            Action::Shuffle(_) | Action::Swap(_) | Action::Push(_) | Action::SwapEnemy(_) => {
+                "position"
+            }
+            Action::Copy(_, _, _) | Action::Transform(_, _, _) | Action::Repeat(_) => "copy",
+            Action::AddToCounter(_, _) => "counter",
+            Action::PushTrigger(_) => "trigger",
+            Action::Custom(_) => "custom",
+            Action::Multiple(actions) => {
+                return actions
+                    .iter()
+                    .flat_map(|action| action.tags())
+                    .unique()
+                    .collect();
+            }
+            Action::Conditional(_, if_action, else_action) => {
+                return if_action
+                    .tags()
+                    .into_iter()
+                    .chain(else_action.tags())
+                    .unique()
+                    .collect();
+            }
+            Action::ConditionalChain(branches, else_action) => {
+                return branches
+                    .iter()
+                    .flat_map(|(_, action)| action.tags())
+                    .chain(else_action.tags())
+                    .unique()
+                    .collect();
+            }
+            Action::None => return vec![],
+        };
+        vec![tag]
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
+    db::record::PetRecord,
     effects::{
         actions::Action,
         state::{Outcome, Position, Target},
     },
     error::SAPTestError,
+    pets::pet::{query_pet_record, MAX_PET_LEVEL, MIN_PET_LEVEL},
     toys::names::ToyName,
     FoodName, Pet, PetName,
 };
@@ -55,9 +57,83 @@ pub struct Effect {
     pub action: Action,
     /// Number of uses of effect per trigger.
     /// * `None` indicates unlimited uses.
+    /// * Restored at the start of each turn or battle with `uses_refresh`.
     pub uses: Option<usize>,
     /// If the effect is temporary or not.
     pub temp: bool,
+    /// Number of shop turns [`Statistics`](crate::Statistics) added by a temporary effect last.
+    /// * `None` removes them when the [`Shop`](crate::Shop) next opens.
+    #[serde(default)]
+    pub duration: Option<usize>,
+    /// Tags classifying the effect.
+    /// * Built-in tags from [`Action::tags`] are also checked by [`Effect::has_tag`].
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Limit on the number of activations of the effect within a turn or battle.
+    /// * `None` indicates no limit beyond `uses`.
+    #[serde(default)]
+    pub limit: Option<ActivationLimit>,
+    /// Number of uses restored at the start of each turn or battle.
+    /// * `None` leaves uses as is until the effect is reset.
+    #[serde(default)]
+    pub uses_refresh: Option<UsesRefresh>,
+}
+
+/// Scope an [`ActivationLimit`] is counted within.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum LimitScope {
+    /// Count is reset at the start of each battle.
+    Battle,
+    /// Count is reset when the [`Shop`](crate::Shop) opens.
+    Turn,
+}
+
+/// Maximum number of activations of an [`Effect`] within a [`LimitScope`].
+/// * Unlike `uses`, which are restored per trigger, the count is kept until the scope ends.
+/// * Reset with [`Team::reset_activation_limits`](crate::Team::reset_activation_limits).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ActivationLimit {
+    /// Scope the limit is counted within.
+    pub scope: LimitScope,
+    /// Maximum number of activations.
+    pub max: usize,
+    /// Number of activations within the current scope.
+    #[serde(default)]
+    pub count: usize,
+}
+
+impl ActivationLimit {
+    /// Create an [`ActivationLimit`] with no activations.
+    /// ```
+    /// use saptest::effects::effect::{ActivationLimit, LimitScope};
+    ///
+    /// let limit = ActivationLimit::new(LimitScope::Turn, 1);
+    /// assert!(!limit.is_reached());
+    /// ```
+    pub fn new(scope: LimitScope, max: usize) -> Self {
+        ActivationLimit {
+            scope,
+            max,
+            count: 0,
+        }
+    }
+
+    /// Check if the maximum number of activations was reached.
+    pub fn is_reached(&self) -> bool {
+        self.count >= self.max
+    }
+}
+
+/// Number of uses of an [`Effect`] restored at the start of a [`LimitScope`].
+/// * [`LimitScope::Turn`] restores uses when the [`Shop`](crate::Shop) opens. Ex. once-per-turn shop effects.
+/// * [`LimitScope::Battle`] restores uses at the start of each battle.
+/// * Restore with [`Team::reset_uses`](crate::Team::reset_uses).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct UsesRefresh {
+    /// Scope uses are restored within.
+    pub scope: LimitScope,
+    /// Number of uses restored.
+    pub uses: usize,
 }
 
 impl PartialEq for Effect {
@@ -68,6 +144,10 @@ impl PartialEq for Effect {
             && self.action == other.action
             && self.uses == other.uses
             && self.temp == other.temp
+            && self.duration == other.duration
+            && self.tags == other.tags
+            && self.limit == other.limit
+            && self.uses_refresh == other.uses_refresh
     }
 }
 
@@ -127,9 +207,29 @@ impl Effect {
             action,
             uses,
             temp: temporary,
+            ..Default::default()
         }
     }
 
+    /// Check if the effect has a tag.
+    /// * Includes built-in tags of its [`Action`]. See [`Action::tags`].
+    /// # Example
+    /// ```
+    /// use saptest::{
+    ///     Effect,
+    ///     effects::actions::{Action, SummonType}
+    /// };
+    /// let effect = Effect::builder()
+    ///     .set_action(Action::Summon(SummonType::DefaultPet(saptest::PetName::Bee)))
+    ///     .add_tag("token")
+    ///     .build();
+    /// assert!(effect.has_tag("summon") && effect.has_tag("token"));
+    /// assert!(!effect.has_tag("damage"));
+    /// ```
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|effect_tag| effect_tag == tag) || self.action.tags().contains(&tag)
+    }
+
     /// Get owner of effect.
     /// # Example
     /// ```
@@ -185,8 +285,200 @@ impl Effect {
             && self.trigger.position == trigger.position
             && self.trigger.affected_team == trigger.affected_team
             && self.trigger.status == trigger.status;
-        // Either match and not out of uses.
-        (exact_match || non_specific_match) && self.uses != Some(0)
+        // Either match and not out of uses or at its activation limit.
+        (exact_match || non_specific_match)
+            && self.uses != Some(0)
+            && !self.limit.is_some_and(|limit| limit.is_reached())
+    }
+
+    /// Construct an [`Effect`] with an [`EffectBuilder`].
+    /// # Example
+    /// ```
+    /// use saptest::{
+    ///     Effect, Statistics,
+    ///     effects::{
+    ///         trigger::TRIGGER_SELF_FAINT,
+    ///         state::{Position, Target, ItemCondition},
+    ///         actions::{Action, StatChangeType}
+    ///     }
+    /// };
+    /// let lvl_1_ant_effect = Effect::builder()
+    ///     .set_trigger(TRIGGER_SELF_FAINT)
+    ///     .set_target(Target::Friend)
+    ///     .set_position(Position::Any(ItemCondition::None))
+    ///     .set_action(Action::Add(StatChangeType::Static(Statistics {attack: 2, health: 1})))
+    ///     .build();
+    /// assert_eq!(lvl_1_ant_effect.uses, Some(1));
+    /// assert!(!lvl_1_ant_effect.temp);
+    /// ```
+    pub fn builder() -> EffectBuilder {
+        EffectBuilder::default()
+    }
+
+    /// Construct the [`Effect`]s of a pet from its [`PetRecord`] at a given level.
+    /// * If the record isn't at `lvl`, the pet's record at `lvl` is queried.
+    /// * Effects are built without an owner.
+    /// # Example
+    /// ```
+    /// use saptest::{
+    ///     Effect, PetName, SAPDB, SAPQuery, Entity,
+    ///     db::record::SAPRecord, PetColumn,
+    /// };
+    ///
+    /// let query = SAPQuery::builder()
+    ///     .set_table(Entity::Pet)
+    ///     .set_param(PetColumn::Name, vec![PetName::Ant])
+    ///     .set_param(PetColumn::Lvl, vec![1]);
+    /// let Some(SAPRecord::Pet(record)) = SAPDB.execute_query(query).unwrap().into_iter().next() else {
+    ///     panic!("No ant record.")
+    /// };
+    /// let lvl_1_effects = Effect::from_record_at_level(&record, 1).unwrap();
+    /// let lvl_2_effects = Effect::from_record_at_level(&record, 2).unwrap();
+    /// assert_ne!(lvl_1_effects, lvl_2_effects);
+    /// ```
+    pub fn from_record_at_level(
+        record: &PetRecord,
+        lvl: usize,
+    ) -> Result<Vec<Effect>, SAPTestError> {
+        if !(MIN_PET_LEVEL..=MAX_PET_LEVEL).contains(&lvl) {
+            return Err(SAPTestError::InvalidPetAction {
+                subject: "Invalid Level".to_string(),
+                reason: format!("{} has no effect at level {lvl}.", record.name),
+            });
+        }
+        if record.lvl == lvl {
+            record.clone().try_into()
+        } else {
+            query_pet_record(&record.name, lvl)?.try_into()
+        }
+    }
+}
+
+/// Builder for an [`Effect`].
+/// * Effects are built without an owner. Owners are assigned once added to a [`Team`](crate::Team).
+/// * Effects have `1` use and are permanent by default.
+#[derive(Debug, Clone)]
+pub struct EffectBuilder {
+    effect: Effect,
+}
+
+impl Default for EffectBuilder {
+    fn default() -> Self {
+        EffectBuilder {
+            effect: Effect {
+                uses: Some(1),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl EffectBuilder {
+    /// Set the [`Outcome`] triggering the [`Effect`].
+    pub fn set_trigger(mut self, trigger: Outcome) -> Self {
+        self.effect.trigger = trigger;
+        self
+    }
+
+    /// Set the [`Target`] of the [`Effect`].
+    pub fn set_target(mut self, target: Target) -> Self {
+        self.effect.target = target;
+        self
+    }
+
+    /// Set the [`Position`] of targets to affect.
+    pub fn set_position(mut self, position: Position) -> Self {
+        self.effect.position = position;
+        self
+    }
+
+    /// Set the [`Action`] to take.
+    pub fn set_action(mut self, action: Action) -> Self {
+        self.effect.action = action;
+        self
+    }
+
+    /// Set the number of uses of the [`Effect`] per trigger.
+    /// * `None` indicates unlimited uses.
+    /// ```
+    /// use saptest::Effect;
+    ///
+    /// let effect = Effect::builder().set_uses(None).build();
+    /// assert_eq!(effect.uses, None);
+    /// ```
+    pub fn set_uses(mut self, uses: Option<usize>) -> Self {
+        self.effect.uses = uses;
+        self
+    }
+
+    /// Set if the [`Effect`] is temporary.
+    /// ```
+    /// use saptest::Effect;
+    ///
+    /// let effect = Effect::builder().set_temp(true).build();
+    /// assert!(effect.temp);
+    /// ```
+    pub fn set_temp(mut self, temporary: bool) -> Self {
+        self.effect.temp = temporary;
+        self
+    }
+
+    /// Set the number of shop turns a temporary [`Effect`] lasts.
+    /// ```
+    /// use saptest::Effect;
+    ///
+    /// let effect = Effect::builder().set_temp(true).set_duration(Some(2)).build();
+    /// assert_eq!(effect.duration, Some(2));
+    /// ```
+    pub fn set_duration(mut self, duration: Option<usize>) -> Self {
+        self.effect.duration = duration;
+        self
+    }
+
+    /// Limit the number of activations of the [`Effect`] within a [`LimitScope`].
+    /// ```
+    /// use saptest::{Effect, effects::effect::{ActivationLimit, LimitScope}};
+    ///
+    /// // Only the first two activations each battle count.
+    /// let effect = Effect::builder().set_limit(LimitScope::Battle, 2).build();
+    /// assert_eq!(effect.limit, Some(ActivationLimit::new(LimitScope::Battle, 2)));
+    /// ```
+    pub fn set_limit(mut self, scope: LimitScope, max: usize) -> Self {
+        self.effect.limit = Some(ActivationLimit::new(scope, max));
+        self
+    }
+
+    /// Set the number of uses of the [`Effect`] restored at the start of each [`LimitScope`].
+    /// * Also sets the current number of uses.
+    /// ```
+    /// use saptest::{Effect, effects::effect::{LimitScope, UsesRefresh}};
+    ///
+    /// // Activates twice per turn.
+    /// let effect = Effect::builder().set_uses_refresh(LimitScope::Turn, 2).build();
+    /// assert_eq!(effect.uses, Some(2));
+    /// assert_eq!(effect.uses_refresh, Some(UsesRefresh { scope: LimitScope::Turn, uses: 2 }));
+    /// ```
+    pub fn set_uses_refresh(mut self, scope: LimitScope, uses: usize) -> Self {
+        self.effect.uses = Some(uses);
+        self.effect.uses_refresh = Some(UsesRefresh { scope, uses });
+        self
+    }
+
+    /// Add a tag to the [`Effect`].
+    /// ```
+    /// use saptest::Effect;
+    ///
+    /// let effect = Effect::builder().add_tag("scaling").build();
+    /// assert_eq!(effect.tags, ["scaling"]);
+    /// ```
+    pub fn add_tag<T: Into<String>>(mut self, tag: T) -> Self {
+        self.effect.tags.push(tag.into());
+        self
+    }
+
+    /// Build the [`Effect`].
+    pub fn build(self) -> Effect {
+        self.effect
     }
 }
 
@@ -219,6 +511,9 @@ pub trait EffectModify {
     /// assert_eq!(dolphin_effect.uses, Some(0));
     /// ```
     fn remove_uses(&mut self, n: usize) -> &mut Self;
+
+    /// Count an activation towards the [`Effect`]'s [`ActivationLimit`], if any.
+    fn add_activation(&mut self) -> &mut Self;
 }
 
 impl EffectModify for Effect {
@@ -237,4 +532,11 @@ impl EffectModify for Effect {
         };
         self
     }
+
+    fn add_activation(&mut self) -> &mut Self {
+        if let Some(limit) = self.limit.as_mut() {
+            limit.count += 1
+        };
+        self
+    }
 }
@@ -3,7 +3,10 @@ use itertools::Itertools;
 use crate::{Effect, ItemCondition};
 
 use super::{
-    actions::{Action, ConditionType, CopyType, GainType, LogicType, StatChangeType, SummonType},
+    actions::{
+        Action, ConditionType, CopyType, GainType, GoldChangeType, LogicType, StatChangeType,
+        StealType, SummonType,
+    },
     state::{EqualityCondition, Outcome, Status, TeamCondition},
 };
 
@@ -45,6 +48,40 @@ impl std::fmt::Display for StatChangeType {
             StatChangeType::CurrentAttack => write!(f, "To Current Attack"),
             StatChangeType::CurrentHealth => write!(f, "To Current Health"),
             StatChangeType::TeamCounter(counter_key) => write!(f, "Based on {counter_key}"),
+            StatChangeType::ShopTierMultiplier(stats) => write!(f, "{stats} x Shop Tier"),
+            StatChangeType::TurnMultiplier(stats) => write!(f, "{stats} x Turns"),
+            StatChangeType::TriggerAffectedStats(stats) => {
+                write!(
+                    f,
+                    "({}%, {}%) of Trigger Affected Stats",
+                    stats.attack, stats.health
+                )
+            }
+            StatChangeType::TriggerAfflictingStats(stats) => {
+                write!(
+                    f,
+                    "({}%, {}%) of Trigger Afflicting Stats",
+                    stats.attack, stats.health
+                )
+            }
+            StatChangeType::ShopTierPetsMultiplier(stats, tier) => {
+                write!(f, "{stats} x Number of Tier {tier} Shop Pets")
+            }
+            StatChangeType::ShopFoodsMultiplier(stats) => {
+                write!(f, "{stats} x Number of Shop Foods")
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for GoldChangeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoldChangeType::Static(change) => write!(f, "{change}"),
+            GoldChangeType::Percent(percent) => write!(f, "{percent}%"),
+            GoldChangeType::Clamped(change, min_coins) => {
+                write!(f, "{change} (Min. {min_coins})")
+            }
         }
     }
 }
@@ -88,6 +125,16 @@ impl std::fmt::Display for GainType {
             GainType::RandomShopItem => write!(f, "Random Shop Item"),
             GainType::StoredItem(item) => write!(f, "{item}"),
             GainType::NoItem => write!(f, "No Item"),
+            GainType::NoAilment => write!(f, "No Ailment"),
+        }
+    }
+}
+
+impl std::fmt::Display for StealType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StealType::Item => write!(f, "Item"),
+            StealType::Gold(gold) => write!(f, "{gold} Gold"),
         }
     }
 }
@@ -143,6 +190,11 @@ impl std::fmt::Display for SummonType {
                     "Pet {stats_str} from Shop Tier ({tier_diff}) at Level {lvl}"
                 )
             }
+            SummonType::FaintedPet(stats, lvl) => {
+                let stats_str =
+                    stats.map_or_else(|| "(Default Stats)".to_string(), |stats| stats.to_string());
+                write!(f, "Last Fainted Pet {stats_str} at Level {lvl}")
+            }
         }
     }
 }
@@ -157,11 +209,16 @@ impl std::fmt::Display for Action {
             Action::Shuffle(shuffle_type) => write!(f, "Shuffle {shuffle_type:?}"),
             Action::Swap(swap_type) => write!(f, "Swap {swap_type:?}"),
             Action::Push(pos) => write!(f, "Push from Current to {pos:?} Position"),
+            Action::SwapEnemy(pos) => write!(f, "Swap Positions with {pos:?} Enemy"),
             Action::Copy(copy_type, target, pos) => {
                 write!(f, "Copy {copy_type} to {pos:?} Pet(s) on {target:?} Team.")
             }
+            Action::Steal { what, from } => write!(f, "Steal {what} from {from:?} Team"),
             Action::Negate(stats) => write!(f, "Negate {stats}"),
             Action::Critical(percentage) => write!(f, "Critical Chance {percentage}%"),
+            Action::LowerTierDamage(percentage) => {
+                write!(f, "Deal {percentage}% Extra Damage to Lower Tier Pets")
+            }
             Action::Whale(lvl, pos) => write!(f, "Evolve {pos:?} to {lvl}"),
             Action::Transform(petname, stats, lvl) => {
                 let stats_str =
@@ -174,6 +231,7 @@ impl std::fmt::Display for Action {
             Action::GetToy(get_toy_type) => write!(f, "Get a toy {get_toy_type:?}"),
             Action::AddShopStats(stats) => write!(f, "Add Shop {stats}"),
             Action::AddShopFood(food) => write!(f, "Add {food} to Shop"),
+            Action::AddShopFoodStats(stats) => write!(f, "Add {stats} to Shop Foods"),
             Action::AddShopPet(pet) => write!(f, "Add {pet} to Shop"),
             Action::ClearShop(item_type) => write!(f, "Clear Shop {item_type:?}"),
             Action::AlterGold(gold_change) => write!(f, "Alter gold by {gold_change}"),
@@ -181,8 +239,16 @@ impl std::fmt::Display for Action {
             Action::Discount(item_type, gold) => {
                 write!(f, "Discount {gold} Gold from {item_type:?}")
             }
+            Action::Markup(price_type, gold) => {
+                write!(f, "Increase {price_type:?} Price by {gold} Gold")
+            }
             Action::SaveGold { limit } => write!(f, "Save Remaining Gold up to {limit} Gold"),
             Action::FreeRoll(rolls) => write!(f, "Gain {rolls} Free Rolls"),
+            Action::Unfreeze(item_type) => write!(f, "Unfreeze Shop {item_type}"),
+            Action::Repeat(source) => write!(f, "Repeat Effects of {source:?}"),
+            Action::MultiplyFoodStats(multiplier) => {
+                write!(f, "Multiply Bought Food Stats by {multiplier}")
+            }
             Action::Summon(summon_type) => write!(f, "Summon {summon_type}"),
             Action::Multiple(actions) => {
                 let action_str = actions
@@ -192,12 +258,7 @@ impl std::fmt::Display for Action {
                 write!(f, "Do {action_str}.")
             }
             Action::Lynx => write!(f, "Lynx (Damage Equal Sum Levels)"),
-            Action::Stegosaurus(stats) => write!(f, "Stegosaurus (Add {stats} x Turns)"),
             Action::Cockroach => write!(f, "Cockroach (Set Attack Equal Shop Tier + Level)"),
-            Action::Moose { stats, tier } => write!(
-                f,
-                "Moose (Unfreeze And Add {stats} x Number of Tier {tier} Pets)"
-            ),
             Action::Fox(item_type, multiplier) => {
                 write!(f, "Fox (Steal {item_type:?} With {multiplier}x Stats)")
             }
@@ -215,9 +276,24 @@ impl std::fmt::Display for Action {
                     )
                 }
             }
+            Action::ConditionalChain(branches, else_action) => {
+                let branch_str = branches
+                    .iter()
+                    .map(|(logic_type, action)| {
+                        if let LogicType::ForEach(_) = logic_type {
+                            format!("{logic_type} {action}.")
+                        } else {
+                            format!("{logic_type} Then {action}.")
+                        }
+                    })
+                    .join(" Else ");
+                write!(f, "{branch_str} Otherwise, {else_action}.")
+            }
             Action::AddToCounter(counter, count_change) => {
                 write!(f, "Adjust {counter} by {count_change}")
             }
+            Action::PushTrigger(trigger) => write!(f, "Push Trigger {:?}", trigger.status),
+            Action::Custom(custom_action) => write!(f, "Custom {}", custom_action.name()),
         }
     }
 }
@@ -271,6 +347,8 @@ impl std::fmt::Display for LogicType {
             LogicType::If(cond) => write!(f, "If {cond}"),
             LogicType::IfNot(cond) => write!(f, "If Not {cond}"),
             LogicType::IfAny(cond) => write!(f, "If Any {cond}"),
+            LogicType::All(conds) => write!(f, "If {}", conds.iter().join(" And ")),
+            LogicType::Any(conds) => write!(f, "If {}", conds.iter().join(" Or ")),
         }
     }
 }
@@ -280,14 +358,17 @@ mod test {
     use crate::{
         effects::{
             actions::{
-                Action, ConditionType, CopyType, GainType, LogicType, RandomizeType,
-                StatChangeType, SummonType,
+                Action, ConditionType, CopyType, GainType, GoldChangeType, LogicType,
+                RandomizeType, StatChangeType, SummonType,
+            },
+            state::{
+                CondOrdering, EqualityCondition, ShopCondition, Status, Target, TeamCondition,
             },
-            state::{EqualityCondition, Status, Target, TeamCondition},
+            trigger::TRIGGER_KNOCKOUT,
         },
         teams::team::TeamFightOutcome,
-        Entity, EntityName, Food, FoodName, ItemCondition, Pet, PetName, Position, SAPQuery,
-        Statistics,
+        Entity, EntityName, Food, FoodColumn, FoodName, ItemCondition, Pet, PetColumn, PetName,
+        Position, SAPQuery, Statistics,
     };
 
     #[test]
@@ -340,9 +421,21 @@ mod test {
         });
         assert_eq!("Negate (2, 0)", format!("{negate_action}"));
 
+        let swap_enemy_action = Action::SwapEnemy(Position::First);
+        assert_eq!(
+            "Swap Positions with First Enemy",
+            format!("{swap_enemy_action}")
+        );
+
         let critical_action = Action::Critical(25);
         assert_eq!("Critical Chance 25%", format!("{critical_action}"));
 
+        let lower_tier_dmg_action = Action::LowerTierDamage(100);
+        assert_eq!(
+            "Deal 100% Extra Damage to Lower Tier Pets",
+            format!("{lower_tier_dmg_action}")
+        );
+
         let whale_action = Action::Whale(2, Position::Nearest(1));
         assert_eq!("Evolve Nearest(1) to 2", format!("{whale_action}"));
 
@@ -378,14 +471,11 @@ mod test {
         let lynx_action = Action::Lynx;
         assert_eq!("Lynx (Damage Equal Sum Levels)", format!("{lynx_action}"));
 
-        let stego_action = Action::Stegosaurus(Statistics {
+        let stego_action = Action::Add(StatChangeType::TurnMultiplier(Statistics {
             attack: 1,
             health: 1,
-        });
-        assert_eq!(
-            "Stegosaurus (Add (1, 1) x Turns)",
-            format!("{stego_action}")
-        );
+        }));
+        assert_eq!("Add (1, 1) x Turns", format!("{stego_action}"));
 
         let cockroach_action = Action::Cockroach;
         assert_eq!(
@@ -393,18 +483,21 @@ mod test {
             format!("{cockroach_action}")
         );
 
-        let moose_action = Action::Moose {
-            stats: Statistics {
+        let moose_action = Action::Add(StatChangeType::ShopTierPetsMultiplier(
+            Statistics {
                 attack: 1,
                 health: 1,
             },
-            tier: 1,
-        };
+            1,
+        ));
         assert_eq!(
-            "Moose (Unfreeze And Add (1, 1) x Number of Tier 1 Pets)",
+            "Add (1, 1) x Number of Tier 1 Shop Pets",
             format!("{moose_action}")
         );
 
+        let unfreeze_action = Action::Unfreeze(Entity::Food);
+        assert_eq!("Unfreeze Shop Food", format!("{unfreeze_action}"));
+
         let fox_action = Action::Fox(Entity::Food, 2);
         assert_eq!("Fox (Steal Food With 2x Stats)", format!("{fox_action}"));
 
@@ -414,6 +507,9 @@ mod test {
         let endure_action = Action::Endure;
         assert_eq!("Endure (Pepper)", format!("{endure_action}"));
 
+        let push_trigger_action = Action::PushTrigger(TRIGGER_KNOCKOUT);
+        assert_eq!("Push Trigger KnockOut", format!("{push_trigger_action}"));
+
         let no_action = Action::None;
         assert_eq!("None", format!("{no_action}"));
     }
@@ -444,9 +540,15 @@ mod test {
         let shop_clear_action = Action::ClearShop(Entity::Food);
         assert_eq!("Clear Shop Food", format!("{shop_clear_action}"));
 
-        let shop_profit_action = Action::AlterGold(3);
+        let shop_profit_action = Action::AlterGold(GoldChangeType::Static(3));
         assert_eq!("Alter gold by 3", format!("{shop_profit_action}"));
 
+        let shop_tax_action = Action::AlterGold(GoldChangeType::Clamped(
+            Box::new(GoldChangeType::Percent(-50)),
+            2,
+        ));
+        assert_eq!("Alter gold by -50% (Min. 2)", format!("{shop_tax_action}"));
+
         let shop_discount_action = Action::Discount(Entity::Food, 3);
         assert_eq!(
             "Discount 3 Gold from Food",
@@ -468,7 +570,7 @@ mod test {
         let gain_query_item_action = Action::Gain(GainType::QueryItem(
             SAPQuery::builder()
                 .set_table(Entity::Food)
-                .set_param("name", vec!["Garlic"])
+                .set_param(FoodColumn::Name, vec!["Garlic"])
                 .to_owned(),
         ));
         assert_eq!(
@@ -492,6 +594,9 @@ mod test {
 
         let gain_no_item_action = Action::Gain(GainType::NoItem);
         assert_eq!("Gain No Item", format!("{gain_no_item_action}"));
+
+        let gain_no_ailment_action = Action::Gain(GainType::NoAilment);
+        assert_eq!("Gain No Ailment", format!("{gain_no_ailment_action}"));
     }
 
     #[test]
@@ -499,7 +604,7 @@ mod test {
         let summon_query_pet_action = Action::Summon(SummonType::QueryPet(
             SAPQuery::builder()
                 .set_table(Entity::Pet)
-                .set_param("name", vec![PetName::Dog]),
+                .set_param(PetColumn::Name, vec![PetName::Dog]),
             Some(Statistics {
                 attack: 50,
                 health: 50,
@@ -565,6 +670,12 @@ mod test {
             "Summon Any (Ignoring Tapir) Team Pet (Owner Stats) at Level 1",
             format!("{summon_self_team_pet}")
         );
+
+        let summon_fainted_pet_action = Action::Summon(SummonType::FaintedPet(None, 1));
+        assert_eq!(
+            "Summon Last Fainted Pet (Default Stats) at Level 1",
+            format!("{summon_fainted_pet_action}")
+        );
     }
 
     #[test]
@@ -710,6 +821,33 @@ mod test {
         assert_eq!(
             "If Pet (Friend) Equal to Tier(3) And Trigger(Faint) Then Add (2, 2). Otherwise, None.",
             format!("{conditional_if_multi_all_action}"),
+        );
+
+        let shop_tier =
+            |tier: usize| ConditionType::Shop(ShopCondition::Tier(Some(CondOrdering::Equal(tier))));
+        let conditional_all_action = Action::Conditional(
+            LogicType::All(vec![shop_tier(1), shop_tier(2)]),
+            Box::new(Action::Kill),
+            Box::new(Action::None),
+        );
+        assert_eq!(
+            "If Shop Tier(Some(Equal(1))) And Shop Tier(Some(Equal(2))) Then Faint. Otherwise, None.",
+            format!("{conditional_all_action}"),
+        );
+
+        let conditional_chain_action = Action::ConditionalChain(
+            vec![
+                (
+                    LogicType::Any(vec![shop_tier(1), shop_tier(2)]),
+                    Action::Kill,
+                ),
+                (LogicType::If(shop_tier(3)), Action::Endure),
+            ],
+            Box::new(Action::None),
+        );
+        assert_eq!(
+            "If Shop Tier(Some(Equal(1))) Or Shop Tier(Some(Equal(2))) Then Faint. Else If Shop Tier(Some(Equal(3))) Then Endure (Pepper). Otherwise, None.",
+            format!("{conditional_chain_action}"),
         )
     }
 }
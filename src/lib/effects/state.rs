@@ -5,11 +5,14 @@ use std::{
 };
 
 use crate::{
+    db::pack::Pack,
     effects::{effect::EntityName, stats::Statistics},
     pets::pet::Pet,
     shop::store::ShopState,
-    teams::team::TeamFightOutcome,
-    Food, PetCombat, Team, TeamShopping, TeamViewer,
+    teams::{counters::CounterKey, team::TeamFightOutcome},
+    toys::toy::Toy,
+    Attachments, Entity, Food, PetColumn, PetCombat, SAPQuery, Team, TeamShopping, TeamViewer,
+    SAPDB,
 };
 
 use super::actions::Action;
@@ -33,6 +36,23 @@ pub enum EqualityCondition {
     Frozen,
     /// Has perk. Pet only.
     HasPerk,
+    /// Was dealt damage. Pet only.
+    Hurt,
+    /// Has attack within this range.
+    Attack(RangeInclusive<isize>),
+    /// Has health within this range.
+    Health(RangeInclusive<isize>),
+    /// Has this many experience points. Pet only.
+    Experience(usize),
+    /// Triggered by exactly this [`Status`].
+    /// * Unlike [`EqualityCondition::Trigger`], any values within the [`Status`] must also match.
+    TriggerExact(Status),
+    /// Is from this [`Pack`]. Pet only.
+    Pack(Pack),
+    /// Has the highest attack of the pets checked. Pet only.
+    IsHighestAttack,
+    /// Has the lowest attack of the pets checked. Pet only.
+    IsLowestAttack,
 }
 
 impl EqualityCondition {
@@ -41,7 +61,25 @@ impl EqualityCondition {
             EqualityCondition::Tier(tier) => food.tier == *tier,
             EqualityCondition::Name(EntityName::Food(food_name)) => food.name == *food_name,
             EqualityCondition::Action(action) => food.ability.action == **action,
-            EqualityCondition::Trigger(trigger) => food.ability.trigger.status == *trigger,
+            EqualityCondition::Trigger(trigger) | EqualityCondition::TriggerExact(trigger) => {
+                food.ability.trigger.status == *trigger
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn matches_toy(&self, toy: &Toy) -> bool {
+        match self {
+            EqualityCondition::Tier(tier) => toy.tier == *tier,
+            EqualityCondition::Name(EntityName::Toy(toy_name)) => toy.name == *toy_name,
+            EqualityCondition::Level(lvl) => toy.lvl == *lvl,
+            EqualityCondition::Action(action) => {
+                toy.effect.iter().any(|effect| effect.action == **action)
+            }
+            EqualityCondition::Trigger(trigger) | EqualityCondition::TriggerExact(trigger) => toy
+                .effect
+                .iter()
+                .any(|effect| effect.trigger.status == *trigger),
             _ => false,
         }
     }
@@ -52,7 +90,24 @@ impl EqualityCondition {
             EqualityCondition::Name(EntityName::Pet(pet_name)) => pet.name == *pet_name,
             EqualityCondition::Action(action) => pet.has_effect_ability(action, false),
             EqualityCondition::Trigger(trigger) => pet.has_effect_trigger(trigger, false),
-            EqualityCondition::HasPerk => pet.item.as_ref().map_or(false, |item| item.holdable),
+            EqualityCondition::HasPerk => pet.items.perk.as_ref().is_some_and(|item| item.holdable),
+            EqualityCondition::Hurt => pet.hurt,
+            EqualityCondition::Attack(range) => range.contains(&pet.stats.attack),
+            EqualityCondition::Health(range) => range.contains(&pet.stats.health),
+            EqualityCondition::Experience(exp) => pet.exp == *exp,
+            EqualityCondition::TriggerExact(trigger) => pet
+                .effect
+                .iter()
+                .any(|effect| effect.trigger.status == *trigger),
+            EqualityCondition::Pack(pack) => {
+                let query = SAPQuery::builder()
+                    .set_table(Entity::Pet)
+                    .set_param(PetColumn::Name, vec![&pet.name])
+                    .set_param(PetColumn::Pack, vec![pack]);
+                SAPDB
+                    .execute_query(query)
+                    .is_ok_and(|records| !records.is_empty())
+            }
             _ => false,
         }
     }
@@ -118,7 +173,7 @@ pub enum TeamCondition {
     /// Counter.
     /// * If used for [`Position::FrontToBack`] and value is [`None`], returns current counter value.
     /// * If used for [`Action::Conditional`], checks if current counter value meets [`CondOrdering`].
-    Counter(String, Option<CondOrdering>),
+    Counter(CounterKey, Option<CondOrdering>),
     /// Number of turns.
     /// * If used for [`Position::FrontToBack`] and value is [`None`], returns current team turn.
     /// * If used for [`Action::Conditional`], checks if current turn meets [`CondOrdering`].
@@ -127,6 +182,9 @@ pub enum TeamCondition {
     NumberPerkPets(Option<CondOrdering>),
     /// Check number of toys.
     NumberToys(Option<CondOrdering>),
+    /// Pet at the front of the team meets an [`EqualityCondition`].
+    /// * If used for [`Position::FrontToBack`], returns `1` if the condition is met.
+    FrontPet(Box<EqualityCondition>),
 }
 
 impl TeamCondition {
@@ -150,7 +208,7 @@ impl TeamCondition {
             TeamCondition::NumberFaintedMultiple(multiple) => team.fainted.len() / multiple,
             TeamCondition::Counter(counter, counter_num) => counter_num
                 .as_ref()
-                .map_or_else(|| *team.counters.get(counter).unwrap_or(&0), get_inner_num),
+                .map_or_else(|| team.counters.get(counter).unwrap_or(0), get_inner_num),
             TeamCondition::NumberTurns(turns) => {
                 turns.as_ref().map_or(team.history.curr_turn, get_inner_num)
             }
@@ -166,6 +224,7 @@ impl TeamCondition {
             TeamCondition::NumberToys(num_toys) => num_toys
                 .as_ref()
                 .map_or_else(|| team.toys.len(), get_inner_num),
+            TeamCondition::FrontPet(_) => usize::from(self.matches_team(team)),
         }
     }
     /// Check if [`TeamCondition`] is met.
@@ -189,7 +248,7 @@ impl TeamCondition {
             TeamCondition::Counter(counter_name, cond_counts) => team
                 .counters
                 .get(counter_name)
-                .and_then(|count| cond_counts.as_ref().map(|cond| cond.check_true(*count)))
+                .and_then(|count| cond_counts.as_ref().map(|cond| cond.check_true(count)))
                 .unwrap_or(false),
             TeamCondition::NumberTurns(cond_turns) => {
                 cond_turns.as_ref().map_or(false, |cond_turns| {
@@ -213,6 +272,9 @@ impl TeamCondition {
                     cond_num_toys.check_true(team.toys.len())
                 })
             }
+            TeamCondition::FrontPet(cond) => team
+                .first()
+                .is_some_and(|pet| cond.matches_pet(&pet.read().unwrap())),
         }
     }
 }
@@ -226,6 +288,8 @@ pub enum ShopCondition {
     /// * If used for [`Position::FrontToBack`] and value is [`None`], returns current shop gold.
     /// * If used for [`Action::Conditional`], checks if current shop gold is equal to provided value.
     Gold(Option<CondOrdering>),
+    /// Current shop gold is at least this amount.
+    GoldAtLeast(usize),
     /// Shop tier.
     /// * If used for [`Position::FrontToBack`] and value is [`None`], returns current shop tier.
     /// * If used for [`Action::Conditional`], checks if current shop tier is equal to provided value.
@@ -234,6 +298,10 @@ pub enum ShopCondition {
     TierMultiple(usize),
     /// Number of pets sold.
     NumberSoldMultiple(usize),
+    /// Number of foods in the shop.
+    /// * If used for [`Position::FrontToBack`] and value is [`None`], returns current number of shop foods.
+    /// * If used for [`Action::Conditional`], checks if current number of shop foods meets [`CondOrdering`].
+    NumberFoods(Option<CondOrdering>),
 }
 
 impl ShopCondition {
@@ -249,6 +317,9 @@ impl ShopCondition {
             ShopCondition::TierMultiple(tier_multiple) => team.shop.tier() / tier_multiple,
             // Return divisor. Num times multiple goes into num sold pets.
             ShopCondition::NumberSoldMultiple(num_sold_mult) => team.sold.len() / num_sold_mult,
+            ShopCondition::NumberFoods(num_foods) => num_foods
+                .as_ref()
+                .map_or(team.shop.foods.len(), |cond| cond.into()),
             _ => panic!("Can't convert {self:?} to num."),
         }
     }
@@ -259,6 +330,7 @@ impl ShopCondition {
             ShopCondition::Gold(gold) => gold
                 .as_ref()
                 .map_or(false, |gold_cond| gold_cond.check_true(team.gold())),
+            ShopCondition::GoldAtLeast(min_gold) => team.gold() >= *min_gold,
             // Default to false if tier is None.
             ShopCondition::Tier(tier) => tier
                 .as_ref()
@@ -267,6 +339,9 @@ impl ShopCondition {
             ShopCondition::NumberSoldMultiple(sold_multiple) => {
                 team.sold.len() % sold_multiple == 0
             }
+            ShopCondition::NumberFoods(num_foods) => num_foods
+                .as_ref()
+                .is_some_and(|cond_foods| cond_foods.check_true(team.shop.foods.len())),
         }
     }
 }
@@ -376,6 +451,9 @@ pub enum Position {
     Adjacent,
     /// Select pets front-to-back based on a given condition.
     FrontToBack(FrontToBackCondition),
+    /// All frozen [`ShopItem`](crate::ShopItem)s.
+    /// * Only valid for a [`Shop`](crate::Shop).
+    Frozen,
     #[default]
     /// No position.
     None,
@@ -442,6 +520,38 @@ pub struct Outcome {
     #[serde(skip)]
     /// The shop food causing the status update.
     pub(crate) afflicting_food: Option<Weak<RwLock<Food>>>,
+    #[serde(skip)]
+    /// The toy causing the status update.
+    pub(crate) afflicting_toy: Option<Box<Toy>>,
+    #[serde(skip)]
+    /// The affected pet's state when attached to the trigger.
+    pub(crate) affected_snapshot: Option<Box<PetSnapshot>>,
+    #[serde(skip)]
+    /// The afflicting pet's state when attached to the trigger.
+    pub(crate) afflicting_snapshot: Option<Box<PetSnapshot>>,
+}
+
+/// State of a [`Pet`] captured when attached to an [`Outcome`].
+/// * Used by [`Position::TriggerAffected`] and [`Position::TriggerAfflicting`] effects whose pets may have since changed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PetSnapshot {
+    /// [`Statistics`] of the pet.
+    pub stats: Statistics,
+    /// Held items of the pet.
+    pub items: Attachments,
+}
+
+impl PetSnapshot {
+    /// Capture a pet's state.
+    /// * Not captured if the pet is currently being modified.
+    fn capture(pet: &Arc<RwLock<Pet>>) -> Option<Box<Self>> {
+        pet.try_read().ok().map(|pet| {
+            Box::new(PetSnapshot {
+                stats: pet.stats,
+                items: pet.items.clone(),
+            })
+        })
+    }
 }
 
 impl PartialEq for Outcome {
@@ -472,6 +582,9 @@ impl Default for Outcome {
             position: Position::None,
             stat_diff: None,
             afflicting_food: None,
+            afflicting_toy: None,
+            affected_snapshot: None,
+            afflicting_snapshot: None,
         }
     }
 }
@@ -493,6 +606,7 @@ impl Outcome {
     /// ```
     pub fn set_affected(&mut self, pet: &Arc<RwLock<Pet>>) -> &mut Self {
         self.affected_pet = Some(Arc::downgrade(pet));
+        self.affected_snapshot = PetSnapshot::capture(pet);
         self
     }
 
@@ -502,6 +616,12 @@ impl Outcome {
         self
     }
 
+    /// Attach the afflicting toy to this trigger.
+    pub fn set_afflicting_toy(&mut self, toy: &Toy) -> &mut Self {
+        self.afflicting_toy = Some(Box::new(toy.clone()));
+        self
+    }
+
     /// Attach the afflicting pet to this trigger.
     /// # Example.
     /// ```
@@ -519,9 +639,59 @@ impl Outcome {
     /// ```
     pub fn set_afflicting(&mut self, pet: &Arc<RwLock<Pet>>) -> &mut Self {
         self.afflicting_pet = Some(Arc::downgrade(pet));
+        self.afflicting_snapshot = PetSnapshot::capture(pet);
         self
     }
 
+    /// Get the pet that landed the killing blow for a knockout or faint trigger.
+    /// * For [`Status::KnockOut`], this is the affected pet. The knocked out pet is the afflicting pet.
+    /// * For [`Status::Faint`], this is the afflicting pet.
+    /// * Includes indirect damage from effects and held items. ex. Mosquito and Chili
+    /// # Example
+    /// ```
+    /// use std::sync::{Arc, RwLock};
+    /// use saptest::{Pet, PetName, effects::trigger::TRIGGER_KNOCKOUT};
+    ///
+    /// let ant = Arc::new(RwLock::new(Pet::try_from(PetName::Ant).unwrap()));
+    /// let rhino = Arc::new(RwLock::new(Pet::try_from(PetName::Rhino).unwrap()));
+    /// let mut knockout_trigger = TRIGGER_KNOCKOUT;
+    /// knockout_trigger.set_affected(&rhino).set_afflicting(&ant);
+    ///
+    /// assert!(Arc::ptr_eq(&knockout_trigger.knockout_pet().unwrap(), &rhino));
+    /// ```
+    pub fn knockout_pet(&self) -> Option<Arc<RwLock<Pet>>> {
+        match self.status {
+            Status::KnockOut => self.affected_pet.as_ref(),
+            Status::Faint => self.afflicting_pet.as_ref(),
+            _ => None,
+        }
+        .and_then(|pet| pet.upgrade())
+    }
+
+    /// Get the state of the affected pet when it was attached to this trigger.
+    /// # Example
+    /// ```
+    /// use std::sync::{Arc, RwLock};
+    /// use saptest::{Pet, PetName, Statistics, effects::trigger::TRIGGER_SELF_FAINT};
+    ///
+    /// let ant = Arc::new(RwLock::new(Pet::try_from(PetName::Ant).unwrap()));
+    /// let mut faint_trigger = TRIGGER_SELF_FAINT.clone();
+    /// faint_trigger.set_affected(&ant);
+    ///
+    /// // Stats at the time of the trigger are kept.
+    /// ant.write().unwrap().stats = Statistics::new(0, 0).unwrap();
+    /// let snapshot = faint_trigger.affected_snapshot().unwrap();
+    /// assert_eq!(snapshot.stats, Statistics::new(2, 2).unwrap());
+    /// ```
+    pub fn affected_snapshot(&self) -> Option<&PetSnapshot> {
+        self.affected_snapshot.as_deref()
+    }
+
+    /// Get the state of the afflicting pet when it was attached to this trigger.
+    pub fn afflicting_snapshot(&self) -> Option<&PetSnapshot> {
+        self.afflicting_snapshot.as_deref()
+    }
+
     /// Get the affected pet of a trigger.
     /// # Example
     /// ```
@@ -558,6 +728,8 @@ pub enum Status {
     StartOfBattle,
     /// After start of battle, prior to first battle.
     BeforeFirstBattle,
+    /// End of Battle. After the battle outcome is decided.
+    EndOfBattle,
     /// Before pet attacks.
     BeforeAttack,
     /// Pet is attacking.
@@ -603,6 +775,8 @@ pub enum Status {
     Pushed,
     /// Toy broke.
     BrokeToy,
+    /// Pet transformed into another pet.
+    Transformed,
     /// No status change.
     None,
 }
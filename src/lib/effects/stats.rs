@@ -28,7 +28,25 @@ impl Display for Statistics {
     }
 }
 
+impl From<(isize, isize)> for Statistics {
+    fn from((attack, health): (isize, isize)) -> Self {
+        Statistics { attack, health }
+    }
+}
+
 impl Statistics {
+    /// Minimum [`Statistics`] of a [`Pet`](crate::Pet).
+    pub const MIN: Statistics = Statistics {
+        attack: MIN_PET_STATS,
+        health: MIN_PET_STATS,
+    };
+
+    /// Maximum [`Statistics`] of a [`Pet`](crate::Pet).
+    pub const MAX: Statistics = Statistics {
+        attack: MAX_PET_STATS,
+        health: MAX_PET_STATS,
+    };
+
     /// Constructor method for [`Statistics`].
     ///
     /// # Examples
@@ -53,6 +71,67 @@ impl Statistics {
         Ok(Statistics { attack, health })
     }
 
+    /// Create [`Statistics`] with values restricted to [`Statistics::MIN`] and [`Statistics::MAX`].
+    /// # Examples
+    /// ```
+    /// use saptest::Statistics;
+    ///
+    /// assert_eq!(Statistics::saturating(-2, 60), Statistics::new(0, 50).unwrap());
+    /// ```
+    pub fn saturating(attack: isize, health: isize) -> Self {
+        Statistics {
+            attack: attack.clamp(MIN_PET_STATS, MAX_PET_STATS),
+            health: health.clamp(MIN_PET_STATS, MAX_PET_STATS),
+        }
+    }
+
+    /// Create [`Statistics`] only if values are within [`Statistics::MIN`] and [`Statistics::MAX`].
+    /// # Examples
+    /// ```
+    /// use saptest::Statistics;
+    ///
+    /// assert_eq!(Statistics::checked(2, 1), Some(Statistics::new(2, 1).unwrap()));
+    /// assert_eq!(Statistics::checked(2, 51), None);
+    /// ```
+    pub fn checked(attack: isize, health: isize) -> Option<Self> {
+        let stats_range = MIN_PET_STATS..=MAX_PET_STATS;
+        (stats_range.contains(&attack) && stats_range.contains(&health))
+            .then_some(Statistics { attack, health })
+    }
+
+    /// Get a percentage of these stats.
+    /// * Values are rounded and restricted to [`Statistics::MIN`] and [`Statistics::MAX`].
+    /// # Examples
+    /// ```
+    /// use saptest::Statistics;
+    ///
+    /// let gorilla_stats = Statistics::new(6, 9).unwrap();
+    /// assert_eq!(gorilla_stats.percent_of(50), Statistics::new(3, 5).unwrap());
+    /// ```
+    pub fn percent_of(&self, percent: isize) -> Self {
+        self.mult_perc(&Statistics {
+            attack: percent,
+            health: percent,
+        })
+    }
+
+    /// Scale these stats by some factor.
+    /// * Values are rounded and restricted to [`Statistics::MIN`] and [`Statistics::MAX`].
+    /// # Examples
+    /// ```
+    /// use saptest::Statistics;
+    ///
+    /// let stats = Statistics::new(3, 5).unwrap();
+    /// assert_eq!(stats.scale_by(1.5), Statistics::new(5, 8).unwrap());
+    /// assert_eq!(stats.scale_by(20.0), Statistics::MAX);
+    /// ```
+    pub fn scale_by(&self, factor: f32) -> Self {
+        Statistics::saturating(
+            (self.attack as f32 * factor).round() as isize,
+            (self.health as f32 * factor).round() as isize,
+        )
+    }
+
     /// Multiply stats by percentage values on rhs.
     pub fn mult_perc(&self, rhs: &Self) -> Self {
         let new_atk = (self.attack as f32 * (rhs.attack as f32 / 100.0)).round();
@@ -184,6 +263,110 @@ impl Statistics {
     }
 }
 
+/// Cap on the [`Statistics`] of a [`Pet`](crate::Pet).
+/// * Set with [`BattleRules::stat_cap`](crate::teams::combat::BattleRules::stat_cap).
+/// * Stats never fall below [`Statistics::MIN`].
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub enum StatCap {
+    /// Stats are restricted to a maximum value.
+    /// * Defaults to [`Statistics::MAX`].
+    Clamp(isize),
+    /// Stats have no maximum and saturate at the bounds of [`isize`].
+    Saturate,
+    /// Stats have no maximum and exceeding the bounds of [`isize`] is an error.
+    Overflow,
+}
+
+impl Default for StatCap {
+    fn default() -> Self {
+        StatCap::Clamp(MAX_PET_STATS)
+    }
+}
+
+impl StatCap {
+    /// Maximum value of `attack` and `health`.
+    /// # Examples
+    /// ```
+    /// use saptest::effects::stats::StatCap;
+    ///
+    /// assert_eq!(StatCap::default().max(), 50);
+    /// assert_eq!(StatCap::Saturate.max(), isize::MAX);
+    /// ```
+    pub fn max(&self) -> isize {
+        match self {
+            StatCap::Clamp(max) => *max,
+            StatCap::Saturate | StatCap::Overflow => isize::MAX,
+        }
+    }
+
+    /// Restrict [`Statistics`] to the cap.
+    /// # Examples
+    /// ```
+    /// use saptest::{Statistics, effects::stats::StatCap};
+    ///
+    /// let stats = Statistics::new(60, -1).unwrap();
+    /// assert_eq!(StatCap::Clamp(20).apply(stats), Statistics::new(20, 0).unwrap());
+    /// assert_eq!(StatCap::Saturate.apply(stats), Statistics::new(60, 0).unwrap());
+    /// ```
+    pub fn apply(&self, mut stats: Statistics) -> Statistics {
+        *stats.clamp(MIN_PET_STATS, self.max())
+    }
+
+    /// Add two [`Statistics`] and restrict the sum to the cap.
+    /// * Errors if using [`StatCap::Overflow`] and the sum exceeds the bounds of [`isize`].
+    /// # Examples
+    /// ```
+    /// use saptest::{Statistics, effects::stats::StatCap};
+    ///
+    /// let stats = Statistics::new(45, 45).unwrap();
+    /// let buff = Statistics::new(10, 1).unwrap();
+    /// assert_eq!(StatCap::default().add(stats, buff).unwrap(), Statistics::new(50, 46).unwrap());
+    /// assert_eq!(StatCap::Overflow.add(stats, buff).unwrap(), Statistics::new(55, 46).unwrap());
+    /// assert!(StatCap::Overflow.add(Statistics::new(isize::MAX, 1).unwrap(), buff).is_err());
+    /// ```
+    pub fn add(&self, stats: Statistics, rhs: Statistics) -> Result<Statistics, SAPTestError> {
+        self.combine(stats, rhs, isize::checked_add, isize::saturating_add)
+    }
+
+    /// Subtract two [`Statistics`] and restrict the difference to the cap.
+    /// * Errors if using [`StatCap::Overflow`] and the difference exceeds the bounds of [`isize`].
+    /// # Examples
+    /// ```
+    /// use saptest::{Statistics, effects::stats::StatCap};
+    ///
+    /// let stats = Statistics::new(80, 2).unwrap();
+    /// let debuff = Statistics::new(5, 5).unwrap();
+    /// assert_eq!(StatCap::default().sub(stats, debuff).unwrap(), Statistics::new(50, 0).unwrap());
+    /// assert_eq!(StatCap::Saturate.sub(stats, debuff).unwrap(), Statistics::new(75, 0).unwrap());
+    /// ```
+    pub fn sub(&self, stats: Statistics, rhs: Statistics) -> Result<Statistics, SAPTestError> {
+        self.combine(stats, rhs, isize::checked_sub, isize::saturating_sub)
+    }
+
+    fn combine(
+        &self,
+        stats: Statistics,
+        rhs: Statistics,
+        checked_op: fn(isize, isize) -> Option<isize>,
+        saturating_op: fn(isize, isize) -> isize,
+    ) -> Result<Statistics, SAPTestError> {
+        let op = |value: isize, rhs_value: isize| {
+            if *self == StatCap::Overflow {
+                checked_op(value, rhs_value).ok_or(SAPTestError::InvalidPetAction {
+                    subject: "Stat Overflow".to_string(),
+                    reason: format!("Combining {stats} and {rhs} overflowed."),
+                })
+            } else {
+                Ok(saturating_op(value, rhs_value))
+            }
+        };
+        Ok(self.apply(Statistics {
+            attack: op(stats.attack, rhs.attack)?,
+            health: op(stats.health, rhs.health)?,
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Statistics;
@@ -253,4 +436,20 @@ mod tests {
         s1 *= s2;
         assert_eq!(product, s1)
     }
+
+    #[test]
+    fn test_stats_bounded() {
+        assert_eq!(Statistics::from((2, 1)), Statistics::new(2, 1).unwrap());
+        assert_eq!(
+            Statistics::saturating(-1, 100),
+            Statistics::new(0, 50).unwrap()
+        );
+        assert_eq!(
+            Statistics::checked(50, 0),
+            Some(Statistics::new(50, 0).unwrap())
+        );
+        assert_eq!(Statistics::checked(-1, 0), None);
+        assert_eq!(Statistics::MAX.scale_by(2.0), Statistics::MAX);
+        assert_eq!(Statistics::MAX.percent_of(0), Statistics::MIN);
+    }
 }
@@ -55,6 +55,18 @@ pub fn get_summon_triggers(pet: Weak<RwLock<Pet>>) -> [Outcome; 3] {
     [self_trigger, any_trigger, any_enemy_trigger]
 }
 
+/// Get transform triggers for a transformed [`Pet`].
+/// * The original pet is the afflicting pet.
+pub fn get_transform_triggers(pet: Weak<RwLock<Pet>>, original: Weak<RwLock<Pet>>) -> [Outcome; 2] {
+    let mut self_trigger = TRIGGER_SELF_TRANSFORMED;
+    let mut any_trigger = TRIGGER_ANY_TRANSFORMED;
+
+    (self_trigger.affected_pet, any_trigger.affected_pet) = (Some(pet.clone()), Some(pet));
+    (self_trigger.afflicting_pet, any_trigger.afflicting_pet) =
+        (Some(original.clone()), Some(original));
+    [self_trigger, any_trigger]
+}
+
 /// Wrapper for [`Vec<Outcomes>`].
 pub struct Outcomes(Vec<Outcome>);
 
@@ -147,6 +159,9 @@ pub const TRIGGER_NO_ENEMIES_LEFT: Outcome = Outcome {
     position: Position::None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger for when one pet left on team.
@@ -159,6 +174,9 @@ pub const TRIGGER_ONE_OR_ZERO_PET_LEFT: Outcome = Outcome {
     position: Position::None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Start of battle trigger.
@@ -169,6 +187,9 @@ pub const TRIGGER_START_BATTLE: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::None,
     afflicting_team: Target::None,
 };
@@ -182,6 +203,25 @@ pub const TRIGGER_BEFORE_FIRST_BATTLE: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
+    affected_team: Target::None,
+    afflicting_team: Target::None,
+};
+
+/// End of battle trigger.
+/// * Activated once the battle outcome is decided.
+pub const TRIGGER_END_BATTLE: Outcome = Outcome {
+    status: Status::EndOfBattle,
+    position: Position::None,
+    affected_pet: None,
+    afflicting_pet: None,
+    stat_diff: None,
+    afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::None,
     afflicting_team: Target::None,
 };
@@ -194,6 +234,9 @@ pub const TRIGGER_START_TURN: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::None,
     afflicting_team: Target::None,
 };
@@ -206,6 +249,9 @@ pub const TRIGGER_END_TURN: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::None,
     afflicting_team: Target::None,
 };
@@ -221,6 +267,9 @@ pub const TRIGGER_DMG_CALC: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Triggers for only attack dmg calculation.
@@ -234,6 +283,9 @@ pub const TRIGGER_ATK_DMG_CALC: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Triggers for only indirect attack calculation.
@@ -247,6 +299,9 @@ pub const TRIGGER_INDIR_DMG_CALC: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger for nothing?
@@ -258,6 +313,9 @@ pub const TRIGGER_NONE: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::None,
     afflicting_team: Target::None,
 };
@@ -270,6 +328,9 @@ pub const TRIGGER_SELF_UNHURT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -282,6 +343,9 @@ pub const TRIGGER_SELF_FAINT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -294,6 +358,9 @@ pub const TRIGGER_SELF_LEVELUP: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -307,6 +374,9 @@ pub const TRIGGER_ANY_FAINT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -320,6 +390,9 @@ pub const TRIGGER_ANY_ENEMY_FAINT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Enemy,
     afflicting_team: Target::None,
 };
@@ -333,6 +406,9 @@ pub const TRIGGER_KNOCKOUT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -345,6 +421,9 @@ pub const TRIGGER_SPEC_ENEMY_FAINT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Enemy,
     afflicting_team: Target::None,
 };
@@ -357,6 +436,9 @@ pub const TRIGGER_AHEAD_FAINT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -369,6 +451,9 @@ pub const TRIGGER_SELF_HURT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -382,6 +467,9 @@ pub const TRIGGER_ANY_HURT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -395,6 +483,9 @@ pub const TRIGGER_ANY_ENEMY_HURT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Enemy,
     afflicting_team: Target::None,
 };
@@ -411,6 +502,9 @@ pub const TRIGGER_BATTLE_FOOD: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -423,6 +517,9 @@ pub const TRIGGER_SELF_ATTACK: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -435,6 +532,9 @@ pub const TRIGGER_SELF_BEFORE_ATTACK: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -448,6 +548,9 @@ pub const TRIGGER_SELF_AFTER_ATTACK: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -461,6 +564,9 @@ pub const TRIGGER_ANY_BEFORE_ATTACK: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -473,6 +579,9 @@ pub const TRIGGER_AHEAD_ATTACK: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -486,6 +595,9 @@ pub const TRIGGER_AHEAD_HURT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -498,6 +610,9 @@ pub const TRIGGER_SELF_SUMMON: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -510,6 +625,9 @@ pub const TRIGGER_ANY_SUMMON: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -522,6 +640,9 @@ pub const TRIGGER_ANY_ENEMY_SUMMON: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Enemy,
     afflicting_team: Target::None,
 };
@@ -534,6 +655,9 @@ pub const TRIGGER_ANY_PUSHED: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -546,10 +670,44 @@ pub const TRIGGER_ANY_ENEMY_PUSHED: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Enemy,
     afflicting_team: Target::None,
 };
 
+/// Trigger for when the current [`Pet`] transforms into another pet.
+/// * The affected pet is the transformed pet.
+pub const TRIGGER_SELF_TRANSFORMED: Outcome = Outcome {
+    status: Status::Transformed,
+    position: Position::OnSelf,
+    affected_pet: None,
+    afflicting_pet: None,
+    stat_diff: None,
+    afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
+    affected_team: Target::Friend,
+    afflicting_team: Target::None,
+};
+
+/// Trigger for when any friendly [`Pet`] transforms into another pet.
+pub const TRIGGER_ANY_TRANSFORMED: Outcome = Outcome {
+    status: Status::Transformed,
+    position: Position::Any(ItemCondition::None),
+    affected_pet: None,
+    afflicting_pet: None,
+    stat_diff: None,
+    afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
+    affected_team: Target::Friend,
+    afflicting_team: Target::None,
+};
+
 /// Trigger for when any friend [`Pet`] levels up.
 pub const TRIGGER_ANY_LEVELUP: Outcome = Outcome {
     status: Status::Levelup,
@@ -558,6 +716,9 @@ pub const TRIGGER_ANY_LEVELUP: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
@@ -571,6 +732,9 @@ pub const TRIGGER_ANY_GAIN_AILMENT: Outcome = Outcome {
     afflicting_pet: None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
     affected_team: Target::Friend,
     afflicting_team: Target::None,
 };
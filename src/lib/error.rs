@@ -21,10 +21,12 @@ pub enum SAPTestError {
     InvalidRequestFailure(#[from] std::io::Error),
 
     /// Failure to execute query from SQLite database.
+    #[cfg(feature = "sqlite")]
     #[error("Failed database query execution.")]
     DatabaseFailure(#[from] rusqlite::Error),
 
     /// Failure to initalize pooled connection with SQLite database.
+    #[cfg(feature = "sqlite")]
     #[error("Failed to initialize database.")]
     DatabasePoolFailure(#[from] r2d2::Error),
 
@@ -82,6 +84,16 @@ pub enum SAPTestError {
         reason: String,
     },
 
+    /// Triggers kept activating past a [`Team`](crate::Team)'s trigger budget.
+    /// * Set with [`Team::set_trigger_budget`](crate::Team::set_trigger_budget).
+    #[error("Trigger budget of {budget} exceeded. Repeating triggers: {cycle:?}")]
+    TriggerLoop {
+        /// Maximum number of triggers allowed.
+        budget: usize,
+        /// Most recent triggers forming a repeating cycle, oldest first.
+        cycle: Vec<String>,
+    },
+
     /// Fallible action used in trigger_effects. Never invoked otherwise.
     #[error("Fallible action.")]
     FallibleAction,
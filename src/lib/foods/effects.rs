@@ -1,9 +1,12 @@
 use crate::{
     db::record::FoodRecord,
     effects::{
-        actions::{Action, RandomizeType, StatChangeType, SummonType},
+        actions::{
+            Action, ConditionType, GainType, GoldChangeType, LogicType, RandomizeType,
+            StatChangeType, SummonType,
+        },
         effect::Effect,
-        state::{ItemCondition, Position, Target},
+        state::{EqualityCondition, ItemCondition, Position, Target},
         stats::Statistics,
         trigger::*,
     },
@@ -14,6 +17,7 @@ use crate::{
         pet::{MAX_PET_STATS, MIN_PET_STATS},
     },
     shop::trigger::TRIGGER_SELF_FOOD_EATEN,
+    teams::counters::CounterKey,
 };
 
 /// May need to be similar to Pet effects as `Vec<Effect>` at some point.
@@ -28,7 +32,7 @@ impl TryFrom<&FoodRecord> for Effect {
         let uses = record.single_use.then_some(1);
 
         Ok(match record.name {
-            FoodName::Chili => Effect {
+            FoodName::Chili | FoodName::Skewer => Effect {
                 owner: None,
                 target: Target::Enemy,
                 // Next enemy relative to current pet position.
@@ -37,6 +41,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_BATTLE_FOOD,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Coconut => Effect {
                 owner: None,
@@ -46,8 +51,9 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_DMG_CALC,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
-            FoodName::Garlic | FoodName::Lemon => Effect {
+            FoodName::Garlic | FoodName::Lemon | FoodName::Lettuce | FoodName::Avocado => Effect {
                 owner: None,
                 target: Target::Friend,
                 position: Position::OnSelf,
@@ -55,6 +61,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_DMG_CALC,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Honey => Effect {
                 owner: None,
@@ -64,6 +71,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_SELF_FAINT,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::MeatBone => Effect {
                 owner: None,
@@ -73,6 +81,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_ATK_DMG_CALC,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Melon => Effect {
                 owner: None,
@@ -82,6 +91,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_DMG_CALC,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Mushroom => Effect {
                 owner: None,
@@ -98,6 +108,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_SELF_FAINT,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Peanut => Effect {
                 owner: None,
@@ -107,6 +118,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_ATK_DMG_CALC,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Steak => Effect {
                 owner: None,
@@ -116,6 +128,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 uses,
                 trigger: TRIGGER_ATK_DMG_CALC,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Weak => {
                 // Invert attack to health and reverse sign so additional damage taken.
@@ -131,6 +144,7 @@ impl TryFrom<&FoodRecord> for Effect {
                     action: Action::Remove(StatChangeType::Static(vulnerable_stats)),
                     uses,
                     temp: record.end_of_battle,
+                    ..Default::default()
                 }
             }
             FoodName::SleepingPill => Effect {
@@ -141,6 +155,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Kill,
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Croissant | FoodName::Cucumber | FoodName::Carrot => Effect {
                 owner: None,
@@ -150,15 +165,17 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Grapes => Effect {
                 owner: None,
                 trigger: TRIGGER_START_TURN,
                 target: Target::Shop,
                 position: Position::None,
-                action: Action::AlterGold(1),
+                action: Action::AlterGold(GoldChangeType::Static(1)),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Chocolate => Effect {
                 owner: None,
@@ -168,6 +185,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Experience(1),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Pepper => Effect {
                 owner: None,
@@ -177,6 +195,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Endure,
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::CannedFood => Effect {
                 owner: None,
@@ -186,6 +205,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::AddShopStats(effect_stats),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::FortuneCookie => Effect {
                 owner: None,
@@ -195,6 +215,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Critical(50),
                 uses: None,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Cheese => Effect {
                 owner: None,
@@ -204,6 +225,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Critical(100),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Pineapple => Effect {
                 owner: None,
@@ -213,6 +235,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::SaladBowl
             | FoodName::Sushi
@@ -234,8 +257,150 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Egg => Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: 1,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Blueberry => Effect {
+                owner: None,
+                trigger: TRIGGER_NONE,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: record.n_targets,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Multiple(vec![
+                    Action::Add(StatChangeType::Static(effect_stats)),
+                    Action::Gain(GainType::DefaultItem(FoodName::Strawberry)),
+                ]),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Cherry => Effect {
+                owner: None,
+                trigger: TRIGGER_START_TURN,
+                target: Target::Shop,
+                position: Position::None,
+                action: Action::AlterGold(GoldChangeType::Static(1)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::ChocolateCake => Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FOOD_EATEN,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Multiple(vec![Action::Experience(1), Action::Kill]),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Rice => Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FOOD_EATEN,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                // Count this rice before adding stats.
+                action: Action::Multiple(vec![
+                    Action::AddToCounter(CounterKey::RiceEaten, 1),
+                    Action::Add(StatChangeType::TeamCounter(CounterKey::RiceEaten)),
+                ]),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Eggplant => Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::SwapEnemy(Position::First),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Waffle => {
+                // Stats per level of the pet eating the waffle.
+                let level_stats = |lvl: usize| -> Result<(LogicType, Action), SAPTestError> {
+                    Ok((
+                        LogicType::If(ConditionType::Pet(
+                            Target::Friend,
+                            ItemCondition::Equal(EqualityCondition::Level(lvl)),
+                        )),
+                        Action::Add(StatChangeType::Static(
+                            effect_stats * Statistics::new(lvl, lvl)?,
+                        )),
+                    ))
+                };
+                Effect {
+                    owner: None,
+                    trigger: TRIGGER_SELF_FOOD_EATEN,
+                    target: Target::Friend,
+                    position: Position::OnSelf,
+                    action: Action::ConditionalChain(
+                        vec![level_stats(3)?, level_stats(2)?],
+                        Box::new(Action::Add(StatChangeType::Static(effect_stats))),
+                    ),
+                    uses,
+                    temp: record.end_of_battle,
+                    ..Default::default()
+                }
+            }
+            FoodName::Donut => Effect {
+                owner: None,
+                trigger: TRIGGER_NONE,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::NotEqual(EqualityCondition::HasPerk),
+                    targets: record.n_targets,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Pancakes => Effect {
+                owner: None,
+                trigger: TRIGGER_NONE,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Salt => Effect {
+                owner: None,
+                trigger: TRIGGER_ATK_DMG_CALC,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                // Double damage.
+                action: Action::LowerTierDamage(100),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Apple
+            | FoodName::Pie
             | FoodName::Pear
             | FoodName::Bacon
             | FoodName::Cookie
@@ -253,6 +418,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Strawberry => Effect {
                 owner: None,
@@ -262,6 +428,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::None,
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Lollipop => Effect {
                 owner: None,
@@ -271,6 +438,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Swap(RandomizeType::Stats),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Popcorn => Effect {
                 owner: None,
@@ -280,6 +448,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::Summon(SummonType::SelfTierPet(None, None)),
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
             FoodName::Ink => {
                 // Invert attack to health and reverse so reduced attack done.
@@ -295,28 +464,74 @@ impl TryFrom<&FoodRecord> for Effect {
                     action: Action::Remove(StatChangeType::Static(attack_stats)),
                     uses,
                     temp: record.end_of_battle,
+                    ..Default::default()
                 }
             }
-            FoodName::Egg => todo!(),
-            FoodName::Blueberry => todo!(),
-            FoodName::Cherry => todo!(),
-            FoodName::ChocolateCake => todo!(),
-            FoodName::Rice => todo!(),
-            FoodName::Avocado => todo!(),
-            FoodName::Eggplant => todo!(),
-            FoodName::Lettuce => todo!(),
-            FoodName::Banana => todo!(),
-            FoodName::Potato => todo!(),
-            FoodName::Waffle => todo!(),
-            FoodName::Pie => todo!(),
-            FoodName::Salt => todo!(),
-            FoodName::Donut => todo!(),
-            FoodName::Onion => todo!(),
-            FoodName::PitaBread => todo!(),
-            FoodName::Pretzel => todo!(),
-            FoodName::Tomato => todo!(),
-            FoodName::Pancakes => todo!(),
-            FoodName::Skewer => todo!(),
+            FoodName::Banana => Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::TriggerAffected(None),
+                action: Action::Summon(SummonType::CustomPet(
+                    PetName::Monkey,
+                    StatChangeType::Static(effect_stats),
+                    1,
+                )),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Potato => Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                // Friend behind.
+                position: Position::Nearest(-1),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Onion => Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Push(Position::Last),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::PitaBread => Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Pretzel => Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_BEFORE_ATTACK,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
+            FoodName::Tomato => Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::Last,
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses,
+                temp: record.end_of_battle,
+                ..Default::default()
+            },
             _ => Effect {
                 owner: None,
                 trigger: TRIGGER_NONE,
@@ -325,6 +540,7 @@ impl TryFrom<&FoodRecord> for Effect {
                 action: Action::None,
                 uses,
                 temp: record.end_of_battle,
+                ..Default::default()
             },
         })
     }
@@ -4,12 +4,89 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     db::{query::SAPQuery, record::FoodRecord},
-    effects::effect::Effect,
+    effects::{
+        actions::{Action, StatChangeType},
+        effect::Effect,
+    },
     error::SAPTestError,
     foods::names::FoodName,
-    Entity, SAPDB,
+    pets::pet::{MAX_PET_LEVEL, MIN_PET_LEVEL},
+    Entity, FoodColumn, Statistics, SAPDB,
 };
 
+/// Kind of item a [`Food`] is when held by a [`Pet`](crate::Pet).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// A food or perk.
+    #[default]
+    Food,
+    /// An ailment.
+    /// * Ailments are held alongside perks and are not removed by eating foods.
+    /// * Gaining an ailment replaces any previous ailment.
+    /// * Ex. [`FoodName::Ink`]
+    Ailment,
+}
+
+/// [`Food`] items attached to a [`Pet`](crate::Pet).
+/// * A pet can hold one perk and one ailment at the same time.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Attachments {
+    /// Held perk.
+    pub perk: Option<Box<Food>>,
+    /// Held ailment.
+    pub ailment: Option<Box<Food>>,
+}
+
+impl Attachments {
+    /// Attach an item to its slot based on its [`ItemKind`].
+    /// * Returns the replaced item, if any.
+    /// # Example
+    /// ```
+    /// use saptest::{Food, FoodName, foods::food::Attachments};
+    ///
+    /// let mut items = Attachments::default();
+    /// items.attach(Food::try_from(FoodName::Melon).unwrap());
+    /// items.attach(Food::try_from(FoodName::Weak).unwrap());
+    ///
+    /// assert_eq!(items.perk.as_ref().unwrap().name, FoodName::Melon);
+    /// assert_eq!(items.ailment.as_ref().unwrap().name, FoodName::Weak);
+    /// ```
+    pub fn attach(&mut self, item: Food) -> Option<Food> {
+        let slot = match item.item_kind {
+            ItemKind::Food => &mut self.perk,
+            ItemKind::Ailment => &mut self.ailment,
+        };
+        slot.replace(Box::new(item)).map(|item| *item)
+    }
+
+    /// Iterate through attached items. Perks are first.
+    pub fn iter(&self) -> impl Iterator<Item = &Food> {
+        self.perk
+            .iter()
+            .chain(self.ailment.iter())
+            .map(|item| item.as_ref())
+    }
+
+    /// Mutably iterate through attached items. Perks are first.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Food> {
+        self.perk
+            .iter_mut()
+            .chain(self.ailment.iter_mut())
+            .map(|item| item.as_mut())
+    }
+
+    /// Check if no items are attached.
+    pub fn is_empty(&self) -> bool {
+        self.perk.is_none() && self.ailment.is_none()
+    }
+
+    /// Remove all attached items.
+    pub fn clear(&mut self) {
+        self.perk = None;
+        self.ailment = None;
+    }
+}
+
 /// A Super Auto Pets food.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Food {
@@ -23,13 +100,17 @@ pub struct Food {
     pub holdable: bool,
     /// Whether an effect is temporary.
     pub temp: bool,
+    /// Number of shop turns the food is held before it expires.
+    /// * `None` indicates the food never expires.
+    #[serde(default)]
+    pub duration: Option<usize>,
     /// The cost of a food.
     pub cost: usize,
     /// Number of targets this food affects.
     pub n_targets: usize,
-    /// Whether this food is an ailment.
-    /// * Ex. [`FoodName::Ink`]
-    pub is_ailment: bool,
+    /// Kind of item.
+    #[serde(default)]
+    pub item_kind: ItemKind,
 }
 impl TryFrom<FoodName> for Food {
     type Error = SAPTestError;
@@ -78,7 +159,7 @@ impl Food {
             } else {
                 let food_query = SAPQuery::builder()
                     .set_table(Entity::Food)
-                    .set_param("name", vec![name]);
+                    .set_param(FoodColumn::Name, vec![name]);
 
                 let food_record: FoodRecord = SAPDB
                     .execute_query(food_query)?
@@ -105,10 +186,116 @@ impl Food {
             tier: food_record.tier,
             ability: effect,
             temp: food_record.single_use,
+            duration: None,
             holdable: food_record.holdable,
             cost: food_record.cost,
             n_targets: food_record.n_targets,
-            is_ailment: food_record.is_ailment,
+            item_kind: if food_record.is_ailment {
+                ItemKind::Ailment
+            } else {
+                ItemKind::Food
+            },
         })
     }
+
+    /// Create a `Food` from a [`FoodName`] with effects scaled to the level of the [`Pet`](crate::Pet) granting it.
+    /// * Foods are stored at level 1 so static stats added or removed by the food are multiplied by `lvl`.
+    ///     * Ex. A level 2 [`Cow`](crate::PetName::Cow) gives [`Milk`](FoodName::Milk) with double the stats.
+    /// * Foods with other effects are unchanged.
+    /// * Errors if `lvl` is not a valid pet level.
+    /// # Example
+    /// ```
+    /// use saptest::{
+    ///     effects::actions::{Action, StatChangeType},
+    ///     Food, FoodName, Statistics,
+    /// };
+    ///
+    /// let milk = Food::try_from(FoodName::Milk).unwrap();
+    /// let lvl_3_milk = Food::new_at_level(&FoodName::Milk, 3).unwrap();
+    ///
+    /// let (
+    ///     Action::Add(StatChangeType::Static(milk_stats)),
+    ///     Action::Add(StatChangeType::Static(lvl_3_milk_stats))
+    /// ) = (milk.ability.action, lvl_3_milk.ability.action) else {
+    ///     panic!("Milk should add stats.")
+    /// };
+    /// assert_eq!(milk_stats * Statistics::new(3, 3).unwrap(), lvl_3_milk_stats);
+    /// assert!(Food::new_at_level(&FoodName::Milk, 4).is_err());
+    /// ```
+    pub fn new_at_level(name: &FoodName, lvl: usize) -> Result<Food, SAPTestError> {
+        if !(MIN_PET_LEVEL..=MAX_PET_LEVEL).contains(&lvl) {
+            return Err(SAPTestError::QueryFailure {
+                subject: "Invalid Food Level".to_string(),
+                reason: format!("{name} has no effect at level {lvl}."),
+            });
+        }
+        let mut food = Food::new(name, None)?;
+        let lvl_multiplier = Statistics::new(lvl, lvl)?;
+        if let Action::Add(StatChangeType::Static(stats))
+        | Action::Remove(StatChangeType::Static(stats)) = &mut food.ability.action
+        {
+            *stats *= lvl_multiplier
+        }
+        Ok(food)
+    }
+
+    /// Build a custom food.
+    /// * Custom foods have a `tier` of `0` and target a single pet.
+    /// * `single_use` foods are consumed after their effect activates.
+    /// # Example
+    /// ```rust
+    /// use saptest::{
+    ///     effects::{
+    ///         actions::{Action, StatChangeType},
+    ///         state::{Position, Target},
+    ///         trigger::TRIGGER_NONE,
+    ///     },
+    ///     Effect, Food, FoodName, Shop, ShopItem, Statistics,
+    /// };
+    /// let big_snack_effect = Effect::new(
+    ///     TRIGGER_NONE,
+    ///     Target::Friend,
+    ///     Position::OnSelf,
+    ///     Action::Add(StatChangeType::Static(Statistics::new(5, 5).unwrap())),
+    ///     Some(1),
+    ///     false,
+    /// );
+    /// let big_snack = Food::custom("BigSnack", 4, big_snack_effect, false, true);
+    /// assert_eq!(big_snack.name, FoodName::Custom("BigSnack".to_string()));
+    /// assert_eq!(big_snack.cost, 4);
+    ///
+    /// // Stock the custom food in a shop.
+    /// let mut shop = Shop::default();
+    /// assert!(shop.add_item(ShopItem::new(big_snack)).is_ok());
+    /// ```
+    pub fn custom(
+        name: &str,
+        cost: usize,
+        effect: Effect,
+        holdable: bool,
+        single_use: bool,
+    ) -> Food {
+        Food {
+            name: FoodName::Custom(name.to_string()),
+            tier: 0,
+            ability: effect,
+            holdable,
+            temp: single_use,
+            duration: None,
+            cost,
+            n_targets: 1,
+            item_kind: ItemKind::Food,
+        }
+    }
+
+    /// Check if this food is an ailment.
+    /// # Example
+    /// ```
+    /// use saptest::{Food, FoodName};
+    /// assert!(Food::try_from(FoodName::Ink).unwrap().is_ailment());
+    /// assert!(!Food::try_from(FoodName::Garlic).unwrap().is_ailment());
+    /// ```
+    pub fn is_ailment(&self) -> bool {
+        self.item_kind == ItemKind::Ailment
+    }
 }
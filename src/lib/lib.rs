@@ -162,39 +162,58 @@ pub mod visualization;
 
 #[doc(inline)]
 pub use crate::effects::{
-    effect::{Effect, Entity, EntityName},
+    effect::{Effect, EffectBuilder, Entity, EntityName},
     state::{ItemCondition, Position},
     stats::Statistics,
 };
 #[doc(inline)]
-pub use crate::teams::{combat::TeamCombat, effects::TeamEffects, team::Team, viewer::TeamViewer};
+pub use crate::teams::{
+    combat::TeamCombat,
+    effects::TeamEffects,
+    team::{KnockoutRecord, ShopRecord, Team},
+    viewer::TeamViewer,
+};
 
 use crate::config::{LibConfig, CONFIG_PATH, DEFAULT_CONFIG};
 #[doc(inline)]
-pub use crate::db::{query::SAPQuery, setup::SapDB};
+pub use crate::db::{
+    column::{FoodColumn, PetColumn, ToyColumn},
+    query::SAPQuery,
+    setup::SapDB,
+};
 #[doc(inline)]
-pub use crate::foods::{food::Food, names::FoodName};
+pub use crate::foods::{
+    food::{Attachments, Food, ItemKind},
+    names::FoodName,
+};
 #[doc(inline)]
 pub use crate::pets::{combat::PetCombat, names::PetName, pet::Pet};
 #[doc(inline)]
 pub use crate::shop::{
-    store::{Shop, ShopItem},
+    economy::Economy,
+    store::{Shop, ShopItem, SlotOdds},
+    strategy::{ShopAction, ShopStrategy},
     team_shopping::TeamShopping,
-    viewer::{ShopItemViewer, ShopViewer},
+    viewer::{ShopItemFilter, ShopItemViewer, ShopViewer},
 };
 #[doc(inline)]
-pub use crate::toys::{names::ToyName, toy::Toy};
+pub use crate::toys::{names::ToyName, team_toys::TeamToys, toy::Toy};
 
 #[doc(inline)]
 pub use crate::visualization::{digraph::create_battle_digraph, tsv::create_battle_df};
 
 #[doc = include_str!("../../README.md")]
+// Wiki pages are only scraped into sqlite databases.
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
 mod config;
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
 mod regex_patterns;
 #[cfg(test)]
 mod tests;
+#[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
 mod wiki_scraper;
 
+#[cfg(feature = "sqlite")]
 const DB_FNAME: &str = "./sap.db";
 const ENV_SAPTEST_CONFIG: &str = "CONFIG_SAPTEST";
 
@@ -212,7 +231,5 @@ lazy_static! {
 
     #[doc(hidden)]
     /// Global pooled database.
-    pub static ref SAPDB: SapDB = SapDB::new(
-        CONFIG.database.filename.as_ref().unwrap_or(&DB_FNAME.to_owned())
-    ).unwrap();
+    pub static ref SAPDB: SapDB = SapDB::from_config().unwrap();
 }
@@ -0,0 +1,111 @@
+use crate::{error::SAPTestError, teams::team::TeamFightOutcome, Pet, PetName, Team, TeamCombat};
+
+/// Number of slots on each board.
+const BOARD_SIZE: usize = 5;
+/// Maximum number of fights in a single battle before it's counted as a draw.
+const MAX_BATTLE_FIGHTS: usize = 100;
+
+/// Outcomes of many battles between two boards.
+/// * Outcomes are from the perspective of the first board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HeadToHead {
+    /// Number of battles simulated.
+    pub n_sims: usize,
+    /// Number of battles won.
+    pub wins: usize,
+    /// Number of battles lost.
+    pub losses: usize,
+    /// Number of battles drawn.
+    pub draws: usize,
+}
+
+impl HeadToHead {
+    /// Fraction of battles won.
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    /// Fraction of battles lost.
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.losses)
+    }
+
+    /// Fraction of battles drawn.
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        if self.n_sims == 0 {
+            0.0
+        } else {
+            count as f64 / self.n_sims as f64
+        }
+    }
+}
+
+/// Battle two single pets at the same level against each other over many seeds.
+/// * See [`board_head_to_head`].
+/// # Example
+/// ```
+/// use saptest::{PetName, pets::analysis::head_to_head};
+///
+/// let results = head_to_head(&PetName::Gorilla, &PetName::Ant, 1, 10).unwrap();
+/// assert_eq!(results.n_sims, 10);
+/// assert_eq!(results.win_rate(), 1.0);
+/// ```
+pub fn head_to_head(
+    pet_a: &PetName,
+    pet_b: &PetName,
+    lvl: usize,
+    n_sims: usize,
+) -> Result<HeadToHead, SAPTestError> {
+    board_head_to_head(
+        &[Some(Pet::new(pet_a.clone(), None, lvl)?)],
+        &[Some(Pet::new(pet_b.clone(), None, lvl)?)],
+        n_sims,
+    )
+}
+
+/// Battle two boards of pets against each other over many seeds.
+/// * Boards have up to five pets.
+/// * Each battle `i` seeds both teams with `i`. Results are reproducible for the same boards.
+/// * Battles that don't end after many fights are counted as draws.
+/// # Example
+/// ```
+/// use saptest::{Pet, PetName, pets::analysis::board_head_to_head};
+///
+/// let ants = vec![Some(Pet::try_from(PetName::Ant).unwrap()); 3];
+/// let mosquitoes = vec![Some(Pet::try_from(PetName::Mosquito).unwrap()); 3];
+///
+/// let results = board_head_to_head(&ants, &mosquitoes, 20).unwrap();
+/// assert_eq!(results.wins + results.losses + results.draws, 20);
+/// ```
+pub fn board_head_to_head(
+    board_a: &[Option<Pet>],
+    board_b: &[Option<Pet>],
+    n_sims: usize,
+) -> Result<HeadToHead, SAPTestError> {
+    let mut results = HeadToHead::default();
+    for seed in 0..n_sims as u64 {
+        let mut team = Team::new(board_a, BOARD_SIZE)?;
+        let mut enemy_team = Team::new(board_b, BOARD_SIZE)?;
+        team.set_seed(Some(seed));
+        enemy_team.set_seed(Some(seed));
+
+        let mut outcome = TeamFightOutcome::None;
+        for _ in 0..MAX_BATTLE_FIGHTS {
+            outcome = team.fight(&mut enemy_team)?;
+            if outcome != TeamFightOutcome::None {
+                break;
+            }
+        }
+        match outcome {
+            TeamFightOutcome::Win => results.wins += 1,
+            TeamFightOutcome::Loss => results.losses += 1,
+            TeamFightOutcome::Draw | TeamFightOutcome::None => results.draws += 1,
+        }
+        results.n_sims += 1;
+    }
+    Ok(results)
+}
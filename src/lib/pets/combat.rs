@@ -9,7 +9,7 @@ use crate::{
         stats::Statistics,
         trigger::*,
     },
-    foods::names::FoodName,
+    foods::{food::Food, names::FoodName},
     pets::pet::{Pet, MAX_PET_STATS, MIN_PET_STATS},
     Team,
 };
@@ -43,9 +43,9 @@ fn max_dmg_received(pet: &Pet) -> isize {
 fn min_dmg_received(pet: &Pet) -> isize {
     // If has melon or coconut, minimum dmg can be 0, Otherwise, should be 1.
     if pet
-        .item
-        .as_ref()
-        .map_or(false, |food| FULL_DMG_NEG_ITEMS.contains(&food.name))
+        .items
+        .iter()
+        .any(|food| FULL_DMG_NEG_ITEMS.contains(&food.name))
     {
         0
     } else {
@@ -53,6 +53,67 @@ fn min_dmg_received(pet: &Pet) -> isize {
     }
 }
 
+/// Gets the stat modifier of a single held food during damage calculation.
+fn food_stat_modifier(pet: &Pet, food: &Food) -> Option<Statistics> {
+    if food.ability.position != Position::OnSelf
+        || !ALLOWED_FOOD_EFFECT_TRIGGER.contains(&food.ability.trigger.status)
+        // None means unlimited uses.
+        || food.ability.uses == Some(0)
+    {
+        return None;
+    }
+
+    match &food.ability.action {
+        // Get stat modifiers from effects.
+        Action::Add(stat_change) | Action::Remove(stat_change) => stat_change
+            .to_stats(Some(pet.stats), None, None, false)
+            .ok(),
+        Action::Negate(stats) => {
+            let mut mod_stats = *stats;
+            // Reverse values so that (2 atk, 0 health) -> (0 atk, 2 health).
+            mod_stats.invert();
+            Some(mod_stats)
+        }
+        Action::Critical(prob) => {
+            let mut rng = ChaCha12Rng::seed_from_u64(pet.seed.unwrap_or_else(random));
+            let prob = (*prob).clamp(0, 100) as f64 / 100.0;
+            // Deal double damage (Add attack twice) if probabilty yields true.
+            let dmg = if rng.gen_bool(prob) {
+                pet.stats.attack
+            } else {
+                0
+            };
+
+            Some(Statistics {
+                attack: dmg,
+                health: 0,
+            })
+        }
+        // Otherwise, no change.
+        _ => None,
+    }
+}
+
+/// Gets the extra damage from held items when attacking a lower tier pet. ex. Salt
+fn lower_tier_dmg_modifier(pet: &Pet, enemy: &Pet) -> Option<Statistics> {
+    if enemy.tier >= pet.tier {
+        return None;
+    }
+    pet.items
+        .iter()
+        .filter(|food| food.ability.uses != Some(0))
+        .find_map(|food| {
+            if let Action::LowerTierDamage(percent) = food.ability.action {
+                Some(Statistics {
+                    attack: pet.stats.attack * percent as isize / 100,
+                    health: 0,
+                })
+            } else {
+                None
+            }
+        })
+}
+
 /// Final damage calculation considering death's touch and endure actions.
 fn final_dmg_calculation(pet: &Pet, dmg: isize, enemy: &Pet) -> isize {
     // Insta-kill if all apply:
@@ -66,9 +127,9 @@ fn final_dmg_calculation(pet: &Pet, dmg: isize, enemy: &Pet) -> isize {
         // If has endure, stay alive at 1 health.
         // Otherwise do normal damage calculation.
         if pet.has_food_ability(&Action::Endure, true) {
-            health.clamp(1, MAX_PET_STATS)
+            health.max(1)
         } else {
-            health.clamp(MIN_PET_STATS, MAX_PET_STATS)
+            health.max(MIN_PET_STATS)
         }
     }
 }
@@ -90,6 +151,7 @@ pub trait PetCombat {
 
     /// Handle the logic of [`Pet`] interaction during the battle phase.
     /// * Decrements a held [`Food`](crate::Food) uses.
+    /// * Returns an [`AttackOutcome`] with the triggers, damage, and item modifiers of the attack.
     /// # Example
     /// ```
     /// use saptest::{Pet, PetName, Food, FoodName, PetCombat};
@@ -99,16 +161,20 @@ pub trait PetCombat {
     ///     Pet::try_from(PetName::Ant).unwrap()
     /// );
     /// // Give first ant melon.
-    /// ant_1.item = Some(Food::try_from(FoodName::Melon).unwrap());
+    /// ant_1.items.perk = Some(Box::new(Food::try_from(FoodName::Melon).unwrap()));
     ///
     /// // Original stats and effect uses.
     /// assert!(ant_1.stats.health == 2 && ant_2.stats.health == 2);
-    /// assert_eq!(ant_1.item.as_ref().unwrap().ability.uses, Some(1));
+    /// assert_eq!(ant_1.items.perk.as_ref().unwrap().ability.uses, Some(1));
     ///
     /// // Attack alters attack, health, and held item uses.
-    /// ant_1.attack(&mut ant_2);
+    /// let outcome = ant_1.attack(&mut ant_2);
     /// assert!(ant_1.stats.health == 2 && ant_2.stats.health == 0);
-    /// assert_eq!(ant_1.item.as_ref().unwrap().ability.uses, Some(0));
+    /// assert_eq!(ant_1.items.perk.as_ref().unwrap().ability.uses, Some(0));
+    ///
+    /// // Melon blocked all damage and first ant knocked out the second.
+    /// assert_eq!(outcome.dmg_received, 0);
+    /// assert!(outcome.knockout && !outcome.fainted);
     /// ```
     fn attack(&mut self, enemy: &mut Pet) -> AttackOutcome;
 
@@ -163,7 +229,7 @@ pub trait PetCombat {
     /// use saptest::{Pet, PetName, Food, FoodName, Statistics, PetCombat};
     ///
     /// let mut ant_1 = Pet::try_from(PetName::Ant).unwrap();
-    /// ant_1.item = Some(Food::try_from(FoodName::Melon).unwrap());
+    /// ant_1.items.perk = Some(Box::new(Food::try_from(FoodName::Melon).unwrap()));
     /// assert_eq!(
     ///     ant_1.get_food_stat_modifier(),
     ///     Some(Statistics::new(0, 20).unwrap())
@@ -175,7 +241,7 @@ pub trait PetCombat {
     /// use saptest::{Pet, PetName, Food, FoodName, Statistics, PetCombat};
     ///
     /// let mut ant_1 = Pet::try_from(PetName::Ant).unwrap();
-    /// ant_1.item = Some(Food::try_from(FoodName::MeatBone).unwrap());
+    /// ant_1.items.perk = Some(Box::new(Food::try_from(FoodName::MeatBone).unwrap()));
     /// assert_eq!(
     ///     ant_1.get_food_stat_modifier(),
     ///     Some(Statistics::new(3, 0).unwrap())
@@ -192,7 +258,7 @@ pub trait PetCombat {
     ///     Food, FoodName, effects::actions::Action
     /// };
     /// let mut ant_1 = Pet::try_from(PetName::Ant).unwrap();
-    /// ant_1.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    /// ant_1.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
     ///
     /// assert!(ant_1.has_food_ability(&Action::Kill, true))
     /// ```
@@ -224,7 +290,8 @@ impl PetCombat for Pet {
             return AttackOutcome::default();
         }
         // Get food status modifier. ex. Melon/Garlic
-        let stat_modifier = self.get_food_stat_modifier().unwrap_or_default();
+        let item_modifier = self.get_food_stat_modifier();
+        let stat_modifier = item_modifier.unwrap_or_default();
 
         let min_enemy_dmg = min_dmg_received(self);
         let max_enemy_dmg = max_dmg_received(self);
@@ -238,16 +305,20 @@ impl PetCombat for Pet {
 
         // Account for endure ability.
         new_health = if self.has_food_ability(&Action::Endure, true) {
-            new_health.clamp(1, MAX_PET_STATS)
+            new_health.max(1)
         } else {
-            new_health.clamp(MIN_PET_STATS, MAX_PET_STATS)
+            new_health.max(MIN_PET_STATS)
         };
 
         // Reduce uses from ability if possible.
-        self.item.as_mut().map(|item| item.ability.remove_uses(1));
+        for item in self.items.iter_mut() {
+            item.ability.remove_uses(1);
+        }
 
         // Use health difference to determine outcome.
         let mut outcome = self.get_atk_outcomes(new_health);
+        outcome.dmg_received = enemy_dmg;
+        outcome.friend_item_modifier = item_modifier;
 
         // If kill by indirect, still counts as knockout.
         if new_health == 0 {
@@ -255,7 +326,8 @@ impl PetCombat for Pet {
         }
 
         // Set new health.
-        self.stats.health = new_health.clamp(MIN_PET_STATS, MAX_PET_STATS);
+        self.hurt |= new_health < self.stats.health;
+        self.stats.health = new_health;
         outcome
     }
 
@@ -305,63 +377,21 @@ impl PetCombat for Pet {
             friends: outcomes,
             opponents: enemy_outcomes,
             friend_stat_change: health_diff_stats,
-            enemy_stat_change: Statistics::default(),
+            fainted: new_health == 0,
+            ..Default::default()
         }
     }
 
     fn get_food_stat_modifier(&self) -> Option<Statistics> {
-        if let Some(food) = self.item.as_ref().filter(|food| {
-            food.ability.position == Position::OnSelf
-                && ALLOWED_FOOD_EFFECT_TRIGGER.contains(&food.ability.trigger.status)
-        }) {
-            let food_effect = if let Some(n_uses) = food.ability.uses {
-                if n_uses > 0 {
-                    // Return the food effect.
-                    &food.ability.action
-                } else {
-                    return None;
-                }
-            } else {
-                // None means unlimited uses.
-                &food.ability.action
-            };
-
-            match food_effect {
-                // Get stat modifiers from effects.
-                Action::Add(stat_change) | Action::Remove(stat_change) => {
-                    stat_change.to_stats(Some(self.stats), None, false).ok()
-                }
-                Action::Negate(stats) => {
-                    let mut mod_stats = *stats;
-                    // Reverse values so that (2 atk, 0 health) -> (0 atk, 2 health).
-                    mod_stats.invert();
-                    Some(mod_stats)
-                }
-                Action::Critical(prob) => {
-                    let mut rng = ChaCha12Rng::seed_from_u64(self.seed.unwrap_or_else(random));
-                    let prob = (*prob).clamp(0, 100) as f64 / 100.0;
-                    // Deal double damage (Add attack twice) if probabilty yields true.
-                    let dmg = if rng.gen_bool(prob) {
-                        self.stats.attack
-                    } else {
-                        0
-                    };
-
-                    Some(Statistics {
-                        attack: dmg,
-                        health: 0,
-                    })
-                }
-                // Otherwise, no change.
-                _ => None,
-            }
-        } else {
-            None
-        }
+        // Combine modifiers of all held items. ex. Melon and Weak.
+        self.items
+            .iter()
+            .filter_map(|food| food_stat_modifier(self, food))
+            .reduce(|modifier_1, modifier_2| modifier_1 + modifier_2)
     }
 
     fn has_food_ability(&self, ability: &Action, check_uses: bool) -> bool {
-        if let Some(food) = self.item.as_ref() {
+        self.items.iter().any(|food| {
             let valid_uses = if check_uses {
                 food.ability.uses != Some(0)
             } else {
@@ -369,9 +399,7 @@ impl PetCombat for Pet {
             };
             std::mem::discriminant(&food.ability.action) == std::mem::discriminant(ability)
                 && valid_uses
-        } else {
-            false
-        }
+        })
     }
 
     fn has_effect_trigger(&self, trigger: &Status, check_uses: bool) -> bool {
@@ -398,38 +426,24 @@ impl PetCombat for Pet {
     }
 
     fn calculate_new_health(&self, enemy: &Pet) -> (isize, isize) {
-        // Get stat modifier from food.
-        let stat_modifier = self.get_food_stat_modifier().unwrap_or_default();
-        let enemy_stat_modifier = enemy.get_food_stat_modifier().unwrap_or_default();
-
-        let min_enemy_dmg = min_dmg_received(self);
-        let min_dmg = min_dmg_received(enemy);
-
-        // If has coconut, maximum dmg is 0. Otherwise, the normal 150.
-        let max_enemy_dmg = max_dmg_received(self);
-        let max_dmg = max_dmg_received(enemy);
-
-        // Any modifiers must apply to ATTACK as we want to only temporarily modify the health attribute of a pet.
-        let enemy_dmg = (enemy.stats.attack + enemy_stat_modifier.attack)
-            .sub(stat_modifier.health)
-            .clamp(min_enemy_dmg, max_enemy_dmg);
-
-        let dmg = (self.stats.attack + stat_modifier.attack)
-            .sub(enemy_stat_modifier.health)
-            .clamp(min_dmg, max_dmg);
-
-        let new_health = final_dmg_calculation(self, enemy_dmg, enemy);
-        let new_enemy_health = final_dmg_calculation(enemy, dmg, self);
-
-        (new_health, new_enemy_health)
+        let dmg_calc = DmgCalculation::new(self, enemy);
+        (dmg_calc.new_health, dmg_calc.new_enemy_health)
     }
 
     fn attack(&mut self, enemy: &mut Pet) -> AttackOutcome {
-        let (new_health, new_enemy_health) = self.calculate_new_health(enemy);
+        let DmgCalculation {
+            stat_modifier,
+            enemy_stat_modifier,
+            dmg,
+            enemy_dmg,
+            new_health,
+            new_enemy_health,
+        } = DmgCalculation::new(self, enemy);
 
         // Decrement number of uses on items, if any.
-        self.item.as_mut().map(|item| item.ability.remove_uses(1));
-        enemy.item.as_mut().map(|item| item.ability.remove_uses(1));
+        for item in self.items.iter_mut().chain(enemy.items.iter_mut()) {
+            item.ability.remove_uses(1);
+        }
 
         // Get outcomes for both pets.
         // This doesn't factor in splash effects as pets outside of battle are affected.
@@ -449,6 +463,8 @@ impl PetCombat for Pet {
         }
 
         // Set the new health of a pet.
+        self.hurt |= new_health < self.stats.health;
+        enemy.hurt |= new_enemy_health < enemy.stats.health;
         self.stats.health = new_health;
         enemy.stats.health = new_enemy_health;
 
@@ -461,6 +477,71 @@ impl PetCombat for Pet {
             opponents: VecDeque::from_iter(enemy_outcome.friends),
             friend_stat_change: outcome.friend_stat_change,
             enemy_stat_change: enemy_outcome.friend_stat_change,
+            dmg_dealt: dmg,
+            dmg_received: enemy_dmg,
+            friend_item_modifier: stat_modifier,
+            enemy_item_modifier: enemy_stat_modifier,
+            knockout: new_enemy_health == 0,
+            fainted: new_health == 0,
+        }
+    }
+}
+
+/// Intermediate values of a direct attack's damage calculation.
+struct DmgCalculation {
+    stat_modifier: Option<Statistics>,
+    enemy_stat_modifier: Option<Statistics>,
+    dmg: isize,
+    enemy_dmg: isize,
+    new_health: isize,
+    new_enemy_health: isize,
+}
+
+impl DmgCalculation {
+    fn new(pet: &Pet, enemy: &Pet) -> Self {
+        // Get stat modifier from food once so random modifiers are consistent.
+        let stat_modifier = [
+            pet.get_food_stat_modifier(),
+            lower_tier_dmg_modifier(pet, enemy),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|modifier_1, modifier_2| modifier_1 + modifier_2);
+        let enemy_stat_modifier = [
+            enemy.get_food_stat_modifier(),
+            lower_tier_dmg_modifier(enemy, pet),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|modifier_1, modifier_2| modifier_1 + modifier_2);
+        let (self_mod, enemy_mod) = (
+            stat_modifier.unwrap_or_default(),
+            enemy_stat_modifier.unwrap_or_default(),
+        );
+
+        let min_enemy_dmg = min_dmg_received(pet);
+        let min_dmg = min_dmg_received(enemy);
+
+        // If has coconut, maximum dmg is 0. Otherwise, the normal 150.
+        let max_enemy_dmg = max_dmg_received(pet);
+        let max_dmg = max_dmg_received(enemy);
+
+        // Any modifiers must apply to ATTACK as we want to only temporarily modify the health attribute of a pet.
+        let enemy_dmg = (enemy.stats.attack + enemy_mod.attack)
+            .sub(self_mod.health)
+            .clamp(min_enemy_dmg, max_enemy_dmg);
+
+        let dmg = (pet.stats.attack + self_mod.attack)
+            .sub(enemy_mod.health)
+            .clamp(min_dmg, max_dmg);
+
+        DmgCalculation {
+            stat_modifier,
+            enemy_stat_modifier,
+            dmg,
+            enemy_dmg,
+            new_health: final_dmg_calculation(pet, enemy_dmg, enemy),
+            new_enemy_health: final_dmg_calculation(enemy, dmg, pet),
         }
     }
 }
@@ -476,6 +557,20 @@ pub struct AttackOutcome {
     pub friend_stat_change: Statistics,
     /// Enemy [`Statisitics`](crate::Statistics) change.
     pub enemy_stat_change: Statistics,
+    /// Damage dealt to the enemy after item modifiers and damage limits.
+    /// * Does not include death's touch or endure.
+    pub dmg_dealt: isize,
+    /// Damage received from the enemy after item modifiers and damage limits.
+    /// * Does not include death's touch or endure.
+    pub dmg_received: isize,
+    /// Held item [`Statistics`] modifier applied to the friend pet.
+    pub friend_item_modifier: Option<Statistics>,
+    /// Held item [`Statistics`] modifier applied to the enemy pet.
+    pub enemy_item_modifier: Option<Statistics>,
+    /// Whether the friend pet knocked out the enemy.
+    pub knockout: bool,
+    /// Whether the friend pet fainted.
+    pub fainted: bool,
 }
 
 impl AttackOutcome {
@@ -499,6 +594,20 @@ impl AttackOutcome {
             }
         }
 
+        // Knockouts of friends by friends stay on the team. ex. Hedgehog
+        let knocked_out_by_friend = afflicting
+            .as_ref()
+            .and_then(|pet| pet.upgrade())
+            .is_some_and(|pet| pet.read().unwrap().team.as_ref() == Some(&team.name));
+        if knocked_out_by_friend {
+            let (knockouts, opponent_triggers) = self
+                .opponents
+                .drain(..)
+                .partition(|trigger| trigger.status == Status::KnockOut);
+            self.opponents = opponent_triggers;
+            self.friends.extend::<VecDeque<Outcome>>(knockouts);
+        }
+
         // Collect triggers for both teams.
         team.triggers.extend(self.friends.drain(..));
         if let Some(opponent) = opponent {
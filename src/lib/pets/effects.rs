@@ -4,10 +4,10 @@ use crate::{
     db::record::PetRecord,
     effects::{
         actions::{
-            Action, ConditionType, CopyType, GainType, LogicType, RandomizeType, StatChangeType,
-            SummonType, ToyType,
+            Action, ConditionType, CopyType, GainType, GoldChangeType, LogicType, RandomizeType,
+            RepeatSource, StatChangeType, StealType, SummonType, ToyFallback, ToyType,
         },
-        effect::{Effect, Entity, EntityName},
+        effect::{ActivationLimit, Effect, Entity, EntityName, LimitScope},
         state::{
             CondOrdering, EqualityCondition, ItemCondition, Position, ShopCondition, Status,
             Target, TeamCondition,
@@ -20,8 +20,8 @@ use crate::{
         store::{ShopState, MAX_SHOP_TIER, MIN_SHOP_TIER},
         trigger::*,
     },
-    teams::team::TeamFightOutcome,
-    Pet, PetName, SAPQuery, Statistics,
+    teams::{counters::CounterKey, team::TeamFightOutcome},
+    Pet, PetColumn, PetName, SAPQuery, Statistics, ToyColumn,
 };
 use std::convert::TryInto;
 
@@ -32,8 +32,9 @@ impl TryFrom<PetRecord> for Vec<Effect> {
 
     fn try_from(record: PetRecord) -> Result<Self, Self::Error> {
         let effect_stats = Statistics::new(record.effect_atk, record.effect_health)?;
+        let uses_refresh = record.uses_refresh();
 
-        Ok(match &record.name {
+        let mut effects = match &record.name {
             PetName::Beaver => vec![Effect {
                 owner: None,
                 trigger: TRIGGER_SELF_PET_SOLD,
@@ -47,6 +48,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Duck => vec![Effect {
                 owner: None,
@@ -56,6 +58,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Fish => match record.lvl {
                 1 | 2 => vec![Effect {
@@ -71,6 +74,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }],
                 _ => vec![],
             },
@@ -87,15 +91,17 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Pig => vec![Effect {
                 owner: None,
                 trigger: TRIGGER_SELF_PET_SOLD,
                 target: Target::Shop,
                 position: Position::None,
-                action: Action::AlterGold(record.lvl.try_into()?),
+                action: Action::AlterGold(GoldChangeType::Static(record.lvl.try_into()?)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Chinchilla => vec![Effect {
                 owner: None,
@@ -109,6 +115,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 )),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Marmoset => vec![Effect {
                 owner: None,
@@ -118,6 +125,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::FreeRoll(record.lvl),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Beetle => vec![{
                 let food = match record.lvl {
@@ -134,6 +142,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Gain(GainType::StoredItem(Box::new(food))),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 }
             }],
             PetName::Bluebird => vec![Effect {
@@ -144,6 +153,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Ladybug => vec![Effect {
                 owner: None,
@@ -153,6 +163,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Cockroach => vec![Effect {
                 owner: None,
@@ -162,6 +173,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Cockroach,
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Duckling => vec![Effect {
                 owner: None,
@@ -171,6 +183,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Kiwi => vec![Effect {
                 owner: None,
@@ -182,17 +195,12 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Mouse => {
-                let mut free_apple = Food::try_from(FoodName::Apple)?;
+                // Better apples at higher levels.
+                let mut free_apple = Food::new_at_level(&FoodName::Apple, record.lvl)?;
                 free_apple.cost = 0;
-                if let Action::Add(stat_change_type) = &free_apple.ability.action {
-                    let add_stats = stat_change_type.to_stats(None, None, false)?;
-                    // Multiple by current level to create better apples.
-                    let new_apple_stats = add_stats * Statistics::new(record.lvl, record.lvl)?;
-                    free_apple.ability.action =
-                        Action::Add(StatChangeType::Static(new_apple_stats));
-                };
                 vec![Effect {
                     owner: None,
                     trigger: TRIGGER_SELF_PET_SOLD,
@@ -204,6 +212,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ]),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Pillbug => vec![Effect {
@@ -214,6 +223,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Ant => vec![Effect {
                 owner: None,
@@ -223,6 +233,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Mosquito => vec![Effect {
                 owner: None,
@@ -237,6 +248,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Cricket => {
                 let zombie_cricket = Box::new(Pet::new(
@@ -252,6 +264,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Summon(SummonType::StoredPet(zombie_cricket)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Horse => vec![Effect {
@@ -262,6 +275,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Bulldog => vec![Effect {
                 owner: None,
@@ -274,6 +288,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 ]),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Chipmunk => vec![
                 Effect {
@@ -284,6 +299,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::ClearShop(Entity::Food),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -296,6 +312,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ]),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -305,6 +322,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Discount(Entity::Food, 2),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
             ],
             PetName::Groundhog => vec![Effect {
@@ -313,9 +331,10 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 target: Target::Friend,
                 // Must have a position to activate effect.
                 position: Position::TriggerAffected(None),
-                action: Action::AddToCounter(String::from("Trumpets"), record.lvl.try_into()?),
+                action: Action::AddToCounter(CounterKey::Trumpets, record.lvl.try_into()?),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::ConeSnail => vec![Effect {
                 owner: None,
@@ -325,6 +344,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Goose => vec![Effect {
                 owner: None,
@@ -334,6 +354,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Debuff(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::PiedTamarin => vec![
                 // Ranged attack.
@@ -355,7 +376,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                             LogicType::IfNot(ConditionType::Team(
                                 Target::Enemy,
                                 TeamCondition::Counter(
-                                    String::from("Trumpets"),
+                                    CounterKey::Trumpets,
                                     Some(CondOrdering::Equal(0)),
                                 ),
                             )),
@@ -366,6 +387,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
                 // Decrement trumpets.
                 Effect {
@@ -381,17 +403,18 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                             LogicType::IfNot(ConditionType::Team(
                                 Target::Friend,
                                 TeamCondition::Counter(
-                                    String::from("Trumpets"),
+                                    CounterKey::Trumpets,
                                     Some(CondOrdering::Equal(0)),
                                 ),
                             )),
-                            Box::new(Action::AddToCounter(String::from("Trumpets"), -1)),
+                            Box::new(Action::AddToCounter(CounterKey::Trumpets, -1)),
                             Box::new(Action::None),
                         )),
                         Box::new(Action::None),
                     ),
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
             ],
             PetName::Opossum => vec![Effect {
@@ -404,6 +427,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::AddShopStats(effect_stats),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Silkmoth => vec![Effect {
                 owner: None,
@@ -413,6 +437,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Magpie => vec![Effect {
                 owner: None,
@@ -422,6 +447,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::SaveGold { limit: record.lvl },
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Crab => vec![Effect {
                 owner: None,
@@ -440,6 +466,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 ),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Dodo => {
                 vec![Effect {
@@ -450,6 +477,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Add(StatChangeType::Multiplier(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Elephant => {
@@ -462,6 +490,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         action: Action::Remove(StatChangeType::Static(effect_stats)),
                         uses: None,
                         temp: record.temp_effect,
+                        ..Default::default()
                     };
                     record.n_triggers
                 ]
@@ -474,6 +503,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Hedgehog => vec![Effect {
                 owner: None,
@@ -483,6 +513,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Peacock => vec![Effect {
                 owner: None,
@@ -492,6 +523,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Rat => {
                 vec![
@@ -499,8 +531,8 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         owner: None,
                         trigger: TRIGGER_SELF_FAINT,
                         target: Target::Enemy,
-                        // TODO: This isn't correct behavior.
-                        position: Position::OnSelf,
+                        // Summoned in front of the opponent's team.
+                        position: Position::First,
                         action: Action::Summon(SummonType::StoredPet(Box::new(Pet::new(
                             PetName::DirtyRat,
                             Some(effect_stats),
@@ -509,6 +541,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         // Activates multiple times per trigger.
                         uses: Some(record.n_triggers),
                         temp: record.temp_effect,
+                        ..Default::default()
                     };
                     record.lvl
                 ]
@@ -523,6 +556,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     // Activates multiple times per trigger.
                     uses: None,
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Spider => {
@@ -535,11 +569,12 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Summon(SummonType::QueryPet(
                         SAPQuery::builder()
                             .set_table(Entity::Pet)
-                            .set_param("lvl", vec![record.lvl])
-                            .set_param("tier", vec![3]),
+                            .set_param(PetColumn::Lvl, vec![record.lvl])
+                            .set_param(PetColumn::Tier, vec![3]),
                         None,
                     )),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Swan => {
@@ -548,9 +583,10 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     trigger: TRIGGER_START_TURN,
                     target: Target::Shop,
                     position: Position::None,
-                    action: Action::AlterGold(record.lvl.try_into()?),
+                    action: Action::AlterGold(GoldChangeType::Static(record.lvl.try_into()?)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Frigatebird => {
@@ -560,9 +596,10 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     target: Target::Friend,
                     position: Position::TriggerAffected(None),
                     // Remove ailment.
-                    action: Action::Gain(GainType::NoItem),
+                    action: Action::Gain(GainType::NoAilment),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::GoldFish => {
@@ -574,6 +611,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::AlterCost(record.lvl.try_into()?),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Dromedary => {
@@ -585,6 +623,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::TabbyCat => {
@@ -596,6 +635,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::GuineaPig => {
@@ -607,6 +647,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Summon(SummonType::SelfPet(Some(effect_stats), None, false)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Jellyfish => {
@@ -618,6 +659,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Salamander => {
@@ -629,6 +671,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Yak => {
@@ -647,6 +690,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ]),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Badger => {
@@ -661,6 +705,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ]),
                     action: Action::Remove(StatChangeType::Multiplier(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Blowfish => vec![Effect {
@@ -671,6 +716,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::None),
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Camel => {
                 vec![Effect {
@@ -684,6 +730,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         record.n_triggers
                     ]),
                     uses: None,
+                    ..Default::default()
                 }]
             }
             PetName::Dog => vec![Effect {
@@ -694,6 +741,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Dolphin => vec![
                 Effect {
@@ -709,6 +757,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     },
                     action: Action::Remove(StatChangeType::Static(effect_stats)),
                     uses: Some(1),
+                    ..Default::default()
                 };
                 record.n_triggers
             ],
@@ -720,6 +769,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Ox => vec![Effect {
                 owner: None,
@@ -732,6 +782,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Action::Gain(GainType::DefaultItem(FoodName::Melon)),
                 ]),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Giraffe => vec![Effect {
                 owner: None,
@@ -741,6 +792,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Relative(1),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Rabbit => vec![Effect {
                 owner: None,
@@ -750,6 +802,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::TriggerAffected(None),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Snail => vec![Effect {
                 owner: None,
@@ -766,6 +819,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::EmperorTamarin => vec![Effect {
                 owner: None,
@@ -775,6 +829,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::First,
                 action: Action::Add(StatChangeType::Multiplier(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Wasp => vec![Effect {
                 owner: None,
@@ -784,6 +839,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Multiplier(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::HatchingChick => {
                 let mut base_effect = Effect {
@@ -794,6 +850,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Nearest(1),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 };
                 match record.lvl {
                     1 | 2 => {}
@@ -820,6 +877,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Pet::new(PetName::Mouse, None, record.lvl)?
                 }))),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Ferret | PetName::Puppy => vec![Effect {
                 owner: None,
@@ -827,14 +885,16 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 trigger: TRIGGER_SELF_PET_BOUGHT,
                 target: Target::Shop,
                 position: Position::None,
-                action: Action::GetToy(ToyType::QueryOneToy(
-                    SAPQuery::builder()
+                action: Action::GetToy(ToyType::QueryOneToy {
+                    query: SAPQuery::builder()
                         .set_table(Entity::Toy)
-                        .set_param("source", vec![record.name])
-                        .set_param("lvl", vec![record.lvl])
+                        .set_param(ToyColumn::Source, vec![record.name])
+                        .set_param(ToyColumn::Lvl, vec![record.lvl])
                         .to_owned(),
-                )),
+                    fallback: ToyFallback::LowerLevel,
+                }),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::TropicalFish => vec![Effect {
                 owner: None,
@@ -844,6 +904,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Adjacent,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Capybara => vec![Effect {
                 owner: None,
@@ -853,6 +914,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::All(ItemCondition::NotEqual(EqualityCondition::Frozen)),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Cassowary => vec![Effect {
                 owner: None,
@@ -871,6 +933,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Leech => vec![
                 // Dmg is hardcoded as regex on captures health buff.
@@ -885,6 +948,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         health: 0,
                     })),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -894,6 +958,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::OnSelf,
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
             ],
             PetName::Okapi => vec![Effect {
@@ -904,6 +969,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Starfish => vec![Effect {
                 owner: None,
@@ -913,6 +979,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Sheep => {
                 vec![
@@ -928,6 +995,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                             record.lvl,
                         )?))),
                         uses: Some(record.n_triggers),
+                        ..Default::default()
                     };
                     2
                 ]
@@ -947,6 +1015,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Penguin => vec![Effect {
                 owner: None,
@@ -964,6 +1033,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 },
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Squirrel => vec![Effect {
                 owner: None,
@@ -973,6 +1043,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::All(ItemCondition::None),
                 action: Action::Discount(Entity::Food, record.lvl),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Worm => vec![Effect {
                 owner: None,
@@ -981,19 +1052,14 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 target: Target::Shop,
                 position: Position::None,
                 action: Action::AddShopFood(GainType::StoredItem(Box::new({
-                    let mut apple = Food::try_from(FoodName::Apple)?;
-                    // Replace apple action with buffed effect based on record level.
-                    if let Action::Add(stat_change_type) = &apple.ability.action {
-                        let add_stats = stat_change_type.to_stats(None, None, false)?;
-                        // Multiple by current level to create better apples.
-                        let new_apple_stats = add_stats * Statistics::new(record.lvl, record.lvl)?;
-                        apple.ability.action = Action::Add(StatChangeType::Static(new_apple_stats));
-                        // Apple is discounted.
-                        apple.cost = 2;
-                    };
+                    // Better apples at higher levels.
+                    let mut apple = Food::new_at_level(&FoodName::Apple, record.lvl)?;
+                    // Apple is discounted.
+                    apple.cost = 2;
                     apple
                 }))),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Dragonfly => {
                 let positions = Position::Multiple(
@@ -1014,6 +1080,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: positions,
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Jerboa => vec![Effect {
@@ -1031,6 +1098,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Mole => {
                 const REQ_PERK_PETS: usize = 3;
@@ -1056,6 +1124,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                             Box::new(Action::None),
                         ),
                         uses: Some(record.n_triggers),
+                        ..Default::default()
                     },
                     Effect {
                         owner: None,
@@ -1070,6 +1139,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         },
                         action: Action::Gain(GainType::NoItem),
                         uses: Some(record.n_triggers),
+                        ..Default::default()
                     },
                 ]
             }
@@ -1085,6 +1155,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Llama => vec![Effect {
                 owner: None,
@@ -1101,6 +1172,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Lobster => vec![Effect {
                 owner: None,
@@ -1114,6 +1186,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Crow => {
                 let mut free_chocolate = Food::try_from(FoodName::Chocolate)?;
@@ -1133,6 +1206,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         action: Action::Multiple(actions),
                         uses: None,
                         temp: record.temp_effect,
+                        ..Default::default()
                     },
                     Effect {
                         owner: None,
@@ -1142,6 +1216,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         action: Action::Discount(Entity::Food, 3),
                         uses: None,
                         temp: record.temp_effect,
+                        ..Default::default()
                     },
                 ]
             }
@@ -1153,6 +1228,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::Illest),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Platypus => {
                 let duck = Pet::new(PetName::Duck, None, record.lvl)?;
@@ -1166,11 +1242,13 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     owner: None,
 
                     temp: record.temp_effect,
+
                     trigger: TRIGGER_SELF_PET_SOLD,
                     target: Target::Friend,
                     position: Position::OnSelf,
                     action: Action::Multiple(summon_actions),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::PrayingMantis => vec![
@@ -1182,6 +1260,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Adjacent,
                     action: Action::Kill,
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -1191,11 +1270,12 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::OnSelf,
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
             ],
             PetName::Deer => {
                 let mut bus = Pet::new(PetName::Bus, Some(effect_stats), record.lvl)?;
-                bus.item = Some(Food::try_from(FoodName::Chili)?);
+                bus.items.perk = Some(Box::new(Food::try_from(FoodName::Chili)?));
                 vec![Effect {
                     owner: None,
                     temp: record.temp_effect,
@@ -1204,6 +1284,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::OnSelf,
                     action: Action::Summon(SummonType::StoredPet(Box::new(bus))),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Hippo => vec![Effect {
@@ -1214,6 +1295,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Parrot => vec![Effect {
                 owner: None,
@@ -1227,6 +1309,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Position::Nearest(1),
                 ),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Rooster => {
                 vec![Effect {
@@ -1244,6 +1327,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         record.lvl
                     ]),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Skunk => vec![Effect {
@@ -1259,6 +1343,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 },
                 action: Action::Debuff(StatChangeType::Multiplier(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Turtle => {
                 let max_pets_behind: isize = record.lvl.try_into()?;
@@ -1270,6 +1355,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Nearest(-max_pets_behind),
                     action: Action::Gain(GainType::DefaultItem(FoodName::Melon)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Whale => vec![Effect {
@@ -1280,6 +1366,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Whale(record.lvl, Position::Nearest(1)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Crocodile => {
                 vec![Effect {
@@ -1295,6 +1382,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         record.n_triggers
                     ]),
                     uses: Some(1),
+                    ..Default::default()
                 }]
             }
             PetName::Rhino => vec![Effect {
@@ -1319,6 +1407,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::Remove(StatChangeType::Static(effect_stats))),
                 ),
                 uses: None,
+                ..Default::default()
             }],
             // No shops so start of turn.
             PetName::Scorpion => vec![Effect {
@@ -1329,6 +1418,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Gain(GainType::DefaultItem(FoodName::Peanut)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Shark => vec![Effect {
                 owner: None,
@@ -1338,6 +1428,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Turkey => vec![Effect {
                 owner: None,
@@ -1347,14 +1438,13 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::TriggerAffected(None),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Cow => {
                 const NUM_MILK: usize = 2;
                 const MILK_DISCOUNT: usize = 3;
-                // Milk stats from food record are incorrect as foods do not store levels.
-                // Stats are set here.
-                let mut milk = Food::try_from(FoodName::Milk)?;
-                milk.ability.action = Action::Add(StatChangeType::Static(effect_stats));
+                // Better milk at higher levels.
+                let milk = Food::new_at_level(&FoodName::Milk, record.lvl)?;
 
                 let mut add_milk_actions =
                     vec![Action::AddShopFood(GainType::StoredItem(Box::new(milk))); NUM_MILK];
@@ -1368,6 +1458,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         position: Position::None,
                         action: Action::Multiple(add_milk_actions),
                         uses: None,
+                        ..Default::default()
                     },
                     Effect {
                         owner: None,
@@ -1377,6 +1468,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         position: Position::All(ItemCondition::None),
                         action: Action::Discount(Entity::Food, MILK_DISCOUNT),
                         uses: None,
+                        ..Default::default()
                     },
                 ]
             }
@@ -1388,6 +1480,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::First,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Seal => vec![Effect {
                 owner: None,
@@ -1402,28 +1495,42 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 },
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
-            // TODO: Moose action must change. Consider adding unfreeze shop action and using Action::Multiple().
-            PetName::Moose => vec![Effect {
-                owner: None,
-                temp: record.temp_effect,
-                trigger: TRIGGER_END_TURN,
-                target: Target::Friend,
-                position: Position::Any(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
-                action: Action::Moose {
-                    stats: effect_stats,
-                    tier: 1,
+            PetName::Moose => vec![
+                Effect {
+                    owner: None,
+                    temp: record.temp_effect,
+                    trigger: TRIGGER_END_TURN,
+                    target: Target::Shop,
+                    position: Position::None,
+                    action: Action::Multiple(vec![
+                        Action::Unfreeze(Entity::Pet),
+                        Action::Unfreeze(Entity::Food),
+                    ]),
+                    uses: None,
+                    ..Default::default()
                 },
-                uses: None,
-            }],
+                Effect {
+                    owner: None,
+                    temp: record.temp_effect,
+                    trigger: TRIGGER_END_TURN,
+                    target: Target::Friend,
+                    position: Position::Any(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
+                    action: Action::Add(StatChangeType::ShopTierPetsMultiplier(effect_stats, 1)),
+                    uses: None,
+                    ..Default::default()
+                },
+            ],
             PetName::Goat => vec![Effect {
                 owner: None,
                 temp: record.temp_effect,
                 trigger: TRIGGER_ANY_PET_BOUGHT,
                 target: Target::Shop,
                 position: Position::None,
-                action: Action::AlterGold(record.lvl.try_into()?),
+                action: Action::AlterGold(GoldChangeType::Static(record.lvl.try_into()?)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Poodle => {
                 let target_positions = (MIN_SHOP_TIER..=MAX_SHOP_TIER)
@@ -1442,6 +1549,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Multiple(target_positions),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Fox => vec![Effect {
@@ -1452,6 +1560,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Fox(Entity::Food, record.lvl),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Hamster => vec![Effect {
                 owner: None,
@@ -1459,8 +1568,9 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 trigger: TRIGGER_ROLL,
                 target: Target::Shop,
                 position: Position::None,
-                action: Action::AlterGold(1),
+                action: Action::AlterGold(GoldChangeType::Static(1)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::PolarBear => vec![Effect {
                 owner: None,
@@ -1470,6 +1580,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::Equal(EqualityCondition::Frozen)),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Shoebill => vec![Effect {
                 owner: None,
@@ -1481,6 +1592,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 ))),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::SiberianHusky => vec![Effect {
                 owner: None,
@@ -1493,6 +1605,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 ])),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Zebra => vec![
                 Effect {
@@ -1503,6 +1616,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Any(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -1512,6 +1626,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Any(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
             ],
             PetName::Boar => vec![Effect {
@@ -1522,6 +1637,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Fly => {
                 // Add exception for other zombie flies.
@@ -1537,6 +1653,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         record.lvl,
                     )?))),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Gorilla => vec![Effect {
@@ -1547,6 +1664,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Gain(GainType::DefaultItem(FoodName::Coconut)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Leopard => {
                 vec![Effect {
@@ -1562,6 +1680,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         record.n_triggers
                     ]),
                     uses: Some(1),
+                    ..Default::default()
                 }]
             }
             PetName::Mammoth => vec![Effect {
@@ -1572,6 +1691,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::All(ItemCondition::None),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Snake => vec![Effect {
                 owner: None,
@@ -1581,6 +1701,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::None),
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
 
             PetName::FrilledDragon => vec![Effect {
@@ -1598,6 +1719,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 ),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             // Only level one for now.
             PetName::Frog => {
@@ -1610,6 +1732,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Swap(RandomizeType::Stats),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 };
                 match record.lvl {
                     1 => {}
@@ -1639,6 +1762,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             // Iguana has two effects that are the same except for their triggers.
             PetName::Iguana => vec![
@@ -1650,6 +1774,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Remove(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -1659,6 +1784,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     action: Action::Remove(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 },
             ],
             PetName::Moth => vec![Effect {
@@ -1669,6 +1795,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Seahorse => vec![Effect {
                 owner: None,
@@ -1678,6 +1805,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Push(Position::Relative(record.lvl.try_into()?)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Bat => vec![Effect {
                 owner: None,
@@ -1692,6 +1820,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::AtlanticPuffin => {
                 // For each level, do an action that removes some amount of stats based on the number of enemies with strawberries.
@@ -1716,6 +1845,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ]),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
             PetName::Dove => vec![Effect {
@@ -1733,6 +1863,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Koala => vec![Effect {
                 owner: None,
@@ -1742,6 +1873,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Panda => {
                 vec![
@@ -1753,6 +1885,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         action: Action::Add(StatChangeType::Multiplier(effect_stats)),
                         uses: Some(record.n_triggers),
                         temp: record.temp_effect,
+                        ..Default::default()
                     },
                     Effect {
                         owner: None,
@@ -1762,6 +1895,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         action: Action::Kill,
                         uses: Some(record.n_triggers),
                         temp: record.temp_effect,
+                        ..Default::default()
                     },
                 ]
             }
@@ -1773,6 +1907,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Experience(record.lvl),
                 uses: Some(record.n_triggers),
                 temp: record.temp_effect,
+                ..Default::default()
             }],
             PetName::Stork => {
                 vec![Effect {
@@ -1787,28 +1922,22 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         tier_diff: Some(-1),
                     }),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
-            PetName::Raccoon => vec![
-                Effect {
-                    owner: None,
-                    temp: record.temp_effect,
-                    trigger: TRIGGER_SELF_BEFORE_ATTACK,
-                    target: Target::Friend,
-                    position: Position::OnSelf,
-                    action: Action::Copy(CopyType::Item(None), Target::Enemy, Position::First),
-                    uses: Some(record.n_triggers),
-                },
-                Effect {
-                    owner: None,
-                    temp: record.temp_effect,
-                    trigger: TRIGGER_SELF_BEFORE_ATTACK,
-                    target: Target::Enemy,
-                    position: Position::First,
-                    action: Action::Gain(GainType::NoItem),
-                    uses: Some(record.n_triggers),
+            PetName::Raccoon => vec![Effect {
+                owner: None,
+                temp: record.temp_effect,
+                trigger: TRIGGER_SELF_BEFORE_ATTACK,
+                target: Target::Enemy,
+                position: Position::First,
+                action: Action::Steal {
+                    what: StealType::Item,
+                    from: Target::Enemy,
                 },
-            ],
+                uses: Some(record.n_triggers),
+                ..Default::default()
+            }],
             PetName::Toucan => {
                 let n_pets_behind: isize = record.lvl.try_into()?;
                 vec![Effect {
@@ -1820,6 +1949,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     // If None, update during team init with current item.
                     action: Action::Gain(GainType::SelfItem),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Wombat => vec![Effect {
@@ -1842,6 +1972,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     },
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Aardvark => vec![Effect {
                 owner: None,
@@ -1851,6 +1982,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Bear => vec![Effect {
                 owner: None,
@@ -1860,6 +1992,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Multiple(vec![Position::Relative(-1), Position::Relative(1)]),
                 action: Action::Gain(GainType::DefaultItem(FoodName::Honey)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Seagull => vec![Effect {
                 owner: None,
@@ -1870,6 +2003,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 // Give currently held food.
                 action: Action::Gain(GainType::SelfItem),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Blobfish => vec![Effect {
                 owner: None,
@@ -1879,6 +2013,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Nearest(-1),
                 action: Action::Experience(1),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Clownfish => vec![Effect {
                 owner: None,
@@ -1888,6 +2023,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::TriggerAffected(None),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Toad => vec![Effect {
                 owner: None,
@@ -1897,6 +2033,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::TriggerAffected(None),
                 action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Woodpecker => vec![Effect {
                 owner: None,
@@ -1909,6 +2046,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     record.lvl
                 ]),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Armadillo => vec![
                 Effect {
@@ -1919,6 +2057,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::All(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -1928,6 +2067,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::All(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
             ],
             PetName::Doberman => vec![Effect {
@@ -1948,6 +2088,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Lynx => vec![Effect {
                 owner: None,
@@ -1957,6 +2098,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::None),
                 action: Action::Multiple(vec![Action::Lynx; record.lvl]),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Porcupine => vec![Effect {
                 owner: None,
@@ -1966,6 +2108,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::TriggerAfflicting(None),
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Caterpillar => match record.lvl {
                 1 | 2 => vec![Effect {
@@ -1976,6 +2119,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::OnSelf,
                     action: Action::Experience(1),
                     uses: None,
+                    ..Default::default()
                 }],
                 3 => {
                     vec![Effect {
@@ -1993,6 +2137,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                             record.lvl,
                         ),
                         uses: Some(record.n_triggers),
+                        ..Default::default()
                     }]
                 }
                 _ => vec![],
@@ -2014,6 +2159,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     },
                 ),
                 uses: Some(1),
+                ..Default::default()
             }],
             PetName::Anteater => {
                 vec![Effect {
@@ -2029,6 +2175,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         2
                     ]),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Donkey => vec![Effect {
@@ -2039,6 +2186,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Last,
                 action: Action::Push(Position::First),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Eel => {
                 vec![Effect {
@@ -2049,6 +2197,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::OnSelf,
                     action: Action::Add(StatChangeType::Multiplier(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 }]
             }
             PetName::Hawk => vec![Effect {
@@ -2059,6 +2208,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Opposite,
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Pelican => {
                 let start_battle_effect = Effect {
@@ -2071,6 +2221,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ))),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 };
                 let mut start_turn_effect = start_battle_effect.clone();
                 start_turn_effect.trigger = TRIGGER_START_TURN;
@@ -2085,6 +2236,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::All(ItemCondition::None),
                     action: Action::None,
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 };
                 match record.lvl {
                     1 => {
@@ -2109,15 +2261,22 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     }
                 }
             }
-            // TODO: Add new Lionfish action. No way currently to implement with existing code. :/
             PetName::Lionfish => vec![Effect {
                 owner: None,
                 temp: record.temp_effect,
                 trigger: TRIGGER_ANY_BEFORE_ATTACK,
-                target: Target::Enemy,
-                position: Position::First,
-                action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
-                uses: None,
+                target: Target::Friend,
+                position: Position::TriggerAffected(None),
+                action: Action::Conditional(
+                    LogicType::If(ConditionType::Team(
+                        Target::Enemy,
+                        TeamCondition::FrontPet(Box::new(EqualityCondition::Hurt)),
+                    )),
+                    Box::new(Action::Add(StatChangeType::Static(effect_stats))),
+                    Box::new(Action::None),
+                ),
+                uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Eagle => vec![Effect {
                 owner: None,
@@ -2131,6 +2290,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     tier_diff: Some(1),
                 }),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Microbe => vec![Effect {
                 owner: None,
@@ -2140,6 +2300,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::All(ItemCondition::None),
                 action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Lion => vec![Effect {
                 owner: None,
@@ -2156,6 +2317,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Swordfish => {
                 let self_dmg_effect = Effect {
@@ -2166,6 +2328,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::OnSelf,
                     action: Action::Remove(StatChangeType::Multiplier(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 };
                 let mut enemy_dmg_effect = self_dmg_effect.clone();
                 enemy_dmg_effect.target = Target::Enemy;
@@ -2185,6 +2348,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Vulture => vec![Effect {
                 owner: None,
@@ -2201,6 +2365,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Alpaca => vec![Effect {
                 owner: None,
@@ -2210,8 +2375,9 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::TriggerAffected(None),
                 action: Action::Experience(1),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
-            // TODO: Cat needs to have limit. Try to reimplement so not hard-coded effect.
+            // Summons are counted per battle rather than by uses.
             PetName::Tapir => vec![Effect {
                 owner: None,
                 temp: record.temp_effect,
@@ -2223,7 +2389,9 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Some(record.lvl),
                     record.name,
                 )),
-                uses: Some(record.n_triggers),
+                uses: None,
+                limit: Some(ActivationLimit::new(LimitScope::Battle, record.n_triggers)),
+                ..Default::default()
             }],
             PetName::Walrus => vec![Effect {
                 owner: None,
@@ -2238,6 +2406,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 },
                 action: Action::Gain(GainType::DefaultItem(FoodName::Peanut)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::WhiteTiger => vec![Effect {
                 owner: None,
@@ -2247,6 +2416,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Nearest(-record.lvl.try_into()?),
                 action: Action::Experience(3),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Octopus => vec![Effect {
                 owner: None,
@@ -2261,6 +2431,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 },
                 action: Action::Remove(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Orca => vec![Effect {
                 owner: None,
@@ -2272,14 +2443,15 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Action::Summon(SummonType::QueryPet(
                         SAPQuery::builder()
                             .set_table(Entity::Pet)
-                            .set_param("effect_trigger", vec!["Faint"])
-                            .set_param("lvl", vec![1])
-                            .set_param("is_token", vec![false]),
+                            .set_param(PetColumn::EffectTrigger, vec!["Faint"])
+                            .set_param(PetColumn::Lvl, vec![1])
+                            .set_param(PetColumn::IsToken, vec![false]),
                         None
                     ));
                     record.lvl
                 ]),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Piranha => vec![
                 Effect {
@@ -2290,6 +2462,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::All(ItemCondition::None),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: None,
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -2299,6 +2472,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::All(ItemCondition::None),
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: None,
+                    ..Default::default()
                 },
             ],
             PetName::Reindeer => vec![Effect {
@@ -2309,6 +2483,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::OnSelf,
                 action: Action::Gain(GainType::DefaultItem(FoodName::Melon)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::SabertoothTiger => vec![Effect {
                 owner: None,
@@ -2319,13 +2494,14 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 action: Action::Summon(SummonType::QueryPet(
                     SAPQuery::builder()
                         .set_table(Entity::Pet)
-                        .set_param("lvl", vec![1])
-                        .set_param("tier", vec![1])
-                        .set_param("-name", vec![PetName::Sloth])
-                        .set_param("is_token", vec![false]),
+                        .set_param(PetColumn::Lvl, vec![1])
+                        .set_param(PetColumn::Tier, vec![1])
+                        .set_not_param(PetColumn::Name, vec![PetName::Sloth])
+                        .set_param(PetColumn::IsToken, vec![false]),
                     Some(effect_stats),
                 )),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Spinosaurus => vec![Effect {
                 owner: None,
@@ -2335,6 +2511,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::Any(ItemCondition::None),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Stegosaurus => vec![Effect {
                 owner: None,
@@ -2345,8 +2522,9 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ItemCondition::Equal(EqualityCondition::Name(EntityName::Food(FoodName::None))),
                     ItemCondition::NotEqual(EqualityCondition::IsSelf),
                 ])),
-                action: Action::Stegosaurus(effect_stats),
+                action: Action::Add(StatChangeType::TurnMultiplier(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Velociraptor => vec![Effect {
                 owner: None,
@@ -2363,6 +2541,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 },
                 action: Action::Gain(GainType::DefaultItem(FoodName::Coconut)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Dragon => vec![Effect {
                 owner: None,
@@ -2372,6 +2551,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::All(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Lioness => vec![Effect {
                 owner: None,
@@ -2381,6 +2561,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::None,
                 action: Action::AddShopStats(effect_stats),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Chicken => vec![Effect {
                 owner: None,
@@ -2390,6 +2571,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::None,
                 action: Action::AddShopStats(effect_stats),
                 uses: None,
+                ..Default::default()
             }],
             PetName::Sauropod => vec![Effect {
                 owner: None,
@@ -2397,8 +2579,9 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 trigger: TRIGGER_ANY_FOOD_BOUGHT,
                 target: Target::Shop,
                 position: Position::None,
-                action: Action::AlterGold(record.lvl.try_into()?),
+                action: Action::AlterGold(GoldChangeType::Static(record.lvl.try_into()?)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Tyrannosaurus => vec![Effect {
                 owner: None,
@@ -2408,6 +2591,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                 position: Position::All(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
                 action: Action::Add(StatChangeType::Static(effect_stats)),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Hammershark => vec![Effect {
                 owner: None,
@@ -2420,10 +2604,13 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                         Target::Friend,
                         ItemCondition::Equal(EqualityCondition::Level(3)),
                     )),
-                    Box::new(Action::AlterGold((record.lvl * 3).try_into()?)),
+                    Box::new(Action::AlterGold(GoldChangeType::Static(
+                        (record.lvl * 3).try_into()?,
+                    ))),
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
             }],
             PetName::Komodo => vec![
                 Effect {
@@ -2434,6 +2621,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Ahead,
                     action: Action::Add(StatChangeType::Static(effect_stats)),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
                 Effect {
                     owner: None,
@@ -2443,6 +2631,7 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     position: Position::Ahead,
                     action: Action::Shuffle(RandomizeType::Positions),
                     uses: Some(record.n_triggers),
+                    ..Default::default()
                 },
             ],
             PetName::Ostrich => vec![Effect {
@@ -2463,9 +2652,30 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     Box::new(Action::None),
                 ),
                 uses: Some(record.n_triggers),
+                ..Default::default()
+            }],
+            // Only the first foods bought each turn are multiplied.
+            PetName::Cat => vec![Effect {
+                owner: None,
+                temp: record.temp_effect,
+                trigger: TRIGGER_NONE,
+                target: Target::Friend,
+                position: Position::None,
+                action: Action::MultiplyFoodStats(record.lvl + 1),
+                uses: None,
+                limit: Some(ActivationLimit::new(LimitScope::Turn, record.n_triggers)),
+                ..Default::default()
+            }],
+            PetName::Tiger => vec![Effect {
+                owner: None,
+                temp: record.temp_effect,
+                trigger: TRIGGER_NONE,
+                target: Target::Friend,
+                position: Position::None,
+                action: Action::Repeat(RepeatSource::FriendAhead),
+                uses: None,
+                ..Default::default()
             }],
-            // PetName::Cat => todo!(),
-            // PetName::Tiger => todo!(),
             PetName::Gecko => {
                 vec![Effect {
                     owner: None,
@@ -2482,84 +2692,904 @@ impl TryFrom<PetRecord> for Vec<Effect> {
                     ),
                     uses: Some(record.n_triggers),
                     temp: record.temp_effect,
+                    ..Default::default()
                 }]
             }
-            // PetName::AfricanPenguin => todo!(),
-            // PetName::BlackNeckedStilt => todo!(),
-            // PetName::DoorHeadAnt => todo!(),
-            // PetName::Gazelle => todo!(),
-            // PetName::HerculesBeetle => todo!(),
-            // PetName::Lizard => todo!(),
-            // PetName::SeaTurtle => todo!(),
-            // PetName::SeaUrchin => todo!(),
-            // PetName::Squid => todo!(),
-            // PetName::Stoat => todo!(),
-            // PetName::BelugaSturgeon => todo!(),
-            // PetName::Lemur => todo!(),
-            // PetName::Mandrill => todo!(),
-            // PetName::Robin => todo!(),
-            // PetName::Baboon => todo!(),
-            // PetName::BettaFish => todo!(),
-            // PetName::Flea => todo!(),
-            // PetName::FlyingFish => todo!(),
-            // PetName::Guineafowl => todo!(),
-            // PetName::Meekrat => todo!(),
-            // PetName::MuskOx => todo!(),
-            // PetName::Osprey => todo!(),
-            // PetName::RoyalFlycatcher => todo!(),
-            // PetName::SurgeonFish => todo!(),
-            // PetName::Weasel => todo!(),
-            // PetName::FlyingSquirrel => todo!(),
-            // PetName::HoopoeBird => todo!(),
-            // PetName::Pangolin => todo!(),
-            // PetName::Cuttlefish => todo!(),
-            // PetName::EgyptianVulture => todo!(),
-            // PetName::Falcon => todo!(),
-            // PetName::Manatee => todo!(),
-            // PetName::MantaRay => todo!(),
-            // PetName::PoisonDartFrog => todo!(),
-            // PetName::SaigaAntelope => todo!(),
-            // PetName::Sealion => todo!(),
-            // PetName::SecretaryBird => todo!(),
-            // PetName::Slug => todo!(),
-            // PetName::Vaquita => todo!(),
-            // PetName::Chameleon => todo!(),
-            // PetName::Gharial => todo!(),
-            // PetName::Tahr => todo!(),
-            // PetName::WhaleShark => todo!(),
-            // PetName::BelugaWhale => todo!(),
-            // PetName::BlueRingedOctopus => todo!(),
-            // PetName::Cockatoo => todo!(),
-            // PetName::Crane => todo!(),
-            // PetName::Emu => todo!(),
-            // PetName::FireAnt => todo!(),
-            // PetName::Macaque => todo!(),
-            // PetName::NurseShark => todo!(),
-            // PetName::Nyala => todo!(),
-            // PetName::SilverFox => todo!(),
-            // PetName::Wolf => todo!(),
-            // PetName::Axolotl => todo!(),
-            // PetName::Mosasaurus => todo!(),
-            // PetName::Panther => todo!(),
-            // PetName::SnappingTurtle => todo!(),
-            // PetName::StingRay => todo!(),
-            // PetName::Stonefish => todo!(),
-            // PetName::BirdofParadise => todo!(),
-            // PetName::Catfish => todo!(),
-            // PetName::Cobra => todo!(),
-            // PetName::GermanShepherd => todo!(),
-            // PetName::GrizzlyBear => todo!(),
-            // PetName::HighlandCow => todo!(),
-            // PetName::Oyster => todo!(),
-            // PetName::Pteranodon => todo!(),
-            // PetName::Warthog => todo!(),
-            // PetName::Wildebeest => todo!(),
-            // PetName::AnglerFish => todo!(),
-            // PetName::ElephantSeal => todo!(),
-            // PetName::MantisShrimp => todo!(),
-            // PetName::Mongoose => todo!(),
-            // PetName::Puma => todo!(),
-            _ => Vec::default(),
-        })
+            PetName::AfricanPenguin => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_END_TURN,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::Multiple(vec![
+                        ItemCondition::Equal(EqualityCondition::Level(2)),
+                        ItemCondition::Equal(EqualityCondition::Level(3)),
+                    ]),
+                    targets: 1,
+                    random: false,
+                    exact_n_targets: false,
+                },
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::BlackNeckedStilt => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: // Must have a position to activate effect.
+                Position::TriggerAffected(None),
+                action: Action::AddToCounter(CounterKey::Trumpets, (2 * record.lvl).try_into()?),
+                uses: None,
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::DoorHeadAnt => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_AHEAD_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Gazelle => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Friend,
+                position: Position::Nearest(-1),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::HerculesBeetle => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_GAIN_PERK,
+                target: Target::Friend,
+                position: Position::TriggerAffected(None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Lizard => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_KNOCKOUT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SeaTurtle => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::Nearest(-record.lvl.try_into()?),
+                action: Action::Gain(GainType::DefaultItem(FoodName::Melon)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SeaUrchin => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Enemy,
+                position: Position::TriggerAfflicting(None),
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Squid => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: record.lvl,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Gain(GainType::DefaultItem(FoodName::Ink)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Stoat => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::Strongest,
+                    targets: record.lvl,
+                    random: false,
+                    exact_n_targets: false,
+                },
+                action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::BelugaSturgeon | PetName::FlyingFish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                // Summoned copy has its effect used up so it doesn't summon again.
+                action: Action::Summon(SummonType::SelfPet(Some(effect_stats), None, false)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Lemur => match record.lvl {
+                1 | 2 => vec![Effect {
+                    owner: None,
+                    trigger: TRIGGER_SELF_LEVELUP,
+                    target: Target::Friend,
+                    position: Position::All(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
+                    action: Action::Add(StatChangeType::Static(effect_stats)),
+                    uses: Some(record.n_triggers),
+                    temp: record.temp_effect,
+                    ..Default::default()
+                }],
+                _ => vec![],
+            },
+            PetName::Mandrill => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_END_TURN,
+                target: Target::Friend,
+                position: Position::Nearest(1),
+                action: Action::Conditional(
+                    // Three or fewer friends excluding self.
+                    LogicType::If(ConditionType::Team(
+                        Target::Friend,
+                        TeamCondition::NumberPets(Some(CondOrdering::LessEqual(4))),
+                    )),
+                    Box::new(Action::Add(StatChangeType::Static(effect_stats))),
+                    Box::new(Action::None),
+                ),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Robin => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_END_TURN,
+                target: Target::Shop,
+                position: Position::None,
+                action: Action::Multiple(vec![
+                    Action::AddShopFood(GainType::DefaultItem(
+                        FoodName::Egg
+                    ));
+                    record.lvl
+                ]),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Baboon => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_PET_SOLD,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::NotEqual(EqualityCondition::IsSelf),
+                    targets: 1,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Add(StatChangeType::ShopFoodsMultiplier(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::BettaFish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::Adjacent,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Flea => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::Healthiest,
+                    targets: record.lvl,
+                    random: false,
+                    exact_n_targets: false,
+                },
+                action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Guineafowl => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_PET_BOUGHT,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::NotEqual(EqualityCondition::IsSelf)),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Meekrat => {
+                let mut canned_food = Food::try_from(FoodName::CannedFood)?;
+                canned_food.cost = 0;
+                vec![Effect {
+                    owner: None,
+                    trigger: TRIGGER_SELF_PET_SOLD,
+                    target: Target::Shop,
+                    position: Position::None,
+                    action: Action::Multiple(vec![
+                        Action::AddShopFood(GainType::StoredItem(
+                            Box::new(canned_food)
+                        ));
+                        record.lvl
+                    ]),
+                    uses: Some(record.n_triggers),
+                    temp: record.temp_effect,
+                    ..Default::default()
+                }]
+            }
+            PetName::MuskOx => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_AHEAD_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Gain(GainType::DefaultItem(FoodName::Melon)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Osprey => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::Nearest(-1),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::RoyalFlycatcher => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_AHEAD_ATTACK,
+                target: Target::Enemy,
+                position: Position::Last,
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: None,
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SurgeonFish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_TURN,
+                target: Target::Friend,
+                position: Position::Nearest(1),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Weasel => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_PET_SOLD,
+                target: Target::Shop,
+                position: Position::All(ItemCondition::None),
+                action: Action::AddShopFoodStats(effect_stats),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::FlyingSquirrel => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: record.lvl,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::HoopoeBird => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Enemy,
+                position: Position::Multiple(vec![Position::First, Position::Last]),
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Pangolin => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Cuttlefish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::Weakest,
+                    targets: record.lvl,
+                    random: false,
+                    exact_n_targets: false,
+                },
+                action: Action::Gain(GainType::DefaultItem(FoodName::Ink)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::EgyptianVulture => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_FAINT,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: 1,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Falcon => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::Last,
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Manatee => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::MantaRay => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_BEFORE_ATTACK,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: record.lvl,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::PoisonDartFrog => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::Nearest(-1),
+                action: Action::Gain(GainType::DefaultItem(FoodName::Peanut)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SaigaAntelope => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Sealion => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::Ahead,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SecretaryBird => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_KNOCKOUT,
+                target: Target::Enemy,
+                position: Position::First,
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Slug => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FOOD_EATEN,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Vaquita => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_END_TURN,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::AddToCounter(CounterKey::Trumpets, record.lvl.try_into()?),
+                uses: None,
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Chameleon => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Copy(
+                    CopyType::Effect(vec![], Some(record.lvl)),
+                    Target::Friend,
+                    Position::Nearest(-1),
+                ),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Gharial => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_FOOD_EATEN,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: 1,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: None,
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Tahr => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::Nearest(-1),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::WhaleShark => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_END_TURN,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Conditional(
+                    LogicType::If(ConditionType::Shop(ShopCondition::NumberFoods(Some(
+                        CondOrdering::Equal(0),
+                    )))),
+                    Box::new(Action::Add(StatChangeType::Static(effect_stats))),
+                    Box::new(Action::None),
+                ),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::BelugaWhale => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Whale(record.lvl, Position::Nearest(-1)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::BlueRingedOctopus => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Gain(GainType::DefaultItem(FoodName::Peanut)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Cockatoo => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Copy(
+                    CopyType::Effect(vec![], Some(record.lvl)),
+                    Target::Friend,
+                    Position::Nearest(1),
+                ),
+                uses: None,
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Crane => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::Equal(EqualityCondition::Level(3))),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Emu => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::First,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::FireAnt => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::Nearest(1),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Macaque => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_END_TURN,
+                target: Target::Friend,
+                position: Position::Last,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::NurseShark => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: record.lvl,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Nyala => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_ENEMY_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SilverFox => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_FOOD_BOUGHT,
+                target: Target::Shop,
+                position: Position::None,
+                action: Action::AlterGold(GoldChangeType::Static(1)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Wolf => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Axolotl => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Mosasaurus => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::Nearest(record.lvl.try_into()?),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Panther => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_AHEAD_ATTACK,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: 1,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SnappingTurtle => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Enemy,
+                position: Position::TriggerAfflicting(None),
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::StingRay => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Enemy,
+                position: Position::TriggerAfflicting(None),
+                action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Stonefish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Enemy,
+                position: Position::TriggerAfflicting(None),
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::BirdofParadise => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Catfish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FOOD_EATEN,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: record.lvl,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Cobra => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::Healthiest,
+                    targets: record.lvl,
+                    random: false,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::GermanShepherd => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_SUMMON,
+                target: Target::Friend,
+                position: Position::TriggerAffected(None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::GrizzlyBear => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Enemy,
+                position: Position::All(ItemCondition::None),
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::HighlandCow => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_HURT,
+                target: Target::Friend,
+                position: Position::Nearest(-record.lvl.try_into()?),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Oyster => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::Nearest(-1),
+                action: Action::Gain(GainType::DefaultItem(FoodName::Melon)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Pteranodon => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Summon(SummonType::FaintedPet(Some(effect_stats), 1)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Warthog => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_KNOCKOUT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Wildebeest => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Enemy,
+                position: Position::First,
+                action: Action::Push(Position::Last),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::AnglerFish => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::N {
+                    condition: ItemCondition::None,
+                    targets: 1,
+                    random: true,
+                    exact_n_targets: false,
+                },
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::ElephantSeal => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_ANY_GAIN_PERK,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::MantisShrimp => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_BEFORE_ATTACK,
+                target: Target::Enemy,
+                position: Position::First,
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Mongoose => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_START_BATTLE,
+                target: Target::Enemy,
+                position: Position::N {
+                    condition: ItemCondition::HighestTier,
+                    targets: record.lvl,
+                    random: false,
+                    exact_n_targets: false,
+                },
+                action: Action::Remove(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::Puma => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_KNOCKOUT,
+                target: Target::Friend,
+                position: Position::All(ItemCondition::None),
+                action: Action::Add(StatChangeType::Static(effect_stats)),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            PetName::SmallerSlug => vec![Effect {
+                owner: None,
+                trigger: TRIGGER_SELF_FAINT,
+                target: Target::Friend,
+                position: Position::OnSelf,
+                action: Action::Summon(SummonType::CustomPet(
+                    PetName::SmallestSlug,
+                    StatChangeType::Static(effect_stats),
+                    record.lvl,
+                )),
+                uses: Some(record.n_triggers),
+                temp: record.temp_effect,
+                ..Default::default()
+            }],
+            // Tokens without abilities. Their stats are set by the effect summoning them.
+            // * Chick: Half of the Rooster's attack and 1 health.
+            // * Golden Retriever: The team's trumpets. See the Golden pack's effect.
+            PetName::SmallestSlug
+            | PetName::LizardTail
+            | PetName::GoldenRetriever
+            | PetName::Chick => Vec::default(),
+            _ => Vec::default(),
+        };
+
+        // Restore uses of effects that work a number of times per turn or battle.
+        if let Some(uses_refresh) = uses_refresh {
+            effects
+                .iter_mut()
+                .filter(|effect| effect.uses.is_some())
+                .for_each(|effect| effect.uses_refresh = Some(uses_refresh));
+        }
+        Ok(effects)
     }
 }
@@ -1,5 +1,7 @@
 //! [`Pet`](crate::Pet) names and logic.
 
+/// Win rates of [`Pet`](crate::Pet)s battling each other.
+pub mod analysis;
 /// Combat for individual [`Pet`](crate::Pet)s.
 pub mod combat;
 /// Effects for each [`Pet`](crate::Pet).
@@ -8,3 +10,7 @@ mod effects;
 pub mod names;
 /// Constructor and methods for [`Pet`](crate::Pet)s.
 pub mod pet;
+/// Registry of custom [`Pet`](crate::Pet)s.
+pub mod registry;
+/// Roles of [`Pet`](crate::Pet)s derived from their effects.
+pub mod role;
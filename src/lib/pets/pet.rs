@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use rand::random;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, RwLock};
@@ -7,11 +8,18 @@ use crate::{
         query::SAPQuery,
         record::{PetRecord, SAPRecord},
     },
-    effects::{effect::Effect, stats::Statistics},
+    effects::{
+        actions::{Action, SummonType},
+        effect::Effect,
+        state::Outcome,
+        stats::Statistics,
+        trigger::{TRIGGER_ANY_GAIN_AILMENT, TRIGGER_SELF_LEVELUP},
+    },
     error::SAPTestError,
-    foods::food::Food,
-    pets::names::PetName,
-    Entity, SAPDB,
+    foods::food::{Attachments, Food},
+    pets::{names::PetName, registry::get_custom_pet, role::PetRole},
+    shop::trigger::{TRIGGER_ANY_GAIN_PERK, TRIGGER_SELF_GAIN_PERK},
+    Economy, Entity, PetColumn, SAPDB,
 };
 
 /// Minimum pet level.
@@ -22,6 +30,8 @@ pub const MAX_PET_LEVEL: usize = 3;
 pub const MIN_PET_STATS: isize = 0;
 /// Maximum pet stats value.
 pub const MAX_PET_STATS: isize = 50;
+/// [`Effect`] tag of an effect releasing a swallowed pet. ex. [`Whale`](PetName::Whale)
+pub const SWALLOWED_TAG: &str = "swallowed";
 
 /// A Super Auto Pet.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,19 +40,28 @@ pub struct Pet {
     pub(crate) id: Option<usize>,
     /// Name for pet.
     pub name: PetName,
+    /// User-facing nickname for pet.
+    /// * Used in place of the pet's name and id in battle digraphs.
+    #[serde(default)]
+    pub nickname: Option<String>,
     /// Tier of pet.
     pub tier: usize,
     /// [`Statistics`] of pet.
     pub stats: Statistics,
     /// Pet [`Effect`]s.
     pub effect: Vec<Effect>,
-    /// Held pet [`Food`] item.
-    pub item: Option<Food>,
+    /// Held pet [`Food`] items.
+    pub items: Attachments,
     /// Seed for pet RNG.
     /// * Used in damage calculation for items like [`Fortune Cookie`](crate::foods::names::FoodName::FortuneCookie)
     pub seed: Option<u64>,
-    /// Cost of food.
+    /// Gold paid for pet.
+    /// * Defaults to the base shop cost of the pet.
     pub cost: usize,
+    /// Gold added to the pet's sell value.
+    /// * Ex. [`GoldFish`](crate::PetName::GoldFish)
+    #[serde(default)]
+    pub sell_modifier: isize,
     /// Level of pet.
     pub(crate) lvl: usize,
     /// Experience of pet.
@@ -51,6 +70,9 @@ pub struct Pet {
     pub(crate) pos: Option<usize>,
     /// Team name.
     pub(crate) team: Option<String>,
+    /// Pet was dealt damage.
+    #[serde(skip)]
+    pub(crate) hurt: bool,
 }
 
 /// Assign effect owner.
@@ -58,7 +80,7 @@ pub(crate) fn reassign_effects(pet: &Arc<RwLock<Pet>>) {
     for effect in pet.write().unwrap().effect.iter_mut() {
         effect.assign_owner(Some(pet));
     }
-    if let Some(food_item) = pet.write().unwrap().item.as_mut() {
+    for food_item in pet.write().unwrap().items.iter_mut() {
         food_item.ability.assign_owner(Some(pet));
     }
 }
@@ -72,18 +94,20 @@ impl PartialEq for Pet {
             && self.lvl == other.lvl
             && self.exp == other.exp
             && self.effect == other.effect
-            && self.item == other.item
+            && self.items == other.items
             && self.pos == other.pos
             && self.cost == other.cost
+            && self.sell_modifier == other.sell_modifier
     }
 }
 
 impl std::fmt::Display for Pet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let item_str = self
-            .item
-            .as_ref()
-            .map_or_else(|| "None".to_string(), |item| item.to_string());
+        let item_str = if self.items.is_empty() {
+            "None".to_string()
+        } else {
+            self.items.iter().join(", ")
+        };
         write!(
             f,
             "[{}: ({},{}) (Level: {} Exp: {}) (Pos: {:?}) (Item: {})]",
@@ -118,20 +142,47 @@ impl TryFrom<PetRecord> for Pet {
             lvl,
             exp: 0,
             effect,
-            item: None,
+            items: Attachments::default(),
             pos: None,
             cost,
             seed: random(),
             team: None,
+            hurt: false,
+            nickname: None,
+            sell_modifier: 0,
         })
     }
 }
 
+/// Query the [`PetRecord`] of a pet at a given level.
+pub(crate) fn query_pet_record(name: &PetName, lvl: usize) -> Result<PetRecord, SAPTestError> {
+    let pet_query = SAPQuery::builder()
+        .set_param(PetColumn::Name, vec![name])
+        .set_param(PetColumn::Lvl, vec![lvl])
+        .set_table(Entity::Pet);
+    SAPDB
+        .execute_query(pet_query)?
+        .into_iter()
+        .next()
+        .and_then(|record| {
+            if let SAPRecord::Pet(record) = record {
+                Some(record)
+            } else {
+                None
+            }
+        })
+        .ok_or(SAPTestError::QueryFailure {
+            subject: "No Pet Effect".to_string(),
+            reason: format!("No effect for {name} at level {lvl}."),
+        })
+}
+
 impl Pet {
     /// Create a new pet.
     /// * All [`Effect`]s are determined by the given `stats` and `lvl`.
     ///     * To use custom [`Effect`]s, use the [`custom`](crate::Pet) constructor.
     /// * Providing `None` for `stats` will yield the default [`Statistics`] for the pet at the given `lvl`.
+    /// * Custom pets are built from the [registry](crate::pets::registry::register_custom_pet).
     /// * By default, pets are randomly seeded.
     /// # Examples
     /// ```
@@ -154,11 +205,29 @@ impl Pet {
     /// )
     /// ```
     pub fn new(name: PetName, stats: Option<Statistics>, lvl: usize) -> Result<Pet, SAPTestError> {
-        let conn = SAPDB.pool.get()?;
-        let mut stmt = conn.prepare("SELECT * FROM pets WHERE name = ? AND lvl = ?")?;
-        let pet_record: PetRecord = stmt
-            .query([name.to_string(), lvl.to_string()])?
-            .next()?
+        if let Some(mut pet) = get_custom_pet(&name) {
+            if !(MIN_PET_LEVEL..=MAX_PET_LEVEL).contains(&lvl) {
+                return Err(SAPTestError::QueryFailure {
+                    subject: "No Pet Found".to_string(),
+                    reason: format!("No pet ({name}) found at level ({lvl})."),
+                });
+            }
+            pet.lvl = lvl;
+            pet.seed = random();
+            if let Some(pet_stats) = stats {
+                pet.stats.attack = pet_stats.attack.clamp(MIN_PET_STATS, MAX_PET_STATS);
+                pet.stats.health = pet_stats.health.clamp(MIN_PET_STATS, MAX_PET_STATS);
+            };
+            return Ok(pet);
+        }
+        let pet_query = SAPQuery::builder()
+            .set_table(Entity::Pet)
+            .set_param(PetColumn::Name, vec![&name])
+            .set_param(PetColumn::Lvl, vec![lvl]);
+        let pet_record: PetRecord = SAPDB
+            .execute_query(pet_query)?
+            .into_iter()
+            .next()
             .ok_or(SAPTestError::QueryFailure {
                 subject: "No Pet Found".to_string(),
                 reason: format!("No pet ({name}) found at level ({lvl})."),
@@ -178,6 +247,7 @@ impl Pet {
 
     /// Build a custom pet.
     /// * Custom pets have `level` and `tier` of `0` by default.
+    /// * Custom pets must be [registered](crate::pets::registry::register_custom_pet) to be created by name.
     /// # Example
     /// ```rust
     /// use saptest::{
@@ -215,11 +285,14 @@ impl Pet {
             lvl: 1,
             exp: 0,
             effect: effect.to_vec(),
-            item: None,
+            items: Attachments::default(),
             pos: None,
             cost: 3,
             seed: random(),
             team: None,
+            hurt: false,
+            nickname: None,
+            sell_modifier: 0,
         }
     }
 
@@ -238,26 +311,172 @@ impl Pet {
     /// )
     /// ```
     pub fn get_effect(&self, lvl: usize) -> Result<Vec<Effect>, SAPTestError> {
-        let pet_query = SAPQuery::builder()
-            .set_param("name", vec![&self.name])
-            .set_param("lvl", vec![lvl])
-            .set_table(Entity::Pet);
-        SAPDB
-            .execute_query(pet_query)?
-            .into_iter()
-            .next()
-            .and_then(|record| {
-                if let SAPRecord::Pet(record) = record {
-                    Some(record)
-                } else {
-                    None
-                }
+        // Custom pets have the same effects at every level.
+        if let Some(custom_pet) = get_custom_pet(&self.name) {
+            return Ok(custom_pet.effect);
+        }
+        query_pet_record(&self.name, lvl)?.try_into()
+    }
+
+    /// Get the effects of this pet at a given level without changing the pet's level.
+    /// * Errors if the level is outside the range of pet levels.
+    /// * Effects are built without an owner.
+    /// # Examples
+    /// ```rust
+    /// use saptest::{Pet, PetName};
+    ///
+    /// let ant = Pet::try_from(PetName::Ant).unwrap();
+    ///
+    /// // Compare level 1 and level 3 ant effects.
+    /// let lvl_1_effects = ant.effects_at_level(1).unwrap();
+    /// let lvl_3_effects = ant.effects_at_level(3).unwrap();
+    /// assert_ne!(lvl_1_effects, lvl_3_effects);
+    /// assert_eq!(ant.get_level(), 1);
+    ///
+    /// assert!(ant.effects_at_level(4).is_err());
+    /// ```
+    pub fn effects_at_level(&self, lvl: usize) -> Result<Vec<Effect>, SAPTestError> {
+        if !(MIN_PET_LEVEL..=MAX_PET_LEVEL).contains(&lvl) {
+            return Err(SAPTestError::InvalidPetAction {
+                subject: "Invalid Level".to_string(),
+                reason: format!("{} has no effect at level {lvl}.", self.name),
+            });
+        }
+        self.get_effect(lvl)
+    }
+
+    /// Get the pet's effects with a tag.
+    /// * See [`Effect::has_tag`].
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName};
+    ///
+    /// let cricket = Pet::try_from(PetName::Cricket).unwrap();
+    /// assert_eq!(cricket.effects_tagged("summon").len(), 1);
+    /// assert!(cricket.effects_tagged("damage").is_empty());
+    /// ```
+    pub fn effects_tagged(&self, tag: &str) -> Vec<&Effect> {
+        self.effect
+            .iter()
+            .filter(|effect| effect.has_tag(tag))
+            .collect()
+    }
+
+    /// Get the pet's [`PetRole`]s derived from its current effects.
+    /// * See [`PetRole::from_effect`].
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, pets::role::PetRole};
+    ///
+    /// let cricket = Pet::try_from(PetName::Cricket).unwrap();
+    /// assert_eq!(cricket.roles(), [PetRole::Summoner, PetRole::FaintSynergy]);
+    /// ```
+    pub fn roles(&self) -> Vec<PetRole> {
+        PetRole::from_effects(&self.effect)
+    }
+
+    /// Get the pet swallowed by this pet, if any.
+    /// * ex. A [`Whale`](PetName::Whale) swallows the friend ahead at the start of battle and releases it on faint.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName};
+    ///
+    /// let whale = Pet::try_from(PetName::Whale).unwrap();
+    /// assert!(whale.swallowed_pet().is_none());
+    /// ```
+    pub fn swallowed_pet(&self) -> Option<&Pet> {
+        self.effect
+            .iter()
+            .filter(|effect| effect.has_tag(SWALLOWED_TAG))
+            .find_map(|effect| match &effect.action {
+                Action::Summon(SummonType::StoredPet(pet)) => Some(pet.as_ref()),
+                _ => None,
             })
-            .ok_or(SAPTestError::QueryFailure {
-                subject: "No Pet Effect".to_string(),
-                reason: format!("No effect for {} at level {lvl}.", self.name),
-            })?
-            .try_into()
+    }
+
+    /// Give the pet an item to hold.
+    /// * Perks and ailments are held separately. See [`Attachments`].
+    /// * Returns the replaced item of the same [`ItemKind`](crate::ItemKind), if any.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Food, FoodName};
+    ///
+    /// let mut pet = Pet::try_from(PetName::Ant).unwrap();
+    /// pet.gain_item(Food::try_from(FoodName::Ink).unwrap());
+    /// // Eating garlic doesn't remove ink.
+    /// assert!(pet.gain_item(Food::try_from(FoodName::Garlic).unwrap()).is_none());
+    /// assert_eq!(pet.items.perk.as_ref().unwrap().name, FoodName::Garlic);
+    /// assert_eq!(pet.items.ailment.as_ref().unwrap().name, FoodName::Ink);
+    /// ```
+    pub fn gain_item(&mut self, item: Food) -> Option<Food> {
+        self.items.attach(item)
+    }
+
+    /// Equip an item and get the triggers for gaining it.
+    /// * Perks and ailments are held separately. See [`Attachments`].
+    /// * Perks give a [`TRIGGER_SELF_GAIN_PERK`] and [`TRIGGER_ANY_GAIN_PERK`].
+    /// * Ailments give a [`TRIGGER_ANY_GAIN_AILMENT`].
+    ///     * The affected pet is unset as the pet isn't on a [`Team`](crate::Team).
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Food, FoodName, effects::trigger::TRIGGER_ANY_GAIN_AILMENT};
+    ///
+    /// let mut pet = Pet::try_from(PetName::Ant).unwrap();
+    /// let triggers = pet.equip(Food::try_from(FoodName::Ink).unwrap());
+    /// assert_eq!(triggers, vec![TRIGGER_ANY_GAIN_AILMENT]);
+    /// assert_eq!(pet.items.ailment.as_ref().unwrap().name, FoodName::Ink);
+    /// ```
+    pub fn equip(&mut self, item: Food) -> Vec<Outcome> {
+        let triggers = if item.is_ailment() {
+            vec![TRIGGER_ANY_GAIN_AILMENT]
+        } else {
+            vec![TRIGGER_SELF_GAIN_PERK, TRIGGER_ANY_GAIN_PERK]
+        };
+        self.items.attach(item);
+        triggers
+    }
+
+    /// Remove and return all items held by the pet.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Food, FoodName};
+    ///
+    /// let mut pet = Pet::try_from(PetName::Ant).unwrap();
+    /// pet.equip(Food::try_from(FoodName::Garlic).unwrap());
+    ///
+    /// let items = pet.unequip();
+    /// assert_eq!(items.perk.unwrap().name, FoodName::Garlic);
+    /// assert!(pet.items.is_empty());
+    /// ```
+    pub fn unequip(&mut self) -> Attachments {
+        std::mem::take(&mut self.items)
+    }
+
+    /// Give the pet an ailment.
+    /// * Ailments don't stack. The ailment replaces any previous ailment.
+    ///
+    /// Returns the replaced ailment, if any.
+    ///
+    /// Raises an error if the [`Food`] is not an ailment.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Food, FoodName};
+    ///
+    /// let mut pet = Pet::try_from(PetName::Ant).unwrap();
+    /// pet.gain_ailment(Food::try_from(FoodName::Ink).unwrap()).unwrap();
+    ///
+    /// let replaced = pet.gain_ailment(Food::try_from(FoodName::Weak).unwrap()).unwrap();
+    /// assert_eq!(replaced.unwrap().name, FoodName::Ink);
+    /// assert!(pet.gain_ailment(Food::try_from(FoodName::Honey).unwrap()).is_err());
+    /// ```
+    pub fn gain_ailment(&mut self, ailment: Food) -> Result<Option<Food>, SAPTestError> {
+        if !ailment.is_ailment() {
+            return Err(SAPTestError::InvalidPetAction {
+                subject: "Gain Ailment".to_string(),
+                reason: format!("{} is not an ailment.", ailment.name),
+            });
+        }
+        Ok(self.items.attach(ailment))
     }
 
     /// Get pet experience.
@@ -273,6 +492,86 @@ impl Pet {
         self.exp
     }
 
+    /// Get the gold gained from selling this pet.
+    /// * Based on the pet's level and its [`sell_modifier`](Pet::sell_modifier).
+    /// # Example
+    /// ```
+    /// use saptest::{Economy, Pet, PetName};
+    ///
+    /// let mut pet = Pet::try_from(PetName::GoldFish).unwrap();
+    /// let economy = Economy::default();
+    /// assert_eq!(pet.sell_value(&economy), 1);
+    ///
+    /// pet.sell_modifier += 2;
+    /// assert_eq!(pet.sell_value(&economy), 3);
+    ///
+    /// pet.sell_modifier = -5;
+    /// assert_eq!(pet.sell_value(&economy), 0);
+    /// ```
+    pub fn sell_value(&self, economy: &Economy) -> usize {
+        economy
+            .sell_value(self.lvl)
+            .saturating_add_signed(self.sell_modifier)
+    }
+
+    /// Get pet identifier.
+    /// * Ids are assigned deterministically when a pet is added to a [`Team`](crate::Team) and are kept when the team is serialized or copied.
+    /// * Pets not on a team have no id.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Team, TeamViewer};
+    ///
+    /// let pet = Pet::try_from(PetName::Ant).unwrap();
+    /// assert_eq!(pet.get_id(), None);
+    ///
+    /// let team = Team::new(&[Some(pet.clone()), Some(pet)], 5).unwrap();
+    /// assert_eq!(team.nth(1).unwrap().read().unwrap().get_id(), Some(1));
+    /// ```
+    pub fn get_id(&self) -> Option<usize> {
+        self.id
+    }
+
+    /// Set or remove a pet's nickname.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName};
+    ///
+    /// let mut pet = Pet::try_from(PetName::Ant).unwrap();
+    /// pet.set_nickname(Some("Anthony"));
+    /// assert_eq!(pet.nickname.as_deref(), Some("Anthony"));
+    ///
+    /// pet.set_nickname(None);
+    /// assert_eq!(pet.nickname, None);
+    /// ```
+    pub fn set_nickname(&mut self, nickname: Option<&str>) -> &mut Self {
+        self.nickname = nickname.map(|nickname| nickname.to_owned());
+        self
+    }
+
+    /// Get the label of a pet used to identify it in battle digraphs.
+    /// * The label is the pet's nickname if it has one.
+    ///     * Nicknames should be unique within a team.
+    /// * Otherwise, the label is the pet's name and id. ex. `Ant_3`
+    /// * Pets without a nickname or id have no label.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Team, TeamViewer};
+    ///
+    /// let mut pet = Pet::try_from(PetName::Ant).unwrap();
+    /// assert_eq!(pet.label(), None);
+    ///
+    /// let team = Team::new(&[Some(pet.clone())], 5).unwrap();
+    /// assert_eq!(team.first().unwrap().read().unwrap().label().unwrap(), "Ant_0");
+    ///
+    /// pet.set_nickname(Some("Anthony"));
+    /// assert_eq!(pet.label().unwrap(), "Anthony");
+    /// ```
+    pub fn label(&self) -> Option<String> {
+        self.nickname
+            .clone()
+            .or_else(|| self.id.map(|id| format!("{}_{}", self.name, id)))
+    }
+
     /// Get pet level.
     /// # Example
     /// ```
@@ -405,38 +704,75 @@ impl Pet {
             Ok(self)
         }
     }
-    /// Merge a pet with another pet.
-    /// # Examples
+
+    /// Create a fully independent copy of this pet.
+    /// * The copy's [`Effect`]s and held items are owned by the copy rather than this pet.
+    /// * The copy has no id. A new one is assigned when it's added to a [`Team`](crate::Team).
+    /// # Example
     /// ```
+    /// use std::sync::{Arc, RwLock};
     /// use saptest::{Pet, PetName};
     ///
-    /// let mut pet = Pet::try_from(PetName::Gorilla).unwrap();
-    /// let other_pet = Pet::try_from(PetName::Gorilla).unwrap();
+    /// let ant = Arc::new(RwLock::new(Pet::try_from(PetName::Ant).unwrap()));
+    /// ant.write().unwrap().effect[0].assign_owner(Some(&ant));
     ///
-    /// assert!(pet.merge(&other_pet).is_ok());
+    /// let ant_copy = ant.read().unwrap().duplicate();
+    /// let copy_owner = ant_copy.read().unwrap().effect[0].get_owner().unwrap();
+    /// assert!(Arc::ptr_eq(&copy_owner.upgrade().unwrap(), &ant_copy));
     /// ```
-    pub fn merge(&mut self, from: &Pet) -> Result<&mut Self, SAPTestError> {
-        if self.name == from.name {
-            // Stack pet. Take max attack and health from pet.
-            let max_attack = self.stats.attack.max(from.stats.attack);
-            let max_health = self.stats.health.max(from.stats.health);
-            self.stats.attack = max_attack;
-            self.stats.health = max_health;
-
-            // Add exp accumulated by from pet.
-            // * Experience + 1 from pet itself.
-            self.add_experience(from.exp + 1)?;
-
-            // Refresh effects.
-            self.effect = self.get_effect(self.lvl)?;
+    pub fn duplicate(&self) -> Arc<RwLock<Pet>> {
+        let mut copied_pet = self.clone();
+        copied_pet.id = None;
+        let rc_copied_pet = Arc::new(RwLock::new(copied_pet));
+        reassign_effects(&rc_copied_pet);
+        rc_copied_pet
+    }
 
-            Ok(self)
-        } else {
-            Err(SAPTestError::InvalidPetAction {
+    /// Merge another pet into this pet.
+    /// * The higher attack and health of both pets are kept.
+    /// * The consumed pet's experience plus one is added to this pet, giving `+1/+1` per point.
+    /// * This pet keeps its held items. If it holds no perk, it takes the consumed pet's perk.
+    /// * Returns a [`TRIGGER_SELF_LEVELUP`] [`Outcome`] for every level gained.
+    ///     * The affected pet is unset as the pet isn't on a [`Team`](crate::Team).
+    ///
+    /// Errors if the pets have different names or this pet is at the max level.
+    /// # Examples
+    /// ```
+    /// use saptest::{Pet, PetName, Statistics};
+    ///
+    /// let mut pet = Pet::new(PetName::Gorilla, Some(Statistics::new(7, 12).unwrap()), 1).unwrap();
+    /// let mut other_pet = Pet::new(PetName::Gorilla, Some(Statistics::new(9, 10).unwrap()), 1).unwrap();
+    /// other_pet.add_experience(1).unwrap();
+    ///
+    /// // Other pet has 1 exp (10/11) so 2 exp is gained. Pet levels up.
+    /// let levelups = pet.merge(other_pet).unwrap();
+    /// assert_eq!(levelups.len(), 1);
+    /// assert_eq!(pet.get_level(), 2);
+    /// assert_eq!(pet.stats, Statistics::new(12, 14).unwrap());
+    /// ```
+    pub fn merge(&mut self, from: Pet) -> Result<Vec<Outcome>, SAPTestError> {
+        if self.name != from.name {
+            return Err(SAPTestError::InvalidPetAction {
                 subject: "Incompatible Pets".to_string(),
                 reason: format!("Cannot merge {self} with {from}."),
-            })
+            });
         }
+        let prev_lvl = self.lvl;
+
+        // Stack pet. Take max attack and health from pet.
+        self.stats.attack = self.stats.attack.max(from.stats.attack);
+        self.stats.health = self.stats.health.max(from.stats.health);
+
+        // Add exp accumulated by from pet.
+        // * Experience + 1 from pet itself.
+        self.add_experience(from.exp + 1)?;
+
+        // Keep current perk, otherwise take consumed pet's perk.
+        if self.items.perk.is_none() {
+            self.items.perk = from.items.perk;
+        }
+
+        Ok(vec![TRIGGER_SELF_LEVELUP; self.lvl - prev_lvl])
     }
 
     /// Swap a pets stats with another on the team.
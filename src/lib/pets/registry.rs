@@ -0,0 +1,95 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use lazy_static::lazy_static;
+
+use crate::{error::SAPTestError, Pet, PetName};
+
+lazy_static! {
+    /// Registered custom pets by name.
+    static ref CUSTOM_PETS: RwLock<HashMap<String, Pet>> = RwLock::new(HashMap::new());
+}
+
+/// Register a custom [`Pet`] so it can be rebuilt from its [`PetName`].
+/// * Registered pets can be created with [`Pet::new`] or [`Pet::try_from`] like any other pet.
+///     * This allows custom pets to be summoned by name with [`SummonType`](crate::effects::actions::SummonType) and reconstructed after serialization.
+///     * Custom pets have the same [`Effect`](crate::Effect)s at every level.
+/// * Registered pets with a tier can be rolled in a [`Shop`](crate::Shop) if allowed by its [pool filter](crate::Shop::set_pool_filter).
+/// * Registering a pet with the same name replaces the previous pet which is returned.
+/// * Errors if the pet doesn't have a [`PetName::Custom`] name.
+/// # Example
+/// ```
+/// use saptest::{Pet, PetName, Statistics, pets::registry::register_custom_pet};
+///
+/// let bear = Pet::custom("Bear", Statistics::new(5, 5).unwrap(), &[]);
+/// register_custom_pet(&bear).unwrap();
+///
+/// let new_bear = Pet::new(PetName::Custom("Bear".to_string()), None, 2).unwrap();
+/// assert_eq!(new_bear.stats, Statistics::new(5, 5).unwrap());
+/// assert_eq!(new_bear.get_level(), 2);
+///
+/// assert!(register_custom_pet(&Pet::try_from(PetName::Ant).unwrap()).is_err());
+/// ```
+pub fn register_custom_pet(pet: &Pet) -> Result<Option<Pet>, SAPTestError> {
+    let PetName::Custom(name) = &pet.name else {
+        return Err(SAPTestError::InvalidPetAction {
+            subject: "Register Custom Pet".to_string(),
+            reason: format!("{} is not a custom pet.", pet.name),
+        });
+    };
+    // Store pet without any team-specific state.
+    let mut template = pet.clone();
+    template.id = None;
+    template.pos = None;
+    template.team = None;
+    template.hurt = false;
+    template.nickname = None;
+    template.sell_modifier = 0;
+    template.items.clear();
+    for effect in template.effect.iter_mut() {
+        effect.assign_owner(None);
+    }
+    Ok(CUSTOM_PETS
+        .write()
+        .unwrap()
+        .insert(name.to_owned(), template))
+}
+
+/// Remove a registered custom [`Pet`] by its [`PetName`].
+/// * Returns the removed pet if it was registered.
+/// # Example
+/// ```
+/// use saptest::{Pet, PetName, Statistics, pets::registry::{register_custom_pet, unregister_custom_pet}};
+///
+/// let name = PetName::Custom("Moose".to_string());
+/// register_custom_pet(&Pet::custom("Moose", Statistics::new(1, 1).unwrap(), &[])).unwrap();
+///
+/// assert!(unregister_custom_pet(&name).is_some());
+/// assert!(unregister_custom_pet(&name).is_none());
+/// ```
+pub fn unregister_custom_pet(name: &PetName) -> Option<Pet> {
+    let PetName::Custom(name) = name else {
+        return None;
+    };
+    CUSTOM_PETS.write().unwrap().remove(name)
+}
+
+/// Get a copy of a registered custom [`Pet`] by its [`PetName`].
+/// * Returns `None` if no custom pet is registered with this name.
+pub fn get_custom_pet(name: &PetName) -> Option<Pet> {
+    let PetName::Custom(name) = name else {
+        return None;
+    };
+    CUSTOM_PETS.read().unwrap().get(name).cloned()
+}
+
+/// Get copies of all registered custom [`Pet`]s sorted by name.
+pub fn registered_custom_pets() -> Vec<Pet> {
+    let mut pets = CUSTOM_PETS
+        .read()
+        .unwrap()
+        .values()
+        .cloned()
+        .collect::<Vec<Pet>>();
+    pets.sort_by_key(|pet| pet.name.to_string());
+    pets
+}
@@ -0,0 +1,98 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    effects::state::{Position, Status, Target},
+    Effect,
+};
+
+/// Role of a [`Pet`](crate::Pet) derived from its [`Effect`]s.
+/// * Useful for building draft or shop strategies.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PetRole {
+    /// Summons pets.
+    /// * Ex. [`Cricket`](crate::PetName::Cricket)
+    Summoner,
+    /// Gives stats to other friends.
+    /// * Ex. [`Ant`](crate::PetName::Ant)
+    Buffer,
+    /// Deals damage to enemies outside of attacks.
+    /// * Ex. [`Mosquito`](crate::PetName::Mosquito)
+    Sniper,
+    /// Permanently gains stats itself.
+    /// * Ex. [`Kangaroo`](crate::PetName::Kangaroo)
+    Scaler,
+    /// Activates when pets faint.
+    /// * Ex. [`Flamingo`](crate::PetName::Flamingo)
+    FaintSynergy,
+}
+
+impl Display for PetRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PetRole::Summoner => write!(f, "Summoner"),
+            PetRole::Buffer => write!(f, "Buffer"),
+            PetRole::Sniper => write!(f, "Sniper"),
+            PetRole::Scaler => write!(f, "Scaler"),
+            PetRole::FaintSynergy => write!(f, "Faint Synergy"),
+        }
+    }
+}
+
+impl PetRole {
+    /// Get the roles of a single [`Effect`].
+    /// # Example
+    /// ```
+    /// use saptest::{
+    ///     Effect, Statistics,
+    ///     effects::{
+    ///         actions::{Action, StatChangeType},
+    ///         state::{Position, Target},
+    ///         trigger::TRIGGER_SELF_FAINT,
+    ///     },
+    ///     pets::role::PetRole,
+    /// };
+    ///
+    /// let effect = Effect::new(
+    ///     TRIGGER_SELF_FAINT,
+    ///     Target::Enemy,
+    ///     Position::Last,
+    ///     Action::Remove(StatChangeType::Static(Statistics::new(2, 0).unwrap())),
+    ///     Some(1),
+    ///     false,
+    /// );
+    /// assert_eq!(PetRole::from_effect(&effect), [PetRole::Sniper, PetRole::FaintSynergy]);
+    /// ```
+    pub fn from_effect(effect: &Effect) -> Vec<PetRole> {
+        let mut roles = vec![];
+        if effect.has_tag("summon") {
+            roles.push(PetRole::Summoner)
+        }
+        if effect.has_tag("buff") && effect.target == Target::Friend {
+            if effect.position != Position::OnSelf {
+                roles.push(PetRole::Buffer)
+            } else if !effect.temp {
+                roles.push(PetRole::Scaler)
+            }
+        }
+        if effect.has_tag("damage") && effect.target == Target::Enemy {
+            roles.push(PetRole::Sniper)
+        }
+        if effect.trigger.status == Status::Faint {
+            roles.push(PetRole::FaintSynergy)
+        }
+        roles
+    }
+
+    /// Get the unique roles of all [`Effect`]s in order.
+    pub fn from_effects<'a>(effects: impl IntoIterator<Item = &'a Effect>) -> Vec<PetRole> {
+        let mut roles = effects
+            .into_iter()
+            .flat_map(PetRole::from_effect)
+            .collect::<Vec<PetRole>>();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+}
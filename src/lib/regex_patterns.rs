@@ -28,10 +28,12 @@ pub static RGX_PET_ROW: &LRegex = regex!(r#"\{\{:Pets/row([\s\W|\w]*?)\n\}\}"#);
 pub static RGX_PET_NAME: &LRegex = regex!(r#"pet\s*=\s*(.*?)\n"#);
 pub static RGX_PET_STATS: &LRegex =
     regex!(r#"attack\s*=\s*(?P<attack>\d+)\s*\|\s*health\s*=\s*(?P<health>\d+)"#);
-pub static RGX_PET_PACK: &LRegex = regex!(r#"(\w+pack)+"#);
+pub static RGX_PET_PACK: &LRegex = regex!(r#"(\w+pack)\s*=\s*yes"#);
 pub static RGX_PET_EFFECT_TRIGGER: &LRegex = regex!(r#"\|\s*'''(.*?)'''"#);
 pub static RGX_PET_EFFECT: &LRegex = regex!(r#"→\s*(.*?)\n"#);
 pub static RGX_PET_EFFECT_TRIGGERLESS: &LRegex = regex!(r#"\|\s*([^[=]]*?\.*)\n"#);
+pub static RGX_PET_LVL_VALUES: &LRegex =
+    regex!(r#"([+-]?\d+%?)\s*/\s*([+-]?\d+%?)\s*/\s*([+-]?\d+%?)"#);
 
 // Token
 pub static RGX_SUMMON_STATS: &LRegex = regex!(r#"\|\s*colspan="(\d+)"\s*\|\s*([\d\w]+/[\d\w]+)"#);
@@ -48,7 +50,10 @@ pub static RGX_START_TURN: &LRegex = regex!(r#"start\sof\severy\sturn"#);
 pub static RGX_END_TURN: &LRegex = regex!(r#"end\sof\sturn"#);
 pub static RGX_ONE_USE: &LRegex = regex!(r#"once"#);
 pub static RGX_RANDOM: &LRegex = regex!(r#"(a|one|two|three|1|2|3)\srandom"#);
+pub static RGX_N_PETS: &LRegex = regex!(r#"(two|three|all)\spets"#);
 pub static RGX_N_TRIGGERS: &LRegex = regex!(r#"(\d+)\stimes*"#);
+pub static RGX_PER_TURN: &LRegex = regex!(r#"per\sturn"#);
+pub static RGX_PER_BATTLE: &LRegex = regex!(r#"per\sbattle"#);
 pub static RGX_SUMMON_ATK: &LRegex = regex!(r#"(\d+)/"#);
 pub static RGX_SUMMON_HEALTH: &LRegex = regex!(r#"/(\d+)"#);
 pub static RGX_END_OF_BATTLE: &LRegex = regex!(r#"end of battle"#);
@@ -0,0 +1,230 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{error::SAPTestError, shop::viewer::ShopItemViewer, Entity, EntityName, Shop};
+
+/// Frequencies of items seen over many rolls of a [`Shop`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RollStats {
+    /// Number of rolls made.
+    pub n_rolls: usize,
+    /// Number of times each item appeared.
+    pub counts: Vec<(EntityName, usize)>,
+}
+
+impl RollStats {
+    /// Number of times an item appeared.
+    pub fn count(&self, name: &EntityName) -> usize {
+        self.counts
+            .iter()
+            .find_map(|(item, count)| (item == name).then_some(*count))
+            .unwrap_or(0)
+    }
+
+    /// Fraction of stocked slots of the same [`Entity`] type that held an item.
+    /// * Comparable to [`slot_chance`].
+    pub fn frequency(&self, name: &EntityName) -> f64 {
+        let n_items: usize = self
+            .counts
+            .iter()
+            .filter(|(item, _)| std::mem::discriminant(item) == std::mem::discriminant(name))
+            .map(|(_, count)| count)
+            .sum();
+        if n_items == 0 {
+            0.0
+        } else {
+            self.count(name) as f64 / n_items as f64
+        }
+    }
+
+    fn add(&mut self, name: EntityName) {
+        if let Some((_, count)) = self.counts.iter_mut().find(|(item, _)| *item == name) {
+            *count += 1
+        } else {
+            self.counts.push((name, 1))
+        }
+    }
+}
+
+/// Roll a configured [`Shop`] a number of times for each seed and count the items stocked.
+/// * Each seed generates the seeds of its rolls. Results are reproducible for the same seeds.
+/// * Frozen items are kept between rolls and not counted.
+/// * Coins are not spent.
+/// # Example
+/// ```
+/// use saptest::{Shop, EntityName, PetName, shop::analysis::roll_stats};
+///
+/// let shop = Shop::default();
+/// let stats = roll_stats(&shop, 0..10, 10).unwrap();
+/// assert_eq!(stats.n_rolls, 100);
+/// // Tier 1 shops have three pet slots.
+/// let n_pets: usize = stats
+///     .counts
+///     .iter()
+///     .filter_map(|(name, count)| matches!(name, EntityName::Pet(_)).then_some(count))
+///     .sum();
+/// assert_eq!(n_pets, 300);
+/// // Dolphins are tier 4.
+/// assert_eq!(stats.count(&EntityName::Pet(PetName::Dolphin)), 0);
+/// ```
+pub fn roll_stats(
+    shop: &Shop,
+    seeds: impl IntoIterator<Item = u64>,
+    rolls_per_seed: usize,
+) -> Result<RollStats, SAPTestError> {
+    let mut stats = RollStats::default();
+    for seed in seeds {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+        let mut shop = shop.clone();
+        for _ in 0..rolls_per_seed {
+            shop.pets.retain(|pet| pet.is_frozen());
+            shop.foods.retain(|food| food.is_frozen());
+            shop.toys.retain(|toy| toy.is_frozen());
+            shop.seed = Some(rng.gen());
+            shop.restock()?;
+
+            for item in shop
+                .pets
+                .iter()
+                .chain(shop.foods.iter())
+                .chain(shop.toys.iter())
+                .filter(|item| !item.is_frozen())
+            {
+                stats.add(item.name())
+            }
+            stats.n_rolls += 1;
+        }
+    }
+    Ok(stats)
+}
+
+/// Chance a single slot of a [`Shop`] contains an item.
+/// * A tier is chosen using the shop's [`slot_odds`](Shop::slot_odds) and an item is sampled uniformly from that tier of the shop's pool.
+/// * Pack weights and the sloth are not accounted for.
+/// * Raises [`SAPTestError::InvalidShopAction`] if given a [`ToyName`](crate::ToyName).
+/// # Example
+/// ```
+/// use saptest::{Shop, EntityName, PetName, shop::analysis::slot_chance};
+///
+/// let shop = Shop::default();
+/// let chance = slot_chance(&shop, &EntityName::Pet(PetName::Ant)).unwrap();
+/// assert!(chance > 0.0 && chance < 1.0);
+///
+/// // Dolphins are tier 4.
+/// let chance = slot_chance(&shop, &EntityName::Pet(PetName::Dolphin)).unwrap();
+/// assert_eq!(chance, 0.0);
+/// ```
+pub fn slot_chance(shop: &Shop, name: &EntityName) -> Result<f64, SAPTestError> {
+    let entity = match name {
+        EntityName::Pet(_) => Entity::Pet,
+        EntityName::Food(_) => Entity::Food,
+        EntityName::Toy(_) => {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Invalid Shop Item".to_string(),
+                reason: format!("Shop does not contain toys ({name:?})."),
+            })
+        }
+    };
+    let odds = shop.slot_odds()?;
+    let tier_odds = if entity == Entity::Pet {
+        odds.pet_tier_odds
+    } else {
+        odds.food_tier_odds
+    };
+    let pool = shop.pool_items(entity)?;
+    let Some(tier) = pool
+        .iter()
+        .find_map(|(item, tier)| (item == name).then_some(*tier))
+    else {
+        return Ok(0.0);
+    };
+    let n_tier_items = pool
+        .iter()
+        .filter(|(_, item_tier)| *item_tier == tier)
+        .count();
+    let tier_chance = tier_odds.get(tier - 1).copied().unwrap_or(0.0);
+
+    Ok(tier_chance / n_tier_items as f64)
+}
+
+/// Chance a single roll of a [`Shop`] contains at least one of an item.
+/// # Example
+/// ```
+/// use saptest::{Shop, EntityName, PetName, shop::analysis::{roll_chance, slot_chance}};
+///
+/// let shop = Shop::default();
+/// let ant = EntityName::Pet(PetName::Ant);
+/// // Three pet slots at tier 1.
+/// let chance = roll_chance(&shop, &ant).unwrap();
+/// let slot = slot_chance(&shop, &ant).unwrap();
+/// assert!((chance - (1.0 - (1.0 - slot).powi(3))).abs() < 1e-9);
+/// ```
+pub fn roll_chance(shop: &Shop, name: &EntityName) -> Result<f64, SAPTestError> {
+    let odds = shop.slot_odds()?;
+    let n_slots = if let EntityName::Food(_) = name {
+        odds.food_slots
+    } else {
+        odds.pet_slots
+    };
+    let slot_chance = slot_chance(shop, name)?;
+    Ok(1.0 - (1.0 - slot_chance).powi(n_slots as i32))
+}
+
+/// Chance of seeing an item at least once by the end of a given turn.
+/// * Each turn includes the opening shop and some number of rolls.
+/// * The shop's tier follows its [tier schedule](Shop::tier_schedule).
+/// # Example
+/// ```
+/// use saptest::{Shop, EntityName, PetName, shop::analysis::chance_by_turn};
+///
+/// let shop = Shop::default();
+/// let dolphin = EntityName::Pet(PetName::Dolphin);
+/// // Dolphins are tier 4 and unavailable until turn 7.
+/// assert_eq!(chance_by_turn(&shop, &dolphin, 6, 3).unwrap(), 0.0);
+/// assert!(chance_by_turn(&shop, &dolphin, 7, 3).unwrap() > 0.0);
+/// ```
+pub fn chance_by_turn(
+    shop: &Shop,
+    name: &EntityName,
+    turn: usize,
+    rolls_per_turn: usize,
+) -> Result<f64, SAPTestError> {
+    let mut shop = shop.clone();
+    let mut chance_not_seen = 1.0;
+    for curr_turn in 1..=turn {
+        let tier = shop.num_turns_to_tier(curr_turn);
+        shop.set_tier(tier)?;
+        let roll_chance = roll_chance(&shop, name)?;
+        chance_not_seen *= (1.0 - roll_chance).powi(rolls_per_turn as i32 + 1);
+    }
+    Ok(1.0 - chance_not_seen)
+}
+
+/// Expected number of turns to see an item at least once in a [`Shop`] at its current tier.
+/// * Each turn includes the opening shop and some number of rolls.
+/// * Returns [`f64::INFINITY`] if the item can never be found.
+/// # Example
+/// ```
+/// use saptest::{Shop, EntityName, PetName, shop::analysis::expected_turns_to_find};
+///
+/// let shop = Shop::new(4, None).unwrap();
+/// let dolphin = EntityName::Pet(PetName::Dolphin);
+/// let few_rolls = expected_turns_to_find(&shop, &dolphin, 1).unwrap();
+/// let many_rolls = expected_turns_to_find(&shop, &dolphin, 5).unwrap();
+/// assert!(many_rolls < few_rolls);
+///
+/// assert!(expected_turns_to_find(&Shop::default(), &dolphin, 5).unwrap().is_infinite());
+/// ```
+pub fn expected_turns_to_find(
+    shop: &Shop,
+    name: &EntityName,
+    rolls_per_turn: usize,
+) -> Result<f64, SAPTestError> {
+    let roll_chance = roll_chance(shop, name)?;
+    let turn_chance = 1.0 - (1.0 - roll_chance).powi(rolls_per_turn as i32 + 1);
+    Ok(if turn_chance == 0.0 {
+        f64::INFINITY
+    } else {
+        1.0 / turn_chance
+    })
+}
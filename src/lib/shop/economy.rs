@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use crate::FoodName;
+
+/// Default cost of a pet in the [`Shop`](crate::Shop).
+pub const DEFAULT_PET_COST: usize = 3;
+/// Default gold gained from selling a pet at levels `1`, `2`, and `3`.
+pub const DEFAULT_SELL_VALUES: [usize; 3] = [1, 2, 3];
+
+/// Gold costs and limits of a [`Shop`](crate::Shop).
+/// * Costs are applied to items as they are stocked. Discounts then modify the stocked item's cost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Economy {
+    /// Cost of every pet.
+    pub pet_cost: usize,
+    /// Costs of foods overriding their default cost.
+    pub food_costs: Vec<(FoodName, usize)>,
+    /// Gold gained from selling a pet by level.
+    /// * Levels beyond the last value use the last value.
+    pub sell_values: Vec<usize>,
+    /// Maximum gold a [`Shop`](crate::Shop) can hold.
+    pub max_gold: Option<usize>,
+    /// Maximum unspent gold carried over to the next turn.
+    /// * Gold saved by effects like [`Action::SaveGold`](crate::effects::actions::Action::SaveGold) is used instead if greater.
+    pub carry_over: usize,
+    /// Gain `1` gold for every `interest_per` unspent gold at the end of a turn.
+    pub interest_per: Option<usize>,
+    /// Maximum gold gained from interest.
+    pub max_interest: Option<usize>,
+}
+
+impl Default for Economy {
+    fn default() -> Self {
+        Self {
+            pet_cost: DEFAULT_PET_COST,
+            food_costs: vec![],
+            sell_values: DEFAULT_SELL_VALUES.to_vec(),
+            max_gold: None,
+            carry_over: 0,
+            interest_per: None,
+            max_interest: None,
+        }
+    }
+}
+
+impl Economy {
+    /// Get the cost of a food.
+    /// * Uses the `default_cost` if not overriden.
+    /// # Example
+    /// ```
+    /// use saptest::{Economy, FoodName};
+    ///
+    /// let economy = Economy {
+    ///     food_costs: vec![(FoodName::Apple, 2)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(economy.food_cost(&FoodName::Apple, 3), 2);
+    /// assert_eq!(economy.food_cost(&FoodName::Honey, 3), 3);
+    /// ```
+    pub fn food_cost(&self, name: &FoodName, default_cost: usize) -> usize {
+        self.food_costs
+            .iter()
+            .find_map(|(food, cost)| (food == name).then_some(*cost))
+            .unwrap_or(default_cost)
+    }
+
+    /// Get the gold gained from selling a pet at a given level.
+    /// # Example
+    /// ```
+    /// use saptest::Economy;
+    ///
+    /// let economy = Economy::default();
+    /// assert_eq!(economy.sell_value(1), 1);
+    /// assert_eq!(economy.sell_value(3), 3);
+    ///
+    /// let economy = Economy {
+    ///     sell_values: vec![2],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(economy.sell_value(3), 2);
+    /// ```
+    pub fn sell_value(&self, lvl: usize) -> usize {
+        let idx = lvl
+            .saturating_sub(1)
+            .min(self.sell_values.len().saturating_sub(1));
+        self.sell_values.get(idx).copied().unwrap_or(0)
+    }
+
+    /// Limit an amount of gold to the [`Economy::max_gold`].
+    /// # Example
+    /// ```
+    /// use saptest::Economy;
+    ///
+    /// let economy = Economy {
+    ///     max_gold: Some(20),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(economy.cap_gold(25), 20);
+    /// assert_eq!(Economy::default().cap_gold(25), 25);
+    /// ```
+    pub fn cap_gold(&self, coins: usize) -> usize {
+        self.max_gold.map_or(coins, |max_gold| coins.min(max_gold))
+    }
+
+    /// Get the gold added to the start of the next turn.
+    /// * Includes carried over gold and interest on `unspent` gold.
+    /// * `saved` is gold saved by effects.
+    /// # Example
+    /// ```
+    /// use saptest::Economy;
+    ///
+    /// assert_eq!(Economy::default().carried_gold(5, 0), 0);
+    /// assert_eq!(Economy::default().carried_gold(5, 2), 2);
+    ///
+    /// let economy = Economy {
+    ///     carry_over: 3,
+    ///     interest_per: Some(2),
+    ///     max_interest: Some(1),
+    ///     ..Default::default()
+    /// };
+    /// // 3 gold carried and 1 gold interest.
+    /// assert_eq!(economy.carried_gold(5, 0), 4);
+    /// assert_eq!(economy.carried_gold(1, 0), 1);
+    /// ```
+    pub fn carried_gold(&self, unspent: usize, saved: usize) -> usize {
+        let carried = unspent.min(self.carry_over).max(saved);
+        let interest = self
+            .interest_per
+            .filter(|per| *per != 0)
+            .map_or(0, |per| unspent / per);
+        let interest = self
+            .max_interest
+            .map_or(interest, |max_interest| interest.min(max_interest));
+        carried + interest
+    }
+}
@@ -1,7 +1,13 @@
 //! [`Shop`](crate::Shop)s logic for Super Auto Pet [`Team`](crate::Team)s.
 //!
+/// Odds of finding items in a [`Shop`](crate::Shop).
+pub mod analysis;
+/// Gold costs and limits of a [`Shop`](crate::Shop).
+pub mod economy;
 /// A Super Auto Pets [`Shop`](crate::Shop).
 pub mod store;
+/// Automated [`Shop`](crate::Shop) strategies.
+pub mod strategy;
 /// [`Shop`](crate::Shop) for a [`Team`](crate::Team)
 pub mod team_shopping;
 /// [`Shop`](crate::Shop) triggers.
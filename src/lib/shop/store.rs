@@ -5,22 +5,30 @@ use std::{
 };
 
 use itertools::Itertools;
-use rand::prelude::*;
+use rand::{distributions::WeightedIndex, prelude::*};
 use rand_chacha::ChaCha12Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    db::column::Column,
     db::{
         pack::Pack,
         query::SAPQuery,
-        record::{FoodRecord, PetRecord, SAPRecord},
+        record::{FoodRecord, PetRecord, SAPRecord, ToyRecord},
+    },
+    effects::{
+        effect::{Entity, EntityName},
+        stats::Statistics,
     },
-    effects::{effect::Entity, stats::Statistics},
     error::SAPTestError,
     foods::food::Food,
-    pets::{names::PetName, pet::Pet},
-    shop::viewer::ShopViewer,
-    Position, SAPDB,
+    pets::{names::PetName, pet::Pet, registry::registered_custom_pets},
+    shop::{
+        economy::Economy,
+        viewer::{ShopItemViewer, ShopViewer},
+    },
+    toys::toy::Toy,
+    FoodColumn, PetColumn, Position, ToyColumn, SAPDB,
 };
 
 /// Sloth chance.
@@ -29,8 +37,21 @@ const SLOTH_CHANCE: f64 = 0.0001;
 pub const DEFAULT_COIN_COUNT: usize = 10;
 const MAX_SHOP_PETS: usize = 6;
 const MAX_SHOP_FOODS: usize = 4;
+/// Cost of a toy in the shop.
+const TOY_COST: usize = 3;
+/// Cost of a roll.
+const ROLL_COST: usize = 1;
 pub(crate) const MIN_SHOP_TIER: usize = 1;
 pub(crate) const MAX_SHOP_TIER: usize = 6;
+/// Turns at which each shop tier is reached.
+pub const DEFAULT_TIER_SCHEDULE: [usize; MAX_SHOP_TIER] = [1, 3, 5, 7, 9, 11];
+/// Number of pet slots at each shop tier.
+pub const DEFAULT_PET_SLOTS: [usize; MAX_SHOP_TIER] = [3, 3, 4, 4, 5, 5];
+/// Number of food slots at each shop tier.
+pub const DEFAULT_FOOD_SLOTS: [usize; MAX_SHOP_TIER] = [1, 2, 2, 2, 2, 2];
+/// Weight of rolling an item of each tier.
+/// * Each unlocked tier is equally likely regardless of how many items it has.
+pub const DEFAULT_TIER_WEIGHTS: [f64; MAX_SHOP_TIER] = [1.0; MAX_SHOP_TIER];
 
 /// State of shop.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -42,22 +63,40 @@ pub enum ShopState {
 }
 
 /// State of item.
-#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ItemState {
     /// Frozen item.
     Frozen,
+    /// Frozen item reserved for later turns.
+    /// * Kept when the shop is rolled or cleared by an effect.
+    Reserved,
     #[default]
     /// Normal item.
     Normal,
 }
 
 /// Item slot in [`Shop`].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum ItemSlot {
     /// A shop pet.
     Pet(Arc<RwLock<Pet>>),
     /// A shop food.
     Food(Arc<RwLock<Food>>),
+    /// A shop toy.
+    Toy(Arc<RwLock<Toy>>),
+}
+
+impl ItemSlot {
+    /// Copy the inner item into a new reference.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            ItemSlot::Pet(pet) => ItemSlot::Pet(pet.read().unwrap().duplicate()),
+            ItemSlot::Food(food) => {
+                ItemSlot::Food(Arc::new(RwLock::new(food.read().unwrap().clone())))
+            }
+            ItemSlot::Toy(toy) => ItemSlot::Toy(Arc::new(RwLock::new(toy.read().unwrap().clone()))),
+        }
+    }
 }
 
 impl PartialEq for ItemSlot {
@@ -65,13 +104,14 @@ impl PartialEq for ItemSlot {
         match (self, other) {
             (Self::Pet(l0), Self::Pet(r0)) => Arc::ptr_eq(l0, r0),
             (Self::Food(l0), Self::Food(r0)) => Arc::ptr_eq(l0, r0),
+            (Self::Toy(l0), Self::Toy(r0)) => Arc::ptr_eq(l0, r0),
             _ => false,
         }
     }
 }
 
 /// A [`Shop`] item.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShopItem {
     /// Shop item.
     pub(crate) item: ItemSlot,
@@ -125,11 +165,30 @@ impl From<Pet> for ShopItem {
     }
 }
 
+impl From<Toy> for ShopItem {
+    fn from(value: Toy) -> Self {
+        ShopItem {
+            item: ItemSlot::Toy(Arc::new(RwLock::new(value))),
+            state: ItemState::Normal,
+            cost: TOY_COST,
+            pos: None,
+        }
+    }
+}
+
 impl Display for ItemSlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ItemSlot::Pet(pet) => write!(f, "{}", pet.read().unwrap()),
             ItemSlot::Food(food) => write!(f, "{}", food.read().unwrap()),
+            ItemSlot::Toy(toy) => {
+                let toy = toy.read().unwrap();
+                write!(
+                    f,
+                    "[{}: (Level: {}) (Duration: {:?})]",
+                    toy.name, toy.lvl, toy.duration
+                )
+            }
         }
     }
 }
@@ -140,8 +199,24 @@ impl Display for ShopItem {
     }
 }
 
+/// Odds of items appearing when filling a [`Shop`].
+/// * Tier odds are indexed by tier starting at tier 1 and only include unlocked tiers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotOdds {
+    /// Number of pet slots.
+    pub pet_slots: usize,
+    /// Number of food slots.
+    pub food_slots: usize,
+    /// Probability of a pet slot having a pet of a given tier.
+    pub pet_tier_odds: Vec<f64>,
+    /// Probability of a food slot having a food of a given tier.
+    pub food_tier_odds: Vec<f64>,
+}
+
 /// A Super Auto Pets shop.
-#[derive(Debug, Clone)]
+/// * Fields missing when deserializing use the [`Shop::default`] values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Shop {
     pub(crate) state: ShopState,
     /// Current tier of shop.
@@ -154,17 +229,57 @@ pub struct Shop {
     pub pets: Vec<ShopItem>,
     /// Foods in shop.
     pub foods: Vec<ShopItem>,
+    /// Toys in shop.
+    pub toys: Vec<ShopItem>,
+    /// Number of toy slots.
+    /// * No toys are stocked by default.
+    pub toy_slots: usize,
     /// Packs shop should include.
     pub packs: Vec<Pack>,
     /// Global permanent `Statistics` added to all `Pet`s.
     /// * Added via a `CannedFood`
     pub perm_stats: Statistics,
-    /// Temporary stats that are removed on shop opening.
-    pub(crate) temp_stats: Vec<(usize, Statistics)>,
+    /// Temporary stats by pet id that are removed on shop opening once their remaining turns run out.
+    pub(crate) temp_stats: Vec<(usize, Statistics, usize)>,
     /// Free rolls.
     pub free_rolls: usize,
+    /// Extra gold needed to roll for the current turn.
+    pub roll_markup: usize,
+    /// Extra gold needed to buy items stocked in the current turn.
+    pub item_markup: usize,
     /// Saved coins.
     pub saved_coins: usize,
+    /// Offer a pet a tier higher than the shop's tier when a pet levels up.
+    /// * Enabled by default.
+    pub levelup_offer: bool,
+    /// Advance the shop's tier according to its tier schedule when a [`Team`](crate::Team) opens its shop.
+    /// * Enabled by default.
+    pub auto_tier: bool,
+    /// Give the [`Team`](crate::Team) a random hard mode [`Toy`] when opening the shop if it doesn't have one.
+    /// * Disabled by default.
+    pub hard_mode: bool,
+    /// Gold costs and limits.
+    pub economy: Economy,
+    /// Turns at which each tier is reached.
+    tier_schedule: Vec<usize>,
+    /// Number of pet slots at each tier.
+    pet_slot_schedule: Vec<usize>,
+    /// Number of food slots at each tier.
+    food_slot_schedule: Vec<usize>,
+    /// Overriden tier weights for pets.
+    pet_tier_odds: Option<Vec<f64>>,
+    /// Overriden tier weights for foods.
+    food_tier_odds: Option<Vec<f64>>,
+    /// Items allowed to be rolled. Empty allows all items.
+    allowed_items: Vec<EntityName>,
+    /// Items never rolled.
+    denied_items: Vec<EntityName>,
+    /// Weights of packs when rolling.
+    pack_weights: Vec<(Pack, f64)>,
+    /// Packs used on specific turns instead of the shop's packs.
+    pack_rotation: Vec<(usize, Vec<Pack>)>,
+    /// Packs from the rotation used for the current turn.
+    turn_packs: Option<Vec<Pack>>,
 }
 
 impl Default for Shop {
@@ -178,9 +293,27 @@ impl Default for Shop {
             temp_stats: vec![],
             pets: Vec::with_capacity(MAX_SHOP_PETS),
             foods: Vec::with_capacity(MAX_SHOP_FOODS),
+            toys: vec![],
+            toy_slots: 0,
             free_rolls: 0,
+            roll_markup: 0,
+            item_markup: 0,
             packs: vec![Pack::Turtle],
             saved_coins: 0,
+            levelup_offer: true,
+            auto_tier: true,
+            hard_mode: false,
+            economy: Economy::default(),
+            tier_schedule: DEFAULT_TIER_SCHEDULE.to_vec(),
+            pet_slot_schedule: DEFAULT_PET_SLOTS.to_vec(),
+            food_slot_schedule: DEFAULT_FOOD_SLOTS.to_vec(),
+            pet_tier_odds: None,
+            food_tier_odds: None,
+            allowed_items: vec![],
+            denied_items: vec![],
+            pack_weights: vec![],
+            pack_rotation: vec![],
+            turn_packs: None,
         }
     }
 }
@@ -195,6 +328,12 @@ impl Display for Shop {
         for food_slot in self.foods.iter() {
             writeln!(f, "{food_slot}")?;
         }
+        if !self.toys.is_empty() {
+            writeln!(f, "\n(Toys)")?;
+            for toy_slot in self.toys.iter() {
+                writeln!(f, "{toy_slot}")?;
+            }
+        }
         Ok(())
     }
 }
@@ -244,13 +383,14 @@ impl Shop {
     /// );
     /// ```
     pub fn restock(&mut self) -> Result<&mut Self, SAPTestError> {
-        self.fill_pets()?.fill_foods()?;
+        self.fill_pets()?.fill_foods()?.fill_toys()?;
         Ok(self)
     }
 
     /// Add a [`ShopItem`] to the shop.
     /// * Foods added over the limit will remove pets on the **rightmost side**.
     /// * Pets can be added over the limit if the food limit allows the space.
+    /// * Toys cannot exceed the number of [`toy_slots`](Shop::toy_slots).
     /// # Examples
     /// ---
     /// Add a food to the shop.
@@ -321,10 +461,39 @@ impl Shop {
                     item.pos = Some(i)
                 }
             }
+            ItemSlot::Toy(_) => {
+                if self.toys.len() < self.toy_slots {
+                    self.toys.push(item)
+                } else {
+                    return Err(SAPTestError::InvalidShopAction {
+                        subject: "Max Shop Toys".to_string(),
+                        reason: format!("Insufficient space to add {item}."),
+                    });
+                }
+                for (i, item) in self.toys.iter_mut().enumerate() {
+                    item.pos = Some(i)
+                }
+            }
         };
         Ok(self)
     }
 
+    /// Gold needed to roll the `Shop`.
+    /// * Includes the [`Shop::roll_markup`].
+    /// # Example
+    /// ```
+    /// use saptest::Shop;
+    ///
+    /// let mut shop = Shop::default();
+    /// assert_eq!(shop.roll_cost(), 1);
+    ///
+    /// shop.roll_markup = 1;
+    /// assert_eq!(shop.roll_cost(), 2);
+    /// ```
+    pub fn roll_cost(&self) -> usize {
+        ROLL_COST + self.roll_markup
+    }
+
     /// Roll the `Shop`.
     /// * Frozen [`ShopItem`]s are retained.
     /// * Fails if invalid funds to reroll.
@@ -382,7 +551,7 @@ impl Shop {
         // Decrement coin count if possible.
         if self.free_rolls != 0 {
             self.free_rolls = self.free_rolls.saturating_sub(1)
-        } else if let Some(new_coins) = self.coins.checked_sub(1) {
+        } else if let Some(new_coins) = self.coins.checked_sub(self.roll_cost()) {
             self.coins = new_coins;
         } else {
             return Err(SAPTestError::InvalidShopAction {
@@ -391,8 +560,9 @@ impl Shop {
             });
         }
         // Only keep frozen pets/foods.
-        self.foods.retain(|food| food.state == ItemState::Frozen);
-        self.pets.retain(|pet| pet.state == ItemState::Frozen);
+        self.foods.retain(|food| food.is_frozen());
+        self.pets.retain(|pet| pet.is_frozen());
+        self.toys.retain(|toy| toy.is_frozen());
 
         self.restock()?;
 
@@ -424,12 +594,7 @@ impl Shop {
             let items = match item_type {
                 Entity::Pet => self.pets.iter(),
                 Entity::Food => self.foods.iter(),
-                _ => {
-                    return Err(SAPTestError::InvalidPetAction {
-                        subject: String::from("Invalid Freeze Entity"),
-                        reason: format!("Shop does not contain {item_type}."),
-                    })
-                }
+                Entity::Toy => self.toys.iter(),
             };
             items
                 .enumerate()
@@ -438,10 +603,10 @@ impl Shop {
         };
 
         // Then mutate items setting item state to frozen.
-        let items = if let Entity::Pet = item_type {
-            &mut self.pets
-        } else {
-            &mut self.foods
+        let items = match item_type {
+            Entity::Pet => &mut self.pets,
+            Entity::Food => &mut self.foods,
+            Entity::Toy => &mut self.toys,
         };
         for idx in selected_idx {
             if let Some(item) = items.get_mut(idx) {
@@ -455,26 +620,93 @@ impl Shop {
         Ok(self)
     }
 
+    /// Reserve frozen items in the `Shop`.
+    /// * Reserved items are never removed by rolling or effects that clear the shop.
+    /// * Unfreezing an item also removes its reservation.
+    /// * Raises [`SAPTestError::InvalidShopAction`] if any item at the [`Position`] isn't frozen.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, Position, Entity, ShopViewer, ShopItemViewer};
+    ///
+    /// let mut shop = Shop::new(1, Some(12)).unwrap();
+    /// // Items must be frozen before being reserved.
+    /// assert!(shop.reserve(&Position::First, &Entity::Pet).is_err());
+    ///
+    /// shop.freeze(&Position::First, &Entity::Pet).unwrap();
+    /// shop.reserve(&Position::First, &Entity::Pet).unwrap();
+    ///
+    /// let found_pets = shop.get_shop_items_by_pos(&Position::First, &Entity::Pet).unwrap();
+    /// let reserved_pet = found_pets.first().unwrap();
+    /// assert!(reserved_pet.is_reserved() && reserved_pet.is_frozen());
+    /// ```
+    pub fn reserve(
+        &mut self,
+        pos: &Position,
+        item_type: &Entity,
+    ) -> Result<&mut Self, SAPTestError> {
+        let selected_idx: Vec<usize> = {
+            let selected_items = self.get_shop_items_by_pos(pos, item_type)?;
+            if let Some(unfrozen_item) = selected_items.iter().find(|item| !item.is_frozen()) {
+                return Err(SAPTestError::InvalidShopAction {
+                    subject: "Unfrozen Item (Reserve)".to_string(),
+                    reason: format!(
+                        "Item {:?} must be frozen to be reserved.",
+                        unfrozen_item.name()
+                    ),
+                });
+            }
+            let items = match item_type {
+                Entity::Pet => self.pets.iter(),
+                Entity::Food => self.foods.iter(),
+                Entity::Toy => self.toys.iter(),
+            };
+            items
+                .enumerate()
+                .filter_map(|(i, item)| selected_items.contains(&item).then_some(i))
+                .collect_vec()
+        };
+
+        let items = match item_type {
+            Entity::Pet => &mut self.pets,
+            Entity::Food => &mut self.foods,
+            Entity::Toy => &mut self.toys,
+        };
+        for idx in selected_idx {
+            if let Some(item) = items.get_mut(idx) {
+                item.state = ItemState::Reserved
+            }
+        }
+        Ok(self)
+    }
+
     /// Add a new pet to the end of the shop.
     /// * Used on any pet levelup in shop.
-    /// * Added pet will be a tier higher than the current shop tier.
+    /// * Added pet will be a random pet a tier higher than the current shop tier.
+    ///     * Pets at the max shop tier will be from the same tier.
     pub(crate) fn add_levelup_pet(&mut self) -> Result<&mut Self, SAPTestError> {
         // No space so do nothing.
         if self.pets.len() == MAX_SHOP_PETS {
             return Ok(self);
         }
-        let query = SAPQuery::builder()
-            .set_table(Entity::Pet)
-            .set_param(
-                "tier",
-                vec![(self.tier + 1).clamp(MIN_SHOP_TIER, MAX_SHOP_TIER)],
-            )
-            .set_param("lvl", vec![1]);
-        let records = SAPDB.execute_query(query)?;
-
-        if let Some(SAPRecord::Pet(added_pet)) = records.first().cloned() {
-            let pet: Pet = added_pet.try_into()?;
-            self.add_item(pet.into())?;
+        let levelup_tier = (self.tier + 1).clamp(MIN_SHOP_TIER, MAX_SHOP_TIER);
+        let query = self.shop_query(Entity::Pet, levelup_tier..levelup_tier + 1);
+        let possible_pets: Vec<PetRecord> = SAPDB
+            .execute_query(query)?
+            .into_iter()
+            .filter_map(|record| record.try_into().ok())
+            .collect_vec();
+        let mut rng = self.get_rng();
+
+        if let Some(record) = possible_pets.choose(&mut rng) {
+            let mut pet = Pet::try_from(record.name.clone())?;
+            // Add permanent pet stats.
+            pet.stats += self.perm_stats;
+            self.add_item(ShopItem {
+                item: ItemSlot::Pet(Arc::new(RwLock::new(pet))),
+                state: ItemState::Normal,
+                cost: self.economy.pet_cost + self.item_markup,
+                pos: None,
+            })?;
         }
         Ok(self)
     }
@@ -507,10 +739,125 @@ impl Shop {
         Ok(())
     }
 
-    /// Convert tier to num_turns.
-    pub(crate) fn tier_to_num_turns(tier: usize) -> Result<usize, SAPTestError> {
+    /// Convert tier to the first turn it's reached using the shop's tier schedule.
+    pub(crate) fn tier_to_num_turns(&self, tier: usize) -> Result<usize, SAPTestError> {
         Shop::is_valid_shop_tier(tier)?;
-        Ok(1 + (2 * tier.saturating_sub(1)))
+        self.tier_schedule
+            .get(tier - 1)
+            .copied()
+            .ok_or_else(|| SAPTestError::InvalidShopAction {
+                subject: "Shop Tier".to_string(),
+                reason: format!(
+                    "Tier provided ({tier}) is never reached with tier schedule {:?}.",
+                    self.tier_schedule
+                ),
+            })
+    }
+
+    /// Convert num_turns to tier using the shop's tier schedule.
+    pub(crate) fn num_turns_to_tier(&self, turn: usize) -> usize {
+        self.tier_schedule
+            .iter()
+            .filter(|tier_turn| **tier_turn <= turn)
+            .count()
+            .clamp(MIN_SHOP_TIER, MAX_SHOP_TIER)
+    }
+
+    /// Get the turns at which each tier of the `Shop` is reached.
+    /// # Example
+    /// ```
+    /// use saptest::Shop;
+    ///
+    /// let shop = Shop::default();
+    /// assert_eq!(shop.tier_schedule(), &[1, 3, 5, 7, 9, 11]);
+    /// ```
+    pub fn tier_schedule(&self) -> &[usize] {
+        &self.tier_schedule
+    }
+
+    /// Set the turns at which each tier of the `Shop` is reached.
+    /// * Turns must start at `1` and be strictly increasing.
+    /// * Tiers without a turn are never reached.
+    /// # Example
+    /// ```
+    /// use saptest::Shop;
+    ///
+    /// let mut shop = Shop::default();
+    /// // Reach a new tier every turn.
+    /// assert!(shop.set_tier_schedule(&[1, 2, 3, 4, 5, 6]).is_ok());
+    /// // Must start at turn 1.
+    /// assert!(shop.set_tier_schedule(&[2, 3]).is_err());
+    /// ```
+    pub fn set_tier_schedule(&mut self, schedule: &[usize]) -> Result<&mut Self, SAPTestError> {
+        if schedule.first() != Some(&1)
+            || schedule.len() > MAX_SHOP_TIER
+            || schedule.windows(2).any(|turns| turns[0] >= turns[1])
+        {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Invalid Tier Schedule".to_string(),
+                reason: format!("Schedule {schedule:?} must start at turn 1 and have at most {MAX_SHOP_TIER} strictly increasing turns."),
+            });
+        }
+        self.tier_schedule = schedule.to_vec();
+        Ok(self)
+    }
+
+    /// Get the number of slots for an item type at each tier of the `Shop`.
+    /// * Toys use the shop's [`toy_slots`](Shop::toy_slots) at every tier.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, Entity};
+    ///
+    /// let shop = Shop::default();
+    /// assert_eq!(shop.slot_schedule(&Entity::Pet), &[3, 3, 4, 4, 5, 5]);
+    /// assert_eq!(shop.slot_schedule(&Entity::Food), &[1, 2, 2, 2, 2, 2]);
+    /// ```
+    pub fn slot_schedule(&self, item_type: &Entity) -> &[usize] {
+        match item_type {
+            Entity::Pet => &self.pet_slot_schedule,
+            Entity::Food => &self.food_slot_schedule,
+            Entity::Toy => std::slice::from_ref(&self.toy_slots),
+        }
+    }
+
+    /// Set the number of slots for an item type at each tier of the `Shop`.
+    /// * A slot count must be given for every tier.
+    /// * Pets are limited to `6` slots and foods to `4` slots.
+    /// * Items already in the shop are kept until the shop is rolled.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, ShopViewer, Entity};
+    ///
+    /// let mut shop = Shop::default();
+    /// shop.set_slot_schedule(&Entity::Pet, &[5, 5, 5, 6, 6, 6]).unwrap();
+    /// assert_eq!(shop.max_pet_slots(), 5);
+    ///
+    /// // Missing tiers.
+    /// assert!(shop.set_slot_schedule(&Entity::Food, &[1, 2]).is_err());
+    /// ```
+    pub fn set_slot_schedule(
+        &mut self,
+        item_type: &Entity,
+        schedule: &[usize],
+    ) -> Result<&mut Self, SAPTestError> {
+        let (slot_schedule, max_slots) = match item_type {
+            Entity::Pet => (&mut self.pet_slot_schedule, MAX_SHOP_PETS),
+            Entity::Food => (&mut self.food_slot_schedule, MAX_SHOP_FOODS),
+            Entity::Toy => {
+                return Err(SAPTestError::InvalidShopAction {
+                    subject: "Invalid Slot Schedule".to_string(),
+                    reason: "Toy slots are set with Shop::toy_slots.".to_string(),
+                })
+            }
+        };
+        if schedule.len() != MAX_SHOP_TIER || schedule.iter().any(|slots| *slots > max_slots) {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Invalid Slot Schedule".to_string(),
+                reason: format!("Schedule {schedule:?} must have {MAX_SHOP_TIER} tiers with at most {max_slots} {item_type:?} slots."),
+            });
+        }
+        *slot_schedule = schedule.to_vec();
+        Ok(self)
     }
 
     /// Set the tier of a `Shop`.
@@ -531,24 +878,420 @@ impl Shop {
         Ok(self)
     }
 
+    /// Freeze all [`ShopItem`]s in the `Shop`.
+    /// * Reserved items stay reserved.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, Position, Entity, ShopViewer};
+    ///
+    /// let mut shop = Shop::new(1, None).unwrap();
+    /// shop.freeze_all();
+    ///
+    /// let frozen_pets = shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Pet).unwrap();
+    /// let frozen_foods = shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Food).unwrap();
+    /// assert_eq!(frozen_pets.len(), shop.len_pets());
+    /// assert_eq!(frozen_foods.len(), shop.len_foods());
+    /// ```
+    pub fn freeze_all(&mut self) -> &mut Self {
+        for item in self
+            .pets
+            .iter_mut()
+            .chain(self.foods.iter_mut())
+            .chain(self.toys.iter_mut())
+        {
+            if item.state == ItemState::Normal {
+                item.state = ItemState::Frozen
+            }
+        }
+        self
+    }
+
+    /// Unfreeze all [`ShopItem`]s in the `Shop`.
+    /// * Reserved items are also unfrozen.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, Position, Entity, ShopViewer};
+    ///
+    /// let mut shop = Shop::new(1, None).unwrap();
+    /// shop.freeze_all().unfreeze_all();
+    ///
+    /// let frozen_pets = shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Pet).unwrap();
+    /// assert!(frozen_pets.is_empty());
+    /// ```
+    pub fn unfreeze_all(&mut self) -> &mut Self {
+        for item in self
+            .pets
+            .iter_mut()
+            .chain(self.foods.iter_mut())
+            .chain(self.toys.iter_mut())
+        {
+            item.state = ItemState::Normal
+        }
+        self
+    }
+
+    /// Get the number of slots and the per-tier odds used when filling the `Shop`.
+    /// * By default, tiers are weighted by [`DEFAULT_TIER_WEIGHTS`].
+    /// * Overriden odds are set with [`Shop::set_tier_odds`].
+    /// * Tiers without any items in the shop's pool have no chance of being rolled.
+    /// # Example
+    /// ```
+    /// use saptest::Shop;
+    ///
+    /// let shop = Shop::new(3, Some(12)).unwrap();
+    /// let odds = shop.slot_odds().unwrap();
+    ///
+    /// assert_eq!(odds.pet_slots, 4);
+    /// assert_eq!(odds.pet_tier_odds.len(), 3);
+    /// assert!((odds.pet_tier_odds.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn slot_odds(&self) -> Result<SlotOdds, SAPTestError> {
+        let mut tier_odds = Vec::with_capacity(2);
+        for entity in [Entity::Pet, Entity::Food] {
+            let pool_tiers = self
+                .pool_items(entity)?
+                .into_iter()
+                .map(|(_, tier)| tier)
+                .collect_vec();
+            let weights = self.tier_weights(&entity, &pool_tiers);
+            let total: f64 = weights.iter().sum();
+            tier_odds.push(
+                weights
+                    .into_iter()
+                    .map(|weight| if total == 0.0 { 0.0 } else { weight / total })
+                    .collect_vec(),
+            );
+        }
+        let food_tier_odds = tier_odds.pop().unwrap_or_default();
+        let pet_tier_odds = tier_odds.pop().unwrap_or_default();
+
+        Ok(SlotOdds {
+            pet_slots: self.max_pet_slots(),
+            food_slots: self.max_food_slots(),
+            pet_tier_odds,
+            food_tier_odds,
+        })
+    }
+
+    /// Get the names and tiers of all items in the shop's pool.
+    /// * Registered custom pets are included if allowed.
+    pub(crate) fn pool_items(
+        &self,
+        entity: Entity,
+    ) -> Result<Vec<(EntityName, usize)>, SAPTestError> {
+        let query = self.shop_query(entity, 1..self.tier + 1);
+        let mut items = SAPDB
+            .execute_query(query)?
+            .into_iter()
+            .map(|record| match record {
+                SAPRecord::Pet(record) => (EntityName::Pet(record.name), record.tier),
+                SAPRecord::Food(record) => (EntityName::Food(record.name), record.tier),
+                SAPRecord::Toy(record) => (EntityName::Toy(record.name), record.tier),
+            })
+            .collect_vec();
+        if entity == Entity::Pet {
+            items.extend(
+                registered_custom_pets()
+                    .into_iter()
+                    .map(|pet| (EntityName::Pet(pet.name), pet.tier))
+                    .filter(|(name, tier)| {
+                        (1..=self.tier).contains(tier)
+                            && self.allowed_items.contains(name)
+                            && self.in_pool(name)
+                    }),
+            );
+        }
+        Ok(items)
+    }
+
+    /// Get the weight of rolling each unlocked tier.
+    /// * Tiers not in `pool_tiers` have a weight of `0.0`.
+    fn tier_weights(&self, item_type: &Entity, pool_tiers: &[usize]) -> Vec<f64> {
+        let odds = match item_type {
+            Entity::Pet => self.pet_tier_odds.as_deref(),
+            Entity::Food => self.food_tier_odds.as_deref(),
+            Entity::Toy => None,
+        }
+        .unwrap_or(&DEFAULT_TIER_WEIGHTS);
+        (1..=self.tier)
+            .map(|tier| {
+                if pool_tiers.contains(&tier) {
+                    odds.get(tier - 1).copied().unwrap_or(0.0)
+                } else {
+                    0.0
+                }
+            })
+            .collect_vec()
+    }
+
+    /// Override the odds of rolling an item of a given tier.
+    /// * Weights are indexed by tier starting at tier 1 and don't need to sum to `1.0`.
+    /// * Tiers above the shop's current tier are ignored.
+    /// * Providing `None` restores the default odds.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, ShopItemViewer, Entity};
+    ///
+    /// let mut shop = Shop::new(3, Some(12)).unwrap();
+    /// // Only roll tier 3 pets.
+    /// shop.set_tier_odds(&Entity::Pet, Some(&[0.0, 0.0, 1.0])).unwrap();
+    /// shop.roll().unwrap();
+    ///
+    /// assert!(shop.pets.iter().all(|pet| pet.tier() == 3));
+    /// assert_eq!(shop.slot_odds().unwrap().pet_tier_odds, vec![0.0, 0.0, 1.0]);
+    /// ```
+    pub fn set_tier_odds(
+        &mut self,
+        item_type: &Entity,
+        odds: Option<&[f64]>,
+    ) -> Result<&mut Self, SAPTestError> {
+        if let Some(odds) = odds {
+            if odds.len() > MAX_SHOP_TIER
+                || odds
+                    .iter()
+                    .any(|weight| !weight.is_finite() || *weight < 0.0)
+                || odds.iter().sum::<f64>() <= 0.0
+            {
+                return Err(SAPTestError::InvalidShopAction {
+                    subject: "Invalid Tier Odds".to_string(),
+                    reason: format!("Odds {odds:?} must be non-negative weights for at most {MAX_SHOP_TIER} tiers with at least one non-zero weight."),
+                });
+            }
+        }
+        let odds = odds.map(|odds| odds.to_vec());
+        match item_type {
+            Entity::Pet => self.pet_tier_odds = odds,
+            Entity::Food => self.food_tier_odds = odds,
+            _ => {
+                return Err(SAPTestError::InvalidShopAction {
+                    subject: "Invalid Tier Odds Entity".to_string(),
+                    reason: format!("Shop does not contain {item_type}."),
+                })
+            }
+        }
+        Ok(self)
+    }
+
+    /// Restrict the pool of items the `Shop` rolls from.
+    /// * `allowed` items are the only items of their [`Entity`] type that can be rolled.
+    ///     * If no pets or foods are provided, all pets or foods, respectively, are allowed.
+    /// * `denied` items are never rolled.
+    /// * Providing empty slices clears the filter.
+    /// * Raises [`SAPTestError::InvalidShopAction`] if a [`ToyName`](crate::ToyName) is provided.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, ShopItemViewer, EntityName, PetName, FoodName};
+    ///
+    /// let mut shop = Shop::new(1, Some(12)).unwrap();
+    /// shop.set_pool_filter(
+    ///     &[EntityName::Pet(PetName::Ant), EntityName::Pet(PetName::Beaver)],
+    ///     &[EntityName::Food(FoodName::Apple)],
+    /// )
+    /// .unwrap();
+    /// shop.roll().unwrap();
+    ///
+    /// assert!(shop.pets.iter().all(|pet| [EntityName::Pet(PetName::Ant), EntityName::Pet(PetName::Beaver)].contains(&pet.name())));
+    /// assert!(shop.foods.iter().all(|food| food.name() != EntityName::Food(FoodName::Apple)));
+    /// ```
+    pub fn set_pool_filter(
+        &mut self,
+        allowed: &[EntityName],
+        denied: &[EntityName],
+    ) -> Result<&mut Self, SAPTestError> {
+        if let Some(toy) = allowed
+            .iter()
+            .chain(denied.iter())
+            .find(|name| matches!(name, EntityName::Toy(_)))
+        {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Invalid Pool Filter".to_string(),
+                reason: format!("Toys cannot be filtered ({toy:?})."),
+            });
+        }
+        self.allowed_items = allowed.to_vec();
+        self.denied_items = denied.to_vec();
+        Ok(self)
+    }
+
+    /// Check if an item can be rolled based on the shop's pool filter.
+    pub(crate) fn in_pool(&self, name: &EntityName) -> bool {
+        let entity_allowed = self
+            .allowed_items
+            .iter()
+            .filter(|item| std::mem::discriminant(*item) == std::mem::discriminant(name))
+            .collect_vec();
+        (entity_allowed.is_empty() || entity_allowed.contains(&name))
+            && !self.denied_items.contains(name)
+    }
+
+    /// Set the weights of [`Pack`]s when rolling.
+    /// * Each slot first chooses a pack by weight and then an item from that pack.
+    /// * Packs in the shop without a weight have a weight of `1.0`.
+    /// * Providing an empty slice restores the default behavior of sampling from all items in the shop's packs.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, db::pack::Pack};
+    ///
+    /// let mut shop = Shop::new(1, Some(12)).unwrap();
+    /// shop.packs = vec![Pack::Turtle, Pack::Puppy];
+    /// // Only roll items from the puppy pack.
+    /// shop.set_pack_weights(&[(Pack::Turtle, 0.0), (Pack::Puppy, 1.0)]).unwrap();
+    /// assert!(shop.roll().is_ok());
+    /// ```
+    pub fn set_pack_weights(&mut self, weights: &[(Pack, f64)]) -> Result<&mut Self, SAPTestError> {
+        if weights
+            .iter()
+            .any(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+        {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Invalid Pack Weights".to_string(),
+                reason: format!("Weights {weights:?} must be non-negative."),
+            });
+        }
+        self.pack_weights = weights.to_vec();
+        Ok(self)
+    }
+
+    /// Set the [`Pack`]s used on specific turns.
+    /// * Turns without an entry use the shop's [`packs`](Shop::packs).
+    /// * Applied when a [`Team`](crate::Team) opens its shop.
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, db::pack::Pack};
+    ///
+    /// let mut shop = Shop::default();
+    /// // Use the weekly pack on turn 3.
+    /// shop.set_pack_rotation(&[(3, vec![Pack::Weekly])]);
+    /// ```
+    pub fn set_pack_rotation(&mut self, rotation: &[(usize, Vec<Pack>)]) -> &mut Self {
+        self.pack_rotation = rotation.to_vec();
+        self
+    }
+
+    /// Use the [`Pack`]s from the pack rotation for a given turn.
+    pub(crate) fn rotate_packs(&mut self, turn: usize) {
+        self.turn_packs = self
+            .pack_rotation
+            .iter()
+            .find_map(|(rotation_turn, packs)| (*rotation_turn == turn).then(|| packs.clone()));
+    }
+
+    /// Get the [`Pack`]s the shop currently rolls from.
+    pub(crate) fn active_packs(&self) -> &[Pack] {
+        self.turn_packs.as_deref().unwrap_or(&self.packs)
+    }
+
+    /// Choose a pack using pack weights.
+    /// * Returns `None` if no weights are set.
+    fn choose_pack<R: Rng>(&self, rng: &mut R) -> Result<Option<&Pack>, SAPTestError> {
+        if self.pack_weights.is_empty() {
+            return Ok(None);
+        }
+        let packs = self.active_packs();
+        let weights = packs.iter().map(|pack| {
+            self.pack_weights
+                .iter()
+                .find_map(|(weighted_pack, weight)| (weighted_pack == pack).then_some(*weight))
+                .unwrap_or(1.0)
+        });
+        let dist = WeightedIndex::new(weights).map_err(|err| SAPTestError::InvalidShopAction {
+            subject: "Invalid Pack Weights".to_string(),
+            reason: format!("No pack in {packs:?} can be rolled. {err}"),
+        })?;
+        Ok(packs.get(dist.sample(rng)))
+    }
+
+    /// Choose a tier using the shop's tier odds.
+    /// * Only tiers in `pool_tiers` can be chosen.
+    /// * Returns `None` if the pool is empty.
+    fn choose_tier<R: Rng>(
+        &self,
+        item_type: &Entity,
+        pool_tiers: &[usize],
+        rng: &mut R,
+    ) -> Result<Option<usize>, SAPTestError> {
+        if pool_tiers.is_empty() {
+            return Ok(None);
+        }
+        let weights = self.tier_weights(item_type, pool_tiers);
+        let dist = WeightedIndex::new(weights).map_err(|err| SAPTestError::InvalidShopAction {
+            subject: "Invalid Tier Odds".to_string(),
+            reason: format!("No tier can be rolled at tier {}. {err}", self.tier),
+        })?;
+        Ok(Some(dist.sample(rng) + 1))
+    }
+
+    /// Get the [`ShopItem`]s of an [`Entity`] type.
+    pub(crate) fn items(&self, item_type: &Entity) -> &[ShopItem] {
+        match item_type {
+            Entity::Pet => &self.pets,
+            Entity::Food => &self.foods,
+            Entity::Toy => &self.toys,
+        }
+    }
+
     /// Build shop query.
     pub(crate) fn shop_query(&self, entity: Entity, tiers: Range<usize>) -> SAPQuery {
-        let query = SAPQuery::builder()
-            .set_param("tier", tiers.into_iter().collect())
-            .set_param("pack", self.packs.iter().collect());
-
+        let allowed = Shop::pool_names(&self.allowed_items, entity);
+        let denied = Shop::pool_names(&self.denied_items, entity);
+        let packs = self.active_packs();
         match entity {
-            Entity::Pet => query
-                .set_param("lvl", vec![1])
-                .set_table(Entity::Pet)
-                // Exclude sloth. Fixed percentage chance handled in Shop::fill_pets()
-                .set_param("-name", vec![PetName::Sloth])
-                .set_param("is_token", vec![false]),
-            Entity::Food => query.set_table(Entity::Food),
+            Entity::Pet => Shop::pool_query(
+                SAPQuery::builder()
+                    .set_table(Entity::Pet)
+                    .set_param(PetColumn::Tier, tiers.collect())
+                    .set_param(PetColumn::Pack, packs.iter().collect())
+                    .set_param(PetColumn::Lvl, vec![1])
+                    // Exclude sloth. Fixed percentage chance handled in Shop::fill_pets()
+                    .set_not_param(PetColumn::Name, vec![PetName::Sloth])
+                    .set_param(PetColumn::IsToken, vec![false]),
+                PetColumn::Name,
+                allowed,
+                denied,
+            ),
+            Entity::Food => Shop::pool_query(
+                SAPQuery::builder()
+                    .set_table(Entity::Food)
+                    .set_param(FoodColumn::Tier, tiers.collect())
+                    .set_param(FoodColumn::Pack, packs.iter().collect()),
+                FoodColumn::Name,
+                allowed,
+                denied,
+            ),
             _ => unreachable!(),
         }
     }
 
+    /// Restrict a shop query to allowed names and exclude denied names.
+    fn pool_query<C: Column>(
+        query: SAPQuery,
+        name: C,
+        allowed: Vec<String>,
+        denied: Vec<String>,
+    ) -> SAPQuery {
+        let query = if allowed.is_empty() {
+            query
+        } else {
+            query.set_param(name, allowed)
+        };
+        if denied.is_empty() {
+            query
+        } else {
+            query.set_not_param(name, denied)
+        }
+    }
+
+    fn pool_names(items: &[EntityName], entity: Entity) -> Vec<String> {
+        items
+            .iter()
+            .filter_map(|item| match (item, entity) {
+                (EntityName::Pet(name), Entity::Pet) => Some(name.to_string()),
+                (EntityName::Food(name), Entity::Food) => Some(name.to_string()),
+                _ => None,
+            })
+            .collect_vec()
+    }
+
     pub(crate) fn get_rng(&self) -> ChaCha12Rng {
         let seed = self.seed.unwrap_or_else(random);
         ChaCha12Rng::seed_from_u64(seed)
@@ -557,11 +1300,27 @@ impl Shop {
     /// Fill pets based on current tier of shop.
     pub(crate) fn fill_pets(&mut self) -> Result<&mut Self, SAPTestError> {
         let query = self.shop_query(Entity::Pet, 1..self.tier + 1);
-        let possible_pets: Vec<PetRecord> = SAPDB
+        let mut possible_pets: Vec<(PetName, usize, Option<Pack>)> = SAPDB
             .execute_query(query)?
             .into_iter()
-            .filter_map(|record| record.try_into().ok())
+            .filter_map(|record| PetRecord::try_from(record).ok())
+            .map(|record| (record.name, record.tier, Some(record.pack)))
             .collect_vec();
+        // Registered custom pets are only rolled if explicitly allowed.
+        possible_pets.extend(
+            registered_custom_pets()
+                .into_iter()
+                .filter(|pet| {
+                    (1..=self.tier).contains(&pet.tier)
+                        && self
+                            .allowed_items
+                            .contains(&EntityName::Pet(pet.name.clone()))
+                        && !self
+                            .denied_items
+                            .contains(&EntityName::Pet(pet.name.clone()))
+                })
+                .map(|pet| (pet.name, pet.tier, None)),
+        );
         let mut rng = self.get_rng();
 
         // Iterate through slots choose a random pet or sloth.
@@ -573,24 +1332,35 @@ impl Shop {
             self.available_pet_slots()
         };
         for i in 0..n_slots {
-            let (cost, mut pet) = if rng.gen_bool(SLOTH_CHANCE) {
-                (3, Pet::try_from(PetName::Sloth)?)
-            } else {
-                let record =
-                    possible_pets
-                        .choose(&mut rng)
-                        .ok_or_else(|| SAPTestError::QueryFailure {
-                            subject: "Empty Shop Query (Pets)".to_string(),
-                            reason: "Main pet query yielded no pet records.".to_string(),
-                        })?;
-                (record.cost, Pet::try_from(record.name.clone())?)
-            };
+            let (cost, mut pet) =
+                if rng.gen_bool(SLOTH_CHANCE) && self.in_pool(&EntityName::Pet(PetName::Sloth)) {
+                    (self.economy.pet_cost, Pet::try_from(PetName::Sloth)?)
+                } else {
+                    let pack = self.choose_pack(&mut rng)?;
+                    let pack_pets = possible_pets
+                        .iter()
+                        .filter(|(_, _, pet_pack)| {
+                            pack.is_none_or(|pack| pet_pack.as_ref() == Some(pack))
+                        })
+                        .collect_vec();
+                    let pool_tiers = pack_pets.iter().map(|(_, tier, _)| *tier).collect_vec();
+                    let tier = self.choose_tier(&Entity::Pet, &pool_tiers, &mut rng)?;
+                    let record = pack_pets
+                        .into_iter()
+                        .filter(|(_, pet_tier, _)| tier == Some(*pet_tier))
+                        .choose(&mut rng);
+                    let (name, _, _) = record.ok_or_else(|| SAPTestError::QueryFailure {
+                        subject: "Empty Shop Query (Pets)".to_string(),
+                        reason: "Main pet query yielded no pet records.".to_string(),
+                    })?;
+                    (self.economy.pet_cost, Pet::try_from(name.clone())?)
+                };
             // Add permanent pet stats.
             pet.stats += self.perm_stats;
             self.pets.push(ShopItem {
                 item: ItemSlot::Pet(Arc::new(RwLock::new(pet))),
                 state: ItemState::Normal,
-                cost,
+                cost: cost + self.item_markup,
                 pos: Some(i),
             });
         }
@@ -617,18 +1387,82 @@ impl Shop {
             self.available_food_slots()
         };
         for i in 0..n_slots {
-            let food_record =
-                possible_foods
-                    .choose(&mut rng)
-                    .ok_or_else(|| SAPTestError::QueryFailure {
-                        subject: String::from("Empty Shop Query (Food)"),
-                        reason: String::from("Main food query yielded no food records."),
-                    })?;
+            let pack = self.choose_pack(&mut rng)?;
+            let pack_foods = possible_foods
+                .iter()
+                .filter(|record| pack.is_none_or(|pack| &record.pack == pack))
+                .collect_vec();
+            let pool_tiers = pack_foods.iter().map(|record| record.tier).collect_vec();
+            let tier = self.choose_tier(&Entity::Food, &pool_tiers, &mut rng)?;
+            let food_record = pack_foods
+                .into_iter()
+                .filter(|record| tier == Some(record.tier))
+                .choose(&mut rng);
+            let food_record = food_record.ok_or_else(|| SAPTestError::QueryFailure {
+                subject: String::from("Empty Shop Query (Food)"),
+                reason: String::from("Main food query yielded no food records."),
+            })?;
             let food = Food::try_from(food_record.name.clone())?;
             self.foods.push(ShopItem {
                 item: ItemSlot::Food(Arc::new(RwLock::new(food))),
                 state: ItemState::Normal,
-                cost: food_record.cost,
+                cost: self.economy.food_cost(&food_record.name, food_record.cost)
+                    + self.item_markup,
+                pos: Some(i),
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Choose a random level 1 hard mode [`Toy`] up to the current tier of shop.
+    pub(crate) fn hard_mode_toy(&self) -> Result<Option<Toy>, SAPTestError> {
+        let query = SAPQuery::builder()
+            .set_table(Entity::Toy)
+            .set_param(ToyColumn::Tier, (1..self.tier + 1).collect())
+            .set_param(ToyColumn::Lvl, vec![1])
+            .set_param(ToyColumn::HardMode, vec![true]);
+        let mut rng = self.get_rng();
+        SAPDB
+            .execute_query(query)?
+            .into_iter()
+            .filter_map(|record| ToyRecord::try_from(record).ok())
+            .choose(&mut rng)
+            .map(Toy::try_from)
+            .transpose()
+    }
+
+    /// Fill the shop with toys based on current tier of shop.
+    pub(crate) fn fill_toys(&mut self) -> Result<&mut Self, SAPTestError> {
+        let n_slots = self.toy_slots.saturating_sub(self.toys.len());
+        if n_slots == 0 {
+            return Ok(self);
+        }
+        let query = SAPQuery::builder()
+            .set_table(Entity::Toy)
+            .set_param(ToyColumn::Tier, (1..self.tier + 1).collect())
+            .set_param(ToyColumn::Lvl, vec![1])
+            .set_param(ToyColumn::HardMode, vec![false]);
+        let possible_toys: Vec<ToyRecord> = SAPDB
+            .execute_query(query)?
+            .into_iter()
+            .filter_map(|record| record.try_into().ok())
+            .collect_vec();
+        let mut rng = self.get_rng();
+
+        for i in 0..n_slots {
+            let toy_record =
+                possible_toys
+                    .choose(&mut rng)
+                    .ok_or_else(|| SAPTestError::QueryFailure {
+                        subject: String::from("Empty Shop Query (Toy)"),
+                        reason: String::from("Main toy query yielded no toy records."),
+                    })?;
+            let toy = Toy::try_from(toy_record.clone())?;
+            self.toys.push(ShopItem {
+                item: ItemSlot::Toy(Arc::new(RwLock::new(toy))),
+                state: ItemState::Normal,
+                cost: TOY_COST,
                 pos: Some(i),
             });
         }
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error::SAPTestError, Entity, Position, Shop, Team, TeamShopping};
+
+/// Maximum number of actions a [`ShopStrategy`] can take in a single turn.
+/// * Prevents strategies that never end their turn from running indefinitely.
+pub const MAX_STRATEGY_ACTIONS: usize = 100;
+
+/// An action a [`Team`] can take in the [`Shop`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum ShopAction {
+    /// Buy an item. See [`TeamShopping::buy`].
+    Buy {
+        /// Position of item in shop.
+        from: Position,
+        /// Type of item.
+        item_type: Entity,
+        /// Position on team.
+        to: Position,
+    },
+    /// Sell a pet. See [`TeamShopping::sell`].
+    Sell(Position),
+    /// Roll the shop. See [`TeamShopping::roll_shop`].
+    Roll,
+    /// Freeze an item. See [`TeamShopping::freeze_shop`].
+    Freeze {
+        /// Position of item in shop.
+        pos: Position,
+        /// Type of item.
+        item_type: Entity,
+    },
+    /// Reserve a frozen item. See [`TeamShopping::reserve_shop`].
+    Reserve {
+        /// Position of item in shop.
+        pos: Position,
+        /// Type of item.
+        item_type: Entity,
+    },
+    /// Toggle freezing pets and foods. See [`TeamShopping::toggle_freeze`].
+    ToggleFreeze(Position),
+    /// Freeze all items. See [`TeamShopping::freeze_all`].
+    FreezeAll,
+    /// Unfreeze all items. See [`TeamShopping::unfreeze_all`].
+    UnfreezeAll,
+    /// Move pets. See [`TeamShopping::move_pets`].
+    Move {
+        /// Position of pet to move.
+        from: Position,
+        /// Position to move pet to.
+        to: Position,
+        /// Merge pets if possible.
+        merge: bool,
+    },
+    /// Stop shopping for the turn.
+    EndTurn,
+}
+
+/// A strategy for automating a [`Team`]'s actions in the [`Shop`].
+/// * Implemented for closures taking the current [`Team`] and [`Shop`]. See [`TeamShopping::play_shop_turn`].
+pub trait ShopStrategy {
+    /// Choose the next [`ShopAction`] given the current state of the [`Team`] and its [`Shop`].
+    fn act(&mut self, team: &Team, shop: &Shop) -> ShopAction;
+
+    /// Execute actions from this strategy on a [`Team`] with an open [`Shop`].
+    /// * Stops when the strategy returns [`ShopAction::EndTurn`], gold and free rolls run out, or [`MAX_STRATEGY_ACTIONS`] is reached.
+    /// * Returns the actions taken.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Shop, Position, Entity, ShopAction, ShopStrategy};
+    ///
+    /// // Roll until out of gold.
+    /// struct Roller;
+    /// impl ShopStrategy for Roller {
+    ///     fn act(&mut self, _team: &Team, _shop: &Shop) -> ShopAction {
+    ///         ShopAction::Roll
+    ///     }
+    /// }
+    ///
+    /// let mut team = Team::default();
+    /// team.set_shop_seed(Some(42)).open_shop().unwrap();
+    ///
+    /// let actions = Roller.run(&mut team).unwrap();
+    /// assert_eq!(actions.len(), 10);
+    /// assert_eq!(team.gold(), 0);
+    /// ```
+    fn run(&mut self, team: &mut Team) -> Result<Vec<ShopAction>, SAPTestError> {
+        let mut actions = vec![];
+        while actions.len() < MAX_STRATEGY_ACTIONS
+            && (team.gold() >= team.get_shop().roll_cost() || team.get_shop().free_rolls != 0)
+        {
+            let action = self.act(team, team.get_shop());
+            match &action {
+                ShopAction::Buy {
+                    from,
+                    item_type,
+                    to,
+                } => {
+                    team.buy(from, item_type, to)?;
+                }
+                ShopAction::Sell(pos) => {
+                    team.sell(pos)?;
+                }
+                ShopAction::Roll => {
+                    team.roll_shop()?;
+                }
+                ShopAction::Freeze { pos, item_type } => {
+                    team.freeze_shop(pos, item_type)?;
+                }
+                ShopAction::Reserve { pos, item_type } => {
+                    team.reserve_shop(pos, item_type)?;
+                }
+                ShopAction::ToggleFreeze(pos) => {
+                    team.toggle_freeze(pos)?;
+                }
+                ShopAction::FreezeAll => {
+                    team.freeze_all()?;
+                }
+                ShopAction::UnfreezeAll => {
+                    team.unfreeze_all()?;
+                }
+                ShopAction::Move { from, to, merge } => {
+                    team.move_pets(from, to, *merge)?;
+                }
+                ShopAction::EndTurn => {
+                    actions.push(action);
+                    break;
+                }
+            }
+            actions.push(action);
+        }
+        Ok(actions)
+    }
+}
+
+impl<F> ShopStrategy for F
+where
+    F: FnMut(&Team, &Shop) -> ShopAction,
+{
+    fn act(&mut self, team: &Team, shop: &Shop) -> ShopAction {
+        self(team, shop)
+    }
+}
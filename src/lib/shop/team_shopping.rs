@@ -9,25 +9,30 @@ use crate::{
     db::pack::Pack,
     effects::{
         actions::Action,
-        effect::Entity,
-        state::{Status, Target},
+        effect::{Effect, EffectModify, Entity, EntityName, LimitScope},
+        state::{Outcome, Status, Target},
         trigger::*,
     },
     error::SAPTestError,
+    pets::pet::MAX_PET_LEVEL,
     shop::{
+        economy::Economy,
         store::{ItemSlot, ShopState, DEFAULT_COIN_COUNT},
+        strategy::{ShopAction, ShopStrategy},
         trigger::*,
         viewer::ShopViewer,
     },
     teams::{
-        combat::TeamCombat, effect_helpers::EffectApplyHelpers, effects::TeamEffects,
+        combat::TeamCombat,
+        effect_helpers::EffectApplyHelpers,
+        effects::{TeamEffects, TriggerGuard},
+        team::{EndTurnSummary, ShopRecord},
         viewer::TeamViewer,
     },
-    Food, FoodName, ItemCondition, Pet, PetName, Position, Shop, Team,
+    toys::team_toys::TeamToys,
+    Food, FoodName, ItemCondition, Pet, Position, Shop, Team,
 };
 
-use super::store::{MAX_SHOP_TIER, MIN_SHOP_TIER};
-
 pub(crate) trait TeamShoppingHelpers {
     fn merge_behavior(
         &mut self,
@@ -47,6 +52,14 @@ pub(crate) trait TeamShoppingHelpers {
         curr_pet: Option<Arc<RwLock<Pet>>>,
         to_pos: &Position,
     ) -> Result<(), SAPTestError>;
+    fn record_shop_action(
+        &mut self,
+        action: ShopAction,
+        items: Vec<EntityName>,
+        gold_before: usize,
+    );
+    fn process_shop_triggers(&mut self) -> Result<(), SAPTestError>;
+    fn use_food_multiplier(&mut self) -> usize;
 }
 
 /// Implements Super Auto Pets [`Shop`] behavior.
@@ -91,6 +104,53 @@ pub trait TeamShopping {
     /// );
     /// assert!(first_random_item_purchase.is_ok())
     /// ```
+    /// ---
+    /// Choosing which pets receive a food with multiple targets.
+    /// * Without a [`Position::Multiple`], targets are chosen randomly.
+    /// ```
+    /// use saptest::{
+    ///     Food, FoodName, Pet, PetName, Team, TeamShopping, TeamViewer,
+    ///     Position, Entity, Shop, ShopItem
+    /// };
+    ///
+    /// let mut team = Team::new(
+    ///     &vec![Some(Pet::try_from(PetName::Ant).unwrap()); 3],
+    ///     5
+    /// ).unwrap();
+    /// let mut shop = Shop::default();
+    /// shop.add_item(ShopItem::from(Food::try_from(FoodName::SaladBowl).unwrap())).unwrap();
+    /// team.replace_shop(shop).unwrap().open_shop().unwrap();
+    ///
+    /// // Give the salad bowl to the first and last ant.
+    /// team.buy(
+    ///     &Position::Last,
+    ///     &Entity::Food,
+    ///     &Position::Multiple(vec![Position::First, Position::Last])
+    /// ).unwrap();
+    /// let ants = team.all();
+    /// assert!(ants[0].read().unwrap().stats.attack == 3 && ants[2].read().unwrap().stats.attack == 3);
+    /// assert_eq!(ants[1].read().unwrap().stats.attack, 2);
+    /// ```
+    /// ---
+    /// Buying a sleeping pill to faint a pet during the shop phase.
+    /// ```
+    /// use saptest::{
+    ///     Food, FoodName, Pet, PetName, Team, TeamShopping, TeamViewer,
+    ///     Position, Entity, Shop, ShopItem
+    /// };
+    ///
+    /// let mut team = Team::new(
+    ///     &[Some(Pet::try_from(PetName::Cricket).unwrap())],
+    ///     5
+    /// ).unwrap();
+    /// let mut shop = Shop::default();
+    /// shop.add_item(ShopItem::from(Food::try_from(FoodName::SleepingPill).unwrap())).unwrap();
+    /// team.replace_shop(shop).unwrap().open_shop().unwrap();
+    ///
+    /// // Give the pill to the cricket. It faints and summons a zombie cricket.
+    /// team.buy(&Position::Last, &Entity::Food, &Position::First).unwrap();
+    /// assert_eq!(team.first().unwrap().read().unwrap().name, PetName::ZombieCricket);
+    /// ```
     fn buy(
         &mut self,
         from: &Position,
@@ -98,6 +158,38 @@ pub trait TeamShopping {
         to: &Position,
     ) -> Result<&mut Self, SAPTestError>;
 
+    /// Buy a [`ShopItem`](crate::ShopItem) from the [`Shop`] and place it on the [`Team`] automatically.
+    /// * Pets merge into a copy on the team that isn't at the max level. Otherwise, they are placed in the first empty slot.
+    /// * Holdable foods are given to the first pet without an item. Otherwise, foods are given to the first pet.
+    /// * Returns the [`Position`] the item was placed at. This is relative to the first pet on the team.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Team, TeamShopping, TeamViewer, Position, Entity, Shop, ShopItem};
+    ///
+    /// let mut team = Team::new(
+    ///     &[
+    ///         Some(Pet::try_from(PetName::Ant).unwrap()),
+    ///         Some(Pet::try_from(PetName::Mosquito).unwrap()),
+    ///     ],
+    ///     5
+    /// ).unwrap();
+    /// let mut shop = Shop::default();
+    /// shop.add_item(ShopItem::from(Pet::try_from(PetName::Mosquito).unwrap())).unwrap()
+    ///     .add_item(ShopItem::from(Pet::try_from(PetName::Duck).unwrap())).unwrap();
+    /// team.replace_shop(shop).unwrap().open_shop().unwrap();
+    ///
+    /// // Mosquito merges into the mosquito at the second position.
+    /// let pos = team.buy_auto(&Position::First, &Entity::Pet).unwrap();
+    /// assert_eq!(pos, Position::Relative(-1));
+    /// assert_eq!(team.nth(1).unwrap().read().unwrap().get_experience(), 1);
+    ///
+    /// // Duck is placed behind the mosquito.
+    /// let pos = team.buy_auto(&Position::First, &Entity::Pet).unwrap();
+    /// assert_eq!(pos, Position::Relative(-2));
+    /// assert_eq!(team.nth(2).unwrap().read().unwrap().name, PetName::Duck);
+    /// ```
+    fn buy_auto(&mut self, from: &Position, item_type: &Entity) -> Result<Position, SAPTestError>;
+
     /// Sell a [`Pet`] on the [`Team`] for gold.
     /// # Example
     /// ```
@@ -179,6 +271,167 @@ pub trait TeamShopping {
     /// ```
     fn set_shop_packs(&mut self, packs: &[Pack]) -> &mut Self;
 
+    /// Set the [`Shop`] to include items from these [`Pack`]s with the given weights.
+    /// * See [`Shop::set_pack_weights`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, db::pack::Pack};
+    ///
+    /// let mut team = Team::default();
+    /// // Turtle and puppy pack items with 50/50 rolls.
+    /// team.set_shop_pack_weights(&[(Pack::Turtle, 0.5), (Pack::Puppy, 0.5)]).unwrap();
+    ///
+    /// assert_eq!(team.get_shop_packs(), &[Pack::Turtle, Pack::Puppy]);
+    /// assert!(team.open_shop().is_ok());
+    /// ```
+    fn set_shop_pack_weights(&mut self, weights: &[(Pack, f64)])
+        -> Result<&mut Self, SAPTestError>;
+
+    /// Set the [`Pack`]s the [`Shop`] uses on specific turns.
+    /// * See [`Shop::set_pack_rotation`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, db::pack::Pack};
+    ///
+    /// let mut team = Team::default();
+    /// // Puppy pack on turn 1.
+    /// team.set_shop_pack_rotation(&[(1, vec![Pack::Puppy])]);
+    /// assert!(team.open_shop().is_ok());
+    /// ```
+    fn set_shop_pack_rotation(&mut self, rotation: &[(usize, Vec<Pack>)]) -> &mut Self;
+
+    /// Toggle adding a pet a tier higher than the current [`Shop`] tier whenever a pet levels up from merging.
+    /// * Enabled by default.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Pet, PetName, Position, ShopViewer};
+    ///
+    /// let ant = Pet::try_from(PetName::Ant).unwrap();
+    /// let mut team = Team::new(&vec![Some(ant); 3], 5).unwrap();
+    /// team.set_levelup_offer(false).open_shop().unwrap();
+    ///
+    /// // Merge ants to levelup. No pet is added to the shop.
+    /// let n_shop_pets = team.len_shop_pets();
+    /// team.move_pets(&Position::First, &Position::Relative(-1), true).unwrap();
+    /// team.move_pets(&Position::First, &Position::Relative(-1), true).unwrap();
+    /// assert_eq!(team.len_shop_pets(), n_shop_pets);
+    /// ```
+    fn set_levelup_offer(&mut self, enabled: bool) -> &mut Self;
+
+    /// Subscribe to triggers activated during the shop phase.
+    /// * The callback is called with each trigger matching one of the given statuses before its effects activate.
+    /// * Subscribing to no statuses will subscribe to all triggers.
+    /// # Example
+    /// ```
+    /// use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+    /// use saptest::{Team, TeamShopping, effects::state::Status};
+    ///
+    /// let n_rolls = Arc::new(AtomicUsize::new(0));
+    /// let counter = n_rolls.clone();
+    ///
+    /// let mut team = Team::default();
+    /// team.subscribe_shop(&[Status::Roll], move |_trigger| {
+    ///     counter.fetch_add(1, Ordering::SeqCst);
+    /// })
+    /// .open_shop()
+    /// .unwrap();
+    ///
+    /// team.roll_shop().unwrap().roll_shop().unwrap();
+    /// assert_eq!(n_rolls.load(Ordering::SeqCst), 2);
+    /// ```
+    fn subscribe_shop<F>(&mut self, statuses: &[Status], callback: F) -> &mut Self
+    where
+        F: Fn(&Outcome) + Send + Sync + 'static;
+
+    /// Remove all subscribers to [`Shop`] triggers.
+    /// * See [`TeamShopping::subscribe_shop`].
+    fn clear_shop_subscribers(&mut self) -> &mut Self;
+
+    /// Set the number of slots for an item type at each tier of the [`Shop`].
+    /// * See [`Shop::set_slot_schedule`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Entity};
+    ///
+    /// let mut team = Team::default();
+    /// team.set_shop_slot_schedule(&Entity::Pet, &[5; 6]).unwrap();
+    /// team.open_shop().unwrap();
+    /// assert_eq!(team.len_shop_pets(), 5);
+    /// ```
+    fn set_shop_slot_schedule(
+        &mut self,
+        item_type: &Entity,
+        schedule: &[usize],
+    ) -> Result<&mut Self, SAPTestError>;
+
+    /// Toggle playing in hard mode.
+    /// * When the [`Shop`] opens, a random hard mode [`Toy`](crate::Toy) up to the shop's tier is given to the team if it has none.
+    /// * Disabled by default.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping};
+    ///
+    /// let mut team = Team::default();
+    /// team.set_hard_mode(true).open_shop().unwrap();
+    /// assert!(team.toys.iter().any(|toy| toy.hard_mode));
+    /// ```
+    fn set_hard_mode(&mut self, enabled: bool) -> &mut Self;
+
+    /// Set the [`Economy`] of the [`Shop`].
+    /// * Costs apply to items stocked afterwards.
+    /// # Example
+    /// ```
+    /// use saptest::{Economy, Pet, PetName, Position, Team, TeamShopping};
+    ///
+    /// let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    /// team.set_shop_economy(Economy {
+    ///     sell_values: vec![3],
+    ///     max_gold: Some(12),
+    ///     ..Default::default()
+    /// })
+    /// .open_shop()
+    /// .unwrap();
+    ///
+    /// // Selling gives 3 gold but shop gold is capped at 12.
+    /// team.sell(&Position::First).unwrap();
+    /// assert_eq!(team.gold(), 12);
+    /// ```
+    fn set_shop_economy(&mut self, economy: Economy) -> &mut Self;
+
+    /// Toggle advancing the [`Shop`] tier by turn whenever the shop is opened.
+    /// * Enabled by default.
+    /// * If disabled, the tier only changes with [`TeamShopping::set_shop_tier`] or [`TeamShopping::replace_shop`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping};
+    ///
+    /// let mut team = Team::default();
+    /// // Tier 2 is reached on turn 3.
+    /// team.set_shop_tier(2).unwrap();
+    /// // Tier 3 would be reached on turn 3 but tier isn't advanced.
+    /// team.set_shop_tier_schedule(&[1, 2, 3, 4, 5, 6]).unwrap();
+    /// team.set_auto_tier(false).open_shop().unwrap();
+    /// assert_eq!(team.shop_tier(), 2);
+    /// ```
+    fn set_auto_tier(&mut self, enabled: bool) -> &mut Self;
+
+    /// Set the turns at which each [`Shop`] tier is reached.
+    /// * See [`Shop::set_tier_schedule`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping};
+    ///
+    /// let mut team = Team::default();
+    /// // Tier 2 is reached on turn 3.
+    /// team.set_shop_tier(2).unwrap();
+    ///
+    /// // Reach a new tier every turn.
+    /// team.set_shop_tier_schedule(&[1, 2, 3, 4, 5, 6]).unwrap();
+    /// team.open_shop().unwrap();
+    /// assert_eq!(team.shop_tier(), 3);
+    /// ```
+    fn set_shop_tier_schedule(&mut self, schedule: &[usize]) -> Result<&mut Self, SAPTestError>;
+
     /// Returns an immutable reference to the [`Shop`].
     /// # Example
     /// ```rust no_run
@@ -219,6 +472,73 @@ pub trait TeamShopping {
         item_type: &Entity,
     ) -> Result<&mut Self, SAPTestError>;
 
+    /// Reserve a frozen item in the [`Shop`].
+    /// * Reserved items are kept when rolling or when effects clear the shop.
+    /// * See [`Shop::reserve`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Position, Entity, ShopViewer, ShopItemViewer};
+    /// let mut team = Team::default();
+    /// team.open_shop().unwrap();
+    /// team.freeze_shop(&Position::First, &Entity::Pet)
+    ///     .unwrap()
+    ///     .reserve_shop(&Position::First, &Entity::Pet)
+    ///     .unwrap();
+    ///
+    /// let shop = team.get_shop();
+    /// let reserved_pets = shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Pet).unwrap();
+    /// assert!(reserved_pets[0].is_reserved());
+    /// ```
+    fn reserve_shop(
+        &mut self,
+        pos: &Position,
+        item_type: &Entity,
+    ) -> Result<&mut Self, SAPTestError>;
+
+    /// Freeze all pets, foods, and toys in the [`Shop`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Position, Entity, ShopViewer};
+    /// let mut team = Team::default();
+    /// team.open_shop().unwrap();
+    /// team.freeze_all().unwrap();
+    ///
+    /// let shop = team.get_shop();
+    /// let frozen_pets = shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Pet).unwrap();
+    /// assert_eq!(frozen_pets.len(), team.len_shop_pets());
+    /// ```
+    fn freeze_all(&mut self) -> Result<&mut Self, SAPTestError>;
+
+    /// Unfreeze all pets, foods, and toys in the [`Shop`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Position, Entity, ShopViewer};
+    /// let mut team = Team::default();
+    /// team.open_shop().unwrap();
+    /// team.freeze_all().unwrap().unfreeze_all().unwrap();
+    ///
+    /// let shop = team.get_shop();
+    /// let frozen_foods = shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Food).unwrap();
+    /// assert!(frozen_foods.is_empty());
+    /// ```
+    fn unfreeze_all(&mut self) -> Result<&mut Self, SAPTestError>;
+
+    /// Toggle the frozen state of pets and foods at a [`Position`] in the [`Shop`].
+    /// * Unlike [`TeamShopping::freeze_shop`], both pet and food slots are checked.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Position, Entity, ShopViewer};
+    /// let mut team = Team::default();
+    /// team.open_shop().unwrap();
+    /// // Freeze the first pet and food.
+    /// team.toggle_freeze(&Position::First).unwrap();
+    ///
+    /// let shop = team.get_shop();
+    /// assert_eq!(shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Pet).unwrap().len(), 1);
+    /// assert_eq!(shop.get_shop_items_by_pos(&Position::Frozen, &Entity::Food).unwrap().len(), 1);
+    /// ```
+    fn toggle_freeze(&mut self, pos: &Position) -> Result<&mut Self, SAPTestError>;
+
     /// Open the [`Shop`] for a [`Team`].
     /// # Example
     /// ```
@@ -231,6 +551,7 @@ pub trait TeamShopping {
 
     /// Close a [`Shop`] for a [`Team`].
     /// * Enables [`Team`] fighting.
+    /// * Resets gold for the next turn, adding any gold carried over by the shop's [`Economy`].
     /// # Example
     /// ```
     /// use saptest::{Team, TeamShopping};
@@ -241,6 +562,39 @@ pub trait TeamShopping {
     /// ```
     fn close_shop(&mut self) -> Result<&mut Self, SAPTestError>;
 
+    /// Play a full turn in the [`Shop`] using a policy closure.
+    /// * Opens the shop, takes each [`ShopAction`] returned by the policy until it returns [`ShopAction::EndTurn`] or gold runs out, and then closes the shop.
+    /// * See [`ShopStrategy::run`] for when actions stop.
+    /// * Returns the [`EndTurnSummary`] of the turn.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamShopping, Position, Entity, ShopAction};
+    ///
+    /// let mut team = Team::default();
+    /// team.set_shop_seed(Some(12));
+    ///
+    /// // Buy three pets. Then end the turn.
+    /// let mut n_bought = 0;
+    /// let summary = team.play_shop_turn(|_team, _shop| {
+    ///     n_bought += 1;
+    ///     if n_bought <= 3 {
+    ///         ShopAction::Buy {
+    ///             from: Position::First,
+    ///             item_type: Entity::Pet,
+    ///             to: Position::First,
+    ///         }
+    ///     } else {
+    ///         ShopAction::EndTurn
+    ///     }
+    /// }).unwrap();
+    ///
+    /// assert_eq!(summary.bought.len(), 3);
+    /// assert_eq!(summary.gold_spent, 9);
+    /// ```
+    fn play_shop_turn<F>(&mut self, policy: F) -> Result<EndTurnSummary, SAPTestError>
+    where
+        F: FnMut(&Team, &Shop) -> ShopAction;
+
     /// Get [`Shop`] gold available.
     /// # Example
     /// ```
@@ -376,6 +730,65 @@ pub trait TeamShopping {
 
 /// Helper methods for buy/sell behavior.
 impl TeamShoppingHelpers for Team {
+    fn record_shop_action(
+        &mut self,
+        action: ShopAction,
+        items: Vec<EntityName>,
+        gold_before: usize,
+    ) {
+        self.history.shop_records.push(ShopRecord {
+            turn: self.history.curr_turn,
+            action,
+            items,
+            gold_before,
+            gold_after: self.shop.coins,
+        })
+    }
+
+    fn process_shop_triggers(&mut self) -> Result<(), SAPTestError> {
+        let mut guard = TriggerGuard::new(self.trigger_budget);
+        while let Some(trigger) = self.triggers.pop_front() {
+            guard.check(&trigger)?;
+            for subscriber in self.shop_subscribers.iter() {
+                subscriber.notify(&trigger)
+            }
+            self.trigger_effects(&trigger, None)?;
+            self.trigger_items(&trigger, None)?;
+        }
+        Ok(())
+    }
+
+    /// Get the multiplier of the first friend or toy effect that multiplies food stats and use it once.
+    /// * Defaults to `1` if no effects found.
+    fn use_food_multiplier(&mut self) -> usize {
+        let use_multiplier = |effect: &mut Effect| match effect.action {
+            Action::MultiplyFoodStats(multiplier)
+                if effect.uses != Some(0)
+                    && !effect.limit.is_some_and(|limit| limit.is_reached()) =>
+            {
+                effect.remove_uses(1).add_activation();
+                Some(multiplier)
+            }
+            _ => None,
+        };
+        for pet in self.friends.iter().flatten() {
+            if let Some(multiplier) = pet
+                .write()
+                .unwrap()
+                .effect
+                .iter_mut()
+                .find_map(use_multiplier)
+            {
+                return multiplier;
+            }
+        }
+        self.toys
+            .iter_mut()
+            .flat_map(|toy| toy.effect.iter_mut())
+            .find_map(use_multiplier)
+            .unwrap_or(1)
+    }
+
     fn merge_behavior(
         &mut self,
         from_pet: &Arc<RwLock<Pet>>,
@@ -385,7 +798,15 @@ impl TeamShoppingHelpers for Team {
         let mut prev_lvl = to_pet.read().unwrap().lvl;
 
         // Stack pets.
-        to_pet.write().unwrap().merge(&from_pet.read().unwrap())?;
+        let levelup_triggers = to_pet
+            .write()
+            .unwrap()
+            .merge(from_pet.read().unwrap().clone())?;
+
+        // Consumed perk needs its owner reassigned.
+        if let Some(perk) = to_pet.write().unwrap().items.perk.as_mut() {
+            perk.ability.assign_owner(Some(to_pet));
+        }
 
         // Check if pet leveled up. For EACH levelup:
         // * Activate pet effects if trigger is a levelup.
@@ -393,8 +814,7 @@ impl TeamShoppingHelpers for Team {
         //      * Ex. Fish levelup must use lvl. 1 effect not its current effect at lvl. 2.
         // * Add shop pet on level
         // * Add team levelup triggers.
-        for _ in 0..(to_pet.read().unwrap().lvl - prev_lvl) {
-            let mut levelup_trigger = TRIGGER_SELF_LEVELUP;
+        for mut levelup_trigger in levelup_triggers {
             levelup_trigger.set_affected(to_pet);
 
             // For pet effect of leveled up pet.
@@ -409,9 +829,16 @@ impl TeamShoppingHelpers for Team {
             }
             // Increment level.
             prev_lvl += 1;
-
-            // If pet levels, add a pet (tier above current tier) to shop.
-            if self.shop.add_levelup_pet().is_err() {
+            self.history
+                .shop_summary
+                .levelled
+                .push((to_pet.read().unwrap().name.clone(), prev_lvl));
+
+            // If pet levels in an open shop, add a pet (tier above current tier) to shop.
+            if self.shop.levelup_offer
+                && self.shop.state == ShopState::Open
+                && self.shop.add_levelup_pet().is_err()
+            {
                 info!(target: "run", "Maximum pet capacity reached. No levelup pet added.")
             };
 
@@ -442,20 +869,11 @@ impl TeamShoppingHelpers for Team {
 
             for pet in affected_pets {
                 food.write().unwrap().ability.assign_owner(Some(&pet));
-                pet.write().unwrap().item = Some(food.read().unwrap().clone());
 
                 // Create trigger if food eaten.
                 let mut trigger_self_food = TRIGGER_SELF_FOOD_EATEN;
                 let mut trigger_any_food = TRIGGER_ANY_FOOD_EATEN;
-                let mut trigger_any_gained_perk = TRIGGER_ANY_GAIN_PERK;
-                let mut trigger_self_gained_perk = TRIGGER_SELF_GAIN_PERK;
 
-                trigger_any_gained_perk
-                    .set_affected(&pet)
-                    .set_afflicting_food(food);
-                trigger_self_gained_perk
-                    .set_affected(&pet)
-                    .set_afflicting_food(food);
                 trigger_self_food
                     .set_affected(&pet)
                     .set_afflicting_food(food);
@@ -463,12 +881,11 @@ impl TeamShoppingHelpers for Team {
                     .set_affected(&pet)
                     .set_afflicting_food(food);
 
-                self.triggers.extend([
-                    trigger_self_food,
-                    trigger_any_food,
-                    trigger_any_gained_perk,
-                    trigger_self_gained_perk,
-                ]);
+                self.triggers.extend([trigger_self_food, trigger_any_food]);
+
+                // Eaten foods don't remove ailments.
+                let item = food.read().unwrap().clone();
+                self.equip_item(&pet, item, Some(food));
             }
         } else if food.read().unwrap().name == FoodName::CannedFood {
             // Applying any effect requires an owner so assign current pet.
@@ -479,31 +896,21 @@ impl TeamShoppingHelpers for Team {
             self.apply_shop_effect(&food.read().unwrap().ability)?;
         } else {
             let mut food_ability = food.read().unwrap().ability.clone();
-            // If only one position (ex. apple), use target position, otherwise, use the food.ability positions.
-            let target_pos = if food.read().unwrap().n_targets == 1 {
-                to_pos
-            } else {
-                &food_ability.position
-            };
+            // If only one position (ex. apple) or explicit positions given, use target position, otherwise, use the food.ability positions.
+            let target_pos =
+                if food.read().unwrap().n_targets == 1 || matches!(to_pos, Position::Multiple(_)) {
+                    to_pos
+                } else {
+                    &food_ability.position
+                };
             let affected_pets =
                 self.get_pets_by_pos(curr_pet, &food_ability.target, target_pos, None, None)?;
 
-            // Hard-coded cat multiplier.
             // Repeat applying effect if action is to add stats.
-            let cat_multiplier = if matches!(food_ability.action, Action::Add(_)) {
-                self.friends
-                    .iter()
-                    .flatten()
-                    .find_map(|pet| {
-                        if pet.read().unwrap().name == PetName::Cat {
-                            Some(pet.read().unwrap().lvl)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(0)
+            let multiplier = if matches!(food_ability.action, Action::Add(_)) {
+                self.use_food_multiplier()
             } else {
-                0
+                1
             };
 
             // For each pet found by the effect of food bought, apply its effect.
@@ -523,7 +930,7 @@ impl TeamShoppingHelpers for Team {
 
                 self.triggers.extend([trigger_self_food, trigger_any_food]);
 
-                for _ in 0..(1 + cat_multiplier) {
+                for _ in 0..multiplier {
                     self.apply_single_effect(&pet, &pet, &food_ability, None)?;
                 }
             }
@@ -616,8 +1023,7 @@ impl TeamShopping for Team {
             let items_empty = match item_type {
                 Entity::Pet => self.shop.pets.is_empty(),
                 Entity::Food => self.shop.foods.is_empty(),
-                // Entities unobtainable in shop.
-                _ => true,
+                Entity::Toy => self.shop.toys.is_empty(),
             };
             let err_msg = if items_empty {
                 format!("No {item_type:?} items left to purchase.")
@@ -644,6 +1050,40 @@ impl TeamShopping for Team {
             });
         }
 
+        // Sleeping pills must have a pet to faint. Otherwise, gold is wasted.
+        let has_pill = selected_items.iter().any(|item| {
+            matches!(&item.item, ItemSlot::Food(food) if food.read().unwrap().name == FoodName::SleepingPill)
+        });
+        if has_pill
+            && self
+                .get_pets_by_pos(self.first(), &Target::Friend, to, None, None)?
+                .is_empty()
+        {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "No Sleeping Pill Target".to_string(),
+                reason: format!("No pet at {to:?} to give a sleeping pill."),
+            });
+        }
+
+        // Explicit targets for foods can't exceed the food's number of targets.
+        if let Position::Multiple(positions) = to {
+            for item in selected_items.iter() {
+                if let ItemSlot::Food(food) = &item.item {
+                    let n_targets = food.read().unwrap().n_targets;
+                    if positions.len() > n_targets {
+                        return Err(SAPTestError::InvalidShopAction {
+                            subject: "Too Many Food Targets".to_string(),
+                            reason: format!(
+                                "{:?} has {n_targets} target(s) but {} positions were given.",
+                                food.read().unwrap().name,
+                                positions.len()
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
         // Remove sold items.
         match item_type {
             Entity::Pet => self.shop.pets.retain(|pet| !selected_items.contains(pet)),
@@ -651,28 +1091,113 @@ impl TeamShopping for Team {
                 .shop
                 .foods
                 .retain(|food| !selected_items.contains(food)),
-            _ => unreachable!(),
+            Entity::Toy => self.shop.toys.retain(|toy| !selected_items.contains(toy)),
         }
 
         // Buy the item and check if sufficient funds.
+        let gold_before = self.shop.coins;
+        let mut bought = Vec::with_capacity(selected_items.len());
         for item in selected_items.iter() {
             // Decrement coins.
             self.shop.coins -= item.cost;
+            self.history.shop_summary.gold_spent += item.cost;
+            let item_name = match &item.item {
+                ItemSlot::Pet(pet) => EntityName::Pet(pet.read().unwrap().name.clone()),
+                ItemSlot::Food(food) => EntityName::Food(food.read().unwrap().name.clone()),
+                ItemSlot::Toy(toy) => EntityName::Toy(toy.read().unwrap().name.clone()),
+            };
+            self.history.shop_summary.bought.push(item_name.clone());
+            bought.push(item_name);
 
             match &item.item {
-                ItemSlot::Pet(pet) => self.buy_pet_behavior(pet, self.first(), to)?,
+                ItemSlot::Pet(pet) => {
+                    pet.write().unwrap().cost = item.cost;
+                    self.buy_pet_behavior(pet, self.first(), to)?
+                }
                 ItemSlot::Food(food) => self.buy_food_behavior(food, self.first(), to, true)?,
+                ItemSlot::Toy(toy) => self.toys.push(toy.read().unwrap().clone()),
             };
         }
 
-        while let Some(trigger) = self.triggers.pop_front() {
-            self.trigger_effects(&trigger, None)?;
-            self.trigger_items(&trigger, None)?;
-        }
+        self.process_shop_triggers()?;
         self.clear_team();
+        self.record_shop_action(
+            ShopAction::Buy {
+                from: from.clone(),
+                item_type: *item_type,
+                to: to.clone(),
+            },
+            bought,
+            gold_before,
+        );
         Ok(self)
     }
 
+    fn buy_auto(&mut self, from: &Position, item_type: &Entity) -> Result<Position, SAPTestError> {
+        let Some(item) = self
+            .shop
+            .get_shop_items_by_pos(from, item_type)?
+            .first()
+            .map(|item| item.item.clone())
+        else {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "No Items Selectable".to_string(),
+                reason: format!("No {item_type:?} items selected with {from:?} position."),
+            });
+        };
+
+        // Positions are relative to the first pet.
+        let first_pos = self
+            .first()
+            .and_then(|pet| pet.read().unwrap().pos)
+            .unwrap_or(0);
+        let rel_pos = |idx: usize| Position::Relative(first_pos as isize - idx as isize);
+
+        let to = match item {
+            ItemSlot::Pet(pet) => {
+                let name = pet.read().unwrap().name.clone();
+                let merge_pos = self.friends.iter().flatten().find_map(|friend| {
+                    let friend = friend.read().unwrap();
+                    (friend.name == name && friend.lvl < MAX_PET_LEVEL)
+                        .then_some(friend.pos)
+                        .flatten()
+                });
+                if let Some(idx) = merge_pos {
+                    rel_pos(idx)
+                } else if self.all().len() < self.max_size {
+                    let idx = self
+                        .friends
+                        .iter()
+                        .position(Option::is_none)
+                        .unwrap_or(self.friends.len());
+                    rel_pos(idx)
+                } else {
+                    return Err(SAPTestError::InvalidShopAction {
+                        subject: "No Space (Buy Auto)".to_string(),
+                        reason: format!("No copy of {name:?} to merge into and team is full."),
+                    });
+                }
+            }
+            ItemSlot::Food(food) => {
+                let holdable = food.read().unwrap().holdable;
+                self.friends
+                    .iter()
+                    .flatten()
+                    .find_map(|friend| {
+                        let friend = friend.read().unwrap();
+                        (holdable && friend.items.perk.is_none())
+                            .then_some(friend.pos)
+                            .flatten()
+                    })
+                    .map_or(Position::First, rel_pos)
+            }
+            ItemSlot::Toy(_) => Position::None,
+        };
+
+        self.buy(from, item_type, &to)?;
+        Ok(to)
+    }
+
     fn sell(&mut self, pos: &Position) -> Result<&mut Self, SAPTestError> {
         if self.shop.state == ShopState::Closed {
             return Err(SAPTestError::InvalidShopAction {
@@ -683,10 +1208,18 @@ impl TeamShopping for Team {
 
         let affected_pets = self.get_pets_by_pos(self.first(), &Target::Friend, pos, None, None)?;
 
+        let gold_before = self.shop.coins;
+        let mut sold = Vec::with_capacity(affected_pets.len());
         if !affected_pets.is_empty() {
             for pet in affected_pets {
                 // Add coins for sold pet.
-                self.shop.coins += pet.read().unwrap().lvl;
+                let sell_value = pet.read().unwrap().sell_value(&self.shop.economy);
+                self.shop.coins = self.shop.economy.cap_gold(self.shop.coins + sell_value);
+                self.history
+                    .shop_summary
+                    .sold
+                    .push(pet.read().unwrap().name.clone());
+                sold.push(EntityName::Pet(pet.read().unwrap().name.clone()));
 
                 let mut sell_trigger = TRIGGER_SELF_PET_SOLD;
                 sell_trigger.set_affected(&pet);
@@ -726,11 +1259,9 @@ impl TeamShopping for Team {
         }
 
         // Trigger effects here.
-        while let Some(trigger) = self.triggers.pop_front() {
-            self.trigger_effects(&trigger, None)?;
-            self.trigger_items(&trigger, None)?;
-        }
+        self.process_shop_triggers()?;
         self.clear_team();
+        self.record_shop_action(ShopAction::Sell(pos.clone()), sold, gold_before);
 
         Ok(self)
     }
@@ -743,12 +1274,12 @@ impl TeamShopping for Team {
             });
         }
 
+        let prev_coins = self.shop.coins;
         self.shop.roll()?;
+        self.history.shop_summary.gold_spent += prev_coins.saturating_sub(self.shop.coins);
         self.triggers.push_back(TRIGGER_ROLL);
-        while let Some(trigger) = self.triggers.pop_front() {
-            self.trigger_effects(&trigger, None)?;
-            self.trigger_items(&trigger, None)?;
-        }
+        self.process_shop_triggers()?;
+        self.record_shop_action(ShopAction::Roll, vec![], prev_coins);
         Ok(self)
     }
 
@@ -773,7 +1304,73 @@ impl TeamShopping for Team {
         self
     }
 
+    fn set_shop_pack_weights(
+        &mut self,
+        weights: &[(Pack, f64)],
+    ) -> Result<&mut Self, SAPTestError> {
+        self.shop.set_pack_weights(weights)?;
+        self.shop.packs = weights.iter().map(|(pack, _)| pack.clone()).collect();
+        Ok(self)
+    }
+
+    fn set_shop_pack_rotation(&mut self, rotation: &[(usize, Vec<Pack>)]) -> &mut Self {
+        self.shop.set_pack_rotation(rotation);
+        self
+    }
+
+    fn set_auto_tier(&mut self, enabled: bool) -> &mut Self {
+        self.shop.auto_tier = enabled;
+        self
+    }
+
+    fn set_shop_tier_schedule(&mut self, schedule: &[usize]) -> Result<&mut Self, SAPTestError> {
+        self.shop.set_tier_schedule(schedule)?;
+        Ok(self)
+    }
+
+    fn set_levelup_offer(&mut self, enabled: bool) -> &mut Self {
+        self.shop.levelup_offer = enabled;
+        self
+    }
+
+    fn set_shop_slot_schedule(
+        &mut self,
+        item_type: &Entity,
+        schedule: &[usize],
+    ) -> Result<&mut Self, SAPTestError> {
+        self.shop.set_slot_schedule(item_type, schedule)?;
+        Ok(self)
+    }
+
+    fn set_hard_mode(&mut self, enabled: bool) -> &mut Self {
+        self.shop.hard_mode = enabled;
+        self
+    }
+
+    fn set_shop_economy(&mut self, economy: Economy) -> &mut Self {
+        self.shop.economy = economy;
+        self
+    }
+
+    fn subscribe_shop<F>(&mut self, statuses: &[Status], callback: F) -> &mut Self
+    where
+        F: Fn(&Outcome) + Send + Sync + 'static,
+    {
+        self.shop_subscribers.push(ShopSubscriber {
+            statuses: statuses.to_vec(),
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
+    fn clear_shop_subscribers(&mut self) -> &mut Self {
+        self.shop_subscribers.clear();
+        self
+    }
+
     fn set_shop_tier(&mut self, tier: usize) -> Result<&mut Self, SAPTestError> {
+        // Adjust history of team so curr turn reflects tier.
+        let min_turn_to_tier = self.shop.tier_to_num_turns(tier)?;
         // If increasing in tier, for each tier crate shop tier upgrade trigger.
         if let Some(tier_diff) = tier.checked_sub(self.shop_tier()) {
             for _ in 0..tier_diff {
@@ -781,15 +1378,10 @@ impl TeamShopping for Team {
             }
         }
         self.shop.set_tier(tier)?;
-        // Adjust history of team so curr turn reflects tier.
-        let min_turn_to_tier = Shop::tier_to_num_turns(tier)?;
 
         self.history.curr_turn = min_turn_to_tier;
         // Update trigger effects.
-        while let Some(trigger) = self.triggers.pop_front() {
-            self.trigger_effects(&trigger, None)?;
-            self.trigger_items(&trigger, None)?;
-        }
+        self.process_shop_triggers()?;
         self.clear_team();
 
         // Store friends if changed in process.
@@ -820,6 +1412,83 @@ impl TeamShopping for Team {
         }
 
         self.shop.freeze(pos, item_type)?;
+        self.record_shop_action(
+            ShopAction::Freeze {
+                pos: pos.clone(),
+                item_type: *item_type,
+            },
+            vec![],
+            self.shop.coins,
+        );
+        Ok(self)
+    }
+
+    fn reserve_shop(
+        &mut self,
+        pos: &Position,
+        item_type: &Entity,
+    ) -> Result<&mut Self, SAPTestError> {
+        if self.shop.state == ShopState::Closed {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Closed Shop (Reserve)".to_string(),
+                reason: "Cannot perform action on closed shop.".to_string(),
+            });
+        }
+
+        self.shop.reserve(pos, item_type)?;
+        self.record_shop_action(
+            ShopAction::Reserve {
+                pos: pos.clone(),
+                item_type: *item_type,
+            },
+            vec![],
+            self.shop.coins,
+        );
+        Ok(self)
+    }
+
+    fn freeze_all(&mut self) -> Result<&mut Self, SAPTestError> {
+        if self.shop.state == ShopState::Closed {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Closed Shop (Freeze All)".to_string(),
+                reason: "Cannot perform action on closed shop.".to_string(),
+            });
+        }
+
+        self.shop.freeze_all();
+        self.record_shop_action(ShopAction::FreezeAll, vec![], self.shop.coins);
+        Ok(self)
+    }
+
+    fn unfreeze_all(&mut self) -> Result<&mut Self, SAPTestError> {
+        if self.shop.state == ShopState::Closed {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Closed Shop (Unfreeze All)".to_string(),
+                reason: "Cannot perform action on closed shop.".to_string(),
+            });
+        }
+
+        self.shop.unfreeze_all();
+        self.record_shop_action(ShopAction::UnfreezeAll, vec![], self.shop.coins);
+        Ok(self)
+    }
+
+    fn toggle_freeze(&mut self, pos: &Position) -> Result<&mut Self, SAPTestError> {
+        if self.shop.state == ShopState::Closed {
+            return Err(SAPTestError::InvalidShopAction {
+                subject: "Closed Shop (Toggle Freeze)".to_string(),
+                reason: "Cannot perform action on closed shop.".to_string(),
+            });
+        }
+
+        self.shop
+            .freeze(pos, &Entity::Pet)?
+            .freeze(pos, &Entity::Food)?;
+        self.record_shop_action(
+            ShopAction::ToggleFreeze(pos.clone()),
+            vec![],
+            self.shop.coins,
+        );
         Ok(self)
     }
 
@@ -832,62 +1501,109 @@ impl TeamShopping for Team {
         }
 
         self.shop.state = ShopState::Open;
-        /*
-        (1 / 2 = 0) + (1 % 2 = 0) = 1
-        (2 / 2 = 1) + (2 % 2 = 0) = 1
-        (3 / 2 = 1) + (3 % 2 = 1) = 2
-        (4 / 2 = 2) + (4 % 2 = 0) = 2
-        (5 / 2 = 2) + (5 % 2 = 1) = 3
-        */
-        let calc_tier = ((self.history.curr_turn / 2) + (self.history.curr_turn % 2))
-            .clamp(MIN_SHOP_TIER, MAX_SHOP_TIER);
 
         // Remove sold pets from prev round.
         self.sold.clear();
+        self.history.shop_summary = Default::default();
 
-        // Shop tier upgraded.
-        if self.shop.tier() + 1 == calc_tier {
-            self.triggers.push_back(TRIGGER_SHOP_TIER_UPGRADED)
+        if self.shop.auto_tier {
+            let calc_tier = self.shop.num_turns_to_tier(self.history.curr_turn);
+            // Shop tier upgraded.
+            if self.shop.tier() + 1 == calc_tier {
+                self.triggers.push_back(TRIGGER_SHOP_TIER_UPGRADED)
+            }
+            self.shop.set_tier(calc_tier)?;
         }
-        self.shop.set_tier(calc_tier)?;
+        self.shop.rotate_packs(self.history.curr_turn);
         // Restore team to previous state.
         self.restore();
-
-        // Remove temporary stats.
-        for (pet_id, stats) in self.shop.temp_stats.iter() {
+        self.reset_activation_limits(LimitScope::Turn);
+        self.reset_uses(LimitScope::Turn);
+
+        // Remove temporary stats that have run out.
+        let stat_cap = self.battle_rules.stat_cap;
+        for (pet_id, stats, turns) in self.shop.temp_stats.iter_mut() {
+            *turns = turns.saturating_sub(1);
+            if *turns != 0 {
+                continue;
+            }
             if let Some(pet) = self
                 .friends
                 .iter()
                 .flatten()
                 .find(|pet| pet.read().unwrap().id.as_ref() == Some(pet_id))
             {
-                pet.write().unwrap().stats -= *stats
+                let mut pet = pet.write().unwrap();
+                pet.stats = stat_cap.sub(pet.stats, *stats)?;
             }
         }
+        self.shop.temp_stats.retain(|(_, _, turns)| *turns != 0);
+
+        // Remove held items that have expired.
+        for friend in self.friends.iter().flatten() {
+            let mut friend = friend.write().unwrap();
+            let mut expired = false;
+            let items = &mut friend.items;
+            for slot in [&mut items.perk, &mut items.ailment] {
+                if let Some(duration) = slot.as_mut().and_then(|item| item.duration.as_mut()) {
+                    *duration = duration.saturating_sub(1);
+                    if *duration == 0 {
+                        *slot = None;
+                        expired = true;
+                    }
+                }
+            }
+            if expired {
+                info!(target: "run", "(\"{}\")\nRemoved expired item from {}.", self.name, friend);
+            }
+        }
+        // Remove last turn's price increases from frozen items.
+        let item_markup = std::mem::take(&mut self.shop.item_markup);
+        for item in self.shop.pets.iter_mut().chain(self.shop.foods.iter_mut()) {
+            item.cost = item.cost.saturating_sub(item_markup)
+        }
+        self.shop.roll_markup = 0;
+
         // Trigger start of turn.
         self.triggers.push_front(TRIGGER_START_TURN);
         self.shop.restock()?;
 
-        // Decrease duration of toys.
-        for toy in self.toys.iter_mut() {
-            if let Some(duration) = toy.duration.as_mut() {
-                *duration = duration.saturating_sub(1);
+        // Decrease duration of toys and break toys that have run out.
+        self.age_toys();
+        // Replace expired hard mode toys.
+        if self.shop.hard_mode
+            && !self
+                .toys
+                .iter()
+                .any(|toy| toy.hard_mode && toy.duration != Some(0))
+        {
+            if let Some(toy) = self.shop.hard_mode_toy()? {
+                info!(target: "run", "(\"{}\")\nAdded hard mode toy {}.", self.name, toy.name);
+                self.toys.push(toy)
             }
         }
 
         // Activate all effects given a trigger.
-        while let Some(trigger) = self.triggers.pop_front() {
-            self.trigger_effects(&trigger, None)?;
-            self.trigger_items(&trigger, None)?;
-        }
+        self.process_shop_triggers()?;
         self.clear_team();
 
         // Clear toys that have run out.
         self.toys.retain_mut(|toy| toy.duration != Some(0));
+        self.refresh_auras();
 
         Ok(self)
     }
 
+    fn play_shop_turn<F>(&mut self, mut policy: F) -> Result<EndTurnSummary, SAPTestError>
+    where
+        F: FnMut(&Team, &Shop) -> ShopAction,
+    {
+        self.open_shop()?;
+        policy.run(self)?;
+        self.close_shop()?;
+        Ok(self.end_turn_summary())
+    }
+
     fn close_shop(&mut self) -> Result<&mut Self, SAPTestError> {
         if self.shop.state == ShopState::Closed {
             return Err(SAPTestError::InvalidShopAction {
@@ -910,10 +1626,7 @@ impl TeamShopping for Team {
 
         // Trigger end of turn.
         self.triggers.push_front(TRIGGER_END_TURN);
-        while let Some(trigger) = self.triggers.pop_front() {
-            self.trigger_effects(&trigger, None)?;
-            self.trigger_items(&trigger, None)?;
-        }
+        self.process_shop_triggers()?;
         self.clear_team();
 
         // Store friends.
@@ -923,8 +1636,15 @@ impl TeamShopping for Team {
             .map(|slot| slot.as_ref().map(|pet| pet.read().unwrap().clone()))
             .collect_vec();
 
-        // Reset coins adding saved coins.
-        self.shop.coins = DEFAULT_COIN_COUNT + self.shop.saved_coins;
+        // Reset coins adding saved coins and interest.
+        let carried_gold = self
+            .shop
+            .economy
+            .carried_gold(self.shop.coins, self.shop.saved_coins);
+        self.shop.coins = self
+            .shop
+            .economy
+            .cap_gold(DEFAULT_COIN_COUNT + carried_gold);
         self.shop.saved_coins = 0;
         self.shop.state = ShopState::Closed;
 
@@ -933,7 +1653,7 @@ impl TeamShopping for Team {
 
     fn replace_shop(&mut self, shop: Shop) -> Result<&mut Self, SAPTestError> {
         // If shop has invalid tier, return err.
-        let adj_turn = Shop::tier_to_num_turns(shop.tier())?;
+        let adj_turn = shop.tier_to_num_turns(shop.tier())?;
         // Adjust turns to reflect tier.
         self.history.curr_turn = adj_turn;
         self.shop = shop;
@@ -1009,10 +1729,7 @@ impl TeamShopping for Team {
                 self.friends.insert(to_pos, from_pet)
             }
 
-            while let Some(trigger) = self.triggers.pop_front() {
-                self.trigger_effects(&trigger, None)?;
-                self.trigger_items(&trigger, None)?;
-            }
+            self.process_shop_triggers()?;
             self.clear_team();
         }
         Ok(self)
@@ -1,8 +1,39 @@
+use std::{fmt::Debug, sync::Arc};
+
 use crate::{
     effects::state::{EqualityCondition, ItemCondition, Outcome, Status, Target},
     Position,
 };
 
+/// Callback observing [`Outcome`] triggers activated during the shop phase.
+pub type ShopCallback = Arc<dyn Fn(&Outcome) + Send + Sync>;
+
+/// Subscriber to shop triggers.
+#[derive(Clone)]
+pub(crate) struct ShopSubscriber {
+    /// Statuses subscribed to. Empty subscribes to all statuses.
+    pub(crate) statuses: Vec<Status>,
+    /// Callback for a trigger.
+    pub(crate) callback: ShopCallback,
+}
+
+impl Debug for ShopSubscriber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShopSubscriber")
+            .field("statuses", &self.statuses)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ShopSubscriber {
+    /// Call the subscriber's callback if subscribed to the trigger's status.
+    pub(crate) fn notify(&self, trigger: &Outcome) {
+        if self.statuses.is_empty() || self.statuses.contains(&trigger.status) {
+            (self.callback)(trigger)
+        }
+    }
+}
+
 /// Create a trigger for when a pet is bought with an effect that triggers with this status.
 pub(crate) fn trigger_any_pet_bought_status(status: Status) -> Outcome {
     Outcome {
@@ -14,6 +45,9 @@ pub(crate) fn trigger_any_pet_bought_status(status: Status) -> Outcome {
         position: Position::Any(ItemCondition::Equal(EqualityCondition::Trigger(status))),
         stat_diff: None,
         afflicting_food: None,
+        afflicting_toy: None,
+        affected_snapshot: None,
+        afflicting_snapshot: None,
     }
 }
 
@@ -28,6 +62,9 @@ pub(crate) fn trigger_any_pet_bought_tier(tier: usize) -> Outcome {
         position: Position::Any(ItemCondition::Equal(EqualityCondition::Tier(tier))),
         stat_diff: None,
         afflicting_food: None,
+        afflicting_toy: None,
+        affected_snapshot: None,
+        afflicting_snapshot: None,
     }
 }
 
@@ -42,6 +79,9 @@ pub(crate) fn trigger_any_pet_sold_status(status: Status) -> Outcome {
         position: Position::Any(ItemCondition::Equal(EqualityCondition::Trigger(status))),
         stat_diff: None,
         afflicting_food: None,
+        afflicting_toy: None,
+        affected_snapshot: None,
+        afflicting_snapshot: None,
     }
 }
 
@@ -55,6 +95,9 @@ pub const TRIGGER_ANY_FOOD_BOUGHT: Outcome = Outcome {
     position: Position::Any(ItemCondition::None),
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when any food bought and eaten.
@@ -67,6 +110,9 @@ pub const TRIGGER_ANY_FOOD_EATEN: Outcome = Outcome {
     position: Position::Any(ItemCondition::None),
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when food bought.
@@ -79,6 +125,9 @@ pub const TRIGGER_SELF_FOOD_EATEN: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when pet bought.
@@ -91,6 +140,9 @@ pub const TRIGGER_SELF_PET_BOUGHT: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when pet bought.
@@ -103,6 +155,9 @@ pub const TRIGGER_ANY_PET_BOUGHT: Outcome = Outcome {
     position: Position::Any(ItemCondition::None),
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when any pet sold.
@@ -115,6 +170,9 @@ pub const TRIGGER_ANY_PET_SOLD: Outcome = Outcome {
     position: Position::Any(ItemCondition::None),
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when pet sold.
@@ -127,6 +185,9 @@ pub const TRIGGER_SELF_PET_SOLD: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when shop rolled.
@@ -139,9 +200,12 @@ pub const TRIGGER_ROLL: Outcome = Outcome {
     position: Position::None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
-/// Trigger when food bought.
+/// Trigger when shop tier upgraded.
 pub const TRIGGER_SHOP_TIER_UPGRADED: Outcome = Outcome {
     status: Status::ShopTierUpgrade,
     affected_pet: None,
@@ -151,6 +215,9 @@ pub const TRIGGER_SHOP_TIER_UPGRADED: Outcome = Outcome {
     position: Position::None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when any friend gain perk.
@@ -163,6 +230,9 @@ pub const TRIGGER_ANY_GAIN_PERK: Outcome = Outcome {
     position: Position::None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
 /// Trigger when self pet gains food perk.
@@ -175,16 +245,23 @@ pub const TRIGGER_SELF_GAIN_PERK: Outcome = Outcome {
     position: Position::OnSelf,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
 
-/// Trigger when self pet gains food perk.
+/// Trigger when a toy breaks.
+/// * Non-specific so pets can react to any toy breaking.
 pub const TRIGGER_TOY_BREAK: Outcome = Outcome {
     status: Status::BrokeToy,
     affected_pet: None,
     affected_team: Target::Shop,
     afflicting_pet: None,
     afflicting_team: Target::None,
-    position: Position::OnSelf,
+    position: Position::None,
     stat_diff: None,
     afflicting_food: None,
+    afflicting_toy: None,
+    affected_snapshot: None,
+    afflicting_snapshot: None,
 };
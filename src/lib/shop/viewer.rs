@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use itertools::Itertools;
 use rand::{
     random,
@@ -17,6 +19,111 @@ use crate::{
     Entity, Position, Shop,
 };
 
+/// Filter for [`ShopItem`]s.
+/// * Each set criteria must be met for an item to be included.
+/// * Items without stats are excluded if a stat range is set.
+/// # Example
+/// ```
+/// use saptest::{Entity, ShopItemFilter, effects::state::Status};
+///
+/// // All faint-trigger pets costing at most 2 gold.
+/// let filter = ShopItemFilter::new(Entity::Pet)
+///     .set_trigger(Status::Faint)
+///     .set_cost(0..=2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShopItemFilter {
+    /// Type of item.
+    pub item_type: Entity,
+    /// Tiers of item.
+    pub tier: Option<RangeInclusive<usize>>,
+    /// Gold cost of item.
+    pub cost: Option<RangeInclusive<usize>>,
+    /// Effect trigger of item.
+    pub trigger: Option<Status>,
+    /// Attack of item.
+    pub attack: Option<RangeInclusive<isize>>,
+    /// Health of item.
+    pub health: Option<RangeInclusive<isize>>,
+    /// Frozen state of item.
+    pub frozen: Option<bool>,
+}
+
+impl ShopItemFilter {
+    /// Create a filter including all items of an [`Entity`] type.
+    pub fn new(item_type: Entity) -> Self {
+        ShopItemFilter {
+            item_type,
+            tier: None,
+            cost: None,
+            trigger: None,
+            attack: None,
+            health: None,
+            frozen: None,
+        }
+    }
+
+    /// Include only items within this tier range.
+    pub fn set_tier(mut self, tier: RangeInclusive<usize>) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// Include only items within this cost range.
+    pub fn set_cost(mut self, cost: RangeInclusive<usize>) -> Self {
+        self.cost = Some(cost);
+        self
+    }
+
+    /// Include only items with an effect triggered by this [`Status`].
+    pub fn set_trigger(mut self, trigger: Status) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    /// Include only items with attack within this range.
+    pub fn set_attack(mut self, attack: RangeInclusive<isize>) -> Self {
+        self.attack = Some(attack);
+        self
+    }
+
+    /// Include only items with health within this range.
+    pub fn set_health(mut self, health: RangeInclusive<isize>) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// Include only frozen or unfrozen items.
+    pub fn set_frozen(mut self, frozen: bool) -> Self {
+        self.frozen = Some(frozen);
+        self
+    }
+
+    /// Check if a [`ShopItem`] meets this filter's criteria.
+    /// * Doesn't check the item's type.
+    pub fn matches(&self, item: &ShopItem) -> bool {
+        let in_stat_range = |range: &Option<RangeInclusive<isize>>, stat: Option<isize>| {
+            range
+                .as_ref()
+                .is_none_or(|range| stat.is_some_and(|stat| range.contains(&stat)))
+        };
+        self.tier
+            .as_ref()
+            .is_none_or(|tier| tier.contains(&item.tier()))
+            && self
+                .cost
+                .as_ref()
+                .is_none_or(|cost| cost.contains(&item.cost()))
+            && self
+                .trigger
+                .as_ref()
+                .is_none_or(|trigger| item.triggers().contains(trigger))
+            && in_stat_range(&self.attack, item.attack_stat())
+            && in_stat_range(&self.health, item.health_stat())
+            && self.frozen.is_none_or(|frozen| item.is_frozen() == frozen)
+    }
+}
+
 /// Enables viewing [`ShopItem`]s and their state.
 pub trait ShopViewer {
     /// Get [`ShopItem`]s by [`ItemCondition`].
@@ -60,12 +167,32 @@ pub trait ShopViewer {
         item: &Entity,
     ) -> Result<Vec<&ShopItem>, SAPTestError>;
 
+    /// Get [`ShopItem`]s matching a [`ShopItemFilter`].
+    /// # Example
+    /// ```
+    /// use saptest::{Shop, ShopViewer, ShopItemViewer, Entity, ShopItemFilter};
+    ///
+    /// let shop = Shop::new(3, Some(42)).unwrap();
+    ///
+    /// // Tier 2 or 3 pets with at least 3 health.
+    /// let filter = ShopItemFilter::new(Entity::Pet)
+    ///     .set_tier(2..=3)
+    ///     .set_health(3..=50);
+    /// for item in shop.get_shop_items_by_filter(&filter) {
+    ///     assert!((2..=3).contains(&item.tier()) && item.health_stat().unwrap() >= 3)
+    /// }
+    /// ```
+    fn get_shop_items_by_filter(&self, filter: &ShopItemFilter) -> Vec<&ShopItem>;
+
     /// Get the number of foods in the shop.
     fn len_foods(&self) -> usize;
 
     /// Get the number of pets in the shop.
     fn len_pets(&self) -> usize;
 
+    /// Get the number of toys in the shop.
+    fn len_toys(&self) -> usize;
+
     /// Get the number of food [`ShopItem`]s at the shop's current tier.
     fn max_food_slots(&self) -> usize;
 
@@ -80,6 +207,13 @@ pub trait ShopViewer {
 }
 
 impl ShopViewer for Shop {
+    fn get_shop_items_by_filter(&self, filter: &ShopItemFilter) -> Vec<&ShopItem> {
+        self.items(&filter.item_type)
+            .iter()
+            .filter(|item| filter.matches(item))
+            .collect_vec()
+    }
+
     fn len_foods(&self) -> usize {
         self.foods.len()
     }
@@ -88,14 +222,17 @@ impl ShopViewer for Shop {
         self.pets.len()
     }
 
-    // TODO: Needs to be larger. Also include other shop changes. Too lazy to mark inline.
+    fn len_toys(&self) -> usize {
+        self.toys.len()
+    }
+
+    // Default slot schedules follow the game's shop changes.
     // * https://steamcommunity.com/app/1714040/eventcomments/3812906855243976215?snr=1_2108_9__2107
     fn max_food_slots(&self) -> usize {
-        if self.tier() < 2 {
-            1
-        } else {
-            2
-        }
+        self.slot_schedule(&Entity::Food)
+            .get(self.tier() - 1)
+            .copied()
+            .unwrap_or_default()
     }
 
     fn available_food_slots(&self) -> usize {
@@ -103,13 +240,10 @@ impl ShopViewer for Shop {
     }
 
     fn max_pet_slots(&self) -> usize {
-        if self.tier() < 3 {
-            3
-        } else if self.tier() < 5 {
-            4
-        } else {
-            5
-        }
+        self.slot_schedule(&Entity::Pet)
+            .get(self.tier() - 1)
+            .copied()
+            .unwrap_or_default()
     }
 
     fn available_pet_slots(&self) -> usize {
@@ -125,12 +259,7 @@ impl ShopViewer for Shop {
         let all_items = match item_type {
             Entity::Pet => self.pets.iter(),
             Entity::Food => self.foods.iter(),
-            _ => {
-                return Err(SAPTestError::InvalidPetAction {
-                    subject: String::from("Invalid Entity"),
-                    reason: format!("Shop does not contain {item_type}."),
-                })
-            }
+            Entity::Toy => self.toys.iter(),
         };
 
         match cond {
@@ -194,6 +323,14 @@ impl ShopViewer for Shop {
                 EqualityCondition::Frozen => {
                     found_items.extend(all_items.filter(|item| item.is_frozen()))
                 }
+                EqualityCondition::Attack(range) => found_items.extend(
+                    all_items
+                        .filter(|item| item.attack_stat().is_some_and(|atk| range.contains(&atk))),
+                ),
+                EqualityCondition::Health(range) => found_items.extend(all_items.filter(|item| {
+                    item.health_stat()
+                        .is_some_and(|health| range.contains(&health))
+                })),
                 _ => {
                     return Err(SAPTestError::InvalidShopAction {
                         subject: "Invalid Equality ItemCondition".to_string(),
@@ -283,22 +420,14 @@ impl ShopViewer for Shop {
                 found_items.extend(found_found_items)
             }
             Position::First => {
-                let item = if let Entity::Food = item {
-                    self.foods.first()
-                } else {
-                    self.pets.first()
-                };
+                let item = self.items(item).first();
 
                 if let Some(item) = item {
                     found_items.push(item)
                 };
             }
             Position::Last => {
-                let item = if let Entity::Food = item {
-                    self.foods.last()
-                } else {
-                    self.pets.last()
-                };
+                let item = self.items(item).last();
 
                 if let Some(item) = item {
                     found_items.push(item)
@@ -309,11 +438,7 @@ impl ShopViewer for Shop {
                     .clone()
                     .filter_map(|idx| TryInto::<usize>::try_into(idx).ok())
                     .max();
-                let found_found_items = if let Entity::Food = item {
-                    end_idx.map(|idx| self.foods.get(0..=idx))
-                } else {
-                    end_idx.map(|idx| self.pets.get(0..=idx))
-                };
+                let found_found_items = end_idx.map(|idx| self.items(item).get(0..=idx));
                 if let Some(Some(found_found_items)) = found_found_items {
                     found_items.extend(found_found_items)
                 }
@@ -325,11 +450,7 @@ impl ShopViewer for Shop {
                         reason: format!("Invalid relative index. {idx}"),
                     },
                 )?;
-                if let Some(found_item) = if let Entity::Food = item {
-                    self.foods.get(converted_idx)
-                } else {
-                    self.pets.get(converted_idx)
-                } {
+                if let Some(found_item) = self.items(item).get(converted_idx) {
                     found_items.push(found_item)
                 }
             }
@@ -341,6 +462,13 @@ impl ShopViewer for Shop {
                     found_items.extend(pos_found_items.iter())
                 }
             }
+            Position::Frozen => {
+                let found_found_items = self.get_shop_items_by_cond(
+                    &ItemCondition::Equal(EqualityCondition::Frozen),
+                    item,
+                )?;
+                found_items.extend(found_found_items)
+            }
             Position::None => {}
             _ => {
                 return Err(SAPTestError::InvalidShopAction {
@@ -361,7 +489,10 @@ pub trait ShopItemViewer: std::borrow::Borrow<ShopItem> {
     /// Get [`ShopItem`] cost.
     fn cost(&self) -> usize;
     /// Check if [`ShopItem`] in [`Shop`] is frozen.
+    /// * Reserved items are also frozen.
     fn is_frozen(&self) -> bool;
+    /// Check if [`ShopItem`] in [`Shop`] is reserved. See [`Shop::reserve`].
+    fn is_reserved(&self) -> bool;
     /// Get health stat of [`ShopItem`].
     fn health_stat(&self) -> Option<isize>;
     /// Get attack stat of [`ShopItem`].
@@ -379,13 +510,17 @@ impl ShopItemViewer for ShopItem {
         match &self.item {
             ItemSlot::Pet(pet) => EntityName::Pet(pet.read().unwrap().name.clone()),
             ItemSlot::Food(food) => EntityName::Food(food.read().unwrap().name.clone()),
+            ItemSlot::Toy(toy) => EntityName::Toy(toy.read().unwrap().name.clone()),
         }
     }
     fn cost(&self) -> usize {
         self.cost
     }
     fn is_frozen(&self) -> bool {
-        self.state == ItemState::Frozen
+        matches!(self.state, ItemState::Frozen | ItemState::Reserved)
+    }
+    fn is_reserved(&self) -> bool {
+        self.state == ItemState::Reserved
     }
     fn health_stat(&self) -> Option<isize> {
         match &self.item {
@@ -395,6 +530,7 @@ impl ShopItemViewer for ShopItem {
                 Action::Remove(StatChangeType::Static(stats)) => Some(stats.health),
                 _ => None,
             },
+            ItemSlot::Toy(_) => None,
         }
     }
     fn attack_stat(&self) -> Option<isize> {
@@ -406,12 +542,14 @@ impl ShopItemViewer for ShopItem {
                 Action::Negate(stats) => Some(stats.attack),
                 _ => None,
             },
+            ItemSlot::Toy(_) => None,
         }
     }
     fn tier(&self) -> usize {
         match &self.item {
             ItemSlot::Pet(pet) => pet.read().unwrap().tier,
             ItemSlot::Food(food) => food.read().unwrap().tier,
+            ItemSlot::Toy(toy) => toy.read().unwrap().tier,
         }
     }
     fn triggers(&self) -> Vec<Status> {
@@ -424,6 +562,13 @@ impl ShopItemViewer for ShopItem {
                 .map(|effect| effect.trigger.status.clone())
                 .collect_vec(),
             ItemSlot::Food(food) => vec![food.read().unwrap().ability.trigger.status.clone()],
+            ItemSlot::Toy(toy) => toy
+                .read()
+                .unwrap()
+                .effect
+                .iter()
+                .map(|effect| effect.trigger.status.clone())
+                .collect_vec(),
         }
     }
     fn actions(&self) -> Vec<Action> {
@@ -436,6 +581,13 @@ impl ShopItemViewer for ShopItem {
                 .map(|effect| effect.action.clone())
                 .collect_vec(),
             ItemSlot::Food(food) => vec![food.read().unwrap().ability.action.clone()],
+            ItemSlot::Toy(toy) => toy
+                .read()
+                .unwrap()
+                .effect
+                .iter()
+                .map(|effect| effect.action.clone())
+                .collect_vec(),
         }
     }
 }
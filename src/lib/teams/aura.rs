@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{ItemCondition, Statistics, Team, TeamViewer, ToyName};
+
+/// Source keeping an [`Aura`] active.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AuraSource {
+    /// Active while the [`Team`] has an unbroken [`Toy`](crate::Toy) with this name.
+    /// * Includes hard mode toys.
+    Toy(ToyName),
+    /// Active while the [`Pet`](crate::Pet) with this id is alive on the [`Team`].
+    Pet(usize),
+    /// Active until removed with [`Team::remove_aura`].
+    Custom,
+}
+
+/// Passive [`Statistics`] given to [`Pet`](crate::Pet)s on a [`Team`] while its [`AuraSource`] is active.
+/// * Pets gain the stats when they match the aura's [`ItemCondition`] and lose them when they no longer match.
+/// * Once the source is gone, all stats are removed and the aura is dropped from the team.
+/// * Added with [`Team::add_aura`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Aura {
+    /// Name of the aura.
+    pub name: String,
+    /// Source keeping the aura active.
+    pub source: AuraSource,
+    /// Condition pets must match to gain the aura.
+    pub condition: ItemCondition,
+    /// Stats given to affected pets.
+    pub stats: Statistics,
+    /// Ids of pets currently given the aura's stats.
+    #[serde(default)]
+    pub(crate) applied: Vec<usize>,
+}
+
+impl Aura {
+    /// Create an [`Aura`].
+    /// ```
+    /// use saptest::{
+    ///     ItemCondition, Statistics, ToyName,
+    ///     teams::aura::{Aura, AuraSource}
+    /// };
+    ///
+    /// // All pets gain (1, 1) while the team has a balloon.
+    /// let aura = Aura::new(
+    ///     "Balloon Aura",
+    ///     AuraSource::Toy(ToyName::Balloon),
+    ///     ItemCondition::None,
+    ///     Statistics::new(1, 1).unwrap(),
+    /// );
+    /// assert!(aura.affected_pets().is_empty());
+    /// ```
+    pub fn new(
+        name: &str,
+        source: AuraSource,
+        condition: ItemCondition,
+        stats: Statistics,
+    ) -> Self {
+        Aura {
+            name: name.to_owned(),
+            source,
+            condition,
+            stats,
+            applied: vec![],
+        }
+    }
+
+    /// Ids of pets currently given the aura's stats.
+    pub fn affected_pets(&self) -> &[usize] {
+        &self.applied
+    }
+
+    /// Check if the aura's source is still on the team.
+    pub fn is_active(&self, team: &Team) -> bool {
+        match &self.source {
+            AuraSource::Toy(toy_name) => team
+                .toys
+                .iter()
+                .any(|toy| toy.name == *toy_name && toy.duration != Some(0)),
+            AuraSource::Pet(pet_id) => team
+                .all()
+                .iter()
+                .any(|pet| pet.read().unwrap().id == Some(*pet_id)),
+            AuraSource::Custom => true,
+        }
+    }
+
+    /// Update the pets given the aura's stats.
+    /// * Pets already affected keep their stats.
+    /// * Inactive auras remove their stats from all pets.
+    pub(crate) fn update(&mut self, team: &Team) {
+        let targets = if self.is_active(team) {
+            team.get_pets_by_cond(&self.condition)
+                .into_iter()
+                .filter(|pet| pet.read().unwrap().stats.health != 0)
+                .filter_map(|pet| pet.read().unwrap().id)
+                .collect()
+        } else {
+            vec![]
+        };
+        self.set_targets(team, targets)
+    }
+
+    /// Remove the aura's stats from all affected pets.
+    pub(crate) fn remove(&mut self, team: &Team) {
+        self.set_targets(team, vec![])
+    }
+
+    fn set_targets(&mut self, team: &Team, targets: Vec<usize>) {
+        let find_pet = |pet_id: usize| {
+            team.friends
+                .iter()
+                .flatten()
+                .find(|pet| pet.read().unwrap().id == Some(pet_id))
+        };
+        // Remove stats from pets no longer affected.
+        for pet_id in self.applied.iter().filter(|id| !targets.contains(id)) {
+            if let Some(pet) = find_pet(*pet_id) {
+                let mut pet = pet.write().unwrap();
+                let alive = pet.stats.health != 0;
+                pet.stats -= self.stats;
+                // Losing an aura never causes a pet to faint.
+                if alive {
+                    pet.stats.health = pet.stats.health.max(1)
+                }
+            }
+        }
+        // Add stats to newly affected pets.
+        for pet_id in targets.iter().filter(|id| !self.applied.contains(id)) {
+            if let Some(pet) = find_pet(*pet_id) {
+                pet.write().unwrap().stats += self.stats
+            }
+        }
+        self.applied = targets;
+    }
+}
@@ -5,7 +5,9 @@ use crate::{
     db::pack::Pack,
     effects::{
         actions::{Action, SummonType},
+        effect::LimitScope,
         state::Status,
+        stats::StatCap,
         trigger::*,
     },
     error::SAPTestError,
@@ -15,6 +17,64 @@ use crate::{
     Effect, PetCombat, PetName, Team, TeamEffects, TeamViewer, CONFIG,
 };
 
+/// Optional battle mechanics for a [`Team`].
+/// * Set with [`Team::set_battle_rules`].
+/// * The rules of the team calling [`fight`](TeamCombat::fight) are used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BattleRules {
+    /// Activate start of battle effects one [`Pet`](crate::Pet) at a time across both teams by current attack.
+    /// * Triggers from each pet's effects resolve before the next pet activates.
+    /// * Pets that faint before activating have no start of battle effect.
+    pub interleave_start_of_battle: bool,
+    /// Order triggers resolve in during battle.
+    pub faint_order: FaintOrder,
+    /// Order held item effects resolve in relative to pet effects during battle.
+    pub item_order: ItemOrder,
+    /// Cap on pet stats gained from effects, summons, and copies.
+    /// * Pets affected by the team's effects use the team's cap.
+    pub stat_cap: StatCap,
+}
+
+/// Order held item effects (ex. [`Honey`](crate::FoodName::Honey) and [`Mushroom`](crate::FoodName::Mushroom)) resolve in during battle.
+/// * Set with [`BattleRules::item_order`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ItemOrder {
+    /// Item effects resolve once all pending pet effects on both teams are exhausted.
+    #[default]
+    AfterPetEffects,
+    /// Item effects resolve right after the pet effects of the same trigger.
+    /// * Matches the game, where a fainted pet's ability and then its held item activate before other pets react to the faint.
+    /// * Ex. A [`Deer`](crate::PetName::Deer) with a [`Mushroom`](crate::FoodName::Mushroom) summons its bus and revives before a [`Fly`](crate::PetName::Fly) summons a zombie fly.
+    WithTrigger,
+}
+
+/// Order pending triggers on two fighting [`Team`]s resolve in.
+/// * Set with [`BattleRules::faint_order`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FaintOrder {
+    /// Triggers resolve in the order they were queued.
+    /// * One team's triggers are exhausted before the other's.
+    #[default]
+    Queued,
+    /// Faint triggers resolve front-to-back within a team.
+    /// * Teams alternate activating triggers, starting with the team whose triggering pet has the higher attack.
+    FrontToBack,
+}
+
+/// Remove [`Food`](crate::Food) items from a [`Team`]'s pets that only last until the end of battle.
+/// * These are items with a temporary [`Effect`].
+fn remove_end_of_battle_items(team: &Team) {
+    for pet in team.friends.iter().chain(team.fainted.iter()).flatten() {
+        let items = &mut pet.write().unwrap().items;
+        for slot in [&mut items.perk, &mut items.ailment] {
+            if slot.as_ref().is_some_and(|item| item.ability.temp) {
+                let item = slot.take();
+                info!(target: "run", "(\"{}\")\nRemoved end of battle item {:?}.", team.name, item.map(|item| item.name));
+            }
+        }
+    }
+}
+
 const BATTLE_PHASE_COMPLETE_OUTCOMES: [TeamFightOutcome; 3] = [
     TeamFightOutcome::Win,
     TeamFightOutcome::Loss,
@@ -139,7 +199,7 @@ impl TeamCombat for Team {
                         friend.exp = stored_friend.exp;
                         friend.effect = stored_friend.effect.clone();
                         friend.lvl = stored_friend.lvl;
-                        friend.item = stored_friend.item.clone();
+                        friend.items = stored_friend.items.clone();
                         friend.pos = stored_friend.pos;
                         true
                     } else {
@@ -180,7 +240,7 @@ impl TeamCombat for Team {
         self.history.pet_count = self.stored_friends.len();
 
         // Clear counters.
-        self.counters.iter_mut().for_each(|(_, item)| *item = 0);
+        self.counters.reset();
 
         // Reset golden pack effect. Note user added effects are removed.
         let golden_effect: Vec<Effect> = Pack::Golden.into();
@@ -243,7 +303,8 @@ impl TeamCombat for Team {
         if let Some(last_idx) = last_idx {
             self.friends.truncate(last_idx + 1)
         }
-        self
+        // Update auras with remaining pets.
+        self.refresh_auras()
     }
 }
 
@@ -296,6 +357,10 @@ impl BattlePhases for Team {
             if CONFIG.general.build_graph {
                 self.history.graph.update(&self.friends, &opponent.friends);
             }
+            self.reset_activation_limits(LimitScope::Battle);
+            opponent.reset_activation_limits(LimitScope::Battle);
+            self.reset_uses(LimitScope::Battle);
+            opponent.reset_uses(LimitScope::Battle);
             self.trigger_start_battle_effects(opponent)?;
         }
 
@@ -471,6 +536,15 @@ impl BattlePhases for Team {
         let outcome = self.get_battle_outcome(opponent);
         // Update history.
         if BATTLE_PHASE_COMPLETE_OUTCOMES.contains(&outcome) {
+            // Activate end of battle effects once outcome decided.
+            self.triggers.push_back(TRIGGER_END_BATTLE);
+            opponent.triggers.push_back(TRIGGER_END_BATTLE);
+            self.trigger_all_effects(opponent)?;
+
+            // Remove items only lasting until the end of battle.
+            remove_end_of_battle_items(self);
+            remove_end_of_battle_items(opponent);
+
             opponent.history.fight_outcomes.push(outcome.inverse());
             self.history.fight_outcomes.push(outcome.clone());
 
@@ -0,0 +1,243 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+/// Key of a [`Team`](crate::Team) counter.
+/// * Serialized as its name. Names other than built-in counters are [`CounterKey::Custom`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum CounterKey {
+    /// Trumpets gained by pets in the Golden pack.
+    Trumpets,
+    /// Times [`Rice`](crate::FoodName::Rice) was eaten this run.
+    RiceEaten,
+    /// A user-defined counter.
+    Custom(String),
+}
+
+impl From<String> for CounterKey {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "Trumpets" => CounterKey::Trumpets,
+            "RiceEaten" => CounterKey::RiceEaten,
+            _ => CounterKey::Custom(name),
+        }
+    }
+}
+
+impl From<&str> for CounterKey {
+    fn from(name: &str) -> Self {
+        CounterKey::from(name.to_owned())
+    }
+}
+
+impl From<CounterKey> for String {
+    fn from(key: CounterKey) -> Self {
+        key.to_string()
+    }
+}
+
+impl std::fmt::Display for CounterKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CounterKey::Trumpets => write!(f, "Trumpets"),
+            CounterKey::RiceEaten => write!(f, "RiceEaten"),
+            CounterKey::Custom(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// A bounded count.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Counter {
+    /// Current count.
+    pub count: usize,
+    /// Minimum count.
+    pub min: usize,
+    /// Maximum count.
+    /// * [`None`] indicates no maximum.
+    pub max: Option<usize>,
+}
+
+impl Counter {
+    fn clamp(&self, count: usize) -> usize {
+        let count = count.max(self.min);
+        self.max.map_or(count, |max| count.min(max))
+    }
+}
+
+/// Callback observing a counter's key, previous count, and new count.
+pub type CounterCallback = Arc<dyn Fn(&CounterKey, usize, usize) + Send + Sync>;
+
+#[derive(Clone)]
+struct CounterSubscriber {
+    /// Counters subscribed to. Empty subscribes to all counters.
+    keys: Vec<CounterKey>,
+    callback: CounterCallback,
+}
+
+impl Debug for CounterSubscriber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CounterSubscriber")
+            .field("keys", &self.keys)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Counters of a [`Team`](crate::Team).
+/// * Counters can be used with effects through [`StatChangeType::TeamCounter`](crate::effects::actions::StatChangeType::TeamCounter), [`TeamCondition::Counter`](crate::effects::state::TeamCondition::Counter), and [`Action::AddToCounter`](crate::effects::actions::Action::AddToCounter).
+/// * Counts are reset to their minimum after a battle.
+///
+/// # Examples
+/// ```
+/// use saptest::{Team, teams::counters::CounterKey};
+///
+/// let mut team = Team::default();
+/// assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(0));
+///
+/// // Register a counter with a maximum of 3.
+/// let key = CounterKey::from("Apples");
+/// team.counters.register(key.clone(), 0, Some(3));
+/// assert_eq!(team.counters.add(&key, 5), 3);
+/// assert_eq!(team.counters.add(&key, -1), 2);
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Counters {
+    counters: HashMap<CounterKey, Counter>,
+    #[serde(skip)]
+    subscribers: Vec<CounterSubscriber>,
+}
+
+impl PartialEq for Counters {
+    fn eq(&self, other: &Self) -> bool {
+        self.counters == other.counters
+    }
+}
+
+impl<K: Into<CounterKey>> FromIterator<(K, usize)> for Counters {
+    fn from_iter<T: IntoIterator<Item = (K, usize)>>(iter: T) -> Self {
+        let mut counters = Counters::default();
+        for (key, count) in iter {
+            counters.set(&key.into(), count);
+        }
+        counters
+    }
+}
+
+impl Counters {
+    /// Register a counter with bounds.
+    /// * Existing counts are kept and clamped to the new bounds.
+    pub fn register(&mut self, key: CounterKey, min: usize, max: Option<usize>) -> &mut Self {
+        let counter = self.counters.entry(key.clone()).or_default();
+        counter.min = min;
+        counter.max = max;
+        let count = counter.count;
+        self.update(&key, count);
+        self
+    }
+
+    /// Get the current count of a counter.
+    pub fn get(&self, key: &CounterKey) -> Option<usize> {
+        self.counters.get(key).map(|counter| counter.count)
+    }
+
+    /// Get a counter and its bounds.
+    pub fn counter(&self, key: &CounterKey) -> Option<&Counter> {
+        self.counters.get(key)
+    }
+
+    /// Set the count of a counter clamped to its bounds.
+    /// * Unregistered counters are registered with a minimum of `0` and no maximum.
+    /// * Returns the new count.
+    /// ```
+    /// use saptest::{Team, teams::counters::CounterKey};
+    ///
+    /// let mut team = Team::default();
+    /// assert_eq!(team.counters.set(&CounterKey::Trumpets, 5), 5);
+    /// assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(5));
+    /// ```
+    pub fn set(&mut self, key: &CounterKey, count: usize) -> usize {
+        self.update(key, count)
+    }
+
+    /// Add to the count of a counter clamped to its bounds.
+    /// * Unregistered counters are registered with a minimum of `0` and no maximum.
+    /// * Returns the new count.
+    pub fn add(&mut self, key: &CounterKey, change: isize) -> usize {
+        let count = self.get(key).unwrap_or_default();
+        let new_count = if change.is_negative() {
+            count.saturating_sub(change.unsigned_abs())
+        } else {
+            count.saturating_add(change.unsigned_abs())
+        };
+        self.update(key, new_count)
+    }
+
+    /// Reset all counters to their minimum.
+    pub fn reset(&mut self) {
+        let keys = self.counters.keys().cloned().collect::<Vec<CounterKey>>();
+        for key in keys.iter() {
+            self.update(key, 0);
+        }
+    }
+
+    /// Iterate through counters and their current count.
+    pub fn iter(&self) -> impl Iterator<Item = (&CounterKey, usize)> {
+        self.counters
+            .iter()
+            .map(|(key, counter)| (key, counter.count))
+    }
+
+    /// Subscribe to changes in counts.
+    /// * The callback is called with the counter's key, previous count, and new count.
+    /// * Subscribing to no keys will subscribe to all counters.
+    /// ```
+    /// use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+    /// use saptest::{Team, teams::counters::CounterKey};
+    ///
+    /// let n_trumpets = Arc::new(AtomicUsize::new(0));
+    /// let trumpets = n_trumpets.clone();
+    ///
+    /// let mut team = Team::default();
+    /// team.counters.subscribe(&[CounterKey::Trumpets], move |_key, _prev, new| {
+    ///     trumpets.store(new, Ordering::SeqCst);
+    /// });
+    /// team.counters.add(&CounterKey::Trumpets, 2);
+    /// assert_eq!(n_trumpets.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn subscribe<F>(&mut self, keys: &[CounterKey], callback: F) -> &mut Self
+    where
+        F: Fn(&CounterKey, usize, usize) + Send + Sync + 'static,
+    {
+        self.subscribers.push(CounterSubscriber {
+            keys: keys.to_vec(),
+            callback: Arc::new(callback),
+        });
+        self
+    }
+
+    /// Remove all counter subscribers.
+    pub fn clear_subscribers(&mut self) -> &mut Self {
+        self.subscribers.clear();
+        self
+    }
+
+    /// Clamp and set a count, notifying subscribers if changed.
+    fn update(&mut self, key: &CounterKey, count: usize) -> usize {
+        let counter = self.counters.entry(key.clone()).or_default();
+        let prev_count = counter.count;
+        counter.count = counter.clamp(count);
+        let new_count = counter.count;
+
+        if prev_count != new_count {
+            for subscriber in self
+                .subscribers
+                .iter()
+                .filter(|subscriber| subscriber.keys.is_empty() || subscriber.keys.contains(key))
+            {
+                (subscriber.callback)(key, prev_count, new_count)
+            }
+        }
+        new_count
+    }
+}
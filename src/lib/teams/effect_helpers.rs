@@ -1,7 +1,8 @@
 use crate::{
     effects::{
         actions::{
-            Action, ConditionType, CopyType, LogicType, RandomizeType, StatChangeType, SummonType,
+            Action, ConditionType, CopyType, GainType, LogicType, PriceType, RandomizeType,
+            RepeatSource, StatChangeType, StealType, SummonType,
         },
         effect::{Effect, EffectModify, Entity},
         state::{ItemCondition, Outcome, Position, Status, Target},
@@ -11,7 +12,7 @@ use crate::{
     error::SAPTestError,
     pets::{
         names::PetName,
-        pet::{reassign_effects, MAX_PET_STATS, MIN_PET_STATS},
+        pet::{MAX_PET_LEVEL, MIN_PET_LEVEL, SWALLOWED_TAG},
     },
     shop::{
         store::{ItemSlot, ItemState, ShopState},
@@ -122,23 +123,32 @@ pub(crate) trait EffectApplyHelpers {
         opponent: &Option<&mut Team>,
     ) -> Result<bool, SAPTestError>;
 
+    /// Get the number of times a conditional action is run given a [`LogicType`].
+    fn num_conditional_actions(
+        &self,
+        logic_type: &LogicType,
+        effect: &Effect,
+        target_pet: &Arc<RwLock<Pet>>,
+        opponent: &Option<&mut Team>,
+    ) -> Result<usize, SAPTestError>;
+
     /// Apply a condition action.
-    /// * action_set first arg is run if condition met. Otherwise, second arg run.
+    /// * The action of the first branch with its condition met is run. Otherwise, the `else_action` is run.
     fn apply_conditional_action(
         &mut self,
         affected_pet: &Arc<RwLock<Pet>>,
         afflicting_pet: &Arc<RwLock<Pet>>,
-        condition_type: &LogicType,
+        branches: &[(&LogicType, &Action)],
+        else_action: &Action,
         effect: &Effect,
-        action_set: (&Action, &Action),
         opponent: Option<&mut Team>,
     ) -> Result<Vec<Arc<RwLock<Pet>>>, SAPTestError>;
 
-    /// Hard-coded [`Tiger`](crate::PetName::Tiger) behavior.
-    /// * Checks that pet behind current pet is a tiger.
+    /// Repeat the effects of a pet from [`Action::Repeat`] effects on other pets.
+    /// * Checks that pet behind current pet repeats the [`RepeatSource::FriendAhead`].
     /// * Determines if [`Effect`] is valid by same methods in [`trigger_effects`](TeamEffects::trigger_effects).
-    /// * Creates effects of `pet` at given tiger level.
-    fn repeat_effects_if_tiger(
+    /// * Creates effects of `pet` at the level of the repeating pet.
+    fn repeat_effects(
         &self,
         pet: &Arc<RwLock<Pet>>,
         trigger: &Outcome,
@@ -179,13 +189,13 @@ impl EffectApplyHelpers for Team {
         opponent: &mut Team,
     ) -> Result<(), SAPTestError> {
         // Check for food uses.
-        // Then copy food to avoid potential mut borrow.
-        let item = afflicting_pet
+        // Then copy foods to avoid potential mut borrow.
+        let items = afflicting_pet
             .write()
             .unwrap()
-            .item
-            .as_mut()
-            .and_then(|food| {
+            .items
+            .iter_mut()
+            .filter_map(|food| {
                 if food.ability.uses != Some(0)
                     && food.ability.trigger.status == Status::BattleFoodEffect
                 {
@@ -194,8 +204,9 @@ impl EffectApplyHelpers for Team {
                 } else {
                     None
                 }
-            });
-        if let Some(valid_food) = item {
+            })
+            .collect_vec();
+        for valid_food in items {
             // Use effect on affected pets.
             let affected_pets = self.get_pets_by_effect(&valid_food.ability, Some(opponent))?;
             for affected_pet in affected_pets.iter() {
@@ -321,7 +332,7 @@ impl EffectApplyHelpers for Team {
         Ok(affected_pets)
     }
 
-    fn repeat_effects_if_tiger(
+    fn repeat_effects(
         &self,
         pet: &Arc<RwLock<Pet>>,
         trigger: &Outcome,
@@ -336,18 +347,22 @@ impl EffectApplyHelpers for Team {
             })?
         };
 
-        let mut tiger_doubled_effects = vec![];
-        // For Tiger. Check if behind.
+        let mut repeated_effects = vec![];
+        // Check if pet behind repeats effects of pet ahead.
         if let Some(Some(pet_behind)) = self.friends.get(effect_pet_idx + 1) {
-            if pet_behind.read().unwrap().name == PetName::Tiger
-                && self.shop.state == ShopState::Closed
-            {
-                // Get effect at level of tiger and repeat it.
-                let pet_effect_at_tiger_lvl = pet
+            let repeats_friend_ahead = pet_behind
+                .read()
+                .unwrap()
+                .effect
+                .iter()
+                .any(|effect| effect.action == Action::Repeat(RepeatSource::FriendAhead));
+            if repeats_friend_ahead && self.shop.state == ShopState::Closed {
+                // Get effect at level of pet behind and repeat it.
+                let pet_effect_at_behind_lvl = pet
                     .read()
                     .unwrap()
                     .get_effect(pet_behind.read().unwrap().lvl)?;
-                for mut effect in pet_effect_at_tiger_lvl {
+                for mut effect in pet_effect_at_behind_lvl {
                     // Assign owner so new lvled effect matches owner.
                     effect.assign_owner(Some(pet));
 
@@ -358,12 +373,12 @@ impl EffectApplyHelpers for Team {
                         same_pet_as_trigger,
                     );
                     if effect.check_activates(trigger) && valid_effect {
-                        tiger_doubled_effects.push(effect)
+                        repeated_effects.push(effect)
                     }
                 }
             }
         };
-        Ok(tiger_doubled_effects)
+        Ok(repeated_effects)
     }
 
     fn summon_pet(
@@ -373,7 +388,8 @@ impl EffectApplyHelpers for Team {
         opponent: Option<&mut Team>,
     ) -> Result<Arc<RwLock<Pet>>, SAPTestError> {
         // Can't impl TryFrom because requires target pet.
-        let summoned_pet = summon_type.to_pet(self, target_pet)?;
+        let mut summoned_pet = summon_type.to_pet(self, target_pet)?;
+        summoned_pet.stats = self.battle_rules.stat_cap.apply(summoned_pet.stats);
         let target_pet = target_pet.read().unwrap();
         let target_idx = target_pet.pos.ok_or(SAPTestError::InvalidTeamAction {
             subject: "Missing Summon Position".to_string(),
@@ -411,11 +427,25 @@ impl EffectApplyHelpers for Team {
     ) -> Result<Vec<Arc<RwLock<Pet>>>, SAPTestError> {
         let chosen_pet = targets.first().ok_or(SAPTestError::FallibleAction)?;
 
-        // Create a default pet, upgrade its level, and scale its health and attack.
-        let mut leveled_pet = Pet::try_from(chosen_pet.read().unwrap().name.clone())?;
+        // Create a default pet at the given level, keeping the swallowed pet's items.
+        let (pet_name, items) = {
+            let chosen_pet = chosen_pet.read().unwrap();
+            (chosen_pet.name.clone(), chosen_pet.items.clone())
+        };
+        let mut leveled_pet = Pet::try_from(pet_name)?;
         leveled_pet.set_level(lvl)?;
-        leveled_pet.stats.attack *= TryInto::<isize>::try_into(lvl)?;
-        leveled_pet.stats.health *= TryInto::<isize>::try_into(lvl)?;
+        leveled_pet.items = items;
+        // Scale base stats by the experience needed to reach the level. (+1/+1 per exp)
+        let exp = if lvl > MIN_PET_LEVEL {
+            Pet::get_exp_to_level(lvl - 1)
+        } else {
+            0
+        };
+        leveled_pet.exp = exp;
+        leveled_pet.stats = self
+            .battle_rules
+            .stat_cap
+            .add(leveled_pet.stats, Statistics::new(exp, exp)?)?;
 
         // Kill the original pet.
         let mut kill_effect = Effect {
@@ -438,6 +468,8 @@ impl EffectApplyHelpers for Team {
             action: Action::Summon(SummonType::StoredPet(Box::new(leveled_pet.clone()))),
             uses: Some(1),
             temp: true,
+            tags: vec![SWALLOWED_TAG.to_string()],
+            ..Default::default()
         }];
         info!(target: "run", "(\"{}\")\nEvolving {}.", self.name, leveled_pet);
         info!(target: "run", "(\"{}\")\nSet pet {} to summon evolved pet on faint.", self.name, affected_pet_guard);
@@ -461,12 +493,13 @@ impl EffectApplyHelpers for Team {
                 )),
                 CopyType::PercentStats(perc_stats_mult) => {
                     // Multiply the stats of a chosen pet by some multiplier
-                    let mut new_stats = pet_to_copy
-                        .read()
-                        .unwrap()
-                        .stats
-                        .mult_perc(&perc_stats_mult);
-                    new_stats.clamp(MIN_PET_STATS, MAX_PET_STATS);
+                    let stats = pet_to_copy.read().unwrap().stats;
+                    let new_stats = self.battle_rules.stat_cap.apply(Statistics {
+                        attack: (stats.attack as f32 * (perc_stats_mult.attack as f32 / 100.0))
+                            .round() as isize,
+                        health: (stats.health as f32 * (perc_stats_mult.health as f32 / 100.0))
+                            .round() as isize,
+                    });
                     info!(
                         target: "run", "(\"{}\")\nCopied {}% atk and {}% health from {}.",
                         self.name,
@@ -483,9 +516,10 @@ impl EffectApplyHelpers for Team {
                 CopyType::Item(_) => pet_to_copy
                     .read()
                     .unwrap()
-                    .item
+                    .items
+                    .perk
                     .as_ref()
-                    .map(|food| CopyType::Item(Some(Box::new(food.clone())))),
+                    .map(|food| CopyType::Item(Some(food.clone()))),
                 _ => None,
             }
         } else {
@@ -533,12 +567,12 @@ impl EffectApplyHelpers for Team {
                     // Assign ability owner to target_pet.
                     food.ability.assign_owner(Some(receiving_pet));
 
-                    receiving_pet_guard.item = Some(*food);
+                    receiving_pet_guard.gain_item(*food);
                     info!(
                         target: "run", "(\"{}\")\nCopyied item for {} to {:?}.",
                         self.name,
                         receiving_pet_guard,
-                        receiving_pet_guard.item
+                        receiving_pet_guard.items
                     );
                 }
             }
@@ -596,7 +630,11 @@ impl EffectApplyHelpers for Team {
                     let food = food.read().unwrap();
                     Ok(cond.matches_food(&food))
                 }
-                Entity::Toy => todo!(),
+                Entity::Toy => Ok(effect
+                    .trigger
+                    .afflicting_toy
+                    .as_ref()
+                    .is_some_and(|toy| cond.matches_toy(toy))),
             },
         }
     }
@@ -632,32 +670,27 @@ impl EffectApplyHelpers for Team {
         })
     }
 
-    fn apply_conditional_action(
-        &mut self,
-        affected_pet: &Arc<RwLock<Pet>>,
-        afflicting_pet: &Arc<RwLock<Pet>>,
+    fn num_conditional_actions(
+        &self,
         logic_type: &LogicType,
         effect: &Effect,
-        action_set: (&Action, &Action),
-        mut opponent: Option<&mut Team>,
-    ) -> Result<Vec<Arc<RwLock<Pet>>>, SAPTestError> {
-        let mut affected_pets = vec![];
-
-        // Get number of times action should be executed for action and other action.
-        let num_actions = match logic_type {
+        target_pet: &Arc<RwLock<Pet>>,
+        opponent: &Option<&mut Team>,
+    ) -> Result<usize, SAPTestError> {
+        Ok(match logic_type {
             LogicType::ForEach(cond_type) => {
-                cond_type.num_actions_for_each(self, &opponent, Some(&effect.trigger))?
+                cond_type.num_actions_for_each(self, opponent, Some(&effect.trigger))?
             }
             LogicType::If(cond_type) => {
-                usize::from(self.check_condition(cond_type, effect, affected_pet, &opponent)?)
+                usize::from(self.check_condition(cond_type, effect, target_pet, opponent)?)
             }
             LogicType::IfNot(cond_type) => {
-                usize::from(!self.check_condition(cond_type, effect, affected_pet, &opponent)?)
+                usize::from(!self.check_condition(cond_type, effect, target_pet, opponent)?)
             }
             LogicType::IfAny(cond_type) => match cond_type {
                 ConditionType::Pet(target, cond) => {
                     // If any pet matches condition, run action.
-                    usize::from(!self.get_matching_pets(target, cond, &opponent)?.is_empty())
+                    usize::from(!self.get_matching_pets(target, cond, opponent)?.is_empty())
                 }
                 _ => {
                     return Err(SAPTestError::InvalidTeamAction {
@@ -666,7 +699,50 @@ impl EffectApplyHelpers for Team {
                     })
                 }
             },
-        };
+            LogicType::All(cond_types) => {
+                let mut all_met = true;
+                for cond_type in cond_types.iter() {
+                    if !self.check_condition(cond_type, effect, target_pet, opponent)? {
+                        all_met = false;
+                        break;
+                    }
+                }
+                usize::from(all_met)
+            }
+            LogicType::Any(cond_types) => {
+                let mut any_met = false;
+                for cond_type in cond_types.iter() {
+                    if self.check_condition(cond_type, effect, target_pet, opponent)? {
+                        any_met = true;
+                        break;
+                    }
+                }
+                usize::from(any_met)
+            }
+        })
+    }
+
+    fn apply_conditional_action(
+        &mut self,
+        affected_pet: &Arc<RwLock<Pet>>,
+        afflicting_pet: &Arc<RwLock<Pet>>,
+        branches: &[(&LogicType, &Action)],
+        else_action: &Action,
+        effect: &Effect,
+        mut opponent: Option<&mut Team>,
+    ) -> Result<Vec<Arc<RwLock<Pet>>>, SAPTestError> {
+        let mut affected_pets = vec![];
+
+        // Get the first branch with its condition met and the number of times its action should be executed.
+        let mut met_branch = None;
+        for (logic_type, action) in branches.iter() {
+            let num_actions =
+                self.num_conditional_actions(logic_type, effect, affected_pet, &opponent)?;
+            if num_actions != 0 {
+                met_branch = Some((num_actions, *action));
+                break;
+            }
+        }
 
         // Create new effect with action.
         // For each condition met, execute the action.
@@ -701,13 +777,13 @@ impl EffectApplyHelpers for Team {
             Ok(())
         };
 
-        // Execute actions if condition met.
-        effect_copy.action = action_set.0.clone();
-        execute_actions(num_actions, &effect_copy, &mut affected_pets)?;
-
-        // And the other action ONCE if the condition not met.
-        if num_actions == 0 {
-            effect_copy.action = action_set.1.clone();
+        if let Some((num_actions, action)) = met_branch {
+            // Execute actions if condition met.
+            effect_copy.action = action.clone();
+            execute_actions(num_actions, &effect_copy, &mut affected_pets)?;
+        } else {
+            // And the else action ONCE if no condition met.
+            effect_copy.action = else_action.clone();
             execute_actions(1, &effect_copy, &mut affected_pets)?;
         }
         Ok(affected_pets)
@@ -738,6 +814,21 @@ impl EffectApplyHelpers for Team {
                     info!(target: "run", "(\"{}\")\n{err}.", self.name)
                 }
             }
+            Action::AddShopFoodStats(stats) => {
+                for item in self
+                    .shop
+                    .get_shop_items_by_pos(&effect.position, &Entity::Food)?
+                {
+                    if let ItemSlot::Food(food) = &item.item {
+                        if let Action::Add(StatChangeType::Static(food_stats)) =
+                            &mut food.write().unwrap().ability.action
+                        {
+                            *food_stats += *stats
+                        }
+                    }
+                }
+                info!(target: "run", "(\"{}\")\nAdded {} to shop foods.", self.name, stats)
+            }
             Action::AddShopPet(summon_type) => {
                 let new_shop_pet = ShopItem::from(summon_type.to_pet(self, &effect_owner)?);
                 info!(target: "run", "(\"{}\")\nAdding shop item {:?}.", self.name, &new_shop_pet);
@@ -748,8 +839,8 @@ impl EffectApplyHelpers for Team {
             }
             Action::ClearShop(item_type) => {
                 match item_type {
-                    Entity::Pet => self.shop.pets.clear(),
-                    Entity::Food => self.shop.foods.clear(),
+                    Entity::Pet => self.shop.pets.retain(|pet| pet.is_reserved()),
+                    Entity::Food => self.shop.foods.retain(|food| food.is_reserved()),
                     _ => {
                         return Err(SAPTestError::InvalidShopAction {
                             subject: String::from("Invalid Shop Entity"),
@@ -759,15 +850,15 @@ impl EffectApplyHelpers for Team {
                 }
                 info!(target: "run", "(\"{}\")\nCleared shop {item_type:?}.", self.name)
             }
-            Action::AlterGold(coins) => {
-                if coins.is_negative() {
-                    let coin_change: usize = (-*coins).try_into()?;
-                    self.shop.coins = self.shop.coins.saturating_sub(coin_change);
+            Action::AlterGold(gold_change) => {
+                let new_coins = gold_change.apply(self.shop.coins);
+                // Only gains are capped by the economy.
+                self.shop.coins = if new_coins > self.shop.coins {
+                    self.shop.economy.cap_gold(new_coins)
                 } else {
-                    let coin_change: usize = (*coins).try_into()?;
-                    self.shop.coins += coin_change;
-                }
-                info!(target: "run", "(\"{}\")\nAltered shop gold by {}. New coin count: {}", self.name, coins, self.shop.coins)
+                    new_coins
+                };
+                info!(target: "run", "(\"{}\")\nAltered shop gold by {}. New coin count: {}", self.name, gold_change, self.shop.coins)
             }
             Action::Discount(entity, discount) => {
                 // Method only gets immutable refs.
@@ -791,12 +882,42 @@ impl EffectApplyHelpers for Team {
                     item.cost = item.cost.saturating_sub(*discount)
                 }
             }
+            Action::Markup(price_type, markup) => {
+                match price_type {
+                    PriceType::Roll => self.shop.roll_markup += markup,
+                    PriceType::Item => {
+                        self.shop.item_markup += markup;
+                        for item in self.shop.pets.iter_mut().chain(self.shop.foods.iter_mut()) {
+                            item.cost += markup
+                        }
+                    }
+                }
+                info!(target: "run", "(\"{}\")\nIncreased {price_type:?} price by {markup}.", self.name)
+            }
             Action::GetToy(toy_type) => {
                 if let Some(toy) = toy_type.to_toy(self)? {
                     self.toys.push(toy)
                 }
             }
             Action::SaveGold { limit } => self.shop.saved_coins = self.shop.coins.clamp(0, *limit),
+            // Applied outside of triggers.
+            Action::MultiplyFoodStats(_) | Action::Repeat(_) => {}
+            Action::Unfreeze(item_type) => {
+                let shop_items = match item_type {
+                    Entity::Pet => self.shop.pets.iter_mut(),
+                    Entity::Food => self.shop.foods.iter_mut(),
+                    _ => {
+                        return Err(SAPTestError::InvalidShopAction {
+                            subject: String::from("Invalid Shop Entity"),
+                            reason: format!("{item_type} cannot be unfrozen."),
+                        })
+                    }
+                };
+                for item in shop_items.filter(|item| !item.is_reserved()) {
+                    item.state = ItemState::Normal
+                }
+                info!(target: "run", "(\"{}\")\nUnfroze shop {item_type:?}.", self.name)
+            }
             Action::FreeRoll(n_rolls) => {
                 for _ in 0..*n_rolls {
                     self.shop.free_rolls += 1;
@@ -814,9 +935,22 @@ impl EffectApplyHelpers for Team {
                 self.apply_conditional_action(
                     &effect_owner,
                     &effect_owner,
-                    logic_type,
+                    &[(logic_type, if_action)],
+                    else_action,
+                    effect,
+                    None,
+                )?;
+            }
+            Action::ConditionalChain(branches, else_action) => {
+                self.apply_conditional_action(
+                    &effect_owner,
+                    &effect_owner,
+                    &branches
+                        .iter()
+                        .map(|(logic_type, action)| (logic_type, action))
+                        .collect_vec(),
+                    else_action,
                     effect,
-                    (if_action, else_action),
                     None,
                 )?;
             }
@@ -856,7 +990,8 @@ impl EffectApplyHelpers for Team {
                 // Convert stat change to stats with afflicting pet stats.
                 let added_stats = stat_change.to_stats(
                     Some(afflicting_pet_stats),
-                    Some(&self.counters),
+                    Some(self),
+                    Some(&effect.trigger),
                     false,
                 )?;
 
@@ -868,11 +1003,16 @@ impl EffectApplyHelpers for Team {
                     && effect.target == Target::Friend
                     && self.shop.state == ShopState::Open
                 {
-                    self.shop
-                        .temp_stats
-                        .push((affected_pet.read().unwrap().id.unwrap(), added_stats));
+                    self.shop.temp_stats.push((
+                        affected_pet.read().unwrap().id.unwrap(),
+                        added_stats,
+                        effect.duration.unwrap_or(1),
+                    ));
+                }
+                {
+                    let mut pet = affected_pet.write().unwrap();
+                    pet.stats = self.battle_rules.stat_cap.add(pet.stats, added_stats)?;
                 }
-                affected_pet.write().unwrap().stats += added_stats;
                 {
                     let pet = affected_pet.read().unwrap();
                     info!(target: "run", "(\"{}\")\nAdded {} to {}.", self.name, added_stats, pet);
@@ -884,7 +1024,8 @@ impl EffectApplyHelpers for Team {
 
                 let mut remove_stats = stat_change.to_stats(
                     Some(afflicting_pet_stats),
-                    Some(&self.counters),
+                    Some(self),
+                    Some(&effect.trigger),
                     false,
                 )?;
 
@@ -892,14 +1033,15 @@ impl EffectApplyHelpers for Team {
                 if let Some(item) = afflicting_pet
                     .read()
                     .unwrap()
-                    .item
-                    .as_ref()
-                    .filter(|item| Status::IndirectAttackDmgCalc == item.ability.trigger.status)
+                    .items
+                    .iter()
+                    .find(|item| Status::IndirectAttackDmgCalc == item.ability.trigger.status)
                 {
                     if let Action::Add(modifier) = &item.ability.action {
                         let modifier_stats = modifier.to_stats(
                             Some(afflicting_pet_stats),
-                            Some(&self.counters),
+                            Some(self),
+                            Some(&effect.trigger),
                             false,
                         )?;
                         remove_stats += modifier_stats
@@ -919,15 +1061,20 @@ impl EffectApplyHelpers for Team {
                     Action::Remove(StatChangeType::Static(atk_outcome.friend_stat_change));
 
                 // Update triggers from where they came from.
+                // Attribute damage to the afflicting pet if the effect has no owner. ex. Chili
+                let damage_source = effect
+                    .owner
+                    .clone()
+                    .or_else(|| Some(Arc::downgrade(afflicting_pet)));
                 if let Some(opponent) = opponent.as_mut() {
                     atk_outcome.unload_atk_outcomes(
                         self,
                         Some(opponent),
                         affected_pet,
-                        effect.owner.clone(),
+                        damage_source,
                     );
                 } else {
-                    atk_outcome.unload_atk_outcomes(self, None, affected_pet, effect.owner.clone());
+                    atk_outcome.unload_atk_outcomes(self, None, affected_pet, damage_source);
                 }
 
                 affected_pets.push(affected_pet.clone());
@@ -935,76 +1082,87 @@ impl EffectApplyHelpers for Team {
             Action::Set(stat_change) => {
                 let new_stats = {
                     let pet = affected_pet.read().unwrap();
-                    let team_counters = if pet.team.as_ref() == Some(&self.name) {
-                        Some(&self.counters)
+                    let team = if pet.team.as_ref() == Some(&self.name) {
+                        Some(&*self)
                     } else {
-                        opponent.as_ref().map(|team| &team.counters)
+                        opponent.as_deref()
                     };
-                    stat_change.to_stats(Some(pet.stats), team_counters, true)?
+                    stat_change.to_stats(Some(pet.stats), team, Some(&effect.trigger), true)?
                 };
                 affected_pet.write().unwrap().stats = new_stats;
             }
             Action::Gain(gain_food_type) => {
-                let mut food = gain_food_type.to_food(self, afflicting_pet)?;
-                {
-                    let pet = affected_pet.read().unwrap();
-                    if food.is_none() {
+                if let Some(food) = gain_food_type.to_food(self, afflicting_pet)? {
+                    let food_desc = food.to_string();
+                    self.equip_item(affected_pet, food, None);
+                    info!(
+                        target: "run", "(\"{}\")\nGave {} to {}.",
+                        self.name,
+                        food_desc,
+                        affected_pet.read().unwrap()
+                    );
+                } else {
+                    let mut pet = affected_pet.write().unwrap();
+                    if let GainType::NoAilment = gain_food_type {
+                        info!(target: "run", "(\"{}\")\nRemoved ailment from {}.", self.name, pet);
+                        pet.items.ailment = None;
+                    } else {
                         info!(target: "run", "(\"{}\")\nRemoved food from {}.", self.name, pet);
-                    } else if let Some(food) = food.as_mut() {
-                        info!(target: "run", "(\"{}\")\nGave {} to {}.", self.name, food, pet);
-                        food.ability.assign_owner(Some(affected_pet));
-
-                        // Check if given an ailment.
-                        if food.is_ailment {
-                            let mut trigger_ailment = TRIGGER_ANY_GAIN_AILMENT;
-                            trigger_ailment.set_affected(affected_pet);
-                            self.triggers.push_back(trigger_ailment)
-                        }
+                        pet.items.perk = None;
                     }
                 }
-                affected_pet.write().unwrap().item = food;
                 affected_pets.push(affected_pet.clone());
             }
-            Action::AlterCost(cost_change) => {
-                let affected_pet_cost = affected_pet.read().unwrap().cost;
-                if cost_change.is_negative() {
-                    let coin_change: usize = (-*cost_change).try_into()?;
-                    affected_pet.write().unwrap().cost =
-                        affected_pet_cost.saturating_sub(coin_change);
-                } else {
-                    let coin_change: usize = (*cost_change).try_into()?;
-                    affected_pet.write().unwrap().cost += coin_change;
+            Action::Steal { what, from } => match what {
+                StealType::Item => {
+                    let stolen_item = affected_pet.write().unwrap().items.perk.take();
+                    if let Some(mut food) = stolen_item {
+                        info!(
+                            target: "run", "(\"{}\")\nStole {} from {} for {}.",
+                            self.name,
+                            food,
+                            affected_pet.read().unwrap(),
+                            afflicting_pet.read().unwrap()
+                        );
+                        food.ability.assign_owner(Some(afflicting_pet));
+                        afflicting_pet.write().unwrap().items.perk = Some(food);
+                        affected_pets.extend([affected_pet.clone(), afflicting_pet.clone()]);
+                    }
+                }
+                StealType::Gold(gold) => {
+                    // Effects targeting enemy pets are applied by the enemy team so find which team owns the effect.
+                    let owner_on_team =
+                        afflicting_pet.read().unwrap().team.as_ref() == Some(&self.name);
+                    let (Target::Enemy, Some(opponent)) = (from, opponent.as_deref_mut()) else {
+                        return Err(SAPTestError::InvalidTeamAction {
+                            subject: "Invalid Gold Steal".to_owned(),
+                            reason: format!(
+                                "Gold can only be stolen from an opponent, not {from:?}."
+                            ),
+                        });
+                    };
+                    let (thief, victim) = if owner_on_team {
+                        (&mut *self, opponent)
+                    } else {
+                        (opponent, &mut *self)
+                    };
+                    let stolen_gold = victim.shop.coins.min(*gold);
+                    victim.shop.coins -= stolen_gold;
+                    thief.shop.coins = thief.shop.economy.cap_gold(thief.shop.coins + stolen_gold);
+                    info!(target: "run", "(\"{}\")\nStole {} gold. New coin count: {}", thief.name, stolen_gold, thief.shop.coins)
                 }
+            },
+            Action::AlterCost(cost_change) => {
+                affected_pet.write().unwrap().sell_modifier += cost_change;
                 info!(
                     target: "run",
-                    "(\"{}\")\nAltered cost of {:?} by {}. New coin count: {}",
+                    "(\"{}\")\nAltered sell value of {:?} by {}. New sell value: {}",
                     self.name,
                     affected_pet.read().unwrap().id,
                     cost_change,
-                    affected_pet.read().unwrap().cost
+                    affected_pet.read().unwrap().sell_value(&self.shop.economy)
                 )
             }
-            Action::Moose { stats, tier } => {
-                // TODO: Separate into two different actions: Unfreeze shop + StatChangeType::MultShopTier
-                for item in self.shop.foods.iter_mut() {
-                    item.state = ItemState::Normal
-                }
-                let mut num_tier = 0;
-                for pet in self.shop.pets.iter_mut() {
-                    if pet.tier() == *tier {
-                        num_tier += 1
-                    }
-                    pet.state = ItemState::Normal
-                }
-                let buffed_stats = *stats * Statistics::new(num_tier, num_tier)?;
-                modified_effect.action = Action::Add(StatChangeType::Static(buffed_stats));
-                affected_pets.extend(self.apply_single_effect(
-                    affected_pet,
-                    afflicting_pet,
-                    &modified_effect,
-                    None,
-                )?);
-            }
             Action::Fox(item_type, multiplier) => {
                 let mut rng = ChaCha12Rng::seed_from_u64(self.seed.unwrap_or_else(random));
 
@@ -1048,6 +1206,7 @@ impl EffectApplyHelpers for Team {
                                 false,
                             )?;
                         }
+                        ItemSlot::Toy(_) => unreachable!("Cannot steal toys."),
                     }
                 }
 
@@ -1060,22 +1219,23 @@ impl EffectApplyHelpers for Team {
             Action::Experience(exp) => {
                 for _ in 0..*exp {
                     let mut pet = affected_pet.write().unwrap();
+                    // Experience past the max level is lost.
+                    if pet.lvl >= MAX_PET_LEVEL {
+                        break;
+                    }
                     let prev_target_lvl = pet.lvl;
                     pet.add_experience(1)?;
                     info!(target: "run", "(\"{}\")\nGave experience point to {}.", self.name, pet);
 
-                    // Target leveled up. Create trigger.
-                    let pet_leveled_up = if pet.lvl != prev_target_lvl {
+                    // Target leveled up. Create triggers.
+                    if pet.lvl != prev_target_lvl {
                         info!(target: "run", "(\"{}\")\nPet {} leveled up.", self.name, pet);
-                        let mut lvl_trigger = TRIGGER_ANY_LEVELUP;
-                        lvl_trigger.affected_pet = Some(Arc::downgrade(affected_pet));
-                        Some(lvl_trigger)
-                    } else {
-                        None
-                    };
-
-                    if let Some(level_trigger) = pet_leveled_up {
-                        self.triggers.push_back(level_trigger)
+                        drop(pet);
+                        let mut lvl_trigger = TRIGGER_SELF_LEVELUP;
+                        let mut lvl_any_trigger = TRIGGER_ANY_LEVELUP;
+                        lvl_trigger.set_affected(affected_pet);
+                        lvl_any_trigger.set_affected(affected_pet);
+                        self.triggers.extend([lvl_trigger, lvl_any_trigger]);
                     }
                 }
 
@@ -1094,19 +1254,12 @@ impl EffectApplyHelpers for Team {
                     Position::Relative(rel_idx) => *rel_idx,
                     _ => unimplemented!("Position not implemented for push."),
                 };
-                // Adjust position by number of fainted pets.
-                let num_fainted = self
-                    .friends
-                    .iter()
-                    .flatten()
-                    .filter(|pet| pet.read().unwrap().stats.health == 0)
-                    .count();
-                if let Some(position) = affected_pet
-                    .read()
-                    .unwrap()
-                    .pos
-                    .map(|idx| idx + num_fainted)
-                {
+                // Find the pet's slot. Its position may be outdated if pets ahead of it fainted.
+                let affected_slot = self.friends.iter().position(|slot| {
+                    slot.as_ref()
+                        .is_some_and(|pet| Arc::ptr_eq(pet, affected_pet))
+                });
+                if let Some(position) = affected_slot {
                     info!(target: "run", "(\"{}\")\nPushed pet at position {} by {}.", self.name, position, pos_change);
                     if let Some(opponent) = opponent.as_mut() {
                         self.push_pet(position, pos_change, Some(opponent))?;
@@ -1116,21 +1269,41 @@ impl EffectApplyHelpers for Team {
                     affected_pets.push(affected_pet.clone());
                 }
             }
+            Action::SwapEnemy(position) => {
+                let Some(opponent) = opponent.as_mut() else {
+                    return Err(SAPTestError::InvalidTeamAction {
+                        subject: "No Enemy Team Provided".to_string(),
+                        reason: "Enemy team is required for swapping positions with an enemy."
+                            .to_string(),
+                    });
+                };
+                let enemy_pets = opponent.get_pets_by_pos(
+                    opponent.first(),
+                    &Target::Friend,
+                    position,
+                    Some(&effect.trigger),
+                    None,
+                )?;
+                let (Some(enemy_pet), Some(pos)) =
+                    (enemy_pets.first(), affected_pet.read().unwrap().pos)
+                else {
+                    return Ok(affected_pets);
+                };
+                let enemy_pos = enemy_pet.read().unwrap().pos.unwrap_or(0);
+
+                // Push each pet to the position of the other.
+                let pos_change =
+                    TryInto::<isize>::try_into(pos)? - TryInto::<isize>::try_into(enemy_pos)?;
+                info!(target: "run", "(\"{}\")\nSwapped pet at position {} with enemy at position {}.", self.name, pos, enemy_pos);
+                self.push_pet(pos, pos_change, Some(opponent))?;
+                opponent.push_pet(enemy_pos, -pos_change, Some(self))?;
+                affected_pets.extend([affected_pet.clone(), enemy_pet.clone()]);
+            }
             Action::Transform(pet_name, stats, lvl) => {
-                if let Some(target_idx) = affected_pet.read().unwrap().pos {
-                    let mut transformed_pet = Pet::new(pet_name.clone(), *stats, *lvl)?;
-                    transformed_pet.set_pos(target_idx);
-                    transformed_pet.team = Some(self.name.to_owned());
-
-                    if (0..self.friends.len()).contains(&target_idx) {
-                        self.friends.remove(target_idx);
-                        info!(target: "run", "(\"{}\")\nTransformed pet at position {} to {}.", self.name, target_idx, &transformed_pet);
-                        let rc_transformed_pet = Arc::new(RwLock::new(transformed_pet));
-                        reassign_effects(&rc_transformed_pet);
-
-                        affected_pets.extend([rc_transformed_pet.clone(), affected_pet.clone()]);
-                        self.friends.insert(target_idx, Some(rc_transformed_pet));
-                    }
+                let transformed_pet = Pet::new(pet_name.clone(), *stats, *lvl)?;
+                // Pets no longer on the team can't transform.
+                if let Ok(rc_transformed_pet) = self.transform_pet(affected_pet, transformed_pet) {
+                    affected_pets.extend([rc_transformed_pet, affected_pet.clone()]);
                 }
             }
             Action::Summon(summon_type) => {
@@ -1179,23 +1352,36 @@ impl EffectApplyHelpers for Team {
                     }
                 }
             }
-            Action::Conditional(condition_type, if_action, else_action) => {
+            Action::Conditional(_, _, _) | Action::ConditionalChain(_, _) => {
+                let (branches, else_action) = match &effect.action {
+                    Action::Conditional(logic_type, if_action, else_action) => {
+                        (vec![(logic_type, if_action.as_ref())], else_action)
+                    }
+                    Action::ConditionalChain(branches, else_action) => (
+                        branches
+                            .iter()
+                            .map(|(logic_type, action)| (logic_type, action))
+                            .collect_vec(),
+                        else_action,
+                    ),
+                    _ => unreachable!("Not a conditional action."),
+                };
                 let pets = if let Some(opponent) = opponent.as_mut() {
                     self.apply_conditional_action(
                         affected_pet,
                         afflicting_pet,
-                        condition_type,
+                        &branches,
+                        else_action,
                         effect,
-                        (if_action, else_action),
                         Some(opponent),
                     )?
                 } else {
                     self.apply_conditional_action(
                         affected_pet,
                         afflicting_pet,
-                        condition_type,
+                        &branches,
+                        else_action,
                         effect,
-                        (if_action, else_action),
                         None,
                     )?
                 };
@@ -1223,11 +1409,29 @@ impl EffectApplyHelpers for Team {
                 }
                 affected_pets.push(affected_pet.clone());
             }
+            Action::PushTrigger(trigger) => {
+                let mut trigger = trigger.clone();
+                trigger
+                    .set_affected(affected_pet)
+                    .set_afflicting(afflicting_pet);
+                info!(target: "run", "(\"{}\")\nAdded {:?} trigger for {}.", self.name, trigger.status, affected_pet.read().unwrap());
+                self.triggers.push_back(trigger);
+                affected_pets.push(affected_pet.clone());
+            }
+            Action::Custom(custom_action) => {
+                info!(target: "run", "(\"{}\")\nApplied custom action {} to {}.", self.name, custom_action.name(), affected_pet.read().unwrap());
+                custom_action.apply(affected_pet, afflicting_pet, self, opponent.as_deref_mut())?;
+                affected_pets.push(affected_pet.clone());
+            }
             Action::Debuff(perc_stats) => {
                 let mut pet = affected_pet.write().unwrap();
                 // TODO: Change so modifier can be on afflicting or affected pet. Current only affected.
-                let debuff_stats =
-                    perc_stats.to_stats(Some(pet.stats), Some(&self.counters), false)?;
+                let debuff_stats = perc_stats.to_stats(
+                    Some(pet.stats),
+                    Some(self),
+                    Some(&effect.trigger),
+                    false,
+                )?;
                 modified_effect.action = Action::Debuff(StatChangeType::Static(debuff_stats));
 
                 pet.stats -= debuff_stats;
@@ -1290,27 +1494,6 @@ impl EffectApplyHelpers for Team {
                     pets?;
                 }
             }
-            Action::Stegosaurus(stats) => {
-                let mut turn_mult_stats = *stats;
-                // Multiply by turn number. Need to multiply raw values since mult op treats as percent.
-                let turn_multiplier = TryInto::<isize>::try_into(self.history.curr_turn)?;
-                turn_mult_stats.attack *= turn_multiplier;
-                turn_mult_stats.health *= turn_multiplier;
-
-                // Modify action to add turn-multiplied stats and apply effect.
-                modified_effect.action = Action::Add(StatChangeType::Static(turn_mult_stats));
-                let pets = if let Some(opponent) = opponent.as_mut() {
-                    self.apply_single_effect(
-                        affected_pet,
-                        afflicting_pet,
-                        &modified_effect,
-                        Some(*opponent),
-                    )?
-                } else {
-                    self.apply_single_effect(affected_pet, afflicting_pet, &modified_effect, None)?
-                };
-                affected_pets.extend(pets);
-            }
             Action::Cockroach => {
                 let mut pet = affected_pet.write().unwrap();
                 pet.stats.attack = TryInto::<isize>::try_into(self.shop.tier())? + 1;
@@ -1334,51 +1517,27 @@ impl EffectApplyHelpers for Team {
                 affected_pet.write().unwrap().stats.invert();
                 affected_pets.push(affected_pet.clone());
             }
-            Action::AddToCounter(counter, count_change) => {
-                let modify_count = |count: &mut usize| {
-                    if count_change.is_negative() {
-                        let count_change = (-*count_change).try_into().unwrap_or(0);
-                        *count = count.saturating_sub(count_change)
+            Action::AddToCounter(counter, count_change) => match effect.target {
+                Target::Friend => {
+                    self.counters.add(counter, *count_change);
+                }
+                Target::Enemy => {
+                    if let Some(opponent) = opponent.as_mut() {
+                        opponent.counters.add(counter, *count_change);
                     } else {
-                        // TODO: Should probably be clamped.
-                        *count = count.saturating_add((*count_change).try_into().unwrap_or(0))
-                    }
-                };
-                let modify_w_new_count = || {
-                    // Create new count and modify it. Closure ensures always clamped to 0.
-                    let mut new_cnt = 0;
-                    modify_count(&mut new_cnt);
-                    new_cnt
-                };
-                match effect.target {
-                    Target::Friend => {
-                        self.counters
-                            .entry(counter.to_owned())
-                            .and_modify(modify_count)
-                            .or_insert_with(modify_w_new_count);
-                    }
-                    Target::Enemy => {
-                        if let Some(opponent) = opponent.as_mut() {
-                            opponent
-                                .counters
-                                .entry(counter.to_owned())
-                                .and_modify(modify_count)
-                                .or_insert_with(modify_w_new_count);
-                        } else {
-                            return Err(SAPTestError::InvalidTeamAction {
-                                subject: "Opponent Counter Modification".to_owned(),
-                                reason: format!("Opponent required to modify counter, {counter}."),
-                            });
-                        };
-                    }
-                    _ => {
                         return Err(SAPTestError::InvalidTeamAction {
-                            subject: "No Counter".to_string(),
-                            reason: format!("Target ({:?}) has no counter.", effect.target),
-                        })
-                    }
+                            subject: "Opponent Counter Modification".to_owned(),
+                            reason: format!("Opponent required to modify counter, {counter}."),
+                        });
+                    };
                 }
-            }
+                _ => {
+                    return Err(SAPTestError::InvalidTeamAction {
+                        subject: "No Counter".to_string(),
+                        reason: format!("Target ({:?}) has no counter.", effect.target),
+                    })
+                }
+            },
             Action::None => {}
             _ => {
                 return Err(SAPTestError::InvalidTeamAction {
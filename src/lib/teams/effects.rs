@@ -9,8 +9,10 @@ use crate::{
     error::SAPTestError,
     shop::{store::ShopState, trigger::*},
     teams::{
+        combat::{FaintOrder, ItemOrder},
+        counters::CounterKey,
         effect_helpers::{is_pet_effect_exception, EffectApplyHelpers},
-        team::Team,
+        team::{KnockoutRecord, Team},
         viewer::TeamViewer,
     },
     Pet, PetCombat, PetName,
@@ -18,15 +20,18 @@ use crate::{
 use itertools::Itertools;
 
 use std::{
+    cmp::Ordering,
     collections::VecDeque,
+    fmt::Debug,
     sync::{Arc, RwLock},
 };
 
-const NON_COMBAT_TRIGGERS: [Outcome; 11] = [
+const NON_COMBAT_TRIGGERS: [Outcome; 12] = [
     TRIGGER_ANY_LEVELUP,
     TRIGGER_START_TURN,
     TRIGGER_START_BATTLE,
     TRIGGER_BEFORE_FIRST_BATTLE,
+    TRIGGER_END_BATTLE,
     TRIGGER_END_TURN,
     TRIGGER_ANY_FOOD_BOUGHT,
     TRIGGER_ANY_FOOD_EATEN,
@@ -36,6 +41,397 @@ const NON_COMBAT_TRIGGERS: [Outcome; 11] = [
     TRIGGER_SHOP_TIER_UPGRADED,
 ];
 
+/// Comparator ordering two [`Pet`]s. Pets ordered first activate their effects first.
+pub type PetComparator = Arc<dyn Fn(&Pet, &Pet) -> Ordering + Send + Sync>;
+
+/// Order in which [`Pet`]s on a [`Team`] resolve their effects when activated by the same trigger.
+/// * Set with [`Team::set_resolution_order`].
+#[derive(Clone, Default)]
+pub enum ResolutionOrder {
+    /// From highest to lowest attack.
+    #[default]
+    AttackDescending,
+    /// From the front to the back of the team.
+    BoardPosition,
+    /// In the order pets were added to the team.
+    Insertion,
+    /// By a user-provided [`PetComparator`].
+    Custom(PetComparator),
+}
+
+impl Debug for ResolutionOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolutionOrder::AttackDescending => write!(f, "AttackDescending"),
+            ResolutionOrder::BoardPosition => write!(f, "BoardPosition"),
+            ResolutionOrder::Insertion => write!(f, "Insertion"),
+            ResolutionOrder::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// Interceptor inspecting an [`Effect`] just before it is applied.
+/// * Returns the [`Effect`] to apply, possibly rewritten, or [`None`] to block it.
+pub type EffectInterceptor = Arc<dyn Fn(Effect) -> Option<Effect> + Send + Sync>;
+
+/// Interceptors registered on a [`Team`] applied in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct EffectInterceptors(pub(crate) Vec<EffectInterceptor>);
+
+impl Debug for EffectInterceptors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EffectInterceptors({})", self.0.len())
+    }
+}
+
+impl EffectInterceptors {
+    /// Pass an effect through each interceptor, stopping if any blocks it.
+    fn intercept(&self, effect: &Effect) -> Option<Effect> {
+        self.0
+            .iter()
+            .try_fold(effect.clone(), |effect, interceptor| interceptor(effect))
+    }
+}
+
+impl ResolutionOrder {
+    /// Order pets from the front to the back of a team.
+    fn order(&self, pets: &[Option<Arc<RwLock<Pet>>>]) -> Vec<Arc<RwLock<Pet>>> {
+        let pets = pets.iter().flatten();
+        match self {
+            ResolutionOrder::AttackDescending => pets
+                .sorted_by(|pet_1, pet_2| {
+                    pet_1
+                        .read()
+                        .unwrap()
+                        .stats
+                        .attack
+                        .cmp(&pet_2.read().unwrap().stats.attack)
+                })
+                .rev()
+                .cloned()
+                .collect_vec(),
+            ResolutionOrder::BoardPosition => pets.cloned().collect_vec(),
+            ResolutionOrder::Insertion => pets
+                .sorted_by_key(|pet| pet.read().unwrap().id)
+                .cloned()
+                .collect_vec(),
+            ResolutionOrder::Custom(comparator) => pets
+                .sorted_by(|pet_1, pet_2| {
+                    comparator(&pet_1.read().unwrap(), &pet_2.read().unwrap())
+                })
+                .cloned()
+                .collect_vec(),
+        }
+    }
+}
+
+/// Default maximum number of triggers activated before a trigger loop is assumed.
+pub const DEFAULT_TRIGGER_BUDGET: usize = 10_000;
+
+/// Number of most recent triggers kept to find a repeating cycle.
+const TRIGGER_HISTORY_SIZE: usize = 32;
+
+/// Guard against runaway trigger loops.
+/// * Counts activated triggers and errors with the repeating triggers once the budget is exceeded.
+pub(crate) struct TriggerGuard {
+    budget: usize,
+    count: usize,
+    recent: VecDeque<String>,
+}
+
+impl TriggerGuard {
+    pub(crate) fn new(budget: usize) -> Self {
+        TriggerGuard {
+            budget,
+            count: 0,
+            recent: VecDeque::with_capacity(TRIGGER_HISTORY_SIZE),
+        }
+    }
+
+    /// Count an activated trigger.
+    pub(crate) fn check(&mut self, trigger: &Outcome) -> Result<(), SAPTestError> {
+        self.count += 1;
+        if self.recent.len() == TRIGGER_HISTORY_SIZE {
+            self.recent.pop_front();
+        }
+        let affected_pet_name = trigger
+            .affected_pet
+            .as_ref()
+            .and_then(|pet| pet.upgrade())
+            .map(|pet| pet.read().unwrap().name.to_string())
+            .unwrap_or_else(|| "None".to_string());
+        self.recent
+            .push_back(format!("{} ({affected_pet_name})", trigger.status));
+
+        if self.count > self.budget {
+            Err(SAPTestError::TriggerLoop {
+                budget: self.budget,
+                cycle: self.cycle(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Find the shortest sequence of triggers repeating over the latter half of the recent triggers.
+    /// * Defaults to all recent triggers if none repeat.
+    fn cycle(&self) -> Vec<String> {
+        let recent = self.recent.iter().collect_vec();
+        let half = recent.len() / 2;
+        let period = (1..=half)
+            .find(|period| (half..recent.len()).all(|i| recent[i] == recent[i - period]))
+            .unwrap_or(recent.len());
+        recent[recent.len() - period..]
+            .iter()
+            .map(|trigger| trigger.to_string())
+            .collect_vec()
+    }
+}
+
+/// Attack of the pet affected by a trigger when the trigger was created.
+fn trigger_pet_attack(trigger: &Outcome) -> isize {
+    trigger
+        .affected_snapshot
+        .as_ref()
+        .map(|snapshot| snapshot.stats.attack)
+        .or_else(|| {
+            trigger
+                .affected_pet
+                .as_ref()
+                .and_then(|pet| pet.upgrade())
+                .map(|pet| pet.read().unwrap().stats.attack)
+        })
+        .unwrap_or_default()
+}
+
+/// Activate the item effects of a trigger whose pet effects were activated based on the [`ItemOrder`].
+/// * Otherwise, queue the trigger until all pet effects are exhausted.
+fn queue_items(
+    team: &mut Team,
+    opponent: &mut Team,
+    trigger: Outcome,
+    item_triggers: &mut VecDeque<Outcome>,
+    item_order: ItemOrder,
+) -> Result<(), SAPTestError> {
+    match item_order {
+        ItemOrder::AfterPetEffects => item_triggers.push_back(trigger),
+        ItemOrder::WithTrigger => {
+            team.trigger_items(&trigger, Some(opponent))?;
+        }
+    }
+    Ok(())
+}
+
+/// Sort faint triggers front-to-back by the position of their fainted pet.
+/// * Other triggers keep their place in the queue.
+fn sort_faint_triggers(triggers: &mut VecDeque<Outcome>) {
+    let faint_idxs = triggers
+        .iter()
+        .positions(|trigger| trigger.status == Status::Faint)
+        .collect_vec();
+    let sorted_faint_triggers = faint_idxs
+        .iter()
+        .map(|idx| triggers[*idx].clone())
+        .sorted_by_key(|trigger| {
+            trigger
+                .affected_pet
+                .as_ref()
+                .and_then(|pet| pet.upgrade())
+                .and_then(|pet| pet.read().unwrap().pos)
+                .unwrap_or(usize::MAX)
+        })
+        .collect_vec();
+    for (idx, trigger) in faint_idxs.into_iter().zip(sorted_faint_triggers) {
+        triggers[idx] = trigger
+    }
+}
+
+impl Team {
+    /// Exhaust all triggers for two teams using [`FaintOrder::FrontToBack`].
+    fn trigger_all_effects_front_to_back(
+        &mut self,
+        opponent: &mut Team,
+    ) -> Result<&mut Self, SAPTestError> {
+        let mut friend_item_triggers = VecDeque::new();
+        let mut opponent_item_triggers = VecDeque::new();
+        let item_order = self.battle_rules.item_order;
+        // Team to activate next if both teams have triggers.
+        let mut opponent_next: Option<bool> = None;
+        let mut guard = TriggerGuard::new(self.trigger_budget);
+
+        loop {
+            self.history.curr_cycle += 1;
+            opponent.history.curr_cycle += 1;
+
+            sort_faint_triggers(&mut self.triggers);
+            sort_faint_triggers(&mut opponent.triggers);
+
+            let opponent_turn = match (self.triggers.front(), opponent.triggers.front()) {
+                (Some(trigger), Some(enemy_trigger)) => opponent_next.unwrap_or_else(|| {
+                    trigger_pet_attack(enemy_trigger) > trigger_pet_attack(trigger)
+                }),
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (None, None) => {
+                    // Activate items once all pet effects are exhausted.
+                    if let Some(trigger) = friend_item_triggers.pop_front() {
+                        self.trigger_items(&trigger, Some(opponent))?;
+                    } else if let Some(trigger) = opponent_item_triggers.pop_front() {
+                        opponent.trigger_items(&trigger, Some(self))?;
+                    } else {
+                        // Nothing left. All triggers consumed.
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            if opponent_turn {
+                if let Some(trigger) = opponent.triggers.pop_front() {
+                    guard.check(&trigger)?;
+                    opponent.trigger_effects(&trigger, Some(self))?;
+                    queue_items(
+                        opponent,
+                        self,
+                        trigger,
+                        &mut opponent_item_triggers,
+                        item_order,
+                    )?
+                }
+            } else if let Some(trigger) = self.triggers.pop_front() {
+                guard.check(&trigger)?;
+                self.trigger_effects(&trigger, Some(opponent))?;
+                queue_items(
+                    self,
+                    opponent,
+                    trigger,
+                    &mut friend_item_triggers,
+                    item_order,
+                )?
+            }
+            opponent_next = Some(!opponent_turn);
+        }
+        Ok(self)
+    }
+
+    /// Get a pet's start of battle effects, including repeated effects, tagged with the pet's team.
+    fn start_battle_pet_effects(
+        &self,
+        pet: &Arc<RwLock<Pet>>,
+        team: Target,
+    ) -> Result<Vec<(Target, Effect)>, SAPTestError> {
+        // Do not need to mutate to reduce uses as start of battle should only occur once.
+        let mut start_of_battle_effects = pet
+            .read()
+            .unwrap()
+            .effect
+            .iter()
+            .filter_map(|effect| {
+                if effect.trigger.status == Status::StartOfBattle {
+                    Some((team, effect.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect_vec();
+
+        // Check for held items activating at the start of battle. ex. Onion
+        start_of_battle_effects.extend(
+            pet.read()
+                .unwrap()
+                .items
+                .iter()
+                .filter(|item| {
+                    item.ability.trigger.status == Status::StartOfBattle
+                        && item.ability.uses != Some(0)
+                })
+                .map(|item| {
+                    let mut effect = item.ability.clone();
+                    effect.assign_owner(Some(pet));
+                    (team, effect)
+                }),
+        );
+
+        // Check for repeated effects.
+        let repeated_effects = self.repeat_effects(pet, &TRIGGER_START_BATTLE, None, false)?;
+        start_of_battle_effects.extend(repeated_effects.into_iter().map(|effect| (team, effect)));
+        Ok(start_of_battle_effects)
+    }
+
+    /// Preview which pet and item effects would activate for a given trigger.
+    /// * No uses are consumed and no effects are applied.
+    /// * Effects are returned in the order the team would resolve them, paired with the pet owning them.
+    /// * Repeated effects (ex. [`Tiger`](crate::PetName::Tiger)) are included.
+    /// # Example
+    /// ```rust
+    /// use saptest::{Team, TeamViewer, Pet, PetName, effects::trigger::TRIGGER_SELF_FAINT};
+    ///
+    /// let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    /// let mut trigger = TRIGGER_SELF_FAINT;
+    /// trigger.set_affected(&team.first().unwrap());
+    ///
+    /// let preview = team.preview_trigger(&trigger);
+    /// assert_eq!(preview.len(), 1);
+    /// // Effect uses are untouched.
+    /// assert_eq!(team.first().unwrap().read().unwrap().effect[0].uses, Some(1));
+    /// ```
+    pub fn preview_trigger(&self, trigger: &Outcome) -> Vec<(Arc<RwLock<Pet>>, Effect)> {
+        let trigger_pet_name = trigger
+            .affected_pet
+            .as_ref()
+            .and_then(|pet| pet.upgrade())
+            .map(|pet| pet.read().unwrap().name.clone());
+
+        let mut previewed_effects = vec![];
+        for pet in self
+            .get_pet_effect_order(!NON_COMBAT_TRIGGERS.contains(trigger))
+            .into_iter()
+        {
+            let same_pet_as_trigger = trigger
+                .affected_pet
+                .as_ref()
+                .is_some_and(|trigger_pet| trigger_pet.ptr_eq(&Arc::downgrade(&pet)));
+
+            let mut pet_effects = {
+                let pet = pet.read().unwrap();
+                pet.effect
+                    .iter()
+                    .filter(|effect| {
+                        effect.check_activates(trigger)
+                            && !is_pet_effect_exception(
+                                trigger,
+                                trigger_pet_name.as_ref(),
+                                effect,
+                                same_pet_as_trigger,
+                            )
+                    })
+                    .chain(
+                        pet.items
+                            .iter()
+                            .map(|food| &food.ability)
+                            .filter(|ability| ability.check_activates(trigger)),
+                    )
+                    .cloned()
+                    .collect_vec()
+            };
+            if let Ok(repeated_effects) = self.repeat_effects(
+                &pet,
+                trigger,
+                trigger_pet_name.as_ref(),
+                same_pet_as_trigger,
+            ) {
+                pet_effects.extend(repeated_effects)
+            }
+
+            previewed_effects.extend(pet_effects.into_iter().map(|mut effect| {
+                effect.trigger = trigger.clone();
+                (pet.clone(), effect)
+            }));
+        }
+        previewed_effects
+    }
+}
+
 impl From<Pack> for Vec<Effect> {
     fn from(pack: Pack) -> Self {
         match pack {
@@ -45,32 +441,30 @@ impl From<Pack> for Vec<Effect> {
                 target: Target::Friend,
                 position: Position::First,
                 action: Action::Conditional(
-                    LogicType::If(ConditionType::Shop(ShopCondition::InState(
-                        ShopState::Closed,
-                    ))),
-                    Box::new(Action::Conditional(
-                        LogicType::IfNot(ConditionType::Team(
+                    LogicType::All(vec![
+                        ConditionType::Shop(ShopCondition::InState(ShopState::Closed)),
+                        ConditionType::Team(
                             Target::Friend,
                             TeamCondition::Counter(
-                                "Trumpets".to_owned(),
-                                Some(CondOrdering::Equal(0)),
+                                CounterKey::Trumpets,
+                                Some(CondOrdering::Greater(0)),
                             ),
+                        ),
+                    ]),
+                    // Summon Golden Retriever and remove 50 trumpets.
+                    Box::new(Action::Multiple(vec![
+                        Action::Summon(SummonType::CustomPet(
+                            PetName::GoldenRetriever,
+                            StatChangeType::TeamCounter(CounterKey::Trumpets),
+                            1,
                         )),
-                        // Summon Golden Retriever and remove 50 trumpets.
-                        Box::new(Action::Multiple(vec![
-                            Action::Summon(SummonType::CustomPet(
-                                PetName::GoldenRetriever,
-                                StatChangeType::TeamCounter("Trumpets".to_owned()),
-                                1,
-                            )),
-                            Action::AddToCounter("Trumpets".to_owned(), -50),
-                        ])),
-                        Box::new(Action::None),
-                    )),
+                        Action::AddToCounter(CounterKey::Trumpets, -50),
+                    ])),
                     Box::new(Action::None),
                 ),
                 uses: Some(1),
                 temp: true,
+                ..Default::default()
             }],
             _ => Vec::default(),
         }
@@ -105,6 +499,9 @@ pub trait TeamEffects {
     /// * This exhausts all effect [`Outcome`] triggers.
     /// * Fainted [`Pet`]s are not removed.
     /// * Updates cycle for iteration needed to completely empty both teams.
+    /// * Errors with [`SAPTestError::TriggerLoop`] if more triggers than the calling team's trigger budget activate.
+    ///     * Set with [`Team::set_trigger_budget`].
+    /// * Triggers resolve in the order set by the calling team's [`BattleRules::faint_order`](crate::teams::combat::BattleRules::faint_order).
     fn trigger_all_effects(&mut self, opponent: &mut Team) -> Result<&mut Self, SAPTestError>;
 
     /// Trigger the start of battle [`Pet`] [`Effect`]s for two [`Team`]s.
@@ -114,6 +511,8 @@ pub trait TeamEffects {
     /// * This takes all [`Pet`]s into consideration unlike [`trigger_effects`](TeamEffects::trigger_effects) which only activates effects from a single [`Team`].
     /// * This exhausts all effect [`Outcome`] triggers.
     /// * Fainted [`Pet`]s are not removed.
+    /// * If the calling team's [`BattleRules::interleave_start_of_battle`](crate::teams::combat::BattleRules::interleave_start_of_battle) is set, pets activate one at a time by current attack.
+    ///     * Triggers from each pet's effects resolve before the next pet activates and fainted pets do not activate.
     /// # Example
     /// ```
     /// use saptest::{
@@ -191,7 +590,7 @@ pub trait TeamEffects {
     /// };
     ///
     /// let mut mosquito = Pet::try_from(PetName::Mosquito).unwrap();
-    /// mosquito.item = Some(Food::try_from(FoodName::Honey).unwrap());
+    /// mosquito.items.perk = Some(Box::new(Food::try_from(FoodName::Honey).unwrap()));
     /// let mut team = Team::new(&vec![Some(mosquito)], 5).unwrap();
     /// let mut faint_trigger = TRIGGER_SELF_FAINT;
     /// faint_trigger.set_affected(&team.first().unwrap());
@@ -259,7 +658,7 @@ pub trait TeamEffects {
     ) -> Result<Vec<Arc<RwLock<Pet>>>, SAPTestError>;
 
     /// Get effect order for a single [`Team`].
-    /// * Order is found by going from highest to lowest [`Pet`] attack.
+    /// * Order is found using the team's [`ResolutionOrder`]. By default, from highest to lowest [`Pet`] attack.
     /// * If in battle:
     ///     * The first pet on the team is always first in effect priority.
     fn get_pet_effect_order(&self, in_battle: bool) -> Vec<Arc<RwLock<Pet>>>;
@@ -267,21 +666,7 @@ pub trait TeamEffects {
 
 impl TeamEffects for Team {
     fn get_pet_effect_order(&self, in_battle: bool) -> Vec<Arc<RwLock<Pet>>> {
-        let mut ordered_pets = self
-            .friends
-            .iter()
-            .flatten()
-            .sorted_by(|pet_1, pet_2| {
-                pet_1
-                    .read()
-                    .unwrap()
-                    .stats
-                    .attack
-                    .cmp(&pet_2.read().unwrap().stats.attack)
-            })
-            .rev()
-            .cloned()
-            .collect_vec();
+        let mut ordered_pets = self.resolution_order.order(&self.friends);
 
         let curr_pet = self.curr_pet.as_ref().map(|pet| pet.upgrade());
 
@@ -297,6 +682,10 @@ impl TeamEffects for Team {
     }
 
     fn trigger_all_effects(&mut self, opponent: &mut Team) -> Result<&mut Self, SAPTestError> {
+        if self.battle_rules.faint_order == FaintOrder::FrontToBack {
+            return self.trigger_all_effects_front_to_back(opponent);
+        }
+
         // Get first pet. Teams should not be cleared at any step.
         let first_pet = self.friends.iter().flatten().next().cloned();
         let first_enemy_pet = opponent.friends.iter().flatten().next().cloned();
@@ -320,6 +709,8 @@ impl TeamEffects for Team {
 
         let mut friend_item_triggers = VecDeque::new();
         let mut opponent_item_triggers = VecDeque::new();
+        let item_order = self.battle_rules.item_order;
+        let mut guard = TriggerGuard::new(self.trigger_budget);
 
         // The team with a lower attack first pet goes first, otherwise it's reversed.
         // https://youtu.be/NSqjuA32AoA?t=426
@@ -331,11 +722,25 @@ impl TeamEffects for Team {
                 // Activate all pet effects until all triggers consumed. Then move on to items.
                 // Opponent first, then friends.
                 if let Some(trigger) = opponent.triggers.pop_front() {
+                    guard.check(&trigger)?;
                     opponent.trigger_effects(&trigger, Some(self))?;
-                    opponent_item_triggers.push_back(trigger)
+                    queue_items(
+                        opponent,
+                        self,
+                        trigger,
+                        &mut opponent_item_triggers,
+                        item_order,
+                    )?
                 } else if let Some(trigger) = self.triggers.pop_front() {
+                    guard.check(&trigger)?;
                     self.trigger_effects(&trigger, Some(opponent))?;
-                    friend_item_triggers.push_back(trigger)
+                    queue_items(
+                        self,
+                        opponent,
+                        trigger,
+                        &mut friend_item_triggers,
+                        item_order,
+                    )?
                 } else if let Some(trigger) = opponent_item_triggers.pop_front() {
                     opponent.trigger_items(&trigger, Some(self))?;
                 } else if let Some(trigger) = friend_item_triggers.pop_front() {
@@ -353,11 +758,25 @@ impl TeamEffects for Team {
                 // Activate all pet effects until all triggers consumed. Then move on to items.
                 // Opponent first, then friends.
                 if let Some(trigger) = self.triggers.pop_front() {
+                    guard.check(&trigger)?;
                     self.trigger_effects(&trigger, Some(opponent))?;
-                    friend_item_triggers.push_back(trigger)
+                    queue_items(
+                        self,
+                        opponent,
+                        trigger,
+                        &mut friend_item_triggers,
+                        item_order,
+                    )?
                 } else if let Some(trigger) = opponent.triggers.pop_front() {
+                    guard.check(&trigger)?;
                     opponent.trigger_effects(&trigger, Some(self))?;
-                    opponent_item_triggers.push_back(trigger)
+                    queue_items(
+                        opponent,
+                        self,
+                        trigger,
+                        &mut opponent_item_triggers,
+                        item_order,
+                    )?
                 } else if let Some(trigger) = friend_item_triggers.pop_front() {
                     self.trigger_items(&trigger, Some(opponent))?;
                 } else if let Some(trigger) = opponent_item_triggers.pop_front() {
@@ -388,46 +807,64 @@ impl TeamEffects for Team {
             .map(|pet| (Target::Enemy, pet));
 
         let mut activated_effects: Vec<(Target, Effect)> = vec![];
-        for (team, pet) in self_pets
-            .chain(opponent_pets)
-            .sorted_by(|(_, pet_1), (_, pet_2)| {
-                pet_1
-                    .read()
-                    .unwrap()
-                    .stats
-                    .attack
-                    .cmp(&pet_2.read().unwrap().stats.attack)
-            })
-            .rev()
-        {
-            // Do not need to mutate to reduce uses as start of battle should only occur once.
-            let start_of_battle_effects = pet
-                .read()
-                .unwrap()
-                .effect
-                .iter()
-                .filter_map(|effect| {
-                    if effect.trigger.status == Status::StartOfBattle {
-                        Some((team, effect.clone()))
-                    } else {
-                        None
-                    }
-                })
+        if self.battle_rules.interleave_start_of_battle {
+            // Only pets present at the start of battle can activate.
+            let mut waiting_pets = self_pets
+                .chain(opponent_pets)
+                .map(|(team, pet)| (team, pet.clone()))
                 .collect_vec();
 
-            // Check for tiger effects.
-            let tiger_effects = match team {
-                Target::Friend => {
-                    self.repeat_effects_if_tiger(pet, &TRIGGER_START_BATTLE, None, false)?
-                }
-                Target::Enemy => {
-                    opponent.repeat_effects_if_tiger(pet, &TRIGGER_START_BATTLE, None, false)?
+            loop {
+                waiting_pets.retain(|(_, pet)| pet.read().unwrap().stats.health != 0);
+                // Ties activate in the same order as sorting all pets at once.
+                let Some(idx) = waiting_pets
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(_, (_, pet))| pet.read().unwrap().stats.attack)
+                    .map(|(idx, _)| idx)
+                else {
+                    break;
+                };
+                let (team, pet) = waiting_pets.remove(idx);
+                let pet_effects = match team {
+                    Target::Friend => self.start_battle_pet_effects(&pet, team)?,
+                    Target::Enemy => opponent.start_battle_pet_effects(&pet, team)?,
+                    _ => unreachable!("Not possible to get other targets."),
+                };
+                for (team, effect) in pet_effects.iter() {
+                    match team {
+                        Target::Friend => {
+                            self.apply_effect(effect, Some(opponent))?;
+                        }
+                        Target::Enemy => {
+                            opponent.apply_effect(effect, Some(self))?;
+                        }
+                        _ => unreachable!("Not possible to have other targets."),
+                    }
                 }
-                _ => unreachable!("Not possible to get other targets."),
-            };
-
-            activated_effects.extend(start_of_battle_effects);
-            activated_effects.extend(tiger_effects.into_iter().map(|effect| (team, effect)))
+                // Resolve the pet's triggers before the next pet activates.
+                self.trigger_all_effects(opponent)?;
+            }
+        } else {
+            for (team, pet) in self_pets
+                .chain(opponent_pets)
+                .sorted_by(|(_, pet_1), (_, pet_2)| {
+                    pet_1
+                        .read()
+                        .unwrap()
+                        .stats
+                        .attack
+                        .cmp(&pet_2.read().unwrap().stats.attack)
+                })
+                .rev()
+            {
+                let pet_effects = match team {
+                    Target::Friend => self.start_battle_pet_effects(pet, team)?,
+                    Target::Enemy => opponent.start_battle_pet_effects(pet, team)?,
+                    _ => unreachable!("Not possible to get other targets."),
+                };
+                activated_effects.extend(pet_effects)
+            }
         }
 
         // TODO: Persistent effects.
@@ -486,17 +923,17 @@ impl TeamEffects for Team {
 
         for pet in ordered_pets.iter() {
             // Get food and pet effect based on if its trigger is equal to current trigger, if any.
-            if let Some(food) = pet
+            for food in pet
                 .write()
                 .unwrap()
-                .item
-                .as_mut()
+                .items
+                .iter_mut()
                 .filter(|food| food.ability.check_activates(trigger))
             {
                 // Drop uses by one if possible.
-                food.ability.remove_uses(1);
+                food.ability.remove_uses(1).add_activation();
                 applied_effects.push(food.ability.clone())
-            };
+            }
         }
 
         for mut effect in applied_effects.into_iter() {
@@ -516,6 +953,23 @@ impl TeamEffects for Team {
         trigger: &Outcome,
         mut opponent: Option<&mut Team>,
     ) -> Result<&mut Self, SAPTestError> {
+        // Record knockouts landed by this team's pets.
+        if trigger.status == Status::KnockOut {
+            if let (Some(knocked_out_by), Some(knocked_out)) = (
+                trigger.knockout_pet(),
+                trigger
+                    .afflicting_pet
+                    .as_ref()
+                    .and_then(|pet| pet.upgrade()),
+            ) {
+                let record = KnockoutRecord {
+                    phase: self.history.curr_phase,
+                    knocked_out_by: knocked_out_by.read().unwrap().name.clone(),
+                    knocked_out: knocked_out.read().unwrap().name.clone(),
+                };
+                self.history.knockouts.push(record);
+            }
+        }
         let check_effect = |effect: &mut Effect| {
             // Check if persistent effect can be activated.
             if !effect.check_activates(trigger) || effect.uses == Some(0) {
@@ -523,7 +977,7 @@ impl TeamEffects for Team {
             }
             let mut effect_copy = effect.clone();
             // Decrease uses.
-            effect.remove_uses(1);
+            effect.remove_uses(1).add_activation();
 
             // Take the first friend regardless if alive or not.
             if let Some(Some(pet)) = self.friends.first() {
@@ -546,18 +1000,26 @@ impl TeamEffects for Team {
                 toy.effect
                     .iter_mut()
                     .filter_map(|effect|
-                        // If duration of toy is 0, check if it is activated by breaking.
+                        // If duration of toy is 0, check if it is activated by this toy breaking.
                         // * NOTE: The duration is dropped during the start of a shop turn.
                         // Otherwise just decrease the uses of that toy's effects.
                         if toy.duration == Some(0) {
+                            let toy_broke = trigger.status == Status::BrokeToy
+                                && trigger
+                                    .afflicting_toy
+                                    .as_ref()
+                                    .is_some_and(|broken_toy| broken_toy.name == toy.name);
                             // Take the first friend regardless if alive or not.
-                            if let Some(Some(pet)) = self.friends.first() {
+                            if let (true, Some(Some(pet))) = (toy_broke, self.friends.first()) {
                                 let mut effect_copy = effect.clone();
                                 effect_copy.assign_owner(Some(pet));
                                 Some(effect_copy)
                             } else {
                                 None
                             }
+                        } else if effect.trigger.status == Status::BrokeToy {
+                            // Unbroken toys ignore other toys breaking.
+                            None
                         } else {
                             check_effect(effect)
                         }
@@ -603,23 +1065,19 @@ impl TeamEffects for Team {
                         None
                     } else {
                         // Drop uses by one if possible.
-                        effect.remove_uses(1);
+                        effect.remove_uses(1).add_activation();
                         Some(effect.clone())
                     }
                 })
                 .collect_vec();
 
-            // Check if tiger should activate.
+            // Check if effects should be repeated.
             // Also checks if effects are valid.
-            let tiger_effects = self.repeat_effects_if_tiger(
-                pet,
-                trigger,
-                trigger_pet_name.as_ref(),
-                same_pet_as_trigger,
-            )?;
+            let repeated_effects =
+                self.repeat_effects(pet, trigger, trigger_pet_name.as_ref(), same_pet_as_trigger)?;
 
             applied_effects.extend(valid_effects);
-            applied_effects.extend(tiger_effects);
+            applied_effects.extend(repeated_effects);
         }
 
         // Pet sold. Remove pet from friends and add to sold pet.
@@ -648,6 +1106,17 @@ impl TeamEffects for Team {
         effect: &Effect,
         mut opponent: Option<&mut Team>,
     ) -> Result<Vec<Arc<RwLock<Pet>>>, SAPTestError> {
+        // Allow interceptors to rewrite or block the effect.
+        let intercepted_effect;
+        let effect = if self.interceptors.0.is_empty() {
+            effect
+        } else if let Some(rewritten_effect) = self.interceptors.intercept(effect) {
+            intercepted_effect = rewritten_effect;
+            &intercepted_effect
+        } else {
+            return Ok(vec![]);
+        };
+
         // Set current pet.
         self.curr_pet = effect.owner.clone();
         let mut affected_pets = vec![];
@@ -666,6 +1135,28 @@ impl TeamEffects for Team {
             )?),
             // All shop actions go here.
             (Target::Shop, _) => self.apply_shop_effect(effect)?,
+            // Summons to the front of an opponent with no pet in front have no pet to target. ex. Rat
+            (Target::Enemy, Action::Summon(summon_type))
+                if effect.position == Position::First
+                    && opponent
+                        .as_ref()
+                        .is_some_and(|opponent| opponent.first().is_none()) =>
+            {
+                let Some(opponent) = opponent else {
+                    return Ok(affected_pets);
+                };
+                let afflicting_pet = effect.try_into()?;
+                let summoned_pet = summon_type.to_pet(opponent, &afflicting_pet)?;
+                match opponent.add_pet(summoned_pet, 0, Some(self)) {
+                    Ok(_) => affected_pets.extend(opponent.friends.first().cloned().flatten()),
+                    // Fallible error. No space on the opponent's team.
+                    Err(SAPTestError::InvalidPetAction { .. }) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+            // No enemy pets to target while shopping.
+            (Target::Enemy | Target::Either, _)
+                if opponent.is_none() && self.shop.state == ShopState::Open => {}
             // Effects applied to individual pets are here.
             _ => {
                 let target_pets = if let Some(opponent) = opponent.as_ref() {
@@ -12,7 +12,7 @@ use crate::{
     },
     error::SAPTestError,
     pets::combat::AttackOutcome,
-    teams::team::TeamFightOutcome,
+    teams::team::{EndTurnSummary, KnockoutRecord, ShopRecord, TeamFightOutcome},
     Pet, Statistics, Team,
 };
 
@@ -30,6 +30,12 @@ pub struct History {
     pub curr_turn: usize,
     pub pet_count: usize,
     pub fight_outcomes: Vec<TeamFightOutcome>,
+    // Summary of the current or last shop phase.
+    pub shop_summary: EndTurnSummary,
+    // Actions taken in the shop across all turns.
+    pub shop_records: Vec<ShopRecord>,
+    // Knockouts landed by the team's pets in battle.
+    pub knockouts: Vec<KnockoutRecord>,
     pub graph: BattleGraph,
 }
 
@@ -48,6 +54,9 @@ impl Default for History {
             curr_cycle: 1,
             pet_count: 0,
             fight_outcomes: Default::default(),
+            shop_summary: Default::default(),
+            shop_records: Default::default(),
+            knockouts: Default::default(),
             graph: BattleGraph::default(),
         }
     }
@@ -67,9 +76,9 @@ impl BattleGraph {
     ) {
         for pet in friends.iter().chain(enemies.iter()).flatten() {
             let pet = pet.read().unwrap();
-            if let (Some(id), Some(team)) = (&pet.id, &pet.team) {
+            if let (Some(label), Some(team)) = (pet.label(), &pet.team) {
                 let node = PetNode {
-                    id: format!("{}_{}", pet.name, id),
+                    id: label,
                     team: team.to_owned(),
                 };
                 let node_idx = self.phase_graph.add_node(node.clone());
@@ -154,9 +163,9 @@ impl TeamHistoryHelpers for Team {
         if let (Some(affected_team), Some(afflicting_team)) =
             (affected.team.clone(), afflicting.team.clone())
         {
-            if let Some(id) = affected.id {
+            if let Some(label) = affected.label() {
                 let node = PetNode {
-                    id: format!("{}_{}", affected.name, id),
+                    id: label,
                     team: affected_team,
                 };
 
@@ -169,9 +178,9 @@ impl TeamHistoryHelpers for Team {
                         self.history.graph.phase_graph.add_node(node.clone())
                     });
 
-                if let Some(afflicting_pet_id) = afflicting.id {
+                if let Some(afflicting_label) = afflicting.label() {
                     let other_node = PetNode {
-                        id: format!("{}_{}", afflicting.name, afflicting_pet_id),
+                        id: afflicting_label,
                         team: afflicting_team,
                     };
                     let afflicting_node_idx =
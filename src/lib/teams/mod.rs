@@ -1,7 +1,11 @@
 //! [`Team`](crate::Team) battle and effect logic.
 
+/// Passive [`Team`](crate::Team) auras.
+pub mod aura;
 /// Implements [`Team`](crate::Team) battle mechanics.
 pub mod combat;
+/// [`Team`](crate::Team) counters.
+pub mod counters;
 /// [`Effect`](crate::Effect) application to one or more [`Team`](crate::Team)s.
 pub mod effects;
 /// Serialize a [`Team`](crate::Team) using [`serde_json`].
@@ -1,5 +1,6 @@
 //! ### Into `JSON`
 //! Convert a team into JSON.
+//! * The team's [`Shop`](crate::Shop) is included allowing frozen items and saved gold to be restored mid-run.
 //! ```
 //! use saptest::{Pet, PetName, Team, error::SAPTestError};
 //! let team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
@@ -34,7 +35,6 @@ impl FromStr for Team {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut new_team: Team = serde_json::from_str(s)?;
         new_team.reset_pet_references(None);
-        new_team.shop.restock()?;
         Ok(new_team)
     }
 }
@@ -1,16 +1,27 @@
 use crate::{
     db::pack::Pack,
     effects::{
+        effect::{EntityName, LimitScope},
         state::{Outcome, Position, Target},
         trigger::*,
     },
     error::SAPTestError,
     pets::pet::{reassign_effects, Pet},
-    shop::{store::ShopState, team_shopping::TeamShoppingHelpers},
-    teams::{history::History, viewer::TeamViewer},
+    shop::{
+        store::ShopState, strategy::ShopAction, team_shopping::TeamShoppingHelpers,
+        trigger::ShopSubscriber,
+    },
+    teams::{
+        aura::Aura,
+        combat::BattleRules,
+        counters::{CounterKey, Counters},
+        effects::{EffectInterceptors, ResolutionOrder, DEFAULT_TRIGGER_BUDGET},
+        history::History,
+        viewer::TeamViewer,
+    },
     toys::toy::Toy,
     wiki_scraper::parse_names::WordType,
-    Effect, Food, Shop, CONFIG, SAPDB,
+    Effect, Food, PetName, Shop, Statistics, CONFIG, SAPDB,
 };
 
 use itertools::Itertools;
@@ -19,13 +30,16 @@ use rand::{random, seq::IteratorRandom, SeedableRng};
 use rand_chacha::ChaCha12Rng;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::VecDeque,
     fmt::Display,
     sync::{Arc, RwLock, Weak},
 };
 
 const COPY_SUFFIX: &str = "_copy";
 
+/// Original pets and their copies.
+type CopiedPets = Vec<(Weak<RwLock<Pet>>, Arc<RwLock<Pet>>)>;
+
 /// The outcome of a [`Team`] fight.
 ///
 /// # Examples
@@ -70,6 +84,93 @@ impl TeamFightOutcome {
     }
 }
 
+/// Summary of a [`Team`]'s last shop phase.
+/// * Reset when the [`Shop`] is opened.
+///
+/// # Examples
+/// ```rust
+/// use saptest::{Team, TeamShopping, Entity, Position};
+///
+/// let mut team = Team::default();
+/// team.set_shop_seed(Some(12)).open_shop().unwrap();
+/// team.buy(&Position::First, &Entity::Pet, &Position::First).unwrap();
+/// team.close_shop().unwrap();
+///
+/// let summary = team.end_turn_summary();
+/// assert_eq!(summary.gold_spent, 3);
+/// assert_eq!(summary.bought.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct EndTurnSummary {
+    /// Gold spent on items and rolls.
+    pub gold_spent: usize,
+    /// Names of items bought in order of purchase.
+    pub bought: Vec<EntityName>,
+    /// Names of pets sold in order of sale.
+    pub sold: Vec<PetName>,
+    /// Pets that levelled up and the level reached.
+    pub levelled: Vec<(PetName, usize)>,
+    /// Temporary stats granted to pets.
+    /// * These are removed when the shop is opened after their [`Effect::duration`](crate::Effect::duration).
+    pub temp_stats: Vec<(PetName, Statistics)>,
+}
+
+/// A record of an action taken by a [`Team`] in its [`Shop`].
+///
+/// # Examples
+/// ```rust
+/// use saptest::{Team, TeamShopping, ShopAction};
+///
+/// let mut team = Team::default();
+/// team.open_shop().unwrap();
+/// team.roll_shop().unwrap();
+///
+/// let record = team.shop_history().last().unwrap();
+/// assert_eq!(record.action, ShopAction::Roll);
+/// assert_eq!((record.turn, record.gold_before, record.gold_after), (1, 10, 9));
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ShopRecord {
+    /// Turn action was taken.
+    pub turn: usize,
+    /// Action taken.
+    pub action: ShopAction,
+    /// Names of items bought or sold by the action.
+    pub items: Vec<EntityName>,
+    /// Gold before the action.
+    pub gold_before: usize,
+    /// Gold after the action.
+    pub gold_after: usize,
+}
+
+/// A pet knocked out in battle and the pet that landed the killing blow.
+/// * Includes knockouts from indirect damage. ex. Mosquito and Chili
+///
+/// # Examples
+/// ```rust
+/// use saptest::{Pet, PetName, Statistics, Team, TeamCombat};
+///
+/// let mut team = Team::new(
+///     &[Some(Pet::new(PetName::Ant, Some(Statistics::new(50, 50).unwrap()), 1).unwrap())],
+///     5
+/// ).unwrap();
+/// let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+/// team.fight(&mut enemy_team).unwrap();
+///
+/// let record = team.knockout_history().first().unwrap();
+/// assert_eq!((&record.knocked_out_by, &record.knocked_out), (&PetName::Ant, &PetName::Ant));
+/// assert_eq!(record.phase, 1);
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct KnockoutRecord {
+    /// Battle phase of the knockout.
+    pub phase: usize,
+    /// Pet that landed the killing blow.
+    pub knocked_out_by: PetName,
+    /// Pet knocked out.
+    pub knocked_out: PetName,
+}
+
 fn opt_pets_eq(lhs: &[Option<Arc<RwLock<Pet>>>], rhs: &[Option<Arc<RwLock<Pet>>>]) -> bool {
     if lhs.len() != rhs.len() {
         false
@@ -105,7 +206,7 @@ pub struct Team {
     /// * As a result, this will always be empty unless mutated.
     pub triggers: VecDeque<Outcome>,
     /// Pet shop.
-    #[serde(skip)]
+    #[serde(default)]
     pub(crate) shop: Shop,
     /// Effect history of a team.
     #[serde(skip)]
@@ -123,7 +224,29 @@ pub struct Team {
     pub toys: Vec<Toy>,
     /// Counters.
     /// * These can be used with effects.
-    pub counters: HashMap<String, usize>,
+    pub counters: Counters,
+    /// Passive effects active while their source is on the team.
+    #[serde(default)]
+    pub(crate) auras: Vec<Aura>,
+    /// Subscribers to shop triggers.
+    #[serde(skip)]
+    pub(crate) shop_subscribers: Vec<ShopSubscriber>,
+    /// Order pets resolve effects activated by the same trigger.
+    #[serde(skip)]
+    pub(crate) resolution_order: ResolutionOrder,
+    /// Optional battle mechanics.
+    #[serde(skip)]
+    pub(crate) battle_rules: BattleRules,
+    /// Maximum number of triggers activated at once before assuming a trigger loop.
+    #[serde(skip, default = "default_trigger_budget")]
+    pub(crate) trigger_budget: usize,
+    /// Interceptors rewriting effects before they are applied.
+    #[serde(skip)]
+    pub(crate) interceptors: EffectInterceptors,
+}
+
+fn default_trigger_budget() -> usize {
+    DEFAULT_TRIGGER_BUDGET
 }
 
 impl Default for Team {
@@ -149,88 +272,59 @@ impl Default for Team {
             persistent_effects: Vec::default(),
             toys: Vec::default(),
             // Add trumpets.
-            counters: HashMap::from_iter([("Trumpets".to_owned(), 0)]),
+            counters: Counters::from_iter([(CounterKey::Trumpets, 0)]),
+            auras: vec![],
+            shop_subscribers: Vec::default(),
+            resolution_order: ResolutionOrder::default(),
+            battle_rules: BattleRules::default(),
+            trigger_budget: DEFAULT_TRIGGER_BUDGET,
+            interceptors: EffectInterceptors::default(),
         }
     }
 }
 
+/// Copy reference counted pets, mapping each original pet to its copy.
+/// * Pets keep their ids.
 fn copy_rc_pets(
     slots: &[Option<Arc<RwLock<Pet>>>],
-    team_name: Option<String>,
+    copied_refs: &mut CopiedPets,
 ) -> Vec<Option<Arc<RwLock<Pet>>>> {
     slots
         .iter()
         .map(|slot| {
-            if let Some(pet) = slot {
-                let mut copied_pet = pet.read().unwrap().clone();
-                copied_pet.team = team_name.clone().or(copied_pet.team);
-                Some(Arc::new(RwLock::new(copied_pet)))
-            } else {
-                None
-            }
+            slot.as_ref().map(|pet| {
+                let copied_pet = Arc::new(RwLock::new(pet.read().unwrap().clone()));
+                reassign_effects(&copied_pet);
+                copied_refs.push((Arc::downgrade(pet), copied_pet.clone()));
+                copied_pet
+            })
         })
         .collect_vec()
 }
 
 impl Clone for Team {
     fn clone(&self) -> Self {
-        let mut copied_team_name = self.name.clone();
-        copied_team_name.push_str(COPY_SUFFIX);
+        let mut copied_team = self.duplicate();
+        copied_team.name.push_str(COPY_SUFFIX);
 
-        // Because we use reference counted ptrs, default clone impl will just increase strong reference counts.
-        // So we need to clone the inner values and reassign owners.
-        let copied_friends = copy_rc_pets(&self.friends, Some(copied_team_name.clone()));
-        let copied_fainted = copy_rc_pets(&self.fainted, Some(copied_team_name.clone()));
-        let copied_sold = copy_rc_pets(&self.sold, Some(copied_team_name.clone()));
-        let mut copied_stored_friends = self.stored_friends.clone();
-        for friend in copied_stored_friends.iter_mut().flatten() {
-            friend.team = Some(copied_team_name.clone())
+        for friend in copied_team.stored_friends.iter_mut().flatten() {
+            friend.team = Some(copied_team.name.clone())
+        }
+        for friend in copied_team
+            .friends
+            .iter()
+            .chain(copied_team.fainted.iter().chain(copied_team.sold.iter()))
+            .flatten()
+        {
+            friend.write().unwrap().team = Some(copied_team.name.clone())
         }
         // Change pet history to reflect name change.
-        let mut updated_history = self.history.clone();
         if CONFIG.general.build_graph {
-            updated_history
+            copied_team
+                .history
                 .graph
-                .update_nodes_with_team_name(&self.name, &copied_team_name);
+                .update_nodes_with_team_name(&self.name, &copied_team.name);
         }
-
-        // Copy triggers and update them if a pet is affected.
-        let mut copied_triggers = self.triggers.clone();
-        'trigger_loop: for trigger in copied_triggers.iter_mut() {
-            let pet_id = trigger
-                .affected_pet
-                .as_ref()
-                .and_then(|pet| pet.upgrade())
-                .and_then(|pet| pet.read().unwrap().id);
-            // If found id in trigger is same as pet then set trigger to copied friend.
-            if let Some(pet_id) = pet_id.as_ref() {
-                for friend in copied_friends.iter().flatten() {
-                    if friend.read().unwrap().id.as_ref() == Some(pet_id) {
-                        trigger.affected_pet = Some(Arc::downgrade(friend));
-                        continue 'trigger_loop;
-                    }
-                }
-            }
-        }
-
-        let mut copied_team = Self {
-            name: copied_team_name,
-            friends: copied_friends,
-            fainted: copied_fainted,
-            sold: copied_sold,
-            max_size: self.max_size,
-            triggers: copied_triggers,
-            history: updated_history,
-            seed: self.seed,
-            stored_friends: copied_stored_friends,
-            curr_pet: None,
-            shop: self.shop.clone(),
-            persistent_effects: self.persistent_effects.clone(),
-            toys: self.toys.clone(),
-            counters: self.counters.clone(),
-        };
-        // Reassign references.
-        copied_team.reset_pet_references(None);
         copied_team
     }
 }
@@ -361,6 +455,25 @@ impl Team {
         self
     }
 
+    /// Equip an item on a team member and queue the triggers for gaining it.
+    /// * Optionally, attach the food the item came from to the triggers.
+    pub(crate) fn equip_item(
+        &mut self,
+        pet: &Arc<RwLock<Pet>>,
+        mut item: Food,
+        source: Option<&Arc<RwLock<Food>>>,
+    ) {
+        item.ability.assign_owner(Some(pet));
+        let triggers = pet.write().unwrap().equip(item);
+        for mut trigger in triggers {
+            trigger.set_affected(pet);
+            if let Some(food) = source {
+                trigger.set_afflicting_food(food);
+            }
+            self.triggers.push_back(trigger)
+        }
+    }
+
     /// Create reference counted pets.
     pub(crate) fn create_rc_pets(
         pets: &mut [Option<Pet>],
@@ -423,6 +536,302 @@ impl Team {
         self
     }
 
+    /// Set the order pets on the team resolve their effects when activated by the same trigger.
+    /// * See [`ResolutionOrder`] for options.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use saptest::{Team, teams::effects::ResolutionOrder};
+    ///
+    /// let mut team = Team::default();
+    /// // Resolve effects from front to back.
+    /// team.set_resolution_order(ResolutionOrder::BoardPosition);
+    /// // Or from lowest to highest health.
+    /// team.set_resolution_order(ResolutionOrder::Custom(Arc::new(|pet_1, pet_2| {
+    ///     pet_1.stats.health.cmp(&pet_2.stats.health)
+    /// })));
+    /// ```
+    pub fn set_resolution_order(&mut self, order: ResolutionOrder) -> &mut Self {
+        self.resolution_order = order;
+        self
+    }
+
+    /// Set optional battle mechanics for the team.
+    /// * See [`BattleRules`] for options.
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{Team, teams::combat::BattleRules};
+    ///
+    /// let mut team = Team::default();
+    /// // Resolve start of battle effects pet by pet across both teams.
+    /// team.set_battle_rules(BattleRules {
+    ///     interleave_start_of_battle: true,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn set_battle_rules(&mut self, rules: BattleRules) -> &mut Self {
+        self.battle_rules = rules;
+        self
+    }
+
+    /// Set the maximum number of triggers activated at once before assuming a trigger loop.
+    /// * Exceeding the budget errors with [`SAPTestError::TriggerLoop`] listing the repeating triggers.
+    /// * Defaults to [`DEFAULT_TRIGGER_BUDGET`].
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::Team;
+    ///
+    /// let mut team = Team::default();
+    /// team.set_trigger_budget(500);
+    /// ```
+    pub fn set_trigger_budget(&mut self, budget: usize) -> &mut Self {
+        self.trigger_budget = budget;
+        self
+    }
+
+    /// Register an interceptor to inspect and rewrite effects just before they are applied to the team.
+    /// * Interceptors are run in the order they were added.
+    /// * Returning [`None`] blocks the effect.
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{
+    ///     Team,
+    ///     effects::actions::{Action, StatChangeType},
+    /// };
+    ///
+    /// let mut team = Team::default();
+    /// // Double all static stat additions.
+    /// team.add_interceptor(|mut effect| {
+    ///     if let Action::Add(StatChangeType::Static(stats)) = &mut effect.action {
+    ///         stats.attack *= 2;
+    ///         stats.health *= 2;
+    ///     }
+    ///     Some(effect)
+    /// });
+    /// // Block all summons.
+    /// team.add_interceptor(|effect| {
+    ///     (!matches!(effect.action, Action::Summon(_))).then_some(effect)
+    /// });
+    /// ```
+    pub fn add_interceptor<F>(&mut self, interceptor: F) -> &mut Self
+    where
+        F: Fn(Effect) -> Option<Effect> + Send + Sync + 'static,
+    {
+        self.interceptors.0.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Remove all interceptors registered with [`Team::add_interceptor`].
+    pub fn clear_interceptors(&mut self) -> &mut Self {
+        self.interceptors.0.clear();
+        self
+    }
+
+    /// Add an [`Aura`] to the team, immediately giving its stats to matching pets.
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{
+    ///     Pet, PetName, Team, TeamViewer, ItemCondition, Statistics,
+    ///     teams::aura::{Aura, AuraSource}
+    /// };
+    ///
+    /// let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    /// team.add_aura(Aura::new(
+    ///     "Buff",
+    ///     AuraSource::Custom,
+    ///     ItemCondition::None,
+    ///     Statistics::new(1, 1).unwrap(),
+    /// ));
+    /// assert_eq!(team.first().unwrap().read().unwrap().stats, Statistics::new(3, 3).unwrap());
+    ///
+    /// // Removing the aura removes its stats.
+    /// team.remove_aura("Buff");
+    /// assert_eq!(team.first().unwrap().read().unwrap().stats, Statistics::new(2, 2).unwrap());
+    /// ```
+    pub fn add_aura(&mut self, aura: Aura) -> &mut Self {
+        self.auras.push(aura);
+        self.refresh_auras()
+    }
+
+    /// Remove an [`Aura`] by name, removing its stats from any affected pets.
+    pub fn remove_aura(&mut self, name: &str) -> Option<Aura> {
+        let idx = self.auras.iter().position(|aura| aura.name == name)?;
+        let mut aura = self.auras.remove(idx);
+        aura.remove(self);
+        Some(aura)
+    }
+
+    /// Get the [`Aura`]s on the team.
+    pub fn auras(&self) -> &[Aura] {
+        &self.auras
+    }
+
+    /// Update the pets affected by each [`Aura`] and remove auras whose source is gone.
+    /// * Called automatically whenever the team is cleared of fainted pets.
+    pub fn refresh_auras(&mut self) -> &mut Self {
+        let mut auras = std::mem::take(&mut self.auras);
+        for aura in auras.iter_mut() {
+            aura.update(self);
+        }
+        auras.retain(|aura| aura.is_active(self));
+        self.auras = auras;
+        self
+    }
+
+    /// Reset the activation count of all effects with an [`ActivationLimit`] in the given [`LimitScope`].
+    /// * Includes pet, item, persistent, and toy effects.
+    /// * Called automatically when the shop opens ([`LimitScope::Turn`]) and a battle starts ([`LimitScope::Battle`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{Team, effects::effect::LimitScope};
+    ///
+    /// let mut team = Team::default();
+    /// team.reset_activation_limits(LimitScope::Turn);
+    /// ```
+    pub fn reset_activation_limits(&mut self, scope: LimitScope) -> &mut Self {
+        let reset_limit = |effect: &mut Effect| {
+            if let Some(limit) = effect.limit.as_mut().filter(|limit| limit.scope == scope) {
+                limit.count = 0
+            }
+        };
+        for pet in self.friends.iter().flatten() {
+            let mut pet = pet.write().unwrap();
+            pet.effect.iter_mut().for_each(reset_limit);
+            pet.items
+                .iter_mut()
+                .for_each(|item| reset_limit(&mut item.ability));
+        }
+        self.persistent_effects.iter_mut().for_each(reset_limit);
+        self.toys
+            .iter_mut()
+            .flat_map(|toy| toy.effect.iter_mut())
+            .for_each(reset_limit);
+        self
+    }
+
+    /// Restore the uses of all effects with a [`UsesRefresh`](crate::effects::effect::UsesRefresh) in the given [`LimitScope`].
+    /// * Includes pet, item, persistent, and toy effects.
+    /// * Called automatically when the shop opens ([`LimitScope::Turn`]) and a battle starts ([`LimitScope::Battle`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{Effect, Team, effects::effect::LimitScope};
+    ///
+    /// let mut team = Team::default();
+    /// team.persistent_effects
+    ///     .push(Effect::builder().set_uses_refresh(LimitScope::Turn, 1).build());
+    /// team.persistent_effects[0].uses = Some(0);
+    ///
+    /// team.reset_uses(LimitScope::Battle);
+    /// assert_eq!(team.persistent_effects[0].uses, Some(0));
+    /// team.reset_uses(LimitScope::Turn);
+    /// assert_eq!(team.persistent_effects[0].uses, Some(1));
+    /// ```
+    pub fn reset_uses(&mut self, scope: LimitScope) -> &mut Self {
+        let reset_uses = |effect: &mut Effect| {
+            if let Some(refresh) = effect.uses_refresh.filter(|refresh| refresh.scope == scope) {
+                effect.uses = Some(refresh.uses)
+            }
+        };
+        for pet in self.friends.iter().flatten() {
+            let mut pet = pet.write().unwrap();
+            pet.effect.iter_mut().for_each(reset_uses);
+            pet.items
+                .iter_mut()
+                .for_each(|item| reset_uses(&mut item.ability));
+        }
+        self.persistent_effects.iter_mut().for_each(reset_uses);
+        self.toys
+            .iter_mut()
+            .flat_map(|toy| toy.effect.iter_mut())
+            .for_each(reset_uses);
+        self
+    }
+
+    /// Get the pending [`Outcome`] triggers of the team in the order they will be activated.
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{Pet, PetName, Team, effects::state::Status};
+    ///
+    /// let mut team = Team::default();
+    /// team.add_pet(Pet::try_from(PetName::Ant).unwrap(), 0, None).unwrap();
+    ///
+    /// // Ant summon trigger is queued.
+    /// assert!(team
+    ///     .pending_triggers()
+    ///     .iter()
+    ///     .any(|trigger| trigger.status == Status::Summoned));
+    /// ```
+    pub fn pending_triggers(&self) -> &VecDeque<Outcome> {
+        &self.triggers
+    }
+
+    /// Add an [`Outcome`] trigger to the end of the team's pending triggers.
+    /// * Errors if the trigger's affected pet has been dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{Team, effects::trigger::TRIGGER_START_BATTLE};
+    ///
+    /// let mut team = Team::default();
+    /// team.add_trigger(TRIGGER_START_BATTLE).unwrap();
+    /// assert_eq!(team.pending_triggers().back(), Some(&TRIGGER_START_BATTLE));
+    /// ```
+    pub fn add_trigger(&mut self, trigger: Outcome) -> Result<&mut Self, SAPTestError> {
+        if trigger
+            .affected_pet
+            .as_ref()
+            .is_some_and(|pet| pet.upgrade().is_none())
+        {
+            return Err(SAPTestError::InvalidTeamAction {
+                subject: "Dropped Trigger Pet".to_string(),
+                reason: format!("Affected pet of {trigger:?} was dropped."),
+            });
+        }
+        self.triggers.push_back(trigger);
+        Ok(self)
+    }
+
+    /// Get every buy, sell, roll, and freeze taken in the [`Shop`] across all turns.
+    /// * See [`ShopRecord`] for an example.
+    pub fn shop_history(&self) -> &[ShopRecord] {
+        &self.history.shop_records
+    }
+
+    /// Get every knockout landed by the team's pets in battle.
+    /// * See [`KnockoutRecord`] for an example.
+    pub fn knockout_history(&self) -> &[KnockoutRecord] {
+        &self.history.knockouts
+    }
+
+    /// Get a summary of the gold spent, items bought, pets sold or levelled, and temporary stats granted during the last shop phase.
+    /// * See [`EndTurnSummary`] for an example.
+    pub fn end_turn_summary(&self) -> EndTurnSummary {
+        let mut summary = self.history.shop_summary.clone();
+        // Temporary stats are tracked by pet id so find their names.
+        summary.temp_stats = self
+            .shop
+            .temp_stats
+            .iter()
+            .filter_map(|(pet_id, stats, _)| {
+                self.friends
+                    .iter()
+                    .chain(self.sold.iter())
+                    .flatten()
+                    .find(|pet| pet.read().unwrap().id.as_ref() == Some(pet_id))
+                    .map(|pet| (pet.read().unwrap().name.clone(), *stats))
+            })
+            .collect_vec();
+        summary
+    }
+
     /// Get the name of the team.
     pub fn get_name(&self) -> &str {
         &self.name
@@ -433,24 +842,21 @@ impl Team {
     /// ```
     /// use saptest::Team;
     /// let name = Team::get_random_name(5).unwrap();
-    /// assert_eq!(&name, "The Submissive Stickers");
+    /// assert_eq!(&name, "The Spicy Dumplings");
     /// ```
     pub fn get_random_name(seed: u64) -> Result<String, SAPTestError> {
-        let conn = SAPDB.pool.get()?;
+        let names = SAPDB.storage().names()?;
         let mut rng = ChaCha12Rng::seed_from_u64(seed);
 
-        let mut prefix_stmt = conn.prepare("SELECT word FROM names WHERE word_category = ?")?;
-        let mut noun_stmt = conn.prepare("SELECT word FROM names WHERE word_category = ?")?;
-        let prefix: Option<String> = prefix_stmt
-            .query([WordType::Prefix.to_string()])?
-            .mapped(|row| row.get(0))
-            .flatten()
-            .choose(&mut rng);
-        let noun: Option<String> = noun_stmt
-            .query([WordType::Noun.to_string()])?
-            .mapped(|row| row.get(0))
-            .flatten()
-            .choose(&mut rng);
+        let words = |category: WordType| {
+            let category = category.to_string();
+            names
+                .iter()
+                .filter(move |name| name.category == category)
+                .map(|name| name.word.clone())
+        };
+        let prefix: Option<String> = words(WordType::Prefix).choose(&mut rng);
+        let noun: Option<String> = words(WordType::Noun).choose(&mut rng);
 
         if let (Some(mut prefix), Some(noun)) = (prefix, noun) {
             prefix.insert_str(0, "The ");
@@ -507,7 +913,88 @@ impl Team {
         Ok(self)
     }
 
+    /// Create a fully independent copy of this team.
+    /// * Unlike [`Team::clone`], the copy keeps this team's name.
+    /// * All pets, shop items, and their references are rebuilt so changes to the copy never affect this team.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Team, TeamViewer};
+    ///
+    /// let team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    /// let team_copy = team.duplicate();
+    /// assert_eq!(team.get_name(), team_copy.get_name());
+    ///
+    /// // Modifying the copy doesn't change the original.
+    /// team_copy.first().unwrap().write().unwrap().stats.attack = 50;
+    /// assert_ne!(
+    ///     team.first().unwrap().read().unwrap().stats,
+    ///     team_copy.first().unwrap().read().unwrap().stats
+    /// );
+    /// ```
+    pub fn duplicate(&self) -> Team {
+        // Because we use reference counted ptrs, default clone impl will just increase strong reference counts.
+        // So we need to clone the inner values and reassign owners.
+        let mut copied_refs = vec![];
+        let copied_friends = copy_rc_pets(&self.friends, &mut copied_refs);
+        let copied_fainted = copy_rc_pets(&self.fainted, &mut copied_refs);
+        let copied_sold = copy_rc_pets(&self.sold, &mut copied_refs);
+        let find_copy = |pet: &Weak<RwLock<Pet>>| {
+            copied_refs
+                .iter()
+                .find(|(original, _)| original.ptr_eq(pet))
+                .map(|(_, copied_pet)| Arc::downgrade(copied_pet))
+        };
+
+        // Point triggers and the current pet to copied pets.
+        // Pets not on this team (ex. opponents) are left as is.
+        let mut copied_triggers = self.triggers.clone();
+        for trigger in copied_triggers.iter_mut() {
+            if let Some(copied_pet) = trigger.affected_pet.as_ref().and_then(find_copy) {
+                trigger.affected_pet = Some(copied_pet)
+            }
+            if let Some(copied_pet) = trigger.afflicting_pet.as_ref().and_then(find_copy) {
+                trigger.afflicting_pet = Some(copied_pet)
+            }
+        }
+        let copied_curr_pet = self.curr_pet.as_ref().and_then(find_copy);
+
+        let mut copied_shop = self.shop.clone();
+        for shop_item in copied_shop
+            .pets
+            .iter_mut()
+            .chain(copied_shop.foods.iter_mut())
+            .chain(copied_shop.toys.iter_mut())
+        {
+            shop_item.item = shop_item.item.duplicate()
+        }
+
+        Self {
+            name: self.name.clone(),
+            friends: copied_friends,
+            fainted: copied_fainted,
+            sold: copied_sold,
+            max_size: self.max_size,
+            triggers: copied_triggers,
+            history: self.history.clone(),
+            seed: self.seed,
+            stored_friends: self.stored_friends.clone(),
+            curr_pet: copied_curr_pet,
+            shop: copied_shop,
+            persistent_effects: self.persistent_effects.clone(),
+            toys: self.toys.clone(),
+            counters: self.counters.clone(),
+            auras: self.auras.clone(),
+            shop_subscribers: self.shop_subscribers.clone(),
+            resolution_order: self.resolution_order.clone(),
+            battle_rules: self.battle_rules,
+            trigger_budget: self.trigger_budget,
+            interceptors: self.interceptors.clone(),
+        }
+    }
+
     /// Assign an item to a team member.
+    /// * Perks and ailments emit gain triggers that are handled on the next trigger pass.
+    /// * Passing `None` unequips all held items.
     /// # Example
     /// ```
     /// use saptest::{
@@ -525,15 +1012,18 @@ impl Team {
     /// ).unwrap();
     ///
     /// let dog = team.first().unwrap();
-    /// assert_eq!(dog.read().unwrap().item.as_ref().unwrap().name, FoodName::Garlic);
+    /// assert_eq!(dog.read().unwrap().items.perk.as_ref().unwrap().name, FoodName::Garlic);
     /// ```
     pub fn set_item(
         &mut self,
         pos: &Position,
         item: Option<Food>,
     ) -> Result<&mut Self, SAPTestError> {
-        // If item, assign/activate item. Otherwise, set to None.
-        if let Some(food) = item.as_ref() {
+        // Non-holdable foods are eaten. Otherwise, equip or remove item.
+        if let Some(food) = item
+            .as_ref()
+            .filter(|food| !food.holdable && !food.is_ailment())
+        {
             self.buy_food_behavior(
                 &Arc::new(RwLock::new(food.clone())),
                 self.first(),
@@ -549,7 +1039,11 @@ impl Team {
                 })?;
 
             for pet in affected_pets.into_iter() {
-                pet.write().unwrap().item = None
+                if let Some(food) = item.as_ref() {
+                    self.equip_item(&pet, food.clone(), None)
+                } else {
+                    pet.write().unwrap().unequip();
+                }
             }
         }
 
@@ -623,7 +1117,8 @@ impl Team {
         if pos < self.friends.len() {
             let new_pos: usize = if by.is_negative() {
                 let pos_by: usize = (-by).try_into()?;
-                (pos_by + pos).clamp(0, self.friends.len())
+                // Pet is removed before being reinserted.
+                (pos_by + pos).clamp(0, self.friends.len() - 1)
             } else {
                 pos.saturating_sub(by.try_into()?)
             };
@@ -758,6 +1253,75 @@ impl Team {
 
         Ok(self)
     }
+
+    /// Transform a pet on a team into another pet.
+    /// * The transformed pet takes the original pet's position and held items.
+    /// * Experience is kept if both pets are the same level.
+    /// * Emits a [`TRIGGER_SELF_TRANSFORMED`] and [`TRIGGER_ANY_TRANSFORMED`] but no summon or faint triggers.
+    /// * Errors if the pet isn't on the team.
+    ///
+    /// Returns the transformed pet.
+    ///
+    /// # Examples
+    /// ```
+    /// use saptest::{Food, FoodName, Pet, PetName, Team, TeamViewer};
+    ///
+    /// let mut caterpillar = Pet::new(PetName::Caterpillar, None, 3).unwrap();
+    /// caterpillar.items.attach(Food::try_from(FoodName::Melon).unwrap());
+    /// let mut team = Team::new(&[Some(caterpillar)], 5).unwrap();
+    ///
+    /// let caterpillar = team.first().unwrap();
+    /// let butterfly = Pet::new(PetName::Butterfly, None, 3).unwrap();
+    /// let butterfly = team.transform_pet(&caterpillar, butterfly).unwrap();
+    ///
+    /// let butterfly = butterfly.read().unwrap();
+    /// assert_eq!(butterfly.name, PetName::Butterfly);
+    /// assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Butterfly);
+    /// assert_eq!(butterfly.items.perk.as_ref().unwrap().name, FoodName::Melon);
+    /// ```
+    pub fn transform_pet(
+        &mut self,
+        pet: &Arc<RwLock<Pet>>,
+        mut into: Pet,
+    ) -> Result<Arc<RwLock<Pet>>, SAPTestError> {
+        let pos = self
+            .friends
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|friend| Arc::ptr_eq(friend, pet)))
+            .ok_or(SAPTestError::InvalidTeamAction {
+                subject: "Transform Pet".to_string(),
+                reason: format!("{} not on team {}.", pet.read().unwrap().name, self.name),
+            })?;
+
+        // Move items and experience to transformed pet.
+        {
+            let mut original = pet.write().unwrap();
+            into.items = std::mem::take(&mut original.items);
+            if original.lvl == into.lvl {
+                into.exp = original.exp;
+            }
+        }
+        into.id = Some(self.history.pet_count + 1);
+        into.pos = Some(pos);
+        into.team = Some(self.name.clone());
+
+        let rc_transformed_pet = Arc::new(RwLock::new(into));
+        reassign_effects(&rc_transformed_pet);
+
+        info!(target: "run", "(\"{}\")\nTransformed pet at position {} to {}.", self.name, pos, rc_transformed_pet.read().unwrap());
+
+        self.friends[pos] = Some(rc_transformed_pet.clone());
+        self.history.pet_count += 1;
+        if pos == 0 {
+            self.curr_pet = Some(Arc::downgrade(&rc_transformed_pet));
+        }
+
+        self.triggers.extend(get_transform_triggers(
+            Arc::downgrade(&rc_transformed_pet),
+            Arc::downgrade(pet),
+        ));
+        Ok(rc_transformed_pet)
+    }
 }
 
 impl Display for Team {
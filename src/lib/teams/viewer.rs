@@ -291,9 +291,99 @@ pub trait TeamViewer {
     /// assert_eq!(team.filled_slots(), 0);
     /// ```
     fn filled_slots(&self) -> usize;
+
+    /// Get all pets holding a perk with this [`FoodName`].
+    /// * Fainted pets are ignored.
+    /// # Example
+    /// ```
+    /// use saptest::{Food, FoodName, Pet, PetName, Team, TeamViewer};
+    ///
+    /// let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    /// ant.items.attach(Food::try_from(FoodName::Strawberry).unwrap());
+    /// let team = Team::new(&[Some(ant), Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    ///
+    /// let strawberry_pets = team.pets_with_perk(&FoodName::Strawberry);
+    /// assert_eq!(strawberry_pets.len(), 1);
+    /// assert_eq!(strawberry_pets[0].read().unwrap().name, PetName::Ant);
+    /// ```
+    fn pets_with_perk(&self, perk: &FoodName) -> Vec<Arc<RwLock<Pet>>>;
+
+    /// Count the perks held by pets on the [`Team`].
+    /// * Perks are ordered by their first holder from front to back.
+    /// * Fainted pets are ignored.
+    /// # Example
+    /// ```
+    /// use saptest::{Food, FoodName, Pet, PetName, Team, TeamViewer};
+    ///
+    /// let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    /// ant.items.attach(Food::try_from(FoodName::Strawberry).unwrap());
+    /// let team = Team::new(&[Some(ant.clone()), Some(ant), None], 5).unwrap();
+    ///
+    /// assert_eq!(team.count_perks(), [(FoodName::Strawberry, 2)]);
+    /// ```
+    fn count_perks(&self) -> Vec<(FoodName, usize)>;
+
+    /// Get all pets without any held item.
+    /// * Fainted pets are ignored.
+    /// # Example
+    /// ```
+    /// use saptest::{Food, FoodName, Pet, PetName, Team, TeamViewer};
+    ///
+    /// let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    /// ant.items.attach(Food::try_from(FoodName::Strawberry).unwrap());
+    /// let team = Team::new(&[Some(ant), Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    ///
+    /// let no_item_pets = team.pets_without_item();
+    /// assert_eq!(no_item_pets.len(), 1);
+    /// assert_eq!(no_item_pets[0].read().unwrap().name, PetName::Dog);
+    /// ```
+    fn pets_without_item(&self) -> Vec<Arc<RwLock<Pet>>>;
 }
 
 impl TeamViewer for Team {
+    fn pets_with_perk(&self, perk: &FoodName) -> Vec<Arc<RwLock<Pet>>> {
+        self.all()
+            .into_iter()
+            .filter(|pet| {
+                pet.read()
+                    .unwrap()
+                    .items
+                    .perk
+                    .as_ref()
+                    .is_some_and(|held_perk| &held_perk.name == perk)
+            })
+            .collect_vec()
+    }
+
+    fn count_perks(&self) -> Vec<(FoodName, usize)> {
+        let mut perk_counts: Vec<(FoodName, usize)> = vec![];
+        for pet in self.all() {
+            let Some(perk_name) = pet
+                .read()
+                .unwrap()
+                .items
+                .perk
+                .as_ref()
+                .map(|perk| perk.name.clone())
+            else {
+                continue;
+            };
+            if let Some((_, count)) = perk_counts.iter_mut().find(|(name, _)| name == &perk_name) {
+                *count += 1
+            } else {
+                perk_counts.push((perk_name, 1))
+            }
+        }
+        perk_counts
+    }
+
+    fn pets_without_item(&self) -> Vec<Arc<RwLock<Pet>>> {
+        self.all()
+            .into_iter()
+            .filter(|pet| pet.read().unwrap().items.is_empty())
+            .collect_vec()
+    }
+
     fn filled_slots(&self) -> usize {
         self.friends.iter().flatten().count()
     }
@@ -379,13 +469,13 @@ impl TeamViewer for Team {
                     EntityName::Food(item_name) => {
                         // If item_name is None. Means check pet has no food.
                         if item_name == &FoodName::None {
-                            pet.read().unwrap().item.is_none()
+                            pet.read().unwrap().items.is_empty()
                         } else {
                             pet.read()
                                 .unwrap()
-                                .item
-                                .as_ref()
-                                .map_or(false, |food| &food.name == item_name)
+                                .items
+                                .iter()
+                                .any(|food| &food.name == item_name)
                         }
                     }
                     _ => false,
@@ -412,9 +502,21 @@ impl TeamViewer for Team {
                         .any(|effect| effect.action == **action)
                 })
                 .collect_vec(),
-            EqualityCondition::HasPerk => all_pets
+            EqualityCondition::HasPerk
+            | EqualityCondition::Hurt
+            | EqualityCondition::Attack(_)
+            | EqualityCondition::Health(_)
+            | EqualityCondition::Experience(_)
+            | EqualityCondition::TriggerExact(_)
+            | EqualityCondition::Pack(_) => all_pets
                 .filter(|pet| eq_cond.matches_pet(&pet.read().unwrap()))
                 .collect_vec(),
+            EqualityCondition::IsHighestAttack => {
+                all_pets.max_set_by_key(|pet| pet.read().unwrap().stats.attack)
+            }
+            EqualityCondition::IsLowestAttack => {
+                all_pets.min_set_by_key(|pet| pet.read().unwrap().stats.attack)
+            }
             _ => unimplemented!("ItemCondition {eq_cond} not implemented for Team pets."),
         }
     }
@@ -111,7 +111,7 @@ pub fn test_moth_team() -> Team {
 
 pub fn test_hummingbird_team() -> Team {
     let mut duck_w_strawberry = Pet::try_from(PetName::Duck).unwrap();
-    duck_w_strawberry.item = Some(Food::try_from(FoodName::Strawberry).unwrap());
+    duck_w_strawberry.items.perk = Some(Box::new(Food::try_from(FoodName::Strawberry).unwrap()));
 
     Team::new(
         &[
@@ -221,7 +221,7 @@ pub fn test_bat_team() -> Team {
 
 pub fn test_atlantic_puffin_team() -> Team {
     let mut strawberry_ant = Pet::try_from(PetName::Ant).unwrap();
-    strawberry_ant.item = Some(Food::try_from(FoodName::Strawberry).unwrap());
+    strawberry_ant.items.perk = Some(Box::new(Food::try_from(FoodName::Strawberry).unwrap()));
 
     Team::new(
         &[
@@ -244,7 +244,7 @@ pub fn test_dove_team() -> Team {
         1,
     )
     .unwrap();
-    strawberry_ant.item = Some(Food::try_from(FoodName::Strawberry).unwrap());
+    strawberry_ant.items.perk = Some(Box::new(Food::try_from(FoodName::Strawberry).unwrap()));
 
     Team::new(
         &[
@@ -308,7 +308,7 @@ pub fn test_racoon_team() -> Team {
 
 pub fn test_toucan_team() -> Team {
     let mut toucan = Pet::try_from(PetName::Toucan).unwrap();
-    toucan.item = Some(Food::try_from(FoodName::Honey).unwrap());
+    toucan.items.perk = Some(Box::new(Food::try_from(FoodName::Honey).unwrap()));
     Team::new(
         &[
             Some(toucan),
@@ -780,7 +780,7 @@ pub fn test_hawk_team() -> Team {
 
 pub fn test_pelican_team() -> Team {
     let mut strawberry_ant = Pet::try_from(PetName::Ant).unwrap();
-    strawberry_ant.item = Some(Food::try_from(FoodName::Strawberry).unwrap());
+    strawberry_ant.items.perk = Some(Box::new(Food::try_from(FoodName::Strawberry).unwrap()));
 
     Team::new(
         &[
@@ -1322,7 +1322,7 @@ pub fn test_capybara_team() -> Team {
 
 pub fn test_cassowary_team() -> Team {
     let mut cassowary = Pet::try_from(PetName::Cassowary).unwrap();
-    cassowary.item = Some(Food::try_from(FoodName::Strawberry).unwrap());
+    cassowary.items.perk = Some(Box::new(Food::try_from(FoodName::Strawberry).unwrap()));
     Team::new(&[Some(cassowary)], 5).unwrap()
 }
 
@@ -1420,7 +1420,7 @@ pub fn test_jerboa_team() -> Team {
 
 pub fn test_mole_team() -> Team {
     let mut ant_with_honey = Pet::try_from(PetName::Ant).unwrap();
-    ant_with_honey.item = Some(Food::try_from(FoodName::Honey).unwrap());
+    ant_with_honey.items.perk = Some(Box::new(Food::try_from(FoodName::Honey).unwrap()));
     Team::new(
         &[
             Some(Pet::try_from(PetName::Mole).unwrap()),
@@ -70,6 +70,9 @@ mod test_team_t5;
 #[cfg(test)]
 mod test_team_t6;
 
+#[cfg(test)]
+mod test_team_puppy;
+
 #[cfg(test)]
 mod test_hard_mode;
 
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use itertools::Itertools;
+
 use crate::{
     effects::{
         actions::{Action, GainType, StatChangeType},
@@ -10,12 +12,13 @@ use crate::{
     },
     foods::{food::Food, names::FoodName},
     pets::{combat::PetCombat, names::PetName, pet::Pet},
+    teams::counters::CounterKey,
     teams::{
         combat::TeamCombat,
         team::{Team, TeamFightOutcome},
         viewer::TeamViewer,
     },
-    tests::common::test_ant_team,
+    tests::common::{test_ant_team, test_gorilla_team},
     Effect, EntityName, ItemCondition, Shop, ShopItem, ShopItemViewer, ShopViewer, TeamEffects,
     TeamShopping,
 };
@@ -33,11 +36,75 @@ fn test_custom_food() {
             action: Action::Gain(GainType::DefaultItem(FoodName::Weak)),
             uses: Some(1),
             temp: true,
+            ..Default::default()
         }),
     );
     assert!(custom_food.is_ok())
 }
 
+#[test]
+fn test_custom_food_constructor() {
+    let effect_stats = Statistics::new(3, 3).unwrap();
+    let mut custom_food = Food::custom(
+        "Pudding",
+        2,
+        Effect {
+            owner: None,
+            trigger: TRIGGER_START_BATTLE,
+            target: Target::Friend,
+            position: Position::OnSelf,
+            action: Action::Add(StatChangeType::Static(effect_stats)),
+            uses: Some(1),
+            temp: true,
+            ..Default::default()
+        },
+        true,
+        false,
+    );
+    assert_eq!(custom_food.name, FoodName::Custom("Pudding".to_string()));
+    assert!(custom_food.holdable && !custom_food.temp);
+
+    // Custom food survives serialization.
+    let json = serde_json::to_string(&custom_food).unwrap();
+    let deserialized_food: Food = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized_food, custom_food);
+
+    // Give custom food to pet and activate it.
+    let mut team = test_ant_team();
+    let mut enemy_team = test_ant_team();
+    let ant = team.first().unwrap();
+    let ant_stats = ant.read().unwrap().stats;
+    custom_food.ability.assign_owner(Some(&ant));
+    ant.write().unwrap().items.attach(custom_food);
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    assert_eq!(ant.read().unwrap().stats, ant_stats + effect_stats);
+}
+
+#[test]
+fn test_food_new_at_level() {
+    let apple = Food::try_from(FoodName::Apple).unwrap();
+    let Action::Add(StatChangeType::Static(apple_stats)) = apple.ability.action else {
+        panic!("Apple should add stats.")
+    };
+    for lvl in 1..=3 {
+        let lvl_apple = Food::new_at_level(&FoodName::Apple, lvl).unwrap();
+        assert_eq!(
+            lvl_apple.ability.action,
+            Action::Add(StatChangeType::Static(
+                apple_stats * Statistics::new(lvl, lvl).unwrap()
+            ))
+        );
+    }
+    // Foods without static stats are unchanged.
+    assert_eq!(
+        Food::new_at_level(&FoodName::Coconut, 3).unwrap(),
+        Food::try_from(FoodName::Coconut).unwrap()
+    );
+    assert!(Food::new_at_level(&FoodName::Apple, 0).is_err());
+}
+
 #[test]
 fn test_set_food_item() {
     let mut team = Team::new(&vec![Some(Pet::try_from(PetName::Ant).unwrap()); 5], 5).unwrap();
@@ -53,7 +120,7 @@ fn test_set_food_item() {
     // Give first pet garlic.
     team.set_item(&first_pos, Some(garlic)).unwrap();
     assert_eq!(
-        first_pet.read().unwrap().item.as_ref().unwrap().name,
+        first_pet.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Garlic
     );
 
@@ -65,7 +132,7 @@ fn test_set_food_item() {
     team.set_item(&first_pos, Some(apple)).unwrap();
     // First pet still has garlic.
     assert_eq!(
-        first_pet.read().unwrap().item.as_ref().unwrap().name,
+        first_pet.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Garlic
     );
     // And gets apple buff.
@@ -110,6 +177,7 @@ fn test_food_override_effect() {
             })),
             uses: Some(1),
             temp: true,
+            ..Default::default()
         }),
     );
     assert!(buffed_apple.is_ok())
@@ -125,7 +193,7 @@ fn test_attack_meat() {
         1,
     )
     .unwrap();
-    dog_w_meat.item = Some(Food::try_from(FoodName::MeatBone).unwrap());
+    dog_w_meat.items.perk = Some(Box::new(Food::try_from(FoodName::MeatBone).unwrap()));
 
     let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
 
@@ -176,7 +244,7 @@ fn test_attack_garlic() {
     .unwrap();
     let mut dog_w_garlic = dog.clone();
 
-    dog_w_garlic.item = Some(Food::try_from(FoodName::Garlic).unwrap());
+    dog_w_garlic.items.perk = Some(Box::new(Food::try_from(FoodName::Garlic).unwrap()));
 
     assert_eq!(
         dog_w_garlic.stats,
@@ -216,7 +284,7 @@ fn test_attack_garlic_min_1() {
         1,
     )
     .unwrap();
-    dog_w_garlic.item = Some(Food::try_from(FoodName::Garlic).unwrap());
+    dog_w_garlic.items.perk = Some(Box::new(Food::try_from(FoodName::Garlic).unwrap()));
     let mut ant = Pet::new(
         PetName::Ant,
         Some(Statistics {
@@ -265,9 +333,12 @@ fn test_attack_melon() {
         1,
     )
     .unwrap();
-    dog_w_melon.item = Some(Food::try_from(FoodName::Melon).unwrap());
+    dog_w_melon.items.perk = Some(Box::new(Food::try_from(FoodName::Melon).unwrap()));
 
-    assert_eq!(dog_w_melon.item.as_ref().unwrap().ability.uses, Some(1));
+    assert_eq!(
+        dog_w_melon.items.perk.as_ref().unwrap().ability.uses,
+        Some(1)
+    );
 
     let original_stats = dog_w_melon.stats;
 
@@ -294,7 +365,10 @@ fn test_attack_melon() {
     );
 
     // Negating one attack drops uses to 0.
-    assert_eq!(dog_w_melon.item.as_ref().unwrap().ability.uses, Some(0));
+    assert_eq!(
+        dog_w_melon.items.perk.as_ref().unwrap().ability.uses,
+        Some(0)
+    );
 }
 
 #[test]
@@ -308,9 +382,12 @@ fn test_attack_steak() {
         1,
     )
     .unwrap();
-    dog_w_steak.item = Some(Food::try_from(FoodName::Steak).unwrap());
+    dog_w_steak.items.perk = Some(Box::new(Food::try_from(FoodName::Steak).unwrap()));
 
-    assert_eq!(dog_w_steak.item.as_ref().unwrap().ability.uses, Some(1));
+    assert_eq!(
+        dog_w_steak.items.perk.as_ref().unwrap().ability.uses,
+        Some(1)
+    );
 
     let mut smol_ant = Pet::new(
         PetName::Ant,
@@ -334,7 +411,10 @@ fn test_attack_steak() {
     );
 
     // Negating one attack drops uses to 0.
-    assert_eq!(dog_w_steak.item.as_ref().unwrap().ability.uses, Some(0));
+    assert_eq!(
+        dog_w_steak.items.perk.as_ref().unwrap().ability.uses,
+        Some(0)
+    );
 }
 
 #[test]
@@ -348,10 +428,13 @@ fn test_attack_coconut() {
         1,
     )
     .unwrap();
-    dog_w_coconut.item = Some(Food::try_from(FoodName::Coconut).unwrap());
+    dog_w_coconut.items.perk = Some(Box::new(Food::try_from(FoodName::Coconut).unwrap()));
     let original_dog_w_coconut_stats = dog_w_coconut.stats;
 
-    assert_eq!(dog_w_coconut.item.as_ref().unwrap().ability.uses, Some(1));
+    assert_eq!(
+        dog_w_coconut.items.perk.as_ref().unwrap().ability.uses,
+        Some(1)
+    );
 
     let mut big_ant = Pet::new(
         PetName::Ant,
@@ -369,13 +452,16 @@ fn test_attack_coconut() {
     assert_eq!(dog_w_coconut.stats, original_dog_w_coconut_stats);
 
     // Negating one attack drops uses to 0.
-    assert_eq!(dog_w_coconut.item.as_ref().unwrap().ability.uses, Some(0));
+    assert_eq!(
+        dog_w_coconut.items.perk.as_ref().unwrap().ability.uses,
+        Some(0)
+    );
 }
 
 #[test]
 fn test_attack_peanuts() {
     let mut scorpion = Pet::try_from(PetName::Scorpion).unwrap();
-    scorpion.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    scorpion.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
 
     let mut big_ant = Pet::new(
         PetName::Ant,
@@ -401,11 +487,11 @@ fn test_attack_peanuts() {
 #[test]
 fn test_attack_peanuts_coconut() {
     let mut dog_w_coconut = Pet::try_from(PetName::Dog).unwrap();
-    dog_w_coconut.item = Some(Food::try_from(FoodName::Coconut).unwrap());
+    dog_w_coconut.items.perk = Some(Box::new(Food::try_from(FoodName::Coconut).unwrap()));
     let original_dog_w_coconut_stats = dog_w_coconut.stats;
 
     let mut scorpion = Pet::try_from(PetName::Scorpion).unwrap();
-    scorpion.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    scorpion.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
 
     // Dog survives attack with coconut and takes no damage.
     dog_w_coconut.attack(&mut scorpion);
@@ -416,11 +502,11 @@ fn test_attack_peanuts_coconut() {
 #[test]
 fn test_attack_peanuts_melon() {
     let mut dog_w_melon = Pet::try_from(PetName::Dog).unwrap();
-    dog_w_melon.item = Some(Food::try_from(FoodName::Melon).unwrap());
+    dog_w_melon.items.perk = Some(Box::new(Food::try_from(FoodName::Melon).unwrap()));
     let original_dog_w_melon_stats = dog_w_melon.stats;
 
     let mut scorpion = Pet::try_from(PetName::Scorpion).unwrap();
-    scorpion.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    scorpion.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
 
     // Dog survives attack with melon and takes no damage.
     dog_w_melon.attack(&mut scorpion);
@@ -439,7 +525,7 @@ fn test_attack_peanuts_melon_overflow() {
         1,
     )
     .unwrap();
-    dog_w_melon.item = Some(Food::try_from(FoodName::Melon).unwrap());
+    dog_w_melon.items.perk = Some(Box::new(Food::try_from(FoodName::Melon).unwrap()));
 
     // Scorpion has just enough attack (> 20) to deliver death's touch.
     let mut scorpion = Pet::new(
@@ -453,7 +539,7 @@ fn test_attack_peanuts_melon_overflow() {
     .unwrap();
     // Note: Individually fighting pets doesn't trigger gaining peanuts.
     // Adding manually here.
-    scorpion.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    scorpion.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
 
     dog_w_melon.attack(&mut scorpion);
 
@@ -592,7 +678,7 @@ fn test_shop_sleeping_pill() {
     team.set_seed(Some(42))
         .set_shop_tier(4)
         .unwrap()
-        .set_shop_seed(Some(332))
+        .set_shop_seed(Some(23))
         .open_shop()
         .unwrap();
 
@@ -619,6 +705,50 @@ fn test_shop_sleeping_pill() {
     assert_eq!(team.fainted.len(), 1)
 }
 
+#[test]
+fn test_ailment_attachments() {
+    let mut team = test_ant_team();
+    let first_pet = team.first().unwrap();
+    first_pet
+        .write()
+        .unwrap()
+        .gain_ailment(Food::try_from(FoodName::Ink).unwrap())
+        .unwrap();
+
+    let mut gain_effect = |gain_type: GainType| {
+        let mut effect = Effect::builder()
+            .set_target(Target::Friend)
+            .set_position(Position::First)
+            .set_action(Action::Gain(gain_type))
+            .build();
+        effect.assign_owner(Some(&first_pet));
+        team.apply_effect(&effect, None).unwrap();
+    };
+    let held_items = || {
+        let pet = first_pet.read().unwrap();
+        pet.items.iter().map(|item| item.name.clone()).collect_vec()
+    };
+
+    // Gaining a food doesn't remove the ailment. Both are held.
+    gain_effect(GainType::DefaultItem(FoodName::Garlic));
+    assert_eq!(held_items(), [FoodName::Garlic, FoodName::Ink]);
+
+    // But a new ailment replaces it.
+    gain_effect(GainType::DefaultItem(FoodName::Weak));
+    assert_eq!(held_items(), [FoodName::Garlic, FoodName::Weak]);
+
+    // Removing the ailment keeps the perk.
+    gain_effect(GainType::NoAilment);
+    assert_eq!(held_items(), [FoodName::Garlic]);
+
+    // Only ailments can be gained as ailments.
+    assert!(first_pet
+        .write()
+        .unwrap()
+        .gain_ailment(Food::try_from(FoodName::Garlic).unwrap())
+        .is_err());
+}
+
 #[test]
 fn test_shop_end_turn_foods() {
     let mut team = test_ant_team();
@@ -691,7 +821,7 @@ fn test_shop_end_turn_foods() {
 #[test]
 fn test_shop_start_turn_foods() {
     let mut ant = Pet::try_from(PetName::Ant).unwrap();
-    ant.item = Some(Food::try_from(FoodName::Grapes).unwrap());
+    ant.items.perk = Some(Box::new(Food::try_from(FoodName::Grapes).unwrap()));
 
     let mut team = Team::new(&[Some(ant)], 5).unwrap();
 
@@ -715,7 +845,7 @@ fn test_direct_attack_pepper() {
     )
     .unwrap();
 
-    ant.item = Some(Food::try_from(FoodName::Pepper).unwrap());
+    ant.items.perk = Some(Box::new(Food::try_from(FoodName::Pepper).unwrap()));
 
     let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
 
@@ -726,7 +856,7 @@ fn test_direct_attack_pepper() {
 
     // Survives single hit. Pepper uses depleted.
     assert!(ant.stats.health == 1);
-    assert!(ant.item.as_ref().unwrap().ability.uses == Some(0));
+    assert!(ant.items.perk.as_ref().unwrap().ability.uses == Some(0));
 
     // Second attack.
     ant.attack(&mut mammoth);
@@ -746,7 +876,7 @@ fn test_indirect_attack_pepper() {
         1,
     )
     .unwrap();
-    ant.item = Some(Food::try_from(FoodName::Pepper).unwrap());
+    ant.items.perk = Some(Box::new(Food::try_from(FoodName::Pepper).unwrap()));
 
     // At start Ant has 1 health.
     assert!(ant.stats.health == 1);
@@ -759,7 +889,7 @@ fn test_indirect_attack_pepper() {
 
     // Survives single hit. Pepper uses depleted.
     assert!(ant.stats.health == 1);
-    assert!(ant.item.as_ref().unwrap().ability.uses == Some(0));
+    assert!(ant.items.perk.as_ref().unwrap().ability.uses == Some(0));
 
     ant.indirect_attack(&dmg);
 
@@ -778,10 +908,10 @@ fn test_direct_attack_pepper_peanut_1hp() {
         1,
     )
     .unwrap();
-    ant.item = Some(Food::try_from(FoodName::Pepper).unwrap());
+    ant.items.perk = Some(Box::new(Food::try_from(FoodName::Pepper).unwrap()));
 
     let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
-    mammoth.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    mammoth.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
 
     assert!(ant.stats.health == 1);
 
@@ -789,7 +919,7 @@ fn test_direct_attack_pepper_peanut_1hp() {
 
     // Ant resists fainting surviving with 1 health despite peanut.
     assert!(ant.stats.health == 1);
-    assert!(ant.item.as_ref().unwrap().ability.uses == Some(0));
+    assert!(ant.items.perk.as_ref().unwrap().ability.uses == Some(0));
 }
 
 #[test]
@@ -803,17 +933,17 @@ fn test_direct_attack_pepper_peanut() {
         1,
     )
     .unwrap();
-    big_ant.item = Some(Food::try_from(FoodName::Pepper).unwrap());
+    big_ant.items.perk = Some(Box::new(Food::try_from(FoodName::Pepper).unwrap()));
 
     let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
-    mammoth.item = Some(Food::try_from(FoodName::Peanut).unwrap());
+    mammoth.items.perk = Some(Box::new(Food::try_from(FoodName::Peanut).unwrap()));
 
     big_ant.attack(&mut mammoth);
 
     // Big ant faints.
     // https://superautopets.fandom.com/wiki/Pepper
     assert!(big_ant.stats.health == 0);
-    assert!(big_ant.item.as_ref().unwrap().ability.uses == Some(0));
+    assert!(big_ant.items.perk.as_ref().unwrap().ability.uses == Some(0));
 }
 
 #[test]
@@ -827,18 +957,18 @@ fn test_direct_attack_cheese() {
         1,
     )
     .unwrap();
-    ant.item = Some(Food::try_from(FoodName::Cheese).unwrap());
+    ant.items.perk = Some(Box::new(Food::try_from(FoodName::Cheese).unwrap()));
 
     let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
 
     // Single use.
-    assert!(ant.item.as_ref().unwrap().ability.uses == Some(1));
+    assert!(ant.items.perk.as_ref().unwrap().ability.uses == Some(1));
     // Mammoth has 10 health
     assert!(mammoth.stats.health == 10);
 
     ant.attack(&mut mammoth);
 
-    assert!(ant.item.as_ref().unwrap().ability.uses == Some(0));
+    assert!(ant.items.perk.as_ref().unwrap().ability.uses == Some(0));
     // Mammoth has 0 health
     assert!(mammoth.stats.health == 0);
 }
@@ -854,7 +984,7 @@ fn test_direct_attack_fortune_cookie() {
         1,
     )
     .unwrap();
-    ant.item = Some(Food::try_from(FoodName::FortuneCookie).unwrap());
+    ant.items.perk = Some(Box::new(Food::try_from(FoodName::FortuneCookie).unwrap()));
     ant.seed = Some(12);
 
     let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
@@ -876,7 +1006,7 @@ fn test_direct_attack_fortune_cookie() {
 #[test]
 fn test_battle_pineapple() {
     let mut mosq = Pet::try_from(PetName::Mosquito).unwrap();
-    mosq.item = Some(Food::try_from(FoodName::Pineapple).unwrap());
+    mosq.items.perk = Some(Box::new(Food::try_from(FoodName::Pineapple).unwrap()));
 
     let mut team = Team::new(&[Some(mosq)], 5).unwrap();
     let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Mammoth).unwrap())], 5).unwrap();
@@ -907,7 +1037,7 @@ fn test_battle_pineapple() {
 #[test]
 fn test_shop_canned_food() {
     let mut team = test_ant_team();
-    let mut custom_shop = Shop::new(1, Some(12)).unwrap();
+    let mut custom_shop = Shop::new(1, Some(3)).unwrap();
     custom_shop
         .add_item(ShopItem::from(
             Food::try_from(FoodName::CannedFood).unwrap(),
@@ -937,7 +1067,7 @@ fn test_shop_canned_food() {
     assert!(pig.attack_stat() == Some(5) && pig.health_stat() == Some(2));
 
     // Roll shop.
-    team.set_shop_seed(Some(13)).roll_shop().unwrap();
+    team.set_shop_seed(Some(0)).roll_shop().unwrap();
 
     let mosq = first_shop_pet_query(&team);
     // Future pets get buff as permanent stats added to shop.
@@ -1027,3 +1157,446 @@ fn test_battle_popcorns() {
         first_ant.read().unwrap().tier
     );
 }
+
+/// Team of ants with a shop stocked with a single food.
+fn ant_team_with_shop_food(food_name: FoodName, num_ants: usize) -> Team {
+    let ants = vec![Some(Pet::try_from(PetName::Ant).unwrap()); num_ants];
+    let mut team = Team::new(&ants, 5).unwrap();
+    let mut custom_shop = Shop::default();
+    custom_shop
+        .add_item(ShopItem::from(Food::try_from(&food_name).unwrap()))
+        .unwrap();
+    team.replace_shop(custom_shop).unwrap().open_shop().unwrap();
+    team
+}
+
+#[test]
+fn test_battle_egg() {
+    let mut team = test_ant_team();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    enemy_team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    // Give first pet egg on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Egg).unwrap()),
+    )
+    .unwrap();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Enemy takes 2 damage at start of battle.
+    assert_eq!(
+        enemy_team.first().unwrap().read().unwrap().stats,
+        Statistics::new(1, 48).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_blueberry() {
+    let mut team = ant_team_with_shop_food(FoodName::Blueberry, 1);
+    let ant = team.first().unwrap();
+    let ant_stats = ant.read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Ant gains (1,1) and a strawberry.
+    assert_eq!(
+        ant.read().unwrap().stats,
+        ant_stats + Statistics::new(1, 1).unwrap()
+    );
+    assert_eq!(
+        ant.read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .map(|food| food.name.clone()),
+        Some(FoodName::Strawberry)
+    );
+}
+
+#[test]
+fn test_shop_cherry() {
+    let mut team = test_ant_team();
+    let mut team_w_cherry = test_ant_team();
+    team.open_shop().unwrap().close_shop().unwrap();
+    // Give cherry during the turn.
+    team_w_cherry
+        .open_shop()
+        .unwrap()
+        .set_item(
+            &Position::First,
+            Some(Food::try_from(&FoodName::Cherry).unwrap()),
+        )
+        .unwrap();
+    team_w_cherry.close_shop().unwrap();
+
+    team.open_shop().unwrap();
+    team_w_cherry.open_shop().unwrap();
+
+    // Gain 1 gold at start of next turn.
+    assert_eq!(team_w_cherry.gold(), team.gold() + 1);
+}
+
+#[test]
+fn test_shop_chocolate_cake() {
+    let mut team = ant_team_with_shop_food(FoodName::ChocolateCake, 2);
+    let ant = team.first().unwrap();
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Ant gains experience and faints.
+    assert_eq!(ant.read().unwrap().exp, 1);
+    assert_eq!(ant.read().unwrap().stats.health, 0);
+    assert_eq!(team.all().len(), 1);
+}
+
+#[test]
+fn test_shop_rice() {
+    let mut team = ant_team_with_shop_food(FoodName::Rice, 1);
+    let ant = team.first().unwrap();
+    let ant_stats = ant.read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+    // First rice gives (1,1).
+    assert_eq!(
+        ant.read().unwrap().stats,
+        ant_stats + Statistics::new(1, 1).unwrap()
+    );
+
+    team.shop
+        .add_item(ShopItem::from(Food::try_from(&FoodName::Rice).unwrap()))
+        .unwrap();
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+    // Second rice gives (2,2).
+    assert_eq!(
+        ant.read().unwrap().stats,
+        ant_stats + Statistics::new(3, 3).unwrap()
+    );
+    assert_eq!(team.counters.get(&CounterKey::RiceEaten), Some(2));
+}
+
+#[test]
+fn test_battle_eggplant() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Gorilla).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+
+    // Give last pet eggplant on first team.
+    team.set_item(
+        &Position::Last,
+        Some(Food::try_from(&FoodName::Eggplant).unwrap()),
+    )
+    .unwrap();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Dog and first enemy swap positions.
+    assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Dog);
+    assert_eq!(
+        enemy_team.nth(2).unwrap().read().unwrap().name,
+        PetName::Gorilla
+    );
+}
+
+#[test]
+fn test_shop_waffle() {
+    let mut team = ant_team_with_shop_food(FoodName::Waffle, 1);
+    team.set_level(&Position::First, 2).unwrap();
+    let ant = team.first().unwrap();
+    let ant_stats = ant.read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Level 2 ant gains (2,2).
+    assert_eq!(
+        ant.read().unwrap().stats,
+        ant_stats + Statistics::new(2, 2).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_pie() {
+    let mut team = ant_team_with_shop_food(FoodName::Pie, 1);
+    let ant = team.first().unwrap();
+    let ant_stats = ant.read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Ant gains (4,4).
+    assert_eq!(
+        ant.read().unwrap().stats,
+        ant_stats + Statistics::new(4, 4).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_donut() {
+    let mut team = ant_team_with_shop_food(FoodName::Donut, 3);
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Melon).unwrap()),
+    )
+    .unwrap();
+    let ant_stats = team.first().unwrap().read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Ant with perk gains nothing. Others gain (3,3).
+    let stats = team
+        .all()
+        .iter()
+        .map(|pet| pet.read().unwrap().stats)
+        .collect_vec();
+    let buffed_stats = ant_stats + Statistics::new(3, 3).unwrap();
+    assert_eq!(stats, [ant_stats, buffed_stats, buffed_stats]);
+}
+
+#[test]
+fn test_shop_pancakes() {
+    let mut team = ant_team_with_shop_food(FoodName::Pancakes, 3);
+    let ant_stats = team.first().unwrap().read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // All ants gain (2,2).
+    assert!(team
+        .all()
+        .iter()
+        .all(|pet| pet.read().unwrap().stats == ant_stats + Statistics::new(2, 2).unwrap()));
+}
+
+#[test]
+fn test_attack_skewer() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = team.clone();
+
+    // Give first pet skewer on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Skewer).unwrap()),
+    )
+    .unwrap();
+
+    let enemy_second_ant = enemy_team.nth(1).unwrap();
+    let enemy_second_ant_health = enemy_second_ant.read().unwrap().stats.health;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Skewer damages the enemy behind the attacked pet.
+    assert!(enemy_second_ant.read().unwrap().stats.health < enemy_second_ant_health);
+}
+
+#[test]
+fn test_attack_salt() {
+    let mut mammoth = Pet::try_from(PetName::Mammoth).unwrap();
+    mammoth.stats = Statistics::new(3, 50).unwrap();
+    mammoth.items.perk = Some(Box::new(Food::try_from(FoodName::Salt).unwrap()));
+
+    let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    ant.stats = Statistics::new(1, 50).unwrap();
+    let mut other_mammoth = Pet::try_from(PetName::Mammoth).unwrap();
+    other_mammoth.stats = Statistics::new(1, 50).unwrap();
+
+    mammoth.attack(&mut ant);
+    mammoth.attack(&mut other_mammoth);
+
+    // Double damage to lower tier ant.
+    assert_eq!(ant.stats.health, 44);
+    // Normal damage to pet of same tier.
+    assert_eq!(other_mammoth.stats.health, 47);
+}
+
+#[test]
+fn test_attack_lettuce_avocado() {
+    for food_name in [FoodName::Lettuce, FoodName::Avocado] {
+        let mut dog = Pet::new(
+            PetName::Dog,
+            Some(Statistics {
+                attack: 3,
+                health: 4,
+            }),
+            1,
+        )
+        .unwrap();
+        let mut other_dog = dog.clone();
+        let mut dog_w_food = dog.clone();
+        dog_w_food.items.perk = Some(Box::new(Food::try_from(&food_name).unwrap()));
+
+        dog.attack(&mut other_dog);
+        dog.attack(&mut dog_w_food);
+
+        // Food prevents some damage from attack.
+        assert!(dog_w_food.stats.health > other_dog.stats.health);
+    }
+}
+
+#[test]
+fn test_battle_banana() {
+    let mut team = test_ant_team();
+    let mut enemy_team = test_ant_team();
+
+    // Give first pet banana on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Banana).unwrap()),
+    )
+    .unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Ant faints and monkey summoned in its place.
+    assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Monkey)
+}
+
+#[test]
+fn test_battle_potato() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+
+    // Give first pet potato on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Potato).unwrap()),
+    )
+    .unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+
+    let dog = team.nth(1).unwrap();
+    let dog_attack = dog.read().unwrap().stats.attack;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Ant faints and dog behind gains stats.
+    assert!(dog.read().unwrap().stats.attack > dog_attack);
+}
+
+#[test]
+fn test_battle_onion() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+
+    // Give first pet onion on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Onion).unwrap()),
+    )
+    .unwrap();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Ant pushed to the back.
+    assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Dog);
+    assert_eq!(team.last().unwrap().read().unwrap().name, PetName::Ant);
+}
+
+#[test]
+fn test_battle_pita_bread() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    for pet in team.all().into_iter().chain(enemy_team.all()) {
+        pet.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    }
+
+    // Give first pet pita bread on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::PitaBread).unwrap()),
+    )
+    .unwrap();
+
+    let dog = team.first().unwrap();
+    let enemy_dog = enemy_team.first().unwrap();
+    team.fight(&mut enemy_team).unwrap();
+
+    // Dog hurt but recovers some health.
+    assert!(dog.read().unwrap().stats.health > enemy_dog.read().unwrap().stats.health);
+}
+
+#[test]
+fn test_battle_pretzel() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    for pet in team.all().into_iter().chain(enemy_team.all()) {
+        pet.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    }
+
+    // Give first pet pretzel on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Pretzel).unwrap()),
+    )
+    .unwrap();
+
+    let enemy_dog = enemy_team.first().unwrap();
+    team.fight(&mut enemy_team).unwrap();
+
+    // Dog gains attack before attacking.
+    assert!(enemy_dog.read().unwrap().stats.health < 49);
+}
+
+#[test]
+fn test_battle_tomato() {
+    let mut team = test_ant_team();
+    let mut enemy_team = test_gorilla_team();
+
+    // Give first pet tomato on first team.
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(&FoodName::Tomato).unwrap()),
+    )
+    .unwrap();
+
+    let last_enemy = enemy_team.last().unwrap();
+    let last_enemy_health = last_enemy.read().unwrap().stats.health;
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Last enemy damaged at start of battle.
+    assert!(last_enemy.read().unwrap().stats.health < last_enemy_health);
+}
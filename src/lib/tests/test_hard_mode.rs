@@ -1,4 +1,11 @@
-use crate::{teams::team::TeamFightOutcome, Pet, PetName, Team, TeamCombat, TeamViewer};
+use itertools::Itertools;
+
+use crate::{
+    teams::{counters::CounterKey, team::TeamFightOutcome},
+    tests::common::test_ant_team,
+    FoodName, Pet, PetName, Statistics, Team, TeamCombat, TeamEffects, TeamShopping, TeamViewer,
+    Toy, ToyName,
+};
 
 #[test]
 fn test_golden_effect() {
@@ -11,9 +18,7 @@ fn test_golden_effect() {
     let mut team = Team::new(&pets, 5).unwrap();
     let mut enemy_team = Team::new(&enemy_pets, 5).unwrap();
 
-    team.counters
-        .entry("Trumpets".to_owned())
-        .and_modify(|trumpets| *trumpets += 5);
+    team.counters.add(&CounterKey::Trumpets, 5);
 
     let mut outcome = team.fight(&mut enemy_team).unwrap();
     while let TeamFightOutcome::None = outcome {
@@ -27,3 +32,116 @@ fn test_golden_effect() {
         PetName::GoldenRetriever
     );
 }
+
+#[test]
+fn test_hard_mode_run() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.set_shop_seed(Some(42)).set_hard_mode(true);
+
+    let mut active_toys = vec![];
+    for _ in 0..8 {
+        team.open_shop().unwrap();
+
+        // A single hard mode toy from the shop's tier is active during the shop phase.
+        let hard_mode_toys = team.toys.iter().filter(|toy| toy.hard_mode).collect_vec();
+        assert_eq!(hard_mode_toys.len(), 1);
+        let toy = hard_mode_toys[0];
+        assert!(toy.tier <= team.shop_tier());
+
+        // Shop prices are raised by the dice and open piggy bank.
+        let (roll_cost, pet_cost) = match toy.name {
+            ToyName::Dice => (2, 3),
+            ToyName::OpenPiggyBank => (1, 4),
+            _ => (1, 3),
+        };
+        assert_eq!(team.get_shop().roll_cost(), roll_cost);
+        assert!(team.get_shop().pets.iter().all(|pet| pet.cost == pet_cost));
+        active_toys.push(toy.name.clone());
+
+        team.close_shop().unwrap();
+        team.fight(&mut enemy_team).unwrap();
+        team.restore();
+        enemy_team.restore();
+    }
+    // Each toy lasts two turns.
+    assert!(active_toys.chunks(2).all(|toys| toys[0] == toys[1]));
+}
+
+#[test]
+fn test_hard_mode_toys_across_turns() {
+    // Unicycle buffs one more enemy each turn.
+    for turn in 1..=3 {
+        let mut team = test_ant_team();
+        let mut enemy_team = test_ant_team();
+        team.toys.push(Toy::try_from(ToyName::Unicycle).unwrap());
+        team.history.curr_turn = turn;
+
+        let enemy_stats = enemy_team
+            .all()
+            .iter()
+            .map(|pet| pet.read().unwrap().stats)
+            .collect_vec();
+        team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+        let n_buffed = enemy_team
+            .all()
+            .iter()
+            .zip(enemy_stats)
+            .filter(|(pet, stats)| {
+                pet.read().unwrap().stats == *stats + Statistics::new(1, 1).unwrap()
+            })
+            .count();
+        assert_eq!(n_buffed, turn);
+    }
+
+    // Rock bag deals 2 more damage each turn.
+    for turn in 1..=3 {
+        let mut team = Team::new(
+            &[
+                Some(Pet::try_from(PetName::Beaver).unwrap()),
+                Some(Pet::try_from(PetName::Gorilla).unwrap()),
+            ],
+            5,
+        )
+        .unwrap();
+        let mut enemy_team = test_ant_team();
+        team.toys.push(Toy::try_from(ToyName::RockBag).unwrap());
+        team.history.curr_turn = turn;
+
+        let gorilla = team.nth(1).unwrap();
+        let gorilla_health = gorilla.read().unwrap().stats.health;
+        team.fight(&mut enemy_team).unwrap();
+        assert_eq!(
+            gorilla_health - gorilla.read().unwrap().stats.health,
+            2 * turn as isize
+        );
+    }
+
+    // Handkerchief and pen affect one more friend each shop tier.
+    for (toy_name, ailment) in [
+        (ToyName::Handkerchief, FoodName::Weak),
+        (ToyName::Pen, FoodName::Ink),
+    ] {
+        for tier in 1..=3 {
+            let mut team = test_ant_team();
+            let mut enemy_team = test_ant_team();
+            team.set_shop_tier(tier).unwrap();
+            team.toys.push(Toy::try_from(toy_name.clone()).unwrap());
+
+            team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+            let n_affected = team
+                .all()
+                .iter()
+                .filter(|pet| {
+                    pet.read()
+                        .unwrap()
+                        .items
+                        .ailment
+                        .as_ref()
+                        .is_some_and(|item| item.name == ailment)
+                })
+                .count();
+            assert_eq!(n_affected, tier);
+        }
+    }
+}
@@ -149,19 +149,20 @@ fn test_toy_handerkerchief() {
 
     let pets = team.all();
 
-    assert!(pets.iter().all(|pet| pet.read().unwrap().item.is_none()));
+    assert!(pets.iter().all(|pet| pet.read().unwrap().items.is_empty()));
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
 
     // At shop tier of two, only first two friends get weakness.
     assert!(pets.get(0..=1).unwrap().iter().all(|pet| pet
         .read()
         .unwrap()
-        .item
+        .items
+        .ailment
         .as_ref()
         .unwrap()
         .name
         == FoodName::Weak));
-    assert!(pets[2].read().unwrap().item.is_none())
+    assert!(pets[2].read().unwrap().items.is_empty())
 }
 
 #[test]
@@ -177,19 +178,20 @@ fn test_toy_pen() {
 
     let pets = team.all();
 
-    assert!(pets.iter().all(|pet| pet.read().unwrap().item.is_none()));
+    assert!(pets.iter().all(|pet| pet.read().unwrap().items.is_empty()));
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
 
     // At shop tier of two, only first two friends get inked.
     assert!(pets.get(0..=1).unwrap().iter().all(|pet| pet
         .read()
         .unwrap()
-        .item
+        .items
+        .ailment
         .as_ref()
         .unwrap()
         .name
         == FoodName::Ink));
-    assert!(pets[2].read().unwrap().item.is_none())
+    assert!(pets[2].read().unwrap().items.is_empty())
 }
 
 #[test]
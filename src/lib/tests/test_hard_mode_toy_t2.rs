@@ -1,8 +1,10 @@
+use itertools::Itertools;
+
 use crate::{
     effects::state::EqualityCondition,
     teams::team::TeamFightOutcome,
     tests::common::{test_ant_team, test_scorpion_team},
-    Entity, EntityName, FoodName, ItemCondition, PetName, Position, TeamCombat, TeamEffects,
+    Entity, EntityName, FoodName, ItemCondition, PetName, Position, Team, TeamCombat, TeamEffects,
     TeamShopping, TeamViewer, Toy, ToyName,
 };
 
@@ -34,11 +36,26 @@ fn test_toy_dice() {
 
     // Starting gold.
     assert_eq!(10, team.gold());
+    assert_eq!(2, team.get_shop().roll_cost());
 
     team.roll_shop().unwrap();
 
     // Lose two gold per roll instead of one.
     assert_eq!(8, team.gold());
+
+    // Can't roll without enough gold.
+    team.shop.coins = 1;
+    assert!(team.roll_shop().is_err());
+    assert_eq!(1, team.gold());
+
+    // Rolls cost more every turn until the dice breaks.
+    team.close_shop().unwrap();
+    team.open_shop().unwrap();
+    assert_eq!(2, team.get_shop().roll_cost());
+    team.close_shop().unwrap();
+    team.open_shop().unwrap();
+    assert!(team.toys.is_empty());
+    assert_eq!(1, team.get_shop().roll_cost());
 }
 
 #[test]
@@ -52,11 +69,38 @@ fn test_toy_open_piggy_bank() {
 
     // Starting gold.
     assert_eq!(10, team.gold());
+    // Pets and foods cost one more gold.
+    let shop_costs = |team: &Team| {
+        team.get_shop()
+            .pets
+            .iter()
+            .chain(team.get_shop().foods.iter())
+            .map(|item| item.cost)
+            .collect_vec()
+    };
+    assert!(shop_costs(&team).iter().all(|cost| *cost == 4));
+
     // Buy a 3-gold pet.
     team.buy(&Position::First, &Entity::Pet, &Position::First)
         .unwrap();
     // Lose one additional gold per purchase.
-    assert_eq!(6, team.gold())
+    assert_eq!(6, team.gold());
+
+    // Restocked items cost more.
+    team.roll_shop().unwrap();
+    assert!(shop_costs(&team).iter().all(|cost| *cost == 4));
+
+    // Frozen items aren't marked up again next turn.
+    team.freeze_shop(&Position::First, &Entity::Pet).unwrap();
+    team.close_shop().unwrap();
+    team.open_shop().unwrap();
+    assert!(shop_costs(&team).iter().all(|cost| *cost == 4));
+
+    // Prices return to normal after the piggy bank breaks.
+    team.close_shop().unwrap();
+    team.open_shop().unwrap();
+    assert!(team.toys.is_empty());
+    assert!(shop_costs(&team).iter().all(|cost| *cost == 3));
 }
 
 #[test]
@@ -1,11 +1,51 @@
-use crate::{tests::common::spawn_toy_test, PetName, ToyName};
+use crate::{
+    tests::common::{spawn_toy_test, test_gorilla_team},
+    PetName, TeamCombat, Toy, ToyName,
+};
 
 #[test]
 fn test_toy_stuffed_bear() {
     spawn_toy_test(ToyName::StuffedBear, PetName::Bear, 1)
 }
 
-// #[test]
-// fn test_toy_toy_mouse() {
-//     todo!()
-// }
+#[test]
+fn test_toy_toy_mouse() {
+    let mut team = test_gorilla_team();
+    let mut enemy_team = test_gorilla_team();
+    let is_feline = |name: &PetName| {
+        matches!(
+            name,
+            PetName::Cat
+                | PetName::TabbyCat
+                | PetName::Leopard
+                | PetName::Tiger
+                | PetName::Lynx
+                | PetName::Lion
+                | PetName::WhiteTiger
+                | PetName::SabertoothTiger
+                | PetName::Lioness
+                | PetName::Panther
+                | PetName::Puma
+        )
+    };
+
+    team.toys.push(Toy::try_from(ToyName::ToyMouse).unwrap());
+
+    // No felines.
+    assert!(!enemy_team
+        .friends
+        .iter()
+        .flatten()
+        .any(|pet| is_feline(&pet.read().unwrap().name)));
+
+    team.fight(&mut enemy_team).unwrap();
+    team.fight(&mut enemy_team).unwrap();
+    team.fight(&mut enemy_team).unwrap();
+
+    // Enemy summons a feline.
+    assert!(enemy_team
+        .friends
+        .iter()
+        .flatten()
+        .any(|pet| is_feline(&pet.read().unwrap().name)));
+}
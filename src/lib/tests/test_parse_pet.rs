@@ -3,8 +3,8 @@ use crate::wiki_scraper::common::clean_link_text;
 use crate::wiki_scraper::{
     common::remove_icon_names,
     parse_pet::{
-        parse_pet_effect_trigger, parse_pet_effects, parse_pet_packs, parse_pet_stats,
-        parse_single_pet,
+        parse_pet_effect_trigger, parse_pet_effects, parse_pet_lvl_effects, parse_pet_packs,
+        parse_pet_stats, parse_single_pet,
     },
 };
 use crate::PetName;
@@ -43,6 +43,17 @@ const TIGER_ENTRY: &str = "
 }}
 ";
 
+const AXOLOTL_ENTRY: &str = "
+{{:Pets/row
+| pet = {{IconSAP|Axolotl|size=40px}}
+| attack = 3 | health = 4
+| weeklypack = yes | goldenpack = no
+| '''Hurt''' → Gain +1 {{IconSAP|attack|nolink=yes}}.
+| '''Hurt''' → Gain +2 {{IconSAP|attack|nolink=yes}}.
+| '''Hurt''' → Gain +3 {{IconSAP|attack|nolink=yes}}.
+}}
+";
+
 #[test]
 fn test_parse_stats() {
     let stats = parse_pet_stats(MAMMOTH_ENTRY).unwrap();
@@ -55,6 +66,10 @@ fn test_parse_packs() {
     let sloth_packs = parse_pet_packs(SLOTH_ENTRY);
     assert_eq!(mammoth_packs, vec![Pack::Turtle, Pack::Puppy]);
     assert_eq!(sloth_packs, vec![Pack::Turtle, Pack::Puppy, Pack::Star]);
+
+    // Packs not flagged with yes are ignored.
+    let axolotl_packs = parse_pet_packs(AXOLOTL_ENTRY);
+    assert_eq!(axolotl_packs, vec![Pack::Weekly]);
 }
 
 #[test]
@@ -110,6 +125,50 @@ fn test_parse_effects() {
     );
 }
 
+#[test]
+fn test_parse_lvl_effects() {
+    let effect_mammoth = parse_pet_effects(&remove_icon_names(MAMMOTH_ENTRY), true);
+    assert_eq!(
+        parse_pet_lvl_effects(&effect_mammoth).to_vec(),
+        effect_mammoth.into_iter().map(Some).collect::<Vec<_>>()
+    );
+
+    // Shared effect with per-level values.
+    let shared_effect =
+        vec!["Deal 2/4/6 damage to 1 random enemy. Gain +1/+2/+3 attack.".to_string()];
+    assert_eq!(
+        parse_pet_lvl_effects(&shared_effect),
+        [
+            Some("Deal 2 damage to 1 random enemy. Gain +1 attack.".to_string()),
+            Some("Deal 4 damage to 1 random enemy. Gain +2 attack.".to_string()),
+            Some("Deal 6 damage to 1 random enemy. Gain +3 attack.".to_string()),
+        ]
+    );
+
+    // Stats aren't per-level values.
+    let summon_effect = vec!["Summon one 2/2 Dirty Rat.".to_string()];
+    assert_eq!(
+        parse_pet_lvl_effects(&summon_effect),
+        [
+            Some("Summon one 2/2 Dirty Rat.".to_string()),
+            Some("Summon one 2/2 Dirty Rat.".to_string()),
+            Some("Summon one 2/2 Dirty Rat.".to_string())
+        ]
+    );
+
+    // Missing levels reuse the highest level found.
+    let partial_effect = vec!["Gain +1 attack.".to_string(), "Gain +2 attack.".to_string()];
+    assert_eq!(
+        parse_pet_lvl_effects(&partial_effect),
+        [
+            Some("Gain +1 attack.".to_string()),
+            Some("Gain +2 attack.".to_string()),
+            Some("Gain +2 attack.".to_string()),
+        ]
+    );
+    assert_eq!(parse_pet_lvl_effects(&[]), [None, None, None]);
+}
+
 #[test]
 fn test_create_pet_record() {
     let mut pets: Vec<PetRecord> = vec![];
@@ -133,6 +192,7 @@ fn test_create_pet_record() {
             cost: 3,
             img_url: String::from("https://superautopets.wiki.gg/images/f/fe/Mammoth_Icon.png"),
             is_token: false,
+            summonable: true,
         },
         PetRecord {
             name: PetName::Mammoth,
@@ -150,6 +210,7 @@ fn test_create_pet_record() {
             cost: 3,
             img_url: String::from("https://superautopets.wiki.gg/images/f/fe/Mammoth_Icon.png"),
             is_token: false,
+            summonable: true,
         },
         PetRecord {
             name: PetName::Mammoth,
@@ -167,6 +228,7 @@ fn test_create_pet_record() {
             cost: 3,
             img_url: String::from("https://superautopets.wiki.gg/images/f/fe/Mammoth_Icon.png"),
             is_token: false,
+            summonable: true,
         },
         PetRecord {
             name: PetName::Mammoth,
@@ -184,6 +246,7 @@ fn test_create_pet_record() {
             cost: 3,
             img_url: String::from("https://superautopets.wiki.gg/images/f/fe/Mammoth_Icon.png"),
             is_token: false,
+            summonable: true,
         },
         PetRecord {
             name: PetName::Mammoth,
@@ -201,6 +264,7 @@ fn test_create_pet_record() {
             cost: 3,
             img_url: String::from("https://superautopets.wiki.gg/images/f/fe/Mammoth_Icon.png"),
             is_token: false,
+            summonable: true,
         },
         PetRecord {
             name: PetName::Mammoth,
@@ -218,6 +282,7 @@ fn test_create_pet_record() {
             cost: 3,
             img_url: String::from("https://superautopets.wiki.gg/images/f/fe/Mammoth_Icon.png"),
             is_token: false,
+            summonable: true,
         },
     ];
     assert_eq!(
@@ -43,6 +43,7 @@ fn test_parse_single_token_explicit_lvl_stats() {
             cost: 0,
             img_url: String::from("https://superautopets.wiki.gg/images/f/f0/Bus_Icon.png"),
             is_token: true,
+            summonable: false,
         },
         PetRecord {
             name: PetName::Bus,
@@ -60,6 +61,7 @@ fn test_parse_single_token_explicit_lvl_stats() {
             cost: 0,
             img_url: String::from("https://superautopets.wiki.gg/images/f/f0/Bus_Icon.png"),
             is_token: true,
+            summonable: false,
         },
         PetRecord {
             name: PetName::Bus,
@@ -77,6 +79,7 @@ fn test_parse_single_token_explicit_lvl_stats() {
             cost: 0,
             img_url: String::from("https://superautopets.wiki.gg/images/f/f0/Bus_Icon.png"),
             is_token: true,
+            summonable: false,
         },
     ];
     assert_eq!(pets, exp_pets)
@@ -107,6 +110,7 @@ fn test_parse_single_token_colspan() {
             cost: 0,
             img_url: String::from("https://superautopets.wiki.gg/images/3/3d/Butterfly_Icon.png"),
             is_token: true,
+            summonable: false,
         },
         PetRecord {
             name: PetName::Butterfly,
@@ -126,6 +130,7 @@ fn test_parse_single_token_colspan() {
             cost: 0,
             img_url: String::from("https://superautopets.wiki.gg/images/3/3d/Butterfly_Icon.png"),
             is_token: true,
+            summonable: false,
         },
         PetRecord {
             name: PetName::Butterfly,
@@ -145,6 +150,7 @@ fn test_parse_single_token_colspan() {
             cost: 0,
             img_url: String::from("https://superautopets.wiki.gg/images/3/3d/Butterfly_Icon.png"),
             is_token: true,
+            summonable: false,
         },
     ];
 
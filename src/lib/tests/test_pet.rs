@@ -1,14 +1,22 @@
-use std::collections::VecDeque;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, RwLock},
+};
 
 use crate::{
     effects::{
         actions::{Action, StatChangeType},
-        state::{ItemCondition, Position, Target},
+        effect::{LimitScope, UsesRefresh},
+        state::{ItemCondition, Outcome, Position, Target},
         stats::Statistics,
         trigger::*,
     },
-    pets::combat::AttackOutcome,
-    Effect, Pet, PetCombat, PetName,
+    pets::{
+        analysis::{board_head_to_head, head_to_head},
+        combat::AttackOutcome,
+        role::PetRole,
+    },
+    Attachments, Effect, Food, FoodName, Pet, PetCombat, PetName, Team, TeamViewer,
 };
 
 #[test]
@@ -64,11 +72,52 @@ fn test_attack_pet() {
             enemy_stat_change: Statistics {
                 attack: 0,
                 health: 2
-            }
+            },
+            dmg_dealt: 2,
+            dmg_received: 2,
+            friend_item_modifier: None,
+            enemy_item_modifier: None,
+            knockout: false,
+            fainted: true,
         }
     )
 }
 
+#[test]
+fn test_attack_pet_item_modifiers() {
+    let mut ant_t1 = Pet::try_from(PetName::Ant).unwrap();
+    let mut ant_t2 = Pet::try_from(PetName::Ant).unwrap();
+    ant_t1.stats = Statistics::new(2, 10).unwrap();
+    ant_t2.stats = Statistics::new(4, 10).unwrap();
+    ant_t1
+        .items
+        .attach(Food::try_from(FoodName::MeatBone).unwrap());
+    ant_t2
+        .items
+        .attach(Food::try_from(FoodName::Garlic).unwrap());
+
+    let outcome = ant_t1.attack(&mut ant_t2);
+
+    // Meatbone adds attack to first ant and garlic reduces damage to second ant.
+    let (meatbone_mod, garlic_mod) = (
+        outcome.friend_item_modifier.unwrap(),
+        outcome.enemy_item_modifier.unwrap(),
+    );
+    assert!(meatbone_mod.attack > 0 && garlic_mod.health > 0);
+    assert_eq!(
+        outcome.dmg_dealt,
+        (2 + meatbone_mod.attack - garlic_mod.health).max(1)
+    );
+    assert_eq!(outcome.dmg_received, 4);
+    assert_eq!(ant_t2.stats.health, 10 - outcome.dmg_dealt);
+    assert!(!outcome.knockout && !outcome.fainted);
+
+    // Indirect attacks only record damage received.
+    let outcome = ant_t2.indirect_attack(&Statistics::new(50, 0).unwrap());
+    assert_eq!(outcome.dmg_received, 50 - garlic_mod.health);
+    assert!(outcome.fainted && outcome.friend_item_modifier.is_some());
+}
+
 #[test]
 fn test_create_def_pet() {
     assert_eq!(
@@ -90,12 +139,30 @@ fn test_get_effect() {
             position: Position::Any(ItemCondition::None),
             action: Action::Add(StatChangeType::Static(Statistics::new(1, 1).unwrap())),
             uses: Some(1),
-            temp: false
+            temp: false,
+            ..Default::default()
         },],
     );
     assert!(test_ant.get_effect(4).is_err());
 }
 
+#[test]
+fn test_effects_at_level() {
+    let test_ant = Pet::try_from(PetName::Ant).unwrap();
+
+    let lvl_3_effects = test_ant.effects_at_level(3).unwrap();
+    // Pet is unchanged.
+    assert_eq!(test_ant.get_level(), 1);
+    assert_eq!(test_ant.effect, test_ant.effects_at_level(1).unwrap());
+
+    let mut lvl_3_ant = test_ant.clone();
+    lvl_3_ant.set_level(3).unwrap();
+    assert_eq!(lvl_3_ant.effect, lvl_3_effects);
+
+    assert!(test_ant.effects_at_level(0).is_err());
+    assert!(test_ant.effects_at_level(4).is_err());
+}
+
 #[test]
 fn test_levelup() {
     let mut test_ant = Pet::try_from(PetName::Ant).unwrap();
@@ -158,6 +225,8 @@ fn test_create_pet() {
         test_ant,
         Pet {
             name: PetName::Ant,
+            nickname: None,
+            sell_modifier: 0,
             id: None,
             tier: 1,
             stats: Statistics {
@@ -174,12 +243,14 @@ fn test_create_pet() {
                 position: Position::Any(ItemCondition::None),
                 action: Action::Add(StatChangeType::Static(Statistics::new(1, 1).unwrap())),
                 uses: Some(1),
-                temp: false
+                temp: false,
+                ..Default::default()
             },],
-            item: None,
+            items: Attachments::default(),
             pos: None,
             seed: Some(0),
-            team: None
+            team: None,
+            hurt: false
         }
     )
 }
@@ -202,6 +273,8 @@ fn create_pet_token() {
         Pet {
             id: None,
             name: PetName::Bee,
+            nickname: None,
+            sell_modifier: 0,
             tier: 1,
             stats: Statistics {
                 attack: 50,
@@ -211,10 +284,11 @@ fn create_pet_token() {
             exp: 0,
             cost: 0,
             effect: vec![],
-            item: None,
+            items: Attachments::default(),
             pos: None,
             seed: Some(0),
-            team: None
+            team: None,
+            hurt: false
         }
     );
 }
@@ -242,12 +316,44 @@ fn test_merge_pets() {
     let mut pet = Pet::try_from(PetName::Gorilla).unwrap();
     let other_pet = Pet::try_from(PetName::Gorilla).unwrap();
 
-    assert!(pet.merge(&other_pet).is_ok());
+    assert!(pet.merge(other_pet).is_ok());
     assert_eq!(pet.stats, Statistics::new(8, 11).unwrap());
     // Gain 1 experience.
     assert_eq!(pet.exp, 1)
 }
 
+#[test]
+fn test_merge_pets_levelup_and_items() {
+    let mut pet = Pet::try_from(PetName::Gorilla).unwrap();
+    let mut other_pet = Pet::try_from(PetName::Gorilla).unwrap();
+    other_pet.add_experience(1).unwrap();
+    other_pet.items.perk = Some(Box::new(Food::try_from(FoodName::Melon).unwrap()));
+
+    // Gain 2 experience and level up once.
+    let levelups = pet.merge(other_pet).unwrap();
+    assert_eq!(levelups, vec![TRIGGER_SELF_LEVELUP]);
+    assert_eq!(pet.lvl, 2);
+    assert_eq!(pet.exp, 2);
+
+    // No perk held so consumed pet's perk taken.
+    assert_eq!(
+        pet.items.perk.as_ref().map(|food| &food.name),
+        Some(&FoodName::Melon)
+    );
+
+    // Held perk kept over consumed pet's perk.
+    let mut other_pet = Pet::try_from(PetName::Gorilla).unwrap();
+    other_pet.items.perk = Some(Box::new(Food::try_from(FoodName::Garlic).unwrap()));
+    assert!(pet.merge(other_pet).unwrap().is_empty());
+    assert_eq!(
+        pet.items.perk.as_ref().map(|food| &food.name),
+        Some(&FoodName::Melon)
+    );
+
+    // Cannot merge different pets.
+    assert!(pet.merge(Pet::try_from(PetName::Ant).unwrap()).is_err());
+}
+
 #[test]
 fn test_swap_pet_stats() {
     let mut pet_1 = Pet::try_from(PetName::Gorilla).unwrap();
@@ -282,3 +388,143 @@ fn test_add_experience() {
     // Additional experience is not allowed
     assert!(test_ant.add_experience(3).is_err())
 }
+
+#[test]
+fn test_trigger_pet_snapshot() {
+    let bear = Arc::new(RwLock::new(Pet::custom(
+        "Bear",
+        Statistics::new(10, 20).unwrap(),
+        &[],
+    )));
+    let mosquito = Arc::new(RwLock::new(Pet::custom(
+        "Mosquito",
+        Statistics::new(4, 4).unwrap(),
+        &[],
+    )));
+    let mut trigger = TRIGGER_SELF_FAINT;
+    trigger.set_affected(&bear).set_afflicting(&mosquito);
+
+    // Pets change after trigger.
+    bear.write().unwrap().stats = Statistics::new(0, 0).unwrap();
+    mosquito.write().unwrap().stats = Statistics::new(1, 1).unwrap();
+
+    let half = Statistics::new(50, 50).unwrap();
+    assert_eq!(
+        StatChangeType::TriggerAffectedStats(half)
+            .to_stats(None, None, Some(&trigger), false)
+            .unwrap(),
+        Statistics::new(5, 10).unwrap()
+    );
+    assert_eq!(
+        StatChangeType::TriggerAfflictingStats(half)
+            .to_stats(None, None, Some(&trigger), false)
+            .unwrap(),
+        Statistics::new(2, 2).unwrap()
+    );
+    // No snapshot without a trigger pet.
+    assert!(StatChangeType::TriggerAffectedStats(half)
+        .to_stats(None, None, Some(&TRIGGER_START_BATTLE), false)
+        .is_err());
+}
+
+#[test]
+fn test_pet_roles() {
+    let effect = |trigger: Outcome, target: Target, position: Position, action: Action| {
+        Effect::new(trigger, target, position, action, Some(1), false)
+    };
+    let buff = Action::Add(StatChangeType::Static(Statistics::new(1, 1).unwrap()));
+    let dmg = Action::Remove(StatChangeType::Static(Statistics::new(1, 0).unwrap()));
+
+    assert_eq!(
+        PetRole::from_effect(&effect(
+            TRIGGER_START_BATTLE,
+            Target::Friend,
+            Position::OnSelf,
+            buff.clone()
+        )),
+        [PetRole::Scaler]
+    );
+    assert_eq!(
+        PetRole::from_effect(&effect(
+            TRIGGER_SELF_FAINT,
+            Target::Friend,
+            Position::Any(ItemCondition::None),
+            buff.clone()
+        )),
+        [PetRole::Buffer, PetRole::FaintSynergy]
+    );
+    assert_eq!(
+        PetRole::from_effect(&effect(
+            TRIGGER_START_BATTLE,
+            Target::Enemy,
+            Position::Any(ItemCondition::None),
+            dmg
+        )),
+        [PetRole::Sniper]
+    );
+    // Temporary self buffs don't scale.
+    let mut temp_buff = effect(TRIGGER_START_BATTLE, Target::Friend, Position::OnSelf, buff);
+    temp_buff.temp = true;
+    assert!(PetRole::from_effect(&temp_buff).is_empty());
+
+    // Pet roles are unique across effects.
+    let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    assert_eq!(ant.roles(), [PetRole::Buffer, PetRole::FaintSynergy]);
+    ant.effect.extend(ant.effect.clone());
+    assert_eq!(ant.roles(), [PetRole::Buffer, PetRole::FaintSynergy]);
+    assert_eq!(
+        Pet::try_from(PetName::Mosquito).unwrap().roles(),
+        [PetRole::Sniper]
+    );
+}
+
+#[test]
+fn test_pet_uses_refresh() {
+    let uses_refresh =
+        |name: PetName, lvl: usize| Pet::new(name, None, lvl).unwrap().effect[0].uses_refresh;
+    // Works 5 times per turn.
+    assert_eq!(
+        uses_refresh(PetName::Okapi, 1),
+        Some(UsesRefresh {
+            scope: LimitScope::Turn,
+            uses: 5
+        })
+    );
+    // Works 1/2/3 times per battle.
+    assert_eq!(
+        uses_refresh(PetName::Gorilla, 2),
+        Some(UsesRefresh {
+            scope: LimitScope::Battle,
+            uses: 2
+        })
+    );
+    // No refresh cadence.
+    assert_eq!(uses_refresh(PetName::Ant, 1), None);
+
+    // Uses are restored at the start of the next turn.
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Okapi).unwrap())], 5).unwrap();
+    let okapi = team.first().unwrap();
+    okapi.write().unwrap().effect[0].uses = Some(0);
+    team.reset_uses(LimitScope::Battle);
+    assert_eq!(okapi.read().unwrap().effect[0].uses, Some(0));
+    team.reset_uses(LimitScope::Turn);
+    assert_eq!(okapi.read().unwrap().effect[0].uses, Some(5));
+}
+
+#[test]
+fn test_pet_head_to_head() {
+    // Identical pets always draw.
+    let mirror = head_to_head(&PetName::Ant, &PetName::Ant, 1, 5).unwrap();
+    assert_eq!(mirror.draws, 5);
+    assert_eq!(mirror.draw_rate(), 1.0);
+
+    let gorilla_vs_ant = head_to_head(&PetName::Gorilla, &PetName::Ant, 2, 5).unwrap();
+    let ant_vs_gorilla = head_to_head(&PetName::Ant, &PetName::Gorilla, 2, 5).unwrap();
+    assert_eq!(gorilla_vs_ant.win_rate(), 1.0);
+    assert_eq!(gorilla_vs_ant.win_rate(), ant_vs_gorilla.loss_rate());
+
+    // Invalid levels and boards.
+    assert!(head_to_head(&PetName::Ant, &PetName::Ant, 4, 5).is_err());
+    let big_board = vec![Some(Pet::try_from(PetName::Ant).unwrap()); 6];
+    assert!(board_head_to_head(&big_board, &big_board, 1).is_err());
+}
@@ -1,12 +1,16 @@
 use itertools::Itertools;
 
 use crate::{
+    db::pack::Pack,
     effects::{
         actions::{Action, StatChangeType},
         state::{EqualityCondition, Status},
     },
+    shop::analysis::{
+        chance_by_turn, expected_turns_to_find, roll_chance, roll_stats, slot_chance,
+    },
     Entity, EntityName, Food, FoodName, ItemCondition, Pet, PetName, Position, Shop, ShopItem,
-    ShopItemViewer, ShopViewer, Statistics,
+    ShopItemFilter, ShopItemViewer, ShopViewer, Statistics, ToyName, SAPDB,
 };
 
 #[test]
@@ -164,7 +168,7 @@ fn test_roll_no_gold() {
 
 #[test]
 fn test_view_item_attr() {
-    let mut shop = Shop::new(6, Some(122)).unwrap();
+    let mut shop = Shop::new(6, Some(10404)).unwrap();
     shop.add_item(ShopItem::try_from(Food::try_from(FoodName::Garlic).unwrap()).unwrap())
         .unwrap()
         .add_item(ShopItem::try_from(Food::try_from(FoodName::Chocolate).unwrap()).unwrap())
@@ -228,6 +232,28 @@ fn test_view_item_attr() {
     // Choco gives experience. Stats implicit.
     assert!(choco.attack_stat().is_none() && choco.health_stat().is_none())
 }
+/// Tier 6 shop with a fixed set of pets and foods.
+fn test_view_shop() -> Shop {
+    let mut shop = Shop::new(6, Some(122)).unwrap();
+    shop.pets.clear();
+    shop.foods.clear();
+    for name in [
+        PetName::Parrot,
+        PetName::Horse,
+        PetName::Shark,
+        PetName::Leopard,
+        PetName::Elephant,
+    ] {
+        shop.add_item(ShopItem::new(Pet::try_from(name).unwrap()))
+            .unwrap();
+    }
+    for name in [FoodName::Pizza, FoodName::Chili] {
+        shop.add_item(ShopItem::new(Food::try_from(name).unwrap()))
+            .unwrap();
+    }
+    shop
+}
+
 #[test]
 fn test_view_by_cond() {
     /*
@@ -242,7 +268,7 @@ fn test_view_by_cond() {
     (Normal) [$3] [Pizza: [Effect (Uses: None): Action: Add (2, 2) on Friend (N { condition: None, targets: 2, random: true }), Trigger: [Status: None, Position: None, Affected: None, From: None]]]
     (Normal) [$3] [Chili: [Effect (Uses: None): Action: Damage (5, 0) on Enemy (Relative(-1)), Trigger: [Status: BattleFoodEffect, Position: OnSelf, Affected: None, From: None]]]
     */
-    let shop = Shop::new(6, Some(122)).unwrap();
+    let shop = test_view_shop();
 
     let pets = shop
         .get_shop_items_by_pos(&Position::All(ItemCondition::None), &Entity::Pet)
@@ -397,7 +423,7 @@ fn test_view_by_pos() {
     (Normal) [$3] [Pizza: [Effect (Uses: None): Action: Add (2, 2) on Friend (N { condition: None, targets: 2, random: true }), Trigger: [Status: None, Position: None, Affected: None, From: None]]]
     (Normal) [$3] [Chili: [Effect (Uses: None): Action: Damage (5, 0) on Enemy (Relative(-1)), Trigger: [Status: BattleFoodEffect, Position: OnSelf, Affected: None, From: None]]]
     */
-    let shop = Shop::new(6, Some(122)).unwrap();
+    let shop = test_view_shop();
 
     let all_pets = shop
         .get_shop_items_by_pos(&Position::All(ItemCondition::None), &Entity::Pet)
@@ -470,3 +496,216 @@ fn test_view_by_pos() {
     ];
     assert!(!has_first_three_pets.into_iter().all(|cond| cond))
 }
+
+#[test]
+fn test_shop_tier_odds() {
+    let mut shop = Shop::new(2, Some(12)).unwrap();
+
+    // Default odds weigh each unlocked tier equally.
+    let odds = shop.slot_odds().unwrap();
+    assert_eq!(odds.pet_slots, 3);
+    assert_eq!(odds.food_slots, 2);
+    assert_eq!(odds.pet_tier_odds, vec![0.5, 0.5]);
+    assert_eq!(odds.food_tier_odds, vec![0.5, 0.5]);
+
+    // Override to only roll tier 1 foods.
+    shop.set_tier_odds(&Entity::Food, Some(&[2.0, 0.0]))
+        .unwrap();
+    assert_eq!(shop.slot_odds().unwrap().food_tier_odds, vec![1.0, 0.0]);
+    shop.roll().unwrap();
+    assert!(shop.foods.iter().all(|food| food.tier() == 1));
+
+    // Invalid odds.
+    assert!(shop.set_tier_odds(&Entity::Pet, Some(&[-1.0])).is_err());
+    assert!(shop.set_tier_odds(&Entity::Pet, Some(&[0.0, 0.0])).is_err());
+    assert!(shop.set_tier_odds(&Entity::Toy, Some(&[1.0])).is_err());
+
+    // Restore defaults.
+    shop.set_tier_odds(&Entity::Food, None).unwrap();
+    assert_eq!(shop.slot_odds().unwrap(), odds);
+
+    // Tiers without items in the pool are skipped.
+    shop.set_pool_filter(&[EntityName::Pet(PetName::Ant)], &[])
+        .unwrap();
+    assert_eq!(shop.slot_odds().unwrap().pet_tier_odds, vec![1.0, 0.0]);
+    for _ in 0..5 {
+        shop.roll().unwrap();
+        assert!(shop
+            .pets
+            .iter()
+            .all(|pet| pet.name() == EntityName::Pet(PetName::Ant)));
+    }
+}
+
+#[test]
+fn test_shop_pool_filter() {
+    let mut shop = Shop::new(2, Some(12)).unwrap();
+    let allowed_pets = [
+        EntityName::Pet(PetName::Ant),
+        EntityName::Pet(PetName::Swan),
+    ];
+    shop.set_pool_filter(&allowed_pets, &[EntityName::Food(FoodName::Apple)])
+        .unwrap();
+
+    for _ in 0..5 {
+        shop.roll().unwrap();
+        assert!(shop
+            .pets
+            .iter()
+            .all(|pet| allowed_pets.contains(&pet.name())));
+        assert!(shop
+            .foods
+            .iter()
+            .all(|food| food.name() != EntityName::Food(FoodName::Apple)));
+    }
+
+    // Toys can't be filtered.
+    assert!(shop
+        .set_pool_filter(&[EntityName::Toy(ToyName::Balloon)], &[])
+        .is_err());
+
+    // Clear filter.
+    shop.set_pool_filter(&[], &[]).unwrap();
+    assert_eq!(shop.slot_odds().unwrap().pet_tier_odds.len(), 2);
+}
+
+#[test]
+fn test_shop_analysis_odds() {
+    let dolphin = EntityName::Pet(PetName::Dolphin);
+    let mut shop = Shop::new(3, None).unwrap();
+    shop.packs = vec![Pack::Turtle];
+
+    // Not available below tier 4.
+    assert_eq!(slot_chance(&shop, &dolphin).unwrap(), 0.0);
+    assert!(expected_turns_to_find(&shop, &dolphin, 2)
+        .unwrap()
+        .is_infinite());
+
+    // Chance is the tier's odds split among the tier's pets.
+    shop.set_tier(4).unwrap();
+    let odds = shop.slot_odds().unwrap();
+    let n_tier_4_pets = SAPDB
+        .execute_query(shop.shop_query(Entity::Pet, 4..5))
+        .unwrap()
+        .len();
+    let slot = slot_chance(&shop, &dolphin).unwrap();
+    assert!((slot - odds.pet_tier_odds[3] / n_tier_4_pets as f64).abs() < 1e-9);
+
+    let roll = roll_chance(&shop, &dolphin).unwrap();
+    assert!((roll - (1.0 - (1.0 - slot).powi(odds.pet_slots as i32))).abs() < 1e-9);
+
+    // Overriden tier odds are used.
+    shop.set_tier_odds(&Entity::Pet, Some(&[0.0, 0.0, 0.0, 1.0]))
+        .unwrap();
+    assert!((slot_chance(&shop, &dolphin).unwrap() - 1.0 / n_tier_4_pets as f64).abs() < 1e-9);
+    // As is the pool filter.
+    shop.set_pool_filter(&[], &[dolphin.clone()]).unwrap();
+    assert_eq!(slot_chance(&shop, &dolphin).unwrap(), 0.0);
+    shop.set_pool_filter(&[], &[]).unwrap();
+    shop.set_tier_odds(&Entity::Pet, None).unwrap();
+
+    // Finding a pet is more likely the longer the run goes.
+    // * Tier 4 is reached on turn 7.
+    let by_turn_7 = chance_by_turn(&shop, &dolphin, 7, 2).unwrap();
+    let by_turn_8 = chance_by_turn(&shop, &dolphin, 8, 2).unwrap();
+    assert!((by_turn_7 - (1.0 - (1.0 - roll).powi(3))).abs() < 1e-9);
+    assert!(by_turn_8 > by_turn_7);
+
+    // Toys aren't in the shop.
+    assert!(slot_chance(&shop, &EntityName::Toy(ToyName::Balloon)).is_err());
+}
+
+#[test]
+fn test_shop_analysis_roll_stats() {
+    let ant = EntityName::Pet(PetName::Ant);
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::new(Pet::try_from(PetName::Dolphin).unwrap()))
+        .unwrap();
+    shop.freeze(&Position::First, &Entity::Pet).unwrap();
+
+    let stats = roll_stats(&shop, 0..50, 20).unwrap();
+    assert_eq!(stats.n_rolls, 1000);
+    // Frozen dolphin isn't counted.
+    assert_eq!(stats.count(&EntityName::Pet(PetName::Dolphin)), 0);
+    // Two open pet slots and one food slot per roll.
+    let (n_pets, n_foods) = stats
+        .counts
+        .iter()
+        .fold((0, 0), |(pets, foods), (name, count)| match name {
+            EntityName::Pet(_) => (pets + count, foods),
+            _ => (pets, foods + count),
+        });
+    assert_eq!((n_pets, n_foods), (2000, 1000));
+
+    // Frequency near slot chance.
+    let chance = slot_chance(&shop, &ant).unwrap();
+    assert!((stats.frequency(&ant) - chance).abs() < 0.05);
+
+    // Same seeds give same stats.
+    assert_eq!(stats, roll_stats(&shop, 0..50, 20).unwrap());
+}
+
+#[test]
+fn test_shop_item_filter() {
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::new(Pet::try_from(PetName::Mosquito).unwrap()))
+        .unwrap()
+        .add_item(ShopItem::new(Pet::try_from(PetName::Ant).unwrap()))
+        .unwrap()
+        .add_item(ShopItem::new(Food::try_from(FoodName::Apple).unwrap()))
+        .unwrap();
+    shop.freeze(&Position::Last, &Entity::Pet).unwrap();
+
+    // Faint-trigger pets costing at most 3 gold.
+    let filter = ShopItemFilter::new(Entity::Pet)
+        .set_trigger(Status::Faint)
+        .set_cost(0..=3);
+    let found = shop.get_shop_items_by_filter(&filter);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name(), EntityName::Pet(PetName::Ant));
+
+    // No pets cost nothing.
+    let filter = ShopItemFilter::new(Entity::Pet).set_cost(0..=0);
+    assert!(shop.get_shop_items_by_filter(&filter).is_empty());
+
+    // Only the frozen ant.
+    let filter = ShopItemFilter::new(Entity::Pet)
+        .set_frozen(true)
+        .set_attack(2..=2)
+        .set_health(1..=2);
+    let found = shop.get_shop_items_by_filter(&filter);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name(), EntityName::Pet(PetName::Ant));
+
+    // Tier range excludes tier 1 items.
+    let filter = ShopItemFilter::new(Entity::Food).set_tier(2..=6);
+    assert!(shop.get_shop_items_by_filter(&filter).is_empty());
+    let filter = ShopItemFilter::new(Entity::Food).set_tier(1..=6);
+    assert_eq!(shop.get_shop_items_by_filter(&filter).len(), 1);
+}
+
+#[test]
+fn test_shop_slot_schedule() {
+    let mut shop = Shop::default();
+    shop.set_slot_schedule(&Entity::Pet, &[2, 2, 3, 3, 4, 4])
+        .unwrap()
+        .set_slot_schedule(&Entity::Food, &[2, 2, 3, 3, 4, 4])
+        .unwrap();
+    shop.seed = Some(12);
+    shop.restock().unwrap();
+    assert_eq!((shop.len_pets(), shop.len_foods()), (2, 2));
+
+    // Rolling uses the new slot counts.
+    shop.set_tier(3).unwrap();
+    shop.roll().unwrap();
+    assert_eq!((shop.len_pets(), shop.len_foods()), (3, 3));
+
+    // Adding items over the limit fails.
+    let pet = ShopItem::new(Pet::try_from(PetName::Ant).unwrap());
+    assert!(shop.add_item(pet).is_err());
+
+    // Invalid schedules.
+    assert!(shop.set_slot_schedule(&Entity::Pet, &[7; 6]).is_err());
+    assert!(shop.set_slot_schedule(&Entity::Food, &[1, 2]).is_err());
+    assert!(shop.set_slot_schedule(&Entity::Toy, &[1; 6]).is_err());
+}
@@ -1,16 +1,38 @@
-use std::sync::Arc;
+use std::{
+    str::FromStr,
+    sync::{Arc, Mutex, RwLock},
+};
 
 use itertools::Itertools;
 
 use crate::{
-    effects::state::{Status, Target},
-    pets::{names::PetName, pet::Pet},
+    db::{pack::Pack, record::PetRecord},
+    effects::{
+        actions::{Action, ConditionType, CustomAction, LogicType, StatChangeType, SummonType},
+        effect::LimitScope,
+        state::{CondOrdering, EqualityCondition, ItemCondition, ShopCondition, Status, Target},
+        stats::StatCap,
+        trigger::{
+            get_self_faint_triggers, TRIGGER_ANY_FAINT, TRIGGER_END_BATTLE, TRIGGER_END_TURN,
+            TRIGGER_KNOCKOUT, TRIGGER_SELF_FAINT, TRIGGER_SELF_HURT, TRIGGER_START_BATTLE,
+        },
+    },
+    error::SAPTestError,
+    pets::{
+        names::PetName,
+        pet::Pet,
+        registry::{register_custom_pet, unregister_custom_pet},
+    },
     teams::{
-        combat::TeamCombat,
+        aura::{Aura, AuraSource},
+        combat::{BattleRules, FaintOrder, ItemOrder, TeamCombat},
+        counters::{CounterKey, Counters},
+        effects::ResolutionOrder,
         team::{Team, TeamFightOutcome},
         viewer::TeamViewer,
     },
-    Position, Statistics, TeamEffects,
+    Effect, Entity, Food, FoodName, PetColumn, Position, SAPQuery, Statistics, TeamEffects,
+    TeamShopping, SAPDB,
 };
 
 use super::common::test_ant_team;
@@ -123,6 +145,52 @@ fn test_team_restore() {
     }
 }
 
+#[test]
+fn test_team_duplicate() {
+    let mut team = test_ant_team();
+    team.open_shop().unwrap();
+    let mut start_battle_trigger = TRIGGER_START_BATTLE;
+    start_battle_trigger.set_affected(&team.first().unwrap());
+    team.triggers.push_back(start_battle_trigger);
+
+    let mut team_copy = team.duplicate();
+    assert_eq!(team.get_name(), team_copy.get_name());
+
+    // Effects and triggers reference copied pets.
+    for (pet, copied_pet) in team.all().iter().zip(team_copy.all().iter()) {
+        assert!(!Arc::ptr_eq(pet, copied_pet));
+        let owner = copied_pet.read().unwrap().effect[0].get_owner().unwrap();
+        assert!(Arc::ptr_eq(&owner.upgrade().unwrap(), copied_pet));
+    }
+    let affected_pet = team_copy.triggers[0].affected_pet.clone().unwrap();
+    assert!(Arc::ptr_eq(
+        &affected_pet.upgrade().unwrap(),
+        &team_copy.first().unwrap()
+    ));
+
+    // Shop items are independent.
+    let (shop_pet, copied_shop_pet) = (&team.shop.pets[0], &team_copy.shop.pets[0]);
+    assert_ne!(shop_pet, copied_shop_pet);
+
+    // Battling with the copy leaves the original untouched.
+    let original_pets = team
+        .all()
+        .iter()
+        .map(|pet| pet.read().unwrap().clone())
+        .collect_vec();
+    team_copy.close_shop().unwrap();
+    team_copy.fight(&mut test_ant_team()).unwrap();
+    let pets = team
+        .all()
+        .iter()
+        .map(|pet| pet.read().unwrap().clone())
+        .collect_vec();
+    assert_eq!(pets, original_pets);
+
+    // Cloned team is renamed.
+    assert_ne!(team.clone().get_name(), team.get_name());
+}
+
 #[test]
 fn test_team_push() {
     let mut team = Team::new(
@@ -376,3 +444,1054 @@ fn test_start_battle_order() {
         )
     }
 }
+
+#[test]
+fn test_team_resolution_order() {
+    let pets = [1, 3, 2].map(|attack| {
+        let mut ant = Pet::try_from(PetName::Ant).unwrap();
+        ant.stats.attack = attack;
+        Some(ant)
+    });
+    let mut team = Team::new(&pets, 5).unwrap();
+    // Add a pet at the front last.
+    let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    ant.stats.attack = 0;
+    team.add_pet(ant, 0, None).unwrap();
+
+    let attacks = |pets: Vec<Arc<RwLock<Pet>>>| {
+        pets.iter()
+            .map(|pet| pet.read().unwrap().stats.attack)
+            .collect_vec()
+    };
+
+    // Default is highest to lowest attack.
+    assert_eq!(attacks(team.get_pet_effect_order(false)), [3, 2, 1, 0]);
+
+    team.set_resolution_order(ResolutionOrder::BoardPosition);
+    assert_eq!(attacks(team.get_pet_effect_order(false)), [0, 1, 3, 2]);
+
+    team.set_resolution_order(ResolutionOrder::Insertion);
+    assert_eq!(attacks(team.get_pet_effect_order(false)), [1, 3, 2, 0]);
+
+    team.set_resolution_order(ResolutionOrder::Custom(Arc::new(|pet_1, pet_2| {
+        pet_1.stats.attack.cmp(&pet_2.stats.attack)
+    })));
+    assert_eq!(attacks(team.get_pet_effect_order(false)), [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_team_add_trigger() {
+    let mut team = test_ant_team();
+    let mut hurt_trigger = TRIGGER_SELF_HURT;
+    hurt_trigger.set_affected(&team.first().unwrap());
+    team.add_trigger(hurt_trigger).unwrap();
+
+    assert_eq!(team.pending_triggers().len(), 1);
+    assert_eq!(team.pending_triggers().back().unwrap().status, Status::Hurt);
+
+    // Triggers for dropped pets can't be added.
+    let mut dropped_trigger = TRIGGER_SELF_HURT;
+    {
+        let ant = Arc::new(RwLock::new(Pet::try_from(PetName::Ant).unwrap()));
+        dropped_trigger.set_affected(&ant);
+    }
+    assert!(team.add_trigger(dropped_trigger).is_err());
+    assert_eq!(team.pending_triggers().len(), 1);
+}
+
+#[test]
+fn test_team_counters() {
+    let mut team = Team::default();
+    let key = CounterKey::Custom("Apples".to_owned());
+    let changes = Arc::new(RwLock::new(vec![]));
+    let counter_changes = changes.clone();
+    team.counters
+        .register(key.clone(), 1, Some(3))
+        .subscribe(&[key.clone()], move |_key, prev, new| {
+            counter_changes.write().unwrap().push((prev, new))
+        });
+
+    // Registering clamps to the minimum.
+    assert_eq!(team.counters.get(&key), Some(1));
+    assert_eq!(team.counters.add(&key, 5), 3);
+    assert_eq!(team.counters.add(&key, -5), 1);
+    // No change so subscriber isn't called.
+    assert_eq!(team.counters.set(&key, 1), 1);
+    // Other counters don't call the subscriber.
+    team.counters.add(&CounterKey::Trumpets, 2);
+    assert_eq!(*changes.read().unwrap(), [(1, 3), (3, 1)]);
+
+    // Counters are serialized by name.
+    let json = serde_json::to_string(&team.counters).unwrap();
+    let counters: Counters = serde_json::from_str(&json).unwrap();
+    assert_eq!(counters, team.counters);
+    assert_eq!(counters.get(&CounterKey::Trumpets), Some(2));
+
+    // Counters are reset to their minimum.
+    team.counters.reset();
+    assert_eq!(team.counters.get(&key), Some(1));
+    assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(0));
+}
+
+#[test]
+fn test_team_conditional_chain() {
+    let shop_tier =
+        |tier: usize| ConditionType::Shop(ShopCondition::Tier(Some(CondOrdering::Equal(tier))));
+    let add_atk = |atk: isize| Action::Add(StatChangeType::StaticAttack(atk));
+    // Gain 3 attack if tier 2. Else, gain 2 attack if tier 1 and after turn 1. Else, gain 1 attack.
+    // Registered so its effect is kept when effects are reset at the end of the turn.
+    let mut ant = Pet::custom("ChainAnt", Statistics::new(2, 2).unwrap(), &[]);
+    ant.effect = vec![Effect::builder()
+        .set_trigger(TRIGGER_END_TURN)
+        .set_target(Target::Friend)
+        .set_position(Position::OnSelf)
+        .set_action(Action::ConditionalChain(
+            vec![
+                (LogicType::If(shop_tier(2)), add_atk(3)),
+                (
+                    LogicType::All(vec![
+                        shop_tier(1),
+                        ConditionType::Shop(ShopCondition::Tier(Some(CondOrdering::Less(2)))),
+                    ]),
+                    add_atk(2),
+                ),
+                (
+                    LogicType::Any(vec![shop_tier(5), shop_tier(6)]),
+                    add_atk(10),
+                ),
+            ],
+            Box::new(add_atk(1)),
+        ))
+        .set_uses(None)
+        .build()];
+    register_custom_pet(&ant).unwrap();
+    let mut team = Team::new(&[Some(ant)], 5).unwrap();
+    team.set_auto_tier(false);
+    let ant = team.first().unwrap();
+    let start_atk = ant.read().unwrap().stats.attack;
+
+    // Tier 1. First branch with all conditions met.
+    team.open_shop().unwrap().close_shop().unwrap();
+    assert_eq!(ant.read().unwrap().stats.attack, start_atk + 2);
+
+    // Tier 2. First branch.
+    team.set_shop_tier(2).unwrap();
+    team.open_shop().unwrap().close_shop().unwrap();
+    assert_eq!(ant.read().unwrap().stats.attack, start_atk + 5);
+
+    // Tier 3. No branch met so else action.
+    team.set_shop_tier(3).unwrap();
+    team.open_shop().unwrap().close_shop().unwrap();
+    assert_eq!(ant.read().unwrap().stats.attack, start_atk + 6);
+
+    assert!(unregister_custom_pet(&PetName::Custom("ChainAnt".to_string())).is_some());
+}
+
+#[test]
+fn test_team_equality_conditions() {
+    let mut strong_ant = Pet::try_from(PetName::Ant).unwrap();
+    strong_ant.stats = Statistics::new(10, 1).unwrap();
+    strong_ant.exp = 1;
+    let team = Team::new(
+        &[
+            Some(strong_ant),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Cricket).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let (strong_ant, ant, cricket) = (
+        team.nth(0).unwrap(),
+        team.nth(1).unwrap(),
+        team.nth(2).unwrap(),
+    );
+    let get_pets = |cond: EqualityCondition| team.get_pets_by_cond(&ItemCondition::Equal(cond));
+    let is_pets = |pets: Vec<Arc<RwLock<Pet>>>, exp_pets: &[&Arc<RwLock<Pet>>]| {
+        pets.len() == exp_pets.len()
+            && pets
+                .iter()
+                .zip(exp_pets.iter())
+                .all(|(pet, exp_pet)| Arc::ptr_eq(pet, exp_pet))
+    };
+
+    assert!(is_pets(
+        get_pets(EqualityCondition::Attack(5..=10)),
+        &[&strong_ant]
+    ));
+    assert!(is_pets(
+        get_pets(EqualityCondition::Health(2..=2)),
+        &[&ant, &cricket]
+    ));
+    assert!(is_pets(
+        get_pets(EqualityCondition::Experience(1)),
+        &[&strong_ant]
+    ));
+    assert!(is_pets(
+        get_pets(EqualityCondition::IsHighestAttack),
+        &[&strong_ant]
+    ));
+    assert!(is_pets(
+        get_pets(EqualityCondition::IsLowestAttack),
+        &[&cricket]
+    ));
+    assert!(is_pets(
+        get_pets(EqualityCondition::Pack(Pack::Turtle)),
+        &[&strong_ant, &ant, &cricket]
+    ));
+    // Both ants and cricket trigger on faint.
+    assert!(is_pets(
+        get_pets(EqualityCondition::TriggerExact(Status::Faint)),
+        &[&strong_ant, &ant, &cricket]
+    ));
+    assert!(get_pets(EqualityCondition::TriggerExact(Status::StartOfBattle)).is_empty());
+}
+
+/// Give the affected pet the afflicting pet's attack and add a trumpet to both teams.
+struct ShareAttack;
+
+impl CustomAction for ShareAttack {
+    fn name(&self) -> String {
+        "Share Attack".to_string()
+    }
+
+    fn apply(
+        &self,
+        affected_pet: &Arc<RwLock<Pet>>,
+        afflicting_pet: &Arc<RwLock<Pet>>,
+        team: &mut Team,
+        opponent: Option<&mut Team>,
+    ) -> Result<(), SAPTestError> {
+        let attack = afflicting_pet.read().unwrap().stats.attack;
+        affected_pet.write().unwrap().stats.attack += attack;
+        for team in std::iter::once(team).chain(opponent) {
+            team.counters.add(&CounterKey::Trumpets, 1);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_team_custom_action() {
+    let mut team = test_ant_team();
+    let mut enemy_team = test_ant_team();
+    let first_pet = team.first().unwrap();
+    let last_pet = team.last().unwrap();
+
+    let mut effect = Effect::builder()
+        .set_target(Target::Friend)
+        .set_position(Position::Last)
+        .set_action(Action::Custom(Arc::new(ShareAttack)))
+        .build();
+    effect.assign_owner(Some(&first_pet));
+    assert_eq!(effect.action.to_string(), "Custom Share Attack");
+    assert!(effect.action.tags().contains(&"custom"));
+
+    let (first_atk, last_atk) = (
+        first_pet.read().unwrap().stats.attack,
+        last_pet.read().unwrap().stats.attack,
+    );
+    team.apply_effect(&effect, Some(&mut enemy_team)).unwrap();
+    assert_eq!(last_pet.read().unwrap().stats.attack, first_atk + last_atk);
+    assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(1));
+    assert_eq!(enemy_team.counters.get(&CounterKey::Trumpets), Some(1));
+
+    // Serialized as an opaque marker which can't be applied.
+    let json = serde_json::to_string(&effect.action).unwrap();
+    assert_eq!(json, r#"{"Custom":"Share Attack"}"#);
+    let mut deserialized_effect = effect.clone();
+    deserialized_effect.action = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized_effect.action, effect.action);
+    assert!(team.apply_effect(&deserialized_effect, None).is_err());
+}
+
+#[test]
+fn test_team_push_trigger_action() {
+    let mut team = test_ant_team();
+    let mut enemy_team = test_ant_team();
+    enemy_team.set_name("Enemy").unwrap();
+    let first_pet = team.first().unwrap();
+    let last_pet = team.last().unwrap();
+
+    // Last pet counts as having knocked out a pet.
+    let mut effect = Effect::builder()
+        .set_target(Target::Friend)
+        .set_position(Position::Last)
+        .set_action(Action::PushTrigger(TRIGGER_KNOCKOUT))
+        .build();
+    effect.assign_owner(Some(&first_pet));
+    team.apply_effect(&effect, Some(&mut enemy_team)).unwrap();
+
+    let trigger = team.triggers.back().unwrap();
+    assert_eq!(trigger.status, Status::KnockOut);
+    assert!(Arc::ptr_eq(
+        &trigger.get_affected().unwrap().upgrade().unwrap(),
+        &last_pet
+    ));
+    assert!(Arc::ptr_eq(
+        &trigger.get_afflicting().unwrap().upgrade().unwrap(),
+        &first_pet
+    ));
+}
+
+#[test]
+fn test_team_preview_trigger() {
+    let team = test_ant_team();
+    let first_pet = team.first().unwrap();
+
+    let mut trigger = TRIGGER_SELF_FAINT;
+    trigger.set_affected(&first_pet);
+
+    // Only the fainting ant's effect would activate.
+    let preview = team.preview_trigger(&trigger);
+    assert_eq!(preview.len(), 1);
+    let (pet, effect) = &preview[0];
+    assert!(Arc::ptr_eq(pet, &first_pet));
+    assert_eq!(effect.trigger, trigger);
+
+    // Nothing is consumed or queued.
+    assert_eq!(first_pet.read().unwrap().effect[0].uses, Some(1));
+    assert!(team.triggers.is_empty());
+}
+
+#[test]
+fn test_team_effect_interceptors() {
+    let mut team = test_ant_team();
+    let first_pet = team.first().unwrap();
+    let original_stats = first_pet.read().unwrap().stats;
+
+    let mut effect = Effect::builder()
+        .set_target(Target::Friend)
+        .set_position(Position::First)
+        .set_action(Action::Add(StatChangeType::Static(Statistics {
+            attack: 1,
+            health: 1,
+        })))
+        .build();
+    effect.assign_owner(Some(&first_pet));
+
+    // Double all static stat additions.
+    team.add_interceptor(|mut effect| {
+        if let Action::Add(StatChangeType::Static(stats)) = &mut effect.action {
+            stats.attack *= 2;
+            stats.health *= 2;
+        }
+        Some(effect)
+    });
+    team.apply_effect(&effect, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(2, 2).unwrap()
+    );
+
+    // Block everything.
+    team.clear_interceptors().add_interceptor(|_| None);
+    assert!(team.apply_effect(&effect, None).unwrap().is_empty());
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(2, 2).unwrap()
+    );
+}
+
+#[test]
+fn test_team_effect_activation_limit() {
+    let mut team = test_ant_team();
+    let first_pet = team.first().unwrap();
+    let original_stats = first_pet.read().unwrap().stats;
+
+    // Unlimited uses but only activates once per turn.
+    let mut effect = Effect::builder()
+        .set_trigger(TRIGGER_END_TURN)
+        .set_target(Target::Friend)
+        .set_position(Position::OnSelf)
+        .set_action(Action::Add(StatChangeType::Static(Statistics {
+            attack: 1,
+            health: 1,
+        })))
+        .set_uses(None)
+        .set_limit(LimitScope::Turn, 1)
+        .build();
+    effect.assign_owner(Some(&first_pet));
+    first_pet.write().unwrap().effect = vec![effect];
+
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(1, 1).unwrap()
+    );
+
+    // Resetting a different scope does nothing.
+    team.reset_activation_limits(LimitScope::Battle);
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(1, 1).unwrap()
+    );
+
+    team.reset_activation_limits(LimitScope::Turn);
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(2, 2).unwrap()
+    );
+}
+
+#[test]
+fn test_team_effect_uses_refresh() {
+    let mut team = test_ant_team();
+    let first_pet = team.first().unwrap();
+    let original_stats = first_pet.read().unwrap().stats;
+
+    // One use restored each turn.
+    let mut effect = Effect::builder()
+        .set_trigger(TRIGGER_END_TURN)
+        .set_target(Target::Friend)
+        .set_position(Position::OnSelf)
+        .set_action(Action::Add(StatChangeType::Static(Statistics {
+            attack: 1,
+            health: 1,
+        })))
+        .set_uses_refresh(LimitScope::Turn, 1)
+        .build();
+    effect.assign_owner(Some(&first_pet));
+    first_pet.write().unwrap().effect = vec![effect];
+
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(1, 1).unwrap()
+    );
+    assert_eq!(first_pet.read().unwrap().effect[0].uses, Some(0));
+
+    // Starting a battle doesn't restore per-turn uses.
+    team.reset_uses(LimitScope::Battle);
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(1, 1).unwrap()
+    );
+
+    team.reset_uses(LimitScope::Turn);
+    assert_eq!(first_pet.read().unwrap().effect[0].uses, Some(1));
+    team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+    assert_eq!(
+        first_pet.read().unwrap().stats,
+        original_stats + Statistics::new(2, 2).unwrap()
+    );
+}
+
+/// Record the attack of the pet owning the effect.
+struct RecordAttack(Arc<Mutex<Vec<isize>>>);
+
+impl CustomAction for RecordAttack {
+    fn name(&self) -> String {
+        "Record Attack".to_string()
+    }
+
+    fn apply(
+        &self,
+        _affected_pet: &Arc<RwLock<Pet>>,
+        afflicting_pet: &Arc<RwLock<Pet>>,
+        _team: &mut Team,
+        _opponent: Option<&mut Team>,
+    ) -> Result<(), SAPTestError> {
+        self.0
+            .lock()
+            .unwrap()
+            .push(afflicting_pet.read().unwrap().stats.attack);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_team_faint_order() {
+    let faint_order = |rules: BattleRules| {
+        let recorded = Arc::new(Mutex::new(vec![]));
+        let effect = Effect::builder()
+            .set_trigger(TRIGGER_SELF_FAINT)
+            .set_target(Target::Friend)
+            .set_position(Position::OnSelf)
+            .set_action(Action::Custom(Arc::new(RecordAttack(recorded.clone()))))
+            .build();
+
+        let mut team = test_ant_team();
+        let mut enemy_team = test_ant_team();
+        enemy_team.set_name("Enemy").unwrap();
+        team.set_battle_rules(rules);
+
+        // Friends (1, 0) and (2, 0) faint with the back pet queued first. Enemy (3, 0) faints.
+        let fainted_friends = team.all().into_iter().take(2).rev().collect_vec();
+        let fainted_enemies = enemy_team.all().into_iter().take(1).collect_vec();
+        for (pets, attacks) in [(&fainted_friends, [2, 1]), (&fainted_enemies, [3, 3])] {
+            for (pet, attack) in pets.iter().zip(attacks) {
+                let mut pet_effect = effect.clone();
+                pet_effect.assign_owner(Some(pet));
+                pet.write().unwrap().effect = vec![pet_effect];
+                pet.write().unwrap().stats = Statistics::new(attack, 0).unwrap();
+            }
+        }
+        for (pets, team) in [
+            (&fainted_friends, &mut team),
+            (&fainted_enemies, &mut enemy_team),
+        ] {
+            for pet in pets.iter() {
+                let mut trigger = TRIGGER_SELF_FAINT;
+                trigger.set_affected(pet);
+                team.triggers.push_back(trigger);
+            }
+        }
+
+        team.trigger_all_effects(&mut enemy_team).unwrap();
+        let order = recorded.lock().unwrap().clone();
+        order
+    };
+
+    // By default, the team's triggers resolve in queued order before the enemy's.
+    assert_eq!(faint_order(BattleRules::default()), [2, 1, 3]);
+    // Front-to-back, the stronger enemy goes first and teams alternate.
+    assert_eq!(
+        faint_order(BattleRules {
+            faint_order: FaintOrder::FrontToBack,
+            ..Default::default()
+        }),
+        [3, 1, 2]
+    );
+}
+
+#[test]
+fn test_team_stat_cap() {
+    let buffed_stats = |stat_cap: StatCap| {
+        let mut team = test_ant_team();
+        team.set_battle_rules(BattleRules {
+            stat_cap,
+            ..Default::default()
+        });
+        let first_pet = team.first().unwrap();
+        let mut effect = Effect::builder()
+            .set_trigger(TRIGGER_END_TURN)
+            .set_target(Target::Friend)
+            .set_position(Position::OnSelf)
+            .set_action(Action::Add(StatChangeType::Static(Statistics {
+                attack: 5,
+                health: 5,
+            })))
+            .build();
+        effect.assign_owner(Some(&first_pet));
+        first_pet.write().unwrap().stats = Statistics::new(48, 48).unwrap();
+        first_pet.write().unwrap().effect = vec![effect];
+
+        team.trigger_effects(&TRIGGER_END_TURN, None).unwrap();
+        let stats = first_pet.read().unwrap().stats;
+        stats
+    };
+
+    assert_eq!(buffed_stats(StatCap::default()), Statistics::MAX);
+    assert_eq!(
+        buffed_stats(StatCap::Clamp(100)),
+        Statistics::new(53, 53).unwrap()
+    );
+    assert_eq!(
+        buffed_stats(StatCap::Saturate),
+        Statistics::new(53, 53).unwrap()
+    );
+}
+
+#[test]
+fn test_team_item_order() {
+    let item_order = |rules: BattleRules| {
+        let recorded = Arc::new(Mutex::new(vec![]));
+        let record_effect = |trigger| {
+            Effect::builder()
+                .set_trigger(trigger)
+                .set_target(Target::Friend)
+                .set_position(Position::OnSelf)
+                .set_action(Action::Custom(Arc::new(RecordAttack(recorded.clone()))))
+                .build()
+        };
+
+        let mut team = test_ant_team();
+        let mut enemy_team = test_ant_team();
+        enemy_team.set_name("Enemy").unwrap();
+        team.set_battle_rules(rules);
+        for pet in team.all().into_iter().chain(enemy_team.all()) {
+            pet.write().unwrap().effect.clear();
+        }
+
+        // Fainted (1, 0) holds an item like a mushroom. (2, 1) behind it reacts to friends fainting like a fly.
+        let (fainted, behind) = (team.nth(0).unwrap(), team.nth(1).unwrap());
+        let mut item = Food::custom("Record", 3, record_effect(TRIGGER_SELF_FAINT), true, true);
+        item.ability.assign_owner(Some(&fainted));
+        fainted.write().unwrap().items.attach(item);
+        fainted.write().unwrap().stats = Statistics::new(1, 0).unwrap();
+
+        let mut behind_effect = record_effect(TRIGGER_ANY_FAINT);
+        behind_effect.assign_owner(Some(&behind));
+        behind.write().unwrap().effect = vec![behind_effect];
+        behind.write().unwrap().stats = Statistics::new(2, 1).unwrap();
+
+        for mut trigger in get_self_faint_triggers(&None) {
+            trigger.set_affected(&fainted);
+            team.triggers.push_back(trigger);
+        }
+
+        team.trigger_all_effects(&mut enemy_team).unwrap();
+        let order = recorded.lock().unwrap().clone();
+        order
+    };
+
+    // By default, items activate after all pet effects.
+    assert_eq!(item_order(BattleRules::default()), [2, 1]);
+    // Items activate with their trigger before other pets react.
+    for faint_order in [FaintOrder::Queued, FaintOrder::FrontToBack] {
+        assert_eq!(
+            item_order(BattleRules {
+                faint_order,
+                item_order: ItemOrder::WithTrigger,
+                ..Default::default()
+            }),
+            [1, 2]
+        );
+    }
+}
+
+#[test]
+fn test_team_aura_removed_with_source() {
+    let mut team = test_ant_team();
+    let original_stats = team
+        .all()
+        .iter()
+        .map(|pet| pet.read().unwrap().stats)
+        .collect_vec();
+    let first_pet = team.first().unwrap();
+    let first_pet_id = first_pet.read().unwrap().id.unwrap();
+
+    // All pets gain (1, 1) while the first ant is alive.
+    team.add_aura(Aura::new(
+        "Ant Aura",
+        AuraSource::Pet(first_pet_id),
+        ItemCondition::None,
+        Statistics::new(1, 1).unwrap(),
+    ));
+    assert_eq!(team.auras()[0].affected_pets().len(), 3);
+    for (pet, stats) in team.all().iter().zip(original_stats.iter()) {
+        assert_eq!(
+            pet.read().unwrap().stats,
+            *stats + Statistics::new(1, 1).unwrap()
+        );
+    }
+
+    // Refreshing doesn't stack stats.
+    team.refresh_auras();
+    assert_eq!(
+        team.nth(1).unwrap().read().unwrap().stats,
+        original_stats[1] + Statistics::new(1, 1).unwrap()
+    );
+
+    // Source faints and the aura is removed.
+    first_pet.write().unwrap().stats.health = 0;
+    team.clear_team();
+    assert!(team.auras().is_empty());
+    for (pet, stats) in team.all().iter().zip(original_stats.iter().skip(1)) {
+        assert_eq!(pet.read().unwrap().stats, *stats);
+    }
+}
+
+#[test]
+fn test_team_trigger_loop() {
+    let mut team = test_ant_team();
+    let mut enemy_team = test_ant_team();
+    enemy_team.set_name("Enemy").unwrap();
+    let first_pet = team.first().unwrap();
+
+    // Knocking out a pet causes another knockout.
+    let mut effect = Effect::builder()
+        .set_trigger(TRIGGER_KNOCKOUT)
+        .set_target(Target::Friend)
+        .set_position(Position::OnSelf)
+        .set_action(Action::PushTrigger(TRIGGER_KNOCKOUT))
+        .set_uses(None)
+        .build();
+    effect.assign_owner(Some(&first_pet));
+    first_pet.write().unwrap().effect = vec![effect];
+
+    let mut trigger = TRIGGER_KNOCKOUT;
+    trigger.set_affected(&first_pet);
+    team.set_trigger_budget(10).triggers.push_back(trigger);
+    let Err(SAPTestError::TriggerLoop { budget, cycle }) =
+        team.trigger_all_effects(&mut enemy_team)
+    else {
+        panic!("Trigger loop not detected.")
+    };
+    assert_eq!(budget, 10);
+    assert_eq!(cycle, ["KnockOut (Ant)"]);
+}
+
+#[test]
+fn test_team_knockout_attribution() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Mosquito).unwrap()),
+            Some(Pet::try_from(PetName::Pig).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+    enemy_team.set_name("Enemy").unwrap();
+
+    // Mosquito snipes the first enemy and pig snipes the first friend.
+    let snipe = |pet: &Arc<RwLock<Pet>>, target: Target| {
+        let mut effect = Effect::builder()
+            .set_trigger(TRIGGER_START_BATTLE)
+            .set_target(target)
+            .set_position(Position::First)
+            .set_action(Action::Remove(StatChangeType::Static(
+                Statistics::new(50, 0).unwrap(),
+            )))
+            .build();
+        effect.assign_owner(Some(pet));
+        pet.write().unwrap().effect = vec![effect];
+    };
+    for pet in team.all().iter().chain(enemy_team.all().iter()) {
+        pet.write().unwrap().effect.clear();
+    }
+    snipe(&team.nth(1).unwrap(), Target::Enemy);
+    snipe(&team.nth(2).unwrap(), Target::Friend);
+
+    team.triggers.push_back(TRIGGER_START_BATTLE);
+    team.trigger_all_effects(&mut enemy_team).unwrap();
+
+    // Both knockouts are credited to the team that landed them.
+    let knockouts = team
+        .knockout_history()
+        .iter()
+        .map(|record| (record.knocked_out_by.clone(), record.knocked_out.clone()))
+        .sorted_by_key(|(knocked_out_by, _)| knocked_out_by.to_string())
+        .collect_vec();
+    assert_eq!(
+        knockouts,
+        [
+            (PetName::Mosquito, PetName::Ant),
+            (PetName::Pig, PetName::Ant)
+        ]
+    );
+    assert!(team
+        .knockout_history()
+        .iter()
+        .all(|record| record.phase == 1));
+    assert!(enemy_team.knockout_history().is_empty());
+}
+
+#[test]
+fn test_team_end_of_battle_phase() {
+    let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    ant.stats = Statistics::new(40, 40).unwrap();
+    ant.items.ailment = Some(Box::new(Food::try_from(FoodName::Weak).unwrap()));
+    let mut team = Team::new(&[Some(ant)], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+
+    // Gain (1, 1) once the battle ends.
+    let ant = team.first().unwrap();
+    let mut effect = Effect::builder()
+        .set_trigger(TRIGGER_END_BATTLE)
+        .set_target(Target::Friend)
+        .set_position(Position::OnSelf)
+        .set_action(Action::Add(StatChangeType::Static(
+            Statistics::new(1, 1).unwrap(),
+        )))
+        .build();
+    effect.assign_owner(Some(&ant));
+    ant.write().unwrap().effect = vec![effect];
+
+    let mut outcome = team.fight(&mut enemy_team).unwrap();
+    while outcome == TeamFightOutcome::None {
+        outcome = team.fight(&mut enemy_team).unwrap();
+    }
+    assert_eq!(outcome, TeamFightOutcome::Win);
+
+    // End of battle effect activates and weakness only lasts until the end of battle.
+    let ant = ant.read().unwrap();
+    assert_eq!(ant.stats, Statistics::new(41, 36).unwrap());
+    assert!(ant.items.ailment.is_none());
+}
+
+#[test]
+fn test_team_summon_query_excludes_tokens() {
+    let team = test_ant_team();
+    let ant = team.first().unwrap();
+    let token_names = SAPDB
+        .execute_query(
+            SAPQuery::builder()
+                .set_table(Entity::Pet)
+                .set_param(PetColumn::Summonable, vec![false]),
+        )
+        .unwrap()
+        .into_iter()
+        .filter_map(|record| PetRecord::try_from(record).ok())
+        .map(|record| record.name)
+        .collect_vec();
+    assert!(token_names.contains(&PetName::ZombieCricket));
+
+    // Generic queries never summon tokens.
+    let tier_query = SAPQuery::builder()
+        .set_table(Entity::Pet)
+        .set_param(PetColumn::Lvl, vec![1]);
+    for seed in 0..20 {
+        ant.write().unwrap().seed = Some(seed);
+        let pet = SummonType::QueryPet(tier_query.clone(), None)
+            .to_pet(&team, &ant)
+            .unwrap();
+        assert!(!token_names.contains(&pet.name));
+    }
+
+    // Unless explicitly requested.
+    let token_query = SAPQuery::builder()
+        .set_table(Entity::Pet)
+        .set_param(PetColumn::Name, vec![PetName::ZombieCricket]);
+    let pet = SummonType::QueryPet(token_query, None)
+        .to_pet(&team, &ant)
+        .unwrap();
+    assert_eq!(pet.name, PetName::ZombieCricket);
+}
+
+#[test]
+fn test_team_smaller_slug() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::SmallerSlug).unwrap())], 5).unwrap();
+    let mut enemy_team = test_ant_team();
+    assert!(Pet::try_from(PetName::SmallestSlug)
+        .unwrap()
+        .effect
+        .is_empty());
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Smaller slug faints and summons the smallest slug.
+    assert!(team
+        .all()
+        .iter()
+        .chain(team.fainted.iter().flatten())
+        .any(|pet| pet.read().unwrap().name == PetName::SmallestSlug));
+}
+
+#[test]
+fn test_team_perk_helpers() {
+    let mut team = test_ant_team();
+    let strawberry = Food::try_from(FoodName::Strawberry).unwrap();
+    team.set_item(&Position::First, Some(strawberry.clone()))
+        .unwrap()
+        .set_item(&Position::Last, Some(strawberry))
+        .unwrap()
+        .set_item(
+            &Position::Relative(-1),
+            Some(Food::try_from(FoodName::Garlic).unwrap()),
+        )
+        .unwrap();
+
+    let strawberry_pets = team.pets_with_perk(&FoodName::Strawberry);
+    assert_eq!(strawberry_pets.len(), 2);
+    assert!(Arc::ptr_eq(&strawberry_pets[0], &team.first().unwrap()));
+    assert!(team.pets_with_perk(&FoodName::Melon).is_empty());
+    assert_eq!(
+        team.count_perks(),
+        [(FoodName::Strawberry, 2), (FoodName::Garlic, 1)]
+    );
+    assert!(team.pets_without_item().is_empty());
+
+    // Ailments count as held items but not perks.
+    team.set_item(&Position::First, None).unwrap();
+    assert_eq!(team.pets_without_item().len(), 1);
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(FoodName::Weak).unwrap()),
+    )
+    .unwrap();
+    assert!(team.pets_without_item().is_empty());
+    assert_eq!(
+        team.count_perks(),
+        [(FoodName::Garlic, 1), (FoodName::Strawberry, 1)]
+    );
+}
+
+#[test]
+fn test_team_custom_pet_registry() {
+    let phoenix_name = PetName::Custom("Phoenix".to_string());
+    let rebirth_effect = Effect::new(
+        TRIGGER_SELF_FAINT,
+        Target::Friend,
+        Position::OnSelf,
+        Action::Summon(SummonType::DefaultPet(phoenix_name.clone())),
+        Some(1),
+        false,
+    );
+    let phoenix = Pet::custom("Phoenix", Statistics::new(1, 1).unwrap(), &[rebirth_effect]);
+    // Unregistered custom pets can't be rebuilt by name.
+    assert!(Pet::try_from(phoenix_name.clone()).is_err());
+
+    register_custom_pet(&phoenix).unwrap();
+    let mut rebuilt_phoenix = Pet::try_from(phoenix_name.clone()).unwrap();
+    assert_eq!(rebuilt_phoenix.effect, phoenix.effect);
+    // Effects are kept on level up.
+    rebuilt_phoenix.set_level(2).unwrap();
+    assert_eq!(rebuilt_phoenix.effect, phoenix.effect);
+
+    // Serialize team and restore it.
+    let team = Team::new(&[Some(phoenix)], 5).unwrap();
+    let json_team: String = (&team).try_into().unwrap();
+    let mut team = Team::from_str(&json_team).unwrap();
+    let mut enemy_team = Team::new(
+        &[Some(Pet::custom(
+            "Wall",
+            Statistics::new(50, 50).unwrap(),
+            &[],
+        ))],
+        5,
+    )
+    .unwrap();
+
+    // Phoenix faints and summons another phoenix by name.
+    team.fight(&mut enemy_team).unwrap();
+    assert_eq!(team.fainted.len(), 1);
+    let new_phoenix = team.first().unwrap();
+    assert_eq!(new_phoenix.read().unwrap().name, phoenix_name);
+    assert_eq!(
+        new_phoenix.read().unwrap().stats,
+        Statistics::new(1, 1).unwrap()
+    );
+
+    assert!(unregister_custom_pet(&phoenix_name).is_some());
+    assert!(Pet::try_from(phoenix_name).is_err());
+}
+
+#[test]
+fn test_team_pet_nickname() {
+    let mut anthony = Pet::try_from(PetName::Ant).unwrap();
+    anthony.set_nickname(Some("Anthony"));
+    let team = Team::new(
+        &[Some(Pet::try_from(PetName::Ant).unwrap()), Some(anthony)],
+        5,
+    )
+    .unwrap();
+
+    // Ids and nicknames survive serialization.
+    let json_team: String = (&team).try_into().unwrap();
+    let mut team = Team::from_str(&json_team).unwrap();
+    let (ant, anthony) = (team.first().unwrap(), team.nth(1).unwrap());
+    assert_eq!(ant.read().unwrap().get_id(), Some(0));
+    assert_eq!(ant.read().unwrap().label().unwrap(), "Ant_0");
+    assert_eq!(anthony.read().unwrap().get_id(), Some(1));
+    assert_eq!(anthony.read().unwrap().nickname.as_deref(), Some("Anthony"));
+
+    // Nickname replaces name and id in battle graph.
+    let mut enemy_team = test_ant_team();
+    team.fight(&mut enemy_team).unwrap();
+    let node_ids = team
+        .history
+        .graph
+        .pet_nodes
+        .keys()
+        .filter(|node| node.team == team.name)
+        .map(|node| node.id.clone())
+        .collect_vec();
+    assert!(node_ids.contains(&"Anthony".to_string()));
+    assert!(!node_ids.contains(&"Ant_1".to_string()));
+}
+
+#[test]
+fn test_team_battle_experience_levelup() {
+    let train_effect = Effect::new(
+        TRIGGER_START_BATTLE,
+        Target::Friend,
+        Position::First,
+        Action::Experience(2),
+        Some(1),
+        false,
+    );
+    let trainer = Pet::custom("Trainer", Statistics::new(1, 1).unwrap(), &[train_effect]);
+    let mut team = Team::new(
+        &[Some(Pet::try_from(PetName::Fish).unwrap()), Some(trainer)],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+    let (fish, trainer) = (team.first().unwrap(), team.nth(1).unwrap());
+    let fish_stats = fish.read().unwrap().stats;
+    let lvl_2_fish_effect = fish.read().unwrap().effects_at_level(2).unwrap();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Fish levels up mid-battle gaining stats and its level 2 effect.
+    assert_eq!(fish.read().unwrap().get_level(), 2);
+    assert_eq!(
+        fish.read().unwrap().stats,
+        fish_stats + Statistics::new(2, 2).unwrap()
+    );
+    assert_eq!(
+        fish.read()
+            .unwrap()
+            .effect
+            .iter()
+            .map(|effect| &effect.action)
+            .collect_vec(),
+        lvl_2_fish_effect
+            .iter()
+            .map(|effect| &effect.action)
+            .collect_vec()
+    );
+    // Fish level up effect buffs the trainer.
+    assert!(trainer.read().unwrap().stats.attack > 1);
+
+    // Experience past the max level is lost.
+    fish.write().unwrap().set_level(3).unwrap();
+    let mut train_effect = trainer.read().unwrap().effect[0].clone();
+    train_effect.uses = Some(1);
+    assert!(team
+        .apply_effect(&train_effect, Some(&mut enemy_team))
+        .is_ok());
+    assert_eq!(fish.read().unwrap().get_level(), 3);
+}
+
+#[test]
+fn test_team_chick_summon_stats() {
+    let chick_stats = |rooster_stats: Statistics, stat_cap: StatCap| {
+        let mut rooster = Pet::try_from(PetName::Rooster).unwrap();
+        rooster.stats = rooster_stats;
+        let mut team = Team::new(&[Some(rooster)], 5).unwrap();
+        team.set_battle_rules(BattleRules {
+            stat_cap,
+            ..Default::default()
+        });
+        let rooster = team.first().unwrap();
+        let summon = SummonType::CustomPet(
+            PetName::Chick,
+            StatChangeType::Multiplier(Statistics::new(50, 0).unwrap()),
+            1,
+        );
+        let chick = summon.to_pet(&team, &rooster).unwrap();
+        assert_eq!(chick.name, PetName::Chick);
+        assert!(chick.effect.is_empty());
+        chick.stats
+    };
+
+    // Chick has half of the Rooster's attack and 1 health.
+    assert_eq!(
+        chick_stats(Statistics::new(6, 4).unwrap(), StatCap::default()),
+        Statistics::new(3, 1).unwrap()
+    );
+    // But always at least 1 attack.
+    assert_eq!(
+        chick_stats(Statistics::new(1, 4).unwrap(), StatCap::default()),
+        Statistics::new(1, 1).unwrap()
+    );
+    // And never more than the team's stat cap.
+    assert_eq!(
+        chick_stats(Statistics::new(100, 4).unwrap(), StatCap::Clamp(20)),
+        Statistics::new(20, 1).unwrap()
+    );
+    assert_eq!(
+        chick_stats(Statistics::new(100, 4).unwrap(), StatCap::Saturate),
+        Statistics::new(50, 1).unwrap()
+    );
+}
@@ -0,0 +1,96 @@
+use itertools::Itertools;
+
+use crate::{
+    db::{pack::Pack, record::PetRecord},
+    effects::trigger::TRIGGER_SELF_LEVELUP,
+    teams::team::TeamFightOutcome,
+    Effect, Entity, Pet, PetColumn, SAPQuery, Team, TeamCombat, SAPDB,
+};
+
+/// Get the records of all Puppy pack pets that aren't tokens.
+fn puppy_pack_records() -> Vec<PetRecord> {
+    let query = SAPQuery::builder()
+        .set_table(Entity::Pet)
+        .set_param(PetColumn::Pack, vec![Pack::Puppy]);
+    SAPDB
+        .execute_query(query)
+        .unwrap()
+        .into_iter()
+        .filter_map(|record| PetRecord::try_from(record).ok())
+        .filter(|record| !record.is_token)
+        .collect_vec()
+}
+
+/// Fight teams of the level 1 Puppy pack pets of a tier until one team wins.
+fn puppy_pack_tier_battle(tier: usize) {
+    let pets = puppy_pack_records()
+        .into_iter()
+        .filter(|record| record.tier == tier && record.lvl == 1)
+        .map(|record| Some(Pet::try_from(record).unwrap()))
+        .collect_vec();
+    assert!(!pets.is_empty());
+
+    for team_pets in pets.chunks(5) {
+        let mut team = Team::new(team_pets, 5).unwrap();
+        let enemy_pets = team_pets.iter().rev().cloned().collect_vec();
+        let mut enemy_team = Team::new(&enemy_pets, 5).unwrap();
+        team.set_name("Puppies").unwrap();
+        enemy_team.set_name("Enemy Puppies").unwrap();
+
+        let mut outcome = team.fight(&mut enemy_team).unwrap();
+        while let TeamFightOutcome::None = outcome {
+            outcome = team.fight(&mut enemy_team).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_puppy_pack_pet_effects() {
+    let records = puppy_pack_records();
+    assert!(!records.is_empty());
+
+    for record in records {
+        let (name, lvl) = (record.name.clone(), record.lvl);
+        let effects: Vec<Effect> = record.try_into().unwrap();
+        // Pets at max level can't level up.
+        let levelup_effect = Pet::try_from(name.clone())
+            .unwrap()
+            .effect
+            .iter()
+            .any(|effect| effect.trigger == TRIGGER_SELF_LEVELUP);
+        assert!(
+            !effects.is_empty() || (lvl == 3 && levelup_effect),
+            "{name} (Level {lvl}) has no effect."
+        );
+    }
+}
+
+#[test]
+fn test_battle_puppy_pack_t1_teams() {
+    puppy_pack_tier_battle(1)
+}
+
+#[test]
+fn test_battle_puppy_pack_t2_teams() {
+    puppy_pack_tier_battle(2)
+}
+
+#[test]
+fn test_battle_puppy_pack_t3_teams() {
+    puppy_pack_tier_battle(3)
+}
+
+#[test]
+fn test_battle_puppy_pack_t4_teams() {
+    puppy_pack_tier_battle(4)
+}
+
+#[test]
+fn test_battle_puppy_pack_t5_teams() {
+    puppy_pack_tier_battle(5)
+}
+
+#[test]
+fn test_battle_puppy_pack_t6_teams() {
+    puppy_pack_tier_battle(6)
+}
@@ -3,9 +3,16 @@ use std::sync::{Arc, RwLock, Weak};
 use itertools::Itertools;
 
 use crate::{
+    db::{pack::Pack, record::PetRecord},
+    effects::{
+        actions::{Action, ConditionType, GoldChangeType, LogicType, StatChangeType},
+        state::{ShopCondition, Status, Target},
+    },
     shop::store::ShopState,
-    teams::{combat::TeamCombat, viewer::TeamViewer},
-    Entity, ItemCondition, Pet, PetName, Position, ShopItemViewer, ShopViewer, Team, TeamShopping,
+    teams::{combat::TeamCombat, effect_helpers::EffectApplyHelpers, viewer::TeamViewer},
+    Economy, Effect, Entity, EntityName, Food, FoodName, ItemCondition, Pet, PetColumn, PetName,
+    Position, SAPQuery, Shop, ShopAction, ShopItem, ShopItemViewer, ShopStrategy, ShopViewer,
+    Statistics, Team, TeamShopping, SAPDB,
 };
 
 use super::common::test_jellyfish_team;
@@ -249,3 +256,697 @@ fn test_shop_move() {
         )
         .is_err())
 }
+
+#[test]
+fn test_team_end_turn_summary() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Beaver).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.set_shop_seed(Some(42)).open_shop().unwrap();
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+    team.roll_shop().unwrap();
+    team.sell(&Position::Last).unwrap();
+    // Merge ants. 1 exp is not enough to level.
+    team.move_pets(&Position::First, &Position::Relative(-1), true)
+        .unwrap();
+    team.close_shop().unwrap();
+
+    let summary = team.end_turn_summary();
+    assert_eq!(summary.gold_spent, 4);
+    assert_eq!(summary.bought.len(), 1);
+    assert_eq!(summary.sold, vec![PetName::Beaver]);
+    assert!(summary.levelled.is_empty());
+
+    // Reopening the shop resets the summary.
+    team.open_shop().unwrap();
+    assert_eq!(team.end_turn_summary(), Default::default());
+}
+
+#[test]
+fn test_team_shop_levelup_offer() {
+    let ant = Pet::try_from(PetName::Ant).unwrap();
+    let mut team = Team::new(&vec![Some(ant); 3], 5).unwrap();
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+
+    // Make room for the levelup pet.
+    team.shop.pets.pop();
+    let n_shop_pets = team.len_shop_pets();
+
+    // Merge ants to level 2.
+    team.move_pets(&Position::First, &Position::Relative(-1), true)
+        .unwrap();
+    team.move_pets(&Position::First, &Position::Relative(-1), true)
+        .unwrap();
+    assert_eq!(team.first().unwrap().read().unwrap().lvl, 2);
+
+    // Pet a tier higher than the shop was added.
+    assert_eq!(team.len_shop_pets(), n_shop_pets + 1);
+    let levelup_pet = team.shop.pets.last().unwrap();
+    assert_eq!(levelup_pet.tier(), team.shop_tier() + 1);
+    assert_eq!(team.end_turn_summary().levelled, vec![(PetName::Ant, 2)]);
+}
+
+#[test]
+fn test_team_shop_levelup_offer_disabled() {
+    let ant = Pet::try_from(PetName::Ant).unwrap();
+    let mut team = Team::new(&vec![Some(ant); 3], 5).unwrap();
+    team.set_shop_seed(Some(12))
+        .set_levelup_offer(false)
+        .open_shop()
+        .unwrap();
+    team.shop.pets.pop();
+    let n_shop_pets = team.len_shop_pets();
+
+    team.move_pets(&Position::First, &Position::Relative(-1), true)
+        .unwrap();
+    team.move_pets(&Position::First, &Position::Relative(-1), true)
+        .unwrap();
+    assert_eq!(team.len_shop_pets(), n_shop_pets);
+}
+
+#[test]
+fn test_team_shop_strategy() {
+    // Buy the first pet until the team is full then end turn.
+    struct BuyFirstPet;
+    impl ShopStrategy for BuyFirstPet {
+        fn act(&mut self, team: &Team, shop: &Shop) -> ShopAction {
+            if team.all().len() == 5 || shop.pets.is_empty() || team.gold() < 3 {
+                ShopAction::EndTurn
+            } else {
+                ShopAction::Buy {
+                    from: Position::First,
+                    item_type: Entity::Pet,
+                    to: Position::First,
+                }
+            }
+        }
+    }
+
+    let mut team = Team::default();
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+
+    let actions = BuyFirstPet.run(&mut team).unwrap();
+    assert_eq!(actions.last(), Some(&ShopAction::EndTurn));
+    assert_eq!(actions.len(), 4);
+    assert_eq!(team.gold(), 1);
+    assert_eq!(team.end_turn_summary().bought.len(), 3);
+}
+
+#[test]
+fn test_team_shop_pack_weights() {
+    let mut team = Team::default();
+    team.set_shop_seed(Some(12))
+        .set_shop_pack_weights(&[(Pack::Turtle, 0.0), (Pack::Puppy, 1.0)])
+        .unwrap();
+    assert_eq!(team.get_shop_packs(), &[Pack::Turtle, Pack::Puppy]);
+    team.open_shop().unwrap();
+
+    // Only puppy pack pets are rolled.
+    let query = SAPQuery::builder()
+        .set_table(Entity::Pet)
+        .set_param(PetColumn::Pack, vec![Pack::Puppy]);
+    let puppy_pets = SAPDB
+        .execute_query(query)
+        .unwrap()
+        .into_iter()
+        .filter_map(|record| PetRecord::try_from(record).ok())
+        .map(|record| EntityName::Pet(record.name))
+        .collect_vec();
+    assert!(team
+        .shop
+        .pets
+        .iter()
+        .all(|pet| puppy_pets.contains(&pet.name())));
+
+    // Negative weights are invalid.
+    assert!(team.set_shop_pack_weights(&[(Pack::Turtle, -1.0)]).is_err());
+}
+
+#[test]
+fn test_team_shop_pack_rotation() {
+    let mut team = Team::default();
+    team.set_shop_packs(&[Pack::Turtle])
+        .set_shop_pack_rotation(&[(2, vec![Pack::Puppy])]);
+
+    // Turn 1 uses the shop's packs.
+    team.open_shop().unwrap();
+    assert_eq!(team.shop.active_packs(), &[Pack::Turtle]);
+    team.close_shop().unwrap();
+
+    // Turn 2 uses the puppy pack.
+    team.history.curr_turn += 1;
+    team.open_shop().unwrap();
+    assert_eq!(team.shop.active_packs(), &[Pack::Puppy]);
+    assert_eq!(team.get_shop_packs(), &[Pack::Turtle]);
+}
+
+#[test]
+fn test_team_shop_freeze_all() {
+    let mut team = Team::default();
+
+    // Shop must be open.
+    assert!(team.freeze_all().is_err());
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+
+    team.freeze_all().unwrap();
+    let frozen_pets = team
+        .shop
+        .get_shop_items_by_pos(&Position::Frozen, &Entity::Pet)
+        .unwrap();
+    let frozen_foods = team
+        .shop
+        .get_shop_items_by_pos(&Position::Frozen, &Entity::Food)
+        .unwrap();
+    assert_eq!(frozen_pets.len(), team.len_shop_pets());
+    assert_eq!(frozen_foods.len(), team.len_shop_foods());
+
+    // Toggle first pet and food to unfreeze them.
+    team.toggle_freeze(&Position::First).unwrap();
+    assert!(!team.shop.pets[0].is_frozen() && !team.shop.foods[0].is_frozen());
+    assert!(team.shop.pets[1].is_frozen());
+
+    team.unfreeze_all().unwrap();
+    assert!(team
+        .shop
+        .get_shop_items_by_pos(&Position::Frozen, &Entity::Pet)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_team_shop_unfreeze_action() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+    team.freeze_all().unwrap();
+
+    let mut effect = Effect::builder()
+        .set_target(Target::Shop)
+        .set_action(Action::Unfreeze(Entity::Food))
+        .build();
+    effect.assign_owner(team.first().as_ref());
+    team.apply_shop_effect(&effect).unwrap();
+
+    // Only foods are unfrozen.
+    assert!(team.shop.foods.iter().all(|food| !food.is_frozen()));
+    assert!(team.shop.pets.iter().all(|pet| pet.is_frozen()));
+
+    // Toys cannot be unfrozen.
+    effect.action = Action::Unfreeze(Entity::Toy);
+    assert!(team.apply_shop_effect(&effect).is_err());
+}
+
+#[test]
+fn test_team_shop_alter_gold_action() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.open_shop().unwrap();
+    assert_eq!(team.gold(), 10);
+
+    // Lose half of gold down to a minimum of 4 if at least 5 gold. Otherwise, gain 1 gold.
+    let mut effect = Effect::builder()
+        .set_target(Target::Shop)
+        .set_action(Action::Conditional(
+            LogicType::If(ConditionType::Shop(ShopCondition::GoldAtLeast(5))),
+            Box::new(Action::AlterGold(GoldChangeType::Clamped(
+                Box::new(GoldChangeType::Percent(-50)),
+                4,
+            ))),
+            Box::new(Action::AlterGold(GoldChangeType::Static(1))),
+        ))
+        .build();
+    effect.assign_owner(team.first().as_ref());
+
+    team.apply_shop_effect(&effect).unwrap();
+    assert_eq!(team.gold(), 5);
+    // Halving 5 gold would leave 3 gold.
+    team.apply_shop_effect(&effect).unwrap();
+    assert_eq!(team.gold(), 4);
+    team.apply_shop_effect(&effect).unwrap();
+    assert_eq!(team.gold(), 5);
+}
+
+#[test]
+fn test_team_shop_effect_duration() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.open_shop().unwrap();
+    let ant = team.first().unwrap();
+    let ant_stats = ant.read().unwrap().stats;
+
+    // Temporary stats lasting two shop turns.
+    let buff = Statistics::new(1, 1).unwrap();
+    let effect = Effect::builder()
+        .set_target(Target::Friend)
+        .set_position(Position::OnSelf)
+        .set_action(Action::Add(StatChangeType::Static(buff)))
+        .set_temp(true)
+        .set_duration(Some(2))
+        .build();
+    team.apply_single_effect(&ant, &ant, &effect, None).unwrap();
+
+    // Held item expiring after one shop turn.
+    let mut honey = Food::try_from(FoodName::Honey).unwrap();
+    honey.duration = Some(1);
+    ant.write().unwrap().items.perk = Some(Box::new(honey));
+
+    team.close_shop().unwrap().open_shop().unwrap();
+    let ant = team.first().unwrap();
+    assert_eq!(ant.read().unwrap().stats, ant_stats + buff);
+    assert!(ant.read().unwrap().items.is_empty());
+
+    team.close_shop().unwrap().open_shop().unwrap();
+    let ant = team.first().unwrap();
+    assert_eq!(ant.read().unwrap().stats, ant_stats);
+    assert!(team.shop.temp_stats.is_empty());
+}
+
+#[test]
+fn test_team_shop_history() {
+    let mut team = Team::default();
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+
+    team.buy(&Position::First, &Entity::Pet, &Position::First)
+        .unwrap();
+    let bought_pet = team.first().unwrap().read().unwrap().name.clone();
+    team.roll_shop().unwrap();
+    team.freeze_shop(&Position::First, &Entity::Food).unwrap();
+    team.sell(&Position::First).unwrap();
+
+    let history = team.shop_history();
+    assert_eq!(
+        history.iter().map(|record| &record.action).collect_vec(),
+        vec![
+            &ShopAction::Buy {
+                from: Position::First,
+                item_type: Entity::Pet,
+                to: Position::First
+            },
+            &ShopAction::Roll,
+            &ShopAction::Freeze {
+                pos: Position::First,
+                item_type: Entity::Food
+            },
+            &ShopAction::Sell(Position::First),
+        ]
+    );
+    assert!(history.iter().all(|record| record.turn == 1));
+    assert_eq!(
+        history
+            .iter()
+            .take(3)
+            .map(|record| (record.gold_before, record.gold_after))
+            .collect_vec(),
+        vec![(10, 7), (7, 6), (6, 6)]
+    );
+    assert!(history[3].gold_before == 6 && history[3].gold_after > 6);
+    assert_eq!(history[0].items, vec![EntityName::Pet(bought_pet.clone())]);
+    assert_eq!(history[3].items, vec![EntityName::Pet(bought_pet)]);
+
+    // Failed actions aren't recorded.
+    team.close_shop().unwrap();
+    assert!(team.roll_shop().is_err());
+    assert_eq!(team.shop_history().len(), 4);
+}
+
+#[test]
+fn test_team_shop_auto_tier() {
+    let mut team = Team::default();
+    // Default schedule.
+    team.history.curr_turn = 5;
+    team.open_shop().unwrap();
+    assert_eq!(team.shop_tier(), 3);
+    team.close_shop().unwrap();
+
+    // Custom schedule.
+    team.set_shop_tier_schedule(&[1, 2, 3]).unwrap();
+    team.open_shop().unwrap();
+    assert_eq!(team.shop_tier(), 3);
+    team.close_shop().unwrap();
+    team.history.curr_turn = 2;
+    team.open_shop().unwrap();
+    assert_eq!(team.shop_tier(), 2);
+    team.close_shop().unwrap();
+    // Tier 4 is never reached.
+    assert!(team.set_shop_tier(4).is_err());
+
+    // Disabled auto tier keeps the current tier.
+    team.set_auto_tier(false);
+    team.history.curr_turn = 3;
+    team.open_shop().unwrap();
+    assert_eq!(team.shop_tier(), 2);
+
+    // Invalid schedules.
+    assert!(team.set_shop_tier_schedule(&[]).is_err());
+    assert!(team.set_shop_tier_schedule(&[1, 3, 3]).is_err());
+}
+
+#[test]
+fn test_team_shop_toys() {
+    let mut team = Team::default();
+    team.set_shop_seed(Some(12)).set_shop_tier(6).unwrap();
+    team.shop.toy_slots = 1;
+    team.open_shop().unwrap();
+    assert_eq!(team.shop.len_toys(), 1);
+
+    // Frozen toys are kept on roll.
+    team.freeze_shop(&Position::First, &Entity::Toy).unwrap();
+    let frozen_toy = team.shop.toys[0].clone();
+    team.roll_shop().unwrap();
+    assert_eq!(team.shop.toys, vec![frozen_toy.clone()]);
+
+    // Buying a toy adds it to the team.
+    let EntityName::Toy(toy_name) = frozen_toy.name() else {
+        panic!("Not a toy.")
+    };
+    team.buy(&Position::First, &Entity::Toy, &Position::None)
+        .unwrap();
+    assert_eq!(team.shop.len_toys(), 0);
+    assert_eq!(team.toys.last().unwrap().name, toy_name);
+    assert_eq!(
+        team.end_turn_summary().bought,
+        vec![EntityName::Toy(toy_name)]
+    );
+
+    // No toy slots by default.
+    let mut team = Team::default();
+    team.open_shop().unwrap();
+    assert_eq!(team.shop.len_toys(), 0);
+}
+
+#[test]
+fn test_team_shop_sleeping_pill() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Deer).unwrap())], 5).unwrap();
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::from(
+        Food::try_from(FoodName::SleepingPill).unwrap(),
+    ))
+    .unwrap();
+    team.replace_shop(shop).unwrap().open_shop().unwrap();
+
+    // No pet to give pill to. Gold is not spent.
+    assert!(team
+        .buy(&Position::Last, &Entity::Food, &Position::Relative(-2))
+        .is_err());
+    assert_eq!(team.gold(), 10);
+
+    // Deer faints in the shop and summons a bus.
+    team.buy(&Position::Last, &Entity::Food, &Position::First)
+        .unwrap();
+    assert_eq!(team.gold(), 9);
+    assert_eq!(team.fainted.len(), 1);
+    assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Bus);
+
+    // Deer does not return after the turn ends.
+    team.close_shop().unwrap();
+    assert_eq!(team.stored_friends.len(), 1);
+    assert_eq!(team.stored_friends[0].as_ref().unwrap().name, PetName::Bus);
+}
+
+#[test]
+fn test_team_shop_economy() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.set_shop_seed(Some(42))
+        .set_shop_economy(Economy {
+            pet_cost: 2,
+            food_costs: vec![(FoodName::Apple, 1), (FoodName::Honey, 1)],
+            sell_values: vec![4],
+            max_gold: Some(11),
+            ..Default::default()
+        })
+        .open_shop()
+        .unwrap();
+
+    // Costs applied to stocked items.
+    assert!(team.shop.pets.iter().all(|pet| pet.cost == 2));
+    assert!(team.shop.foods.iter().all(|food| food.cost == 1));
+
+    team.buy(&Position::First, &Entity::Pet, &Position::First)
+        .unwrap();
+    assert_eq!(team.gold(), 8);
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+    assert_eq!(team.gold(), 7);
+
+    // Sell values use economy and gold is capped.
+    team.sell(&Position::Last).unwrap();
+    assert_eq!(team.gold(), 11);
+}
+
+#[test]
+fn test_team_shop_food_multiple_targets() {
+    let mut team = Team::new(&vec![Some(Pet::try_from(PetName::Ant).unwrap()); 4], 5).unwrap();
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::from(Food::try_from(FoodName::Sushi).unwrap()))
+        .unwrap();
+    team.replace_shop(shop).unwrap().open_shop().unwrap();
+
+    // Sushi only has three targets.
+    let too_many_targets = Position::Multiple(vec![
+        Position::First,
+        Position::Relative(-1),
+        Position::Relative(-2),
+        Position::Relative(-3),
+    ]);
+    assert!(team
+        .buy(&Position::Last, &Entity::Food, &too_many_targets)
+        .is_err());
+    assert_eq!(team.gold(), 10);
+
+    // Skip the second ant.
+    team.buy(
+        &Position::Last,
+        &Entity::Food,
+        &Position::Multiple(vec![
+            Position::First,
+            Position::Relative(-2),
+            Position::Relative(-3),
+        ]),
+    )
+    .unwrap();
+    let attacks = team
+        .all()
+        .iter()
+        .map(|pet| pet.read().unwrap().stats.attack)
+        .collect_vec();
+    assert_eq!(attacks, vec![3, 2, 3, 3]);
+}
+
+#[test]
+fn test_team_shop_subscribe() {
+    let statuses = Arc::new(RwLock::new(vec![]));
+    let all_statuses = statuses.clone();
+
+    let mut team = Team::default();
+    team.set_shop_seed(Some(42))
+        .subscribe_shop(
+            &[Status::BuyPet, Status::Sell, Status::Roll],
+            move |trigger| statuses.write().unwrap().push(trigger.status.clone()),
+        )
+        .open_shop()
+        .unwrap();
+
+    team.buy(&Position::First, &Entity::Pet, &Position::First)
+        .unwrap()
+        .sell(&Position::First)
+        .unwrap()
+        .roll_shop()
+        .unwrap();
+
+    let found_statuses = all_statuses.read().unwrap().clone();
+    assert!(found_statuses.contains(&Status::BuyPet));
+    assert!(found_statuses.contains(&Status::Sell));
+    assert_eq!(found_statuses.last(), Some(&Status::Roll));
+    // Only subscribed statuses are observed.
+    assert!(found_statuses
+        .iter()
+        .all(|status| [Status::BuyPet, Status::Sell, Status::Roll].contains(status)));
+
+    // No longer notified.
+    let n_statuses = found_statuses.len();
+    team.clear_shop_subscribers().roll_shop().unwrap();
+    assert_eq!(all_statuses.read().unwrap().len(), n_statuses);
+}
+
+#[test]
+fn test_team_shop_buy_auto() {
+    let mut honey_ant = Pet::try_from(PetName::Ant).unwrap();
+    honey_ant.items.perk = Some(Box::new(Food::try_from(FoodName::Honey).unwrap()));
+    let mut pets = vec![Some(honey_ant)];
+    pets.extend(vec![Some(Pet::try_from(PetName::Ant).unwrap()); 3]);
+    let mut team = Team::new(&pets, 5).unwrap();
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::from(Pet::try_from(PetName::Mosquito).unwrap()))
+        .unwrap()
+        .add_item(ShopItem::from(Pet::try_from(PetName::Duck).unwrap()))
+        .unwrap()
+        .add_item(ShopItem::from(Food::try_from(FoodName::Honey).unwrap()))
+        .unwrap();
+    team.replace_shop(shop).unwrap().open_shop().unwrap();
+
+    // Honey given to the first pet without an item.
+    let pos = team.buy_auto(&Position::First, &Entity::Food).unwrap();
+    assert_eq!(pos, Position::Relative(-1));
+    assert!(team.nth(1).unwrap().read().unwrap().items.perk.is_some());
+
+    // Mosquito placed in the last open slot.
+    let pos = team.buy_auto(&Position::First, &Entity::Pet).unwrap();
+    assert_eq!(pos, Position::Relative(-4));
+    assert_eq!(team.nth(4).unwrap().read().unwrap().name, PetName::Mosquito);
+
+    // Team full and no copy of duck to merge into.
+    assert!(team.buy_auto(&Position::First, &Entity::Pet).is_err());
+    assert_eq!(team.len_shop_pets(), 2);
+}
+
+#[test]
+fn test_team_shop_play_turn() {
+    let mut team = Team::default();
+    team.set_shop_seed(Some(42));
+
+    // Roll until out of gold.
+    let summary = team.play_shop_turn(|_, _| ShopAction::Roll).unwrap();
+    assert_eq!(summary.gold_spent, 10);
+    assert_eq!(team.get_shop().state, ShopState::Closed);
+    assert_eq!(team.shop_history().len(), 10);
+
+    // End turn immediately.
+    let summary = team.play_shop_turn(|_, _| ShopAction::EndTurn).unwrap();
+    assert_eq!(summary.gold_spent, 0);
+    assert_eq!(team.get_shop().state, ShopState::Closed);
+
+    // Invalid actions stop the turn.
+    assert!(team
+        .play_shop_turn(|_, _| ShopAction::Sell(Position::First))
+        .is_err());
+}
+
+#[test]
+fn test_team_shop_hard_mode() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+    // No hard mode toys by default.
+    assert!(team.toys.iter().all(|toy| !toy.hard_mode));
+    team.close_shop().unwrap();
+
+    team.set_hard_mode(true).open_shop().unwrap();
+    assert_eq!(team.toys.iter().filter(|toy| toy.hard_mode).count(), 1);
+    let toy_name = team.toys[0].name.clone();
+    team.close_shop().unwrap();
+
+    // Toy is kept while it has duration left.
+    team.open_shop().unwrap();
+    assert_eq!(team.toys.iter().filter(|toy| toy.hard_mode).count(), 1);
+    assert_eq!(team.toys[0].name, toy_name);
+}
+
+#[test]
+fn test_team_shop_carry_over_gold() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.set_shop_economy(Economy {
+        carry_over: 2,
+        interest_per: Some(3),
+        max_interest: Some(2),
+        max_gold: Some(14),
+        ..Default::default()
+    });
+
+    // 10 gold unspent. 2 gold carried and 2 gold interest.
+    team.open_shop().unwrap().close_shop().unwrap();
+    assert_eq!(team.gold(), 14);
+
+    // Capped at max gold.
+    team.open_shop().unwrap().close_shop().unwrap();
+    assert_eq!(team.gold(), 14);
+
+    // Spend all gold. No carry over or interest.
+    team.open_shop().unwrap();
+    team.shop.coins = 0;
+    team.close_shop().unwrap();
+    assert_eq!(team.gold(), 10);
+}
+
+#[test]
+fn test_team_shop_reserve() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Chipmunk).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut shop = Shop::default();
+    shop.set_tier(3).unwrap();
+    shop.add_item(ShopItem::from(Food::try_from(FoodName::Apple).unwrap()))
+        .unwrap()
+        .add_item(ShopItem::from(Food::try_from(FoodName::Honey).unwrap()))
+        .unwrap()
+        .add_item(ShopItem::from(Pet::try_from(PetName::Mosquito).unwrap()))
+        .unwrap();
+    team.replace_shop(shop).unwrap().open_shop().unwrap();
+
+    // Only frozen items can be reserved.
+    assert!(team.reserve_shop(&Position::First, &Entity::Food).is_err());
+    team.freeze_all()
+        .unwrap()
+        .reserve_shop(&Position::First, &Entity::Food)
+        .unwrap()
+        .reserve_shop(&Position::First, &Entity::Pet)
+        .unwrap();
+
+    // Chipmunk clears shop foods but the reserved apple is kept.
+    team.sell(&Position::First).unwrap();
+    let foods = team
+        .shop
+        .get_shop_items_by_pos(&Position::All(ItemCondition::None), &Entity::Food)
+        .unwrap();
+    assert_eq!(foods.len(), 1);
+    assert!(foods[0].is_reserved() && foods[0].name() == EntityName::Food(FoodName::Apple));
+
+    // Reserved items are kept after rolls.
+    team.roll_shop().unwrap();
+    let pet = team.shop.pets.first().unwrap();
+    assert!(pet.is_reserved() && pet.name() == EntityName::Pet(PetName::Mosquito));
+
+    // Unfreezing removes reservation.
+    team.unfreeze_all().unwrap();
+    assert!(team.shop.pets.iter().all(|pet| !pet.is_reserved()));
+}
+
+#[test]
+fn test_tier_scaled_stat_change() {
+    let mut team = Team::default();
+    team.shop.set_tier(3).unwrap();
+    team.history.curr_turn = 4;
+    let stats = Statistics::new(1, 2).unwrap();
+
+    assert_eq!(
+        StatChangeType::ShopTierMultiplier(stats)
+            .to_stats(None, Some(&team), None, false)
+            .unwrap(),
+        Statistics::new(3, 6).unwrap()
+    );
+    assert_eq!(
+        StatChangeType::TurnMultiplier(stats)
+            .to_stats(None, Some(&team), None, false)
+            .unwrap(),
+        Statistics::new(4, 8).unwrap()
+    );
+    // No tier 1 pets in the shop.
+    assert_eq!(
+        StatChangeType::ShopTierPetsMultiplier(stats, 1)
+            .to_stats(None, Some(&team), None, false)
+            .unwrap(),
+        Statistics::new(0, 0).unwrap()
+    );
+    // Needs a team.
+    assert!(StatChangeType::TurnMultiplier(stats)
+        .to_stats(None, None, None, false)
+        .is_err());
+}
@@ -2,7 +2,14 @@ use crate::{
     effects::{effect::EntityName, stats::Statistics, trigger::TRIGGER_START_BATTLE},
     foods::names::FoodName,
     pets::names::PetName,
-    teams::{combat::TeamCombat, effects::TeamEffects, team::TeamFightOutcome, viewer::TeamViewer},
+    shop::team_shopping::TeamShoppingHelpers,
+    teams::{
+        combat::{BattleRules, TeamCombat},
+        counters::CounterKey,
+        effects::TeamEffects,
+        team::TeamFightOutcome,
+        viewer::TeamViewer,
+    },
     tests::common::{
         count_pets, test_ant_team, test_beaver_team, test_beetle_team, test_bluebird_team,
         test_bulldog_team, test_chinchilla_team, test_chipmunk_team, test_cockroach_team,
@@ -142,6 +149,37 @@ fn test_battle_mosquito_team() {
     }
 }
 
+#[test]
+fn test_battle_mosquito_interleaved_start_of_battle() {
+    let mosquito_team = |attack| {
+        Team::new(
+            &[Some(
+                Pet::new(PetName::Mosquito, Some(Statistics { attack, health: 1 }), 1).unwrap(),
+            )],
+            5,
+        )
+        .unwrap()
+    };
+
+    // By default, both mosquitoes activate and snipe each other.
+    let mut team = mosquito_team(2);
+    let mut enemy_team = mosquito_team(3);
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+    assert_eq!(team.all().len(), 0);
+    assert_eq!(enemy_team.all().len(), 0);
+
+    // Interleaved, the stronger enemy mosquito activates first and the fainted mosquito never activates.
+    let mut team = mosquito_team(2);
+    let mut enemy_team = mosquito_team(3);
+    team.set_battle_rules(BattleRules {
+        interleave_start_of_battle: true,
+        ..Default::default()
+    });
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+    assert_eq!(team.all().len(), 0);
+    assert_eq!(enemy_team.all().len(), 1);
+}
+
 #[test]
 fn test_battle_frilled_dragon_team() {
     let mut team = test_frilled_dragon_team();
@@ -246,7 +284,7 @@ fn test_battle_hummingbird_team() {
     // Duck has strawberry.
 
     assert_eq!(
-        duck.read().unwrap().item.as_ref().unwrap().name,
+        duck.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Strawberry
     );
     // Two hummingbirds on team.
@@ -317,7 +355,7 @@ fn test_shop_duck_team() {
 
     assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Duck);
 
-    team.set_shop_seed(Some(11)).open_shop().unwrap();
+    team.set_shop_seed(Some(3)).open_shop().unwrap();
 
     // Search pets.
     let (pet_pos, item_type) = (Position::All(ItemCondition::None), Entity::Pet);
@@ -362,7 +400,7 @@ fn test_shop_fish_team() {
     assert!(fish.read().unwrap().lvl == 1 && fish.read().unwrap().exp == 1);
 
     // Init seeded shop. Has fish.
-    team.set_shop_seed(Some(109784368244)).open_shop().unwrap();
+    team.set_shop_seed(Some(15)).open_shop().unwrap();
 
     let (shop_fish_pos, item_type) = (Position::First, Entity::Pet);
     let found_shop_pets = team
@@ -387,7 +425,7 @@ fn test_shop_fish_team() {
 fn test_shop_otter_team() {
     let mut team = test_duck_team();
     // Seed has otter.
-    team.set_shop_seed(Some(432)).open_shop().unwrap();
+    team.set_shop_seed(Some(4)).open_shop().unwrap();
     // Duck has (2,3)
     assert_eq!(
         team.first().unwrap().read().unwrap().stats,
@@ -479,13 +517,13 @@ fn test_battle_beetle_team() {
 
     let beetle = team.first().unwrap();
     // Beetle has no item.
-    assert!(beetle.read().unwrap().item.is_none());
+    assert!(beetle.read().unwrap().items.is_empty());
 
     team.fight(&mut enemy_team).unwrap();
 
     // Beetle gains honey at start of battle.
     assert_eq!(
-        beetle.read().unwrap().item.as_ref().unwrap().name,
+        beetle.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Honey
     );
 }
@@ -525,7 +563,7 @@ fn test_shop_ladybug_team() {
     let ladybug = team.first().unwrap();
     let original_stats = ladybug.read().unwrap().stats;
 
-    team.set_shop_seed(Some(432))
+    team.set_shop_seed(Some(3))
         .open_shop()
         .unwrap()
         .buy(&Position::First, &Entity::Food, &Position::First)
@@ -548,6 +586,25 @@ fn test_shop_ladybug_team() {
     assert_eq!(ladybug.read().unwrap().stats, original_stats);
 }
 
+#[test]
+fn test_shop_ladybug_team_set_item() {
+    let mut team = test_ladybug_team();
+    team.open_shop().unwrap();
+
+    let ladybug = team.first().unwrap();
+    let original_stats = ladybug.read().unwrap().stats;
+
+    // Directly equipping a perk emits the same gain perk triggers as buying one.
+    team.set_item(
+        &Position::Last,
+        Some(Food::try_from(FoodName::Garlic).unwrap()),
+    )
+    .unwrap();
+    team.process_shop_triggers().unwrap();
+
+    assert!(ladybug.read().unwrap().stats.attack > original_stats.attack);
+}
+
 #[test]
 fn test_shop_cockroach_team() {
     let mut team = test_cockroach_team();
@@ -799,7 +856,7 @@ fn test_battle_bulldog_team() {
 fn test_shop_chipmunk_team() {
     let mut team = test_chipmunk_team();
 
-    team.set_shop_seed(Some(21))
+    team.set_shop_seed(Some(4))
         .open_shop()
         .unwrap()
         // Give cocnut to chipmunk
@@ -833,14 +890,14 @@ fn test_battle_groundhog_team() {
     let mut team = test_groundhog_team();
     let mut enemy_team = test_ant_team();
 
-    assert_eq!(Some(0), team.counters.get("Trumpets").copied());
+    assert_eq!(Some(0), team.counters.get(&CounterKey::Trumpets));
     // One groundhog.
     assert_eq!(team.all().len(), 1);
 
     team.fight(&mut enemy_team).unwrap();
 
     // Groundhog faints providing one trumpet which is consumed when it faints.
-    assert_eq!(Some(0), team.counters.get("Trumpets").copied());
+    assert_eq!(Some(0), team.counters.get(&CounterKey::Trumpets));
     let golden_retriever = team.first().unwrap();
     assert_eq!(
         golden_retriever.read().unwrap().stats,
@@ -849,6 +906,8 @@ fn test_battle_groundhog_team() {
             health: 1
         }
     );
+    // Golden retriever has no ability.
+    assert!(golden_retriever.read().unwrap().effect.is_empty());
 }
 
 #[test]
@@ -906,7 +965,7 @@ fn test_battle_pied_tamarin_team() {
     // First attack kills groundhog providing one trumpet.
     team.fight(&mut enemy_team).unwrap();
 
-    assert_eq!(team.counters.get("Trumpets").copied(), Some(1));
+    assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(1));
     // Two ducks remaining.
     assert_eq!(enemy_team.all().len(), 2);
 
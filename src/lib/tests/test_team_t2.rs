@@ -1,10 +1,11 @@
 use crate::{
     effects::{
-        state::{Position, Status},
-        trigger::TRIGGER_START_BATTLE,
+        actions::{Action, StealType},
+        state::{Position, Status, Target},
+        trigger::{TRIGGER_SELF_BEFORE_ATTACK, TRIGGER_START_BATTLE},
     },
     pets::names::PetName,
-    teams::{combat::TeamCombat, team::TeamFightOutcome, viewer::TeamViewer},
+    teams::{combat::TeamCombat, counters::CounterKey, team::TeamFightOutcome, viewer::TeamViewer},
     tests::common::{
         test_ant_team, test_atlantic_puffin_team, test_bat_team, test_crab_team, test_dove_team,
         test_dromedary_team, test_elephant_peacock_team, test_flamingo_team,
@@ -14,8 +15,8 @@ use crate::{
         test_spider_team, test_stork_team, test_swan_team, test_tabby_cat_team, test_toucan_team,
         test_wombat_team, test_yak_team,
     },
-    Entity, Food, FoodName, Pet, Shop, ShopItem, ShopItemViewer, ShopViewer, Statistics, Team,
-    TeamEffects, TeamShopping,
+    Effect, Entity, Food, FoodName, Pet, Shop, ShopItem, ShopItemViewer, ShopViewer, Statistics,
+    Team, TeamEffects, TeamShopping,
 };
 
 use super::common::test_gorilla_team;
@@ -195,6 +196,39 @@ fn test_battle_rat_lvl_2_team() {
     }
 }
 
+#[test]
+fn test_battle_rat_summons_on_enemy_team() {
+    let mut team = test_rat_team(1);
+    let mut enemy_team = Team::new(
+        &[Some(
+            Pet::new(
+                PetName::Ant,
+                Some(Statistics {
+                    attack: 50,
+                    health: 50,
+                }),
+                1,
+            )
+            .unwrap(),
+        )],
+        5,
+    )
+    .unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Rat faints and summons a dirty rat in front of the enemy ant.
+    assert!(team.all().is_empty());
+    assert_eq!(
+        enemy_team.first().unwrap().read().unwrap().name,
+        PetName::DirtyRat
+    );
+    assert_eq!(
+        enemy_team.nth(1).unwrap().read().unwrap().name,
+        PetName::Ant
+    );
+}
+
 #[test]
 fn test_battle_spider_team() {
     let mut team = test_spider_team();
@@ -219,18 +253,15 @@ fn test_battle_bat_team() {
         enemy_team.first().unwrap().read().unwrap().stats,
         Statistics::new(3, 1).unwrap()
     );
-    assert_eq!(
-        enemy_team
-            .first()
-            .unwrap()
-            .read()
-            .unwrap()
-            .item
-            .as_ref()
-            .unwrap()
-            .name,
-        FoodName::Weak
-    );
+    // Weakness only lasts until the end of battle.
+    assert!(enemy_team
+        .first()
+        .unwrap()
+        .read()
+        .unwrap()
+        .items
+        .ailment
+        .is_none());
 }
 
 #[test]
@@ -245,13 +276,15 @@ fn test_battle_atlantic_puffin_team() {
     assert_eq!(
         team.all()
             .iter()
-            .map(|pet| pet.read().unwrap().item.as_ref().map_or(0, |item| {
-                if item.name == FoodName::Strawberry {
-                    1
-                } else {
-                    0
-                }
-            }))
+            .map(
+                |pet| pet.read().unwrap().items.perk.as_ref().map_or(0, |item| {
+                    if item.name == FoodName::Strawberry {
+                        1
+                    } else {
+                        0
+                    }
+                })
+            )
             .sum::<usize>(),
         2
     );
@@ -286,7 +319,8 @@ fn test_battle_dove_team() {
                 .unwrap()
                 .read()
                 .unwrap()
-                .item
+                .items
+                .perk
                 .as_ref()
                 .unwrap()
                 .name,
@@ -410,17 +444,63 @@ fn test_battle_racoon_team() {
         .unwrap();
 
     // No item for racoon. Mammoth has garlic.
-    assert_eq!(racoon.read().unwrap().item, None);
-    let mammoth_item = mammoth.read().unwrap().item.as_ref().unwrap().name.clone();
+    assert_eq!(racoon.read().unwrap().items.perk, None);
+    let mammoth_item = mammoth
+        .read()
+        .unwrap()
+        .items
+        .perk
+        .as_ref()
+        .unwrap()
+        .name
+        .clone();
     assert_eq!(mammoth_item, FoodName::Garlic);
 
     // Trigger attack.
     team.fight(&mut enemy_team).unwrap();
 
     // Racoon got mammoth's melon. Mammoth loses garlic.
-    let racoon_item = racoon.read().unwrap().item.as_ref().unwrap().name.clone();
+    let racoon_item = racoon
+        .read()
+        .unwrap()
+        .items
+        .perk
+        .as_ref()
+        .unwrap()
+        .name
+        .clone();
     assert_eq!(racoon_item, FoodName::Garlic);
-    assert!(mammoth.read().unwrap().item.is_none());
+    assert!(mammoth.read().unwrap().items.is_empty());
+}
+
+#[test]
+fn test_battle_steal_gold_team() {
+    let mut team = test_ant_team();
+    let mut enemy_team = test_ant_team();
+    team.set_name("Thieves").unwrap();
+    enemy_team.set_name("Victims").unwrap();
+    enemy_team.shop.coins = 1;
+
+    // Ant that steals 2 gold from the enemy team.
+    let ant = team.first().unwrap();
+    let mut steal_effect = Effect::builder()
+        .set_trigger(TRIGGER_SELF_BEFORE_ATTACK)
+        .set_target(Target::Enemy)
+        .set_position(Position::First)
+        .set_action(Action::Steal {
+            what: StealType::Gold(2),
+            from: Target::Enemy,
+        })
+        .build();
+    steal_effect.assign_owner(Some(&ant));
+
+    let coins = team.shop.coins;
+    team.apply_effect(&steal_effect, Some(&mut enemy_team))
+        .unwrap();
+
+    // Only the enemy's single gold is stolen.
+    assert_eq!(team.shop.coins, coins + 1);
+    assert_eq!(enemy_team.shop.coins, 0);
 }
 
 #[test]
@@ -431,17 +511,25 @@ fn test_battle_toucan_team() {
     // Toucan has honey.
     let toucan = team.first().unwrap();
     assert_eq!(
-        toucan.read().unwrap().item.as_ref().unwrap().name.clone(),
+        toucan
+            .read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .unwrap()
+            .name
+            .clone(),
         FoodName::Honey
     );
     // Dog behind toucan has no item.
     let dog = team.nth(1).unwrap();
-    assert_eq!(dog.read().unwrap().item, None);
+    assert_eq!(dog.read().unwrap().items.perk, None);
     team.fight(&mut enemy_team).unwrap();
 
     // Dog behind bee now has honey.
     assert_eq!(
-        dog.read().unwrap().item.as_ref().unwrap().name,
+        dog.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Honey
     );
 }
@@ -554,13 +642,13 @@ fn test_battle_frigate_bird_team() {
     // No pets have any items/effects.
     assert!(all_pets
         .iter()
-        .all(|pet| pet.read().unwrap().item.is_none()));
+        .all(|pet| pet.read().unwrap().items.is_empty()));
     // Trigger bat effect. One random pet gets weakness.
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
     // Frigatebird removes ailment. No pets have any items/effects.
     assert!(all_pets
         .iter()
-        .all(|pet| pet.read().unwrap().item.is_none()));
+        .all(|pet| pet.read().unwrap().items.is_empty()));
     // Reset
     team.restore();
     enemy_team.restore();
@@ -580,7 +668,7 @@ fn test_battle_frigate_bird_team() {
     assert_eq!(
         all_pets
             .iter()
-            .map(|pet| if pet.read().unwrap().item.is_none() {
+            .map(|pet| if pet.read().unwrap().items.is_empty() {
                 0
             } else {
                 1
@@ -593,7 +681,7 @@ fn test_battle_frigate_bird_team() {
 #[test]
 fn test_shop_dromedary_team() {
     let mut team = test_dromedary_team();
-    team.set_shop_seed(Some(12)).open_shop().unwrap();
+    team.set_shop_seed(Some(98)).open_shop().unwrap();
 
     let affected_pos = Position::Multiple(vec![Position::First, Position::Relative(-1)]);
     let affected_shop_pets = team
@@ -625,7 +713,7 @@ fn test_shop_tabbycat_team() {
     let mut team = test_tabby_cat_team();
     team.set_shop_seed(Some(12)).open_shop().unwrap();
 
-    assert!(team.last().unwrap().read().unwrap().item.is_none());
+    assert!(team.last().unwrap().read().unwrap().items.is_empty());
 
     let first_pet = team.first().unwrap();
     let second_pet = team.nth(1).unwrap();
@@ -642,7 +730,7 @@ fn test_shop_tabbycat_team() {
     team.buy(&Position::First, &Entity::Food, &Position::Last)
         .unwrap();
 
-    assert!(team.last().unwrap().read().unwrap().item.is_some());
+    assert!(team.last().unwrap().read().unwrap().items.perk.is_some());
 
     assert_eq!(
         first_pet.read().unwrap().stats,
@@ -835,46 +923,252 @@ fn test_shop_snail_team() {
 }
 
 #[test]
-fn test_shop_african_penguin() {}
+fn test_shop_african_penguin() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::AfricanPenguin).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::new(PetName::Dog, None, 2).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.open_shop().unwrap();
+
+    let (ant, dog) = (team.nth(1).unwrap(), team.nth(2).unwrap());
+    let ant_stats = ant.read().unwrap().stats;
+    let dog_stats = dog.read().unwrap().stats;
+
+    // End turn.
+    team.close_shop().unwrap();
+
+    // Only the level 2 dog gains stats.
+    assert_eq!(ant.read().unwrap().stats, ant_stats);
+    let new_dog_stats = dog.read().unwrap().stats;
+    assert!(new_dog_stats.attack > dog_stats.attack && new_dog_stats.health > dog_stats.health);
+}
 
 #[test]
 fn test_battle_black_necked_stilt() {
     let mut team = Team::new(
-        &[Some(Pet::try_from(PetName::BlackNeckedStilt).unwrap())],
+        &[
+            Some(Pet::try_from(PetName::BlackNeckedStilt).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+        ],
         5,
     )
     .unwrap();
     let mut enemy_team = test_gorilla_team();
 
-    println!("{:?}", team.counters);
-    println!("{enemy_team}");
+    assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(0));
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Stilt faints and team gains two trumpets.
+    assert_eq!(team.fainted.len(), 1);
+    assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(2));
+}
+
+#[test]
+fn test_battle_door_head_ant() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::DoorHeadAnt).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+
+    let door_head_ant = team.nth(1).unwrap();
+    let door_head_ant_stats = door_head_ant.read().unwrap().stats;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Friend ahead faints so door head ant gains stats.
+    let new_stats = door_head_ant.read().unwrap().stats;
+    assert!(
+        new_stats.attack > door_head_ant_stats.attack
+            && new_stats.health > door_head_ant_stats.health
+    );
+}
+
+#[test]
+fn test_battle_gazelle() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Gazelle).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+
+    let (gazelle, dog) = (team.first().unwrap(), team.nth(1).unwrap());
+    gazelle.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    let dog_stats = dog.read().unwrap().stats;
 
     team.fight(&mut enemy_team).unwrap();
 
-    println!("{:?}", team.counters);
-    println!("{enemy_team}");
+    // Gazelle hurt and friend behind gains stats.
+    let new_dog_stats = dog.read().unwrap().stats;
+    assert!(new_dog_stats.attack > dog_stats.attack && new_dog_stats.health > dog_stats.health);
 }
 
-fn test_battle_door_head_ant() {}
+#[test]
+fn test_shop_hercules_beetle() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::HerculesBeetle).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::from(Food::try_from(FoodName::Honey).unwrap()))
+        .unwrap();
+    team.replace_shop(shop).unwrap();
+    team.open_shop().unwrap();
 
-fn test_battle_gazelle() {}
+    let dog = team.nth(1).unwrap();
+    let dog_stats = dog.read().unwrap().stats;
 
-fn test_battle_hercules_beetle() {}
+    team.buy(&Position::First, &Entity::Food, &Position::Last)
+        .unwrap();
 
-fn test_battle_lizard() {}
+    // Dog gains a perk and stats.
+    assert_eq!(
+        dog.read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Honey)
+    );
+    let new_dog_stats = dog.read().unwrap().stats;
+    assert!(new_dog_stats.attack > dog_stats.attack && new_dog_stats.health > dog_stats.health);
+}
 
-fn test_battle_sea_turtle() {}
+#[test]
+fn test_battle_lizard() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Lizard).unwrap())], 5).unwrap();
+    let mut enemy_team = test_ant_team();
 
-fn test_battle_sea_urchin() {}
+    let lizard = team.first().unwrap();
+    lizard.write().unwrap().stats = Statistics::new(40, 40).unwrap();
 
-fn test_battle_squid() {}
+    team.fight(&mut enemy_team).unwrap();
 
-fn test_battle_stoat() {}
+    // Lizard knocks out an ant and gains stats.
+    assert!(lizard.read().unwrap().stats.attack > 40);
+}
 
-fn test_battle_beluga_sturgeon() {}
+#[test]
+fn test_battle_sea_turtle() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::SeaTurtle).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
 
-fn test_battle_lemur() {}
+    team.fight(&mut enemy_team).unwrap();
 
-fn test_battle_mandrill() {}
+    // Level 1 sea turtle gives nearest friend behind a melon.
+    let pets = team.all();
+    let item_names = pets
+        .iter()
+        .skip(1)
+        .map(|pet| {
+            pet.read()
+                .unwrap()
+                .items
+                .perk
+                .as_ref()
+                .map(|food| food.name.clone())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(item_names, [Some(FoodName::Melon), None]);
+}
+
+#[test]
+fn test_battle_sea_urchin() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::SeaUrchin).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    let dog = enemy_team.first().unwrap();
+    dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Sea urchin is hurt and damages the dog beyond its attack.
+    assert!(dog.read().unwrap().stats.health < 49);
+}
 
-fn test_battle_robin() {}
+#[test]
+fn test_battle_squid() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Squid).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Squid faints and gives an enemy ink.
+    assert_eq!(team.fainted.len(), 1);
+    let gorilla = enemy_team.first().unwrap();
+    assert_eq!(
+        gorilla
+            .read()
+            .unwrap()
+            .items
+            .ailment
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Ink)
+    );
+}
+
+#[test]
+fn test_battle_stoat() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Stoat).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Gorilla).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Strongest enemy is weakened.
+    let gorilla = enemy_team.last().unwrap();
+    assert_eq!(
+        gorilla
+            .read()
+            .unwrap()
+            .items
+            .ailment
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Weak)
+    );
+}
@@ -21,7 +21,7 @@ use crate::{
         test_woodpecker_team,
     },
     Entity, EntityName, ItemCondition, Pet, Position, Shop, ShopItem, ShopItemViewer, ShopViewer,
-    TeamEffects, TeamShopping, ToyName,
+    Team, TeamEffects, TeamShopping, ToyName,
 };
 
 #[test]
@@ -36,7 +36,7 @@ fn test_battle_mole_team() {
     assert_eq!(pets_w_perks.len(), 3);
     assert!(pets_w_perks
         .iter()
-        .all(|pet| pet.read().unwrap().item.as_ref().unwrap().name == FoodName::Honey));
+        .all(|pet| pet.read().unwrap().items.perk.as_ref().unwrap().name == FoodName::Honey));
 
     team.fight(&mut enemy_team).unwrap();
 
@@ -53,7 +53,7 @@ fn test_battle_mole_team() {
     // Perks removed.
     assert!(pets_w_perks
         .iter()
-        .all(|pet| pet.read().unwrap().item.is_none()));
+        .all(|pet| pet.read().unwrap().items.is_empty()));
 }
 
 #[test]
@@ -63,10 +63,19 @@ fn test_shop_goldfish_team() {
 
     team.open_shop().unwrap();
 
-    assert_eq!(goldfish.read().unwrap().cost, 3);
+    let economy = team.shop.economy.clone();
+    assert_eq!(goldfish.read().unwrap().sell_value(&economy), 1);
     team.close_shop().unwrap();
 
-    assert_eq!(goldfish.read().unwrap().cost, 4);
+    // Sell value increased but not cost.
+    assert_eq!(goldfish.read().unwrap().sell_value(&economy), 2);
+    assert_eq!(goldfish.read().unwrap().cost, 3);
+
+    // Goldfish sells for more.
+    team.open_shop().unwrap();
+    let coins = team.gold();
+    team.sell(&Position::First).unwrap();
+    assert_eq!(team.gold(), coins + 2);
 }
 
 #[test]
@@ -203,7 +212,7 @@ fn test_battle_ox_team() {
     {
         let ox = team.nth(1).unwrap();
         // No item on default lvl.1 ox.
-        assert!(ox.read().unwrap().item.is_none());
+        assert!(ox.read().unwrap().items.is_empty());
         assert_eq!(
             ox.read().unwrap().stats,
             Statistics {
@@ -220,7 +229,7 @@ fn test_battle_ox_team() {
     // Gets melon armor.
     let mut melon_armor = Food::try_from(&FoodName::Melon).unwrap();
     melon_armor.ability.assign_owner(Some(&ox));
-    assert_eq!(ox.read().unwrap().item, Some(melon_armor));
+    assert_eq!(ox.read().unwrap().items.perk, Some(Box::new(melon_armor)));
     // And an extra attack.
     assert_eq!(
         ox.read().unwrap().stats,
@@ -317,9 +326,16 @@ fn test_battle_bear_team() {
     let mut enemy_team = test_hummingbird_team();
 
     // Dog at position behind bear has no item.
-    assert_eq!(team.nth(1).unwrap().read().unwrap().item, None);
+    assert_eq!(team.nth(1).unwrap().read().unwrap().items.perk, None);
     // Enemy team first pet (duck) has strawberry.
-    let enemy_duck_item = enemy_team.first().unwrap().read().unwrap().item.clone();
+    let enemy_duck_item = enemy_team
+        .first()
+        .unwrap()
+        .read()
+        .unwrap()
+        .items
+        .perk
+        .clone();
     assert_eq!(enemy_duck_item.unwrap().name, FoodName::Strawberry);
     team.fight(&mut enemy_team).unwrap();
 
@@ -336,7 +352,8 @@ fn test_battle_bear_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -348,7 +365,8 @@ fn test_battle_bear_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -369,7 +387,8 @@ fn test_battle_seagull_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -382,7 +401,15 @@ fn test_battle_seagull_team() {
         let zombie_cricket = team.first().unwrap();
         assert!(
             zombie_cricket.read().unwrap().name == PetName::ZombieCricket
-                && zombie_cricket.read().unwrap().item.as_ref().unwrap().name == FoodName::Honey
+                && zombie_cricket
+                    .read()
+                    .unwrap()
+                    .items
+                    .perk
+                    .as_ref()
+                    .unwrap()
+                    .name
+                    == FoodName::Honey
         );
     }
 
@@ -392,7 +419,7 @@ fn test_battle_seagull_team() {
     // Seagull ability only activates once. Bee does not get honey.
     assert!(
         team.first().unwrap().read().unwrap().name == PetName::Bee
-            && team.first().unwrap().read().unwrap().item.is_none()
+            && team.first().unwrap().read().unwrap().items.is_empty()
     );
 }
 
@@ -471,7 +498,8 @@ fn test_battle_toad_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .ailment
             .as_ref()
             .unwrap()
             .name,
@@ -845,7 +873,7 @@ fn test_shop_cassowary_team() {
     );
     // Cassowary has strawberry.
     assert_eq!(
-        cassowary.read().unwrap().item.as_ref().unwrap().name,
+        cassowary.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Strawberry
     );
     team.close_shop().unwrap();
@@ -948,3 +976,442 @@ fn test_shop_starfish_team() {
             }
     );
 }
+
+#[test]
+fn test_battle_flying_squirrel() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::FlyingSquirrel).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+
+    let dog = team.nth(1).unwrap();
+    let dog_stats = dog.read().unwrap().stats;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Flying squirrel faints and dog gains stats.
+    assert_eq!(team.fainted.len(), 1);
+    let new_dog_stats = dog.read().unwrap().stats;
+    assert!(new_dog_stats.attack > dog_stats.attack && new_dog_stats.health > dog_stats.health);
+}
+
+#[test]
+fn test_battle_hoopoe_bird() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::HoopoeBird).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    for pet in enemy_team.all() {
+        pet.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    }
+    let (first_dog, last_dog) = (enemy_team.first().unwrap(), enemy_team.last().unwrap());
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Hoopoe bird faints and damages the first and last enemies.
+    assert!(first_dog.read().unwrap().stats.health < 49);
+    assert!(last_dog.read().unwrap().stats.health < 50);
+}
+
+#[test]
+fn test_battle_pangolin() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Pangolin).unwrap())], 5).unwrap();
+    let mut enemy_team = test_ant_team();
+
+    let pangolin = team.first().unwrap();
+    pangolin.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Pangolin hurt and gains stats.
+    assert!(pangolin.read().unwrap().stats.attack > 1);
+}
+
+#[test]
+fn test_battle_beluga_sturgeon_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::BelugaSturgeon).unwrap())], 5).unwrap();
+    let mut enemy_team = test_gorilla_team();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Sturgeon faints and summons a copy of itself.
+    assert_eq!(team.fainted.len(), 1);
+    let summon = team.first().unwrap();
+    assert_eq!(summon.read().unwrap().name, PetName::BelugaSturgeon);
+    assert_eq!(summon.read().unwrap().stats, Statistics::new(2, 2).unwrap());
+    // The copy's effect is used up so it doesn't summon again.
+    assert_eq!(summon.read().unwrap().effect.first().unwrap().uses, Some(0));
+
+    team.fight(&mut enemy_team).unwrap();
+    assert_eq!(team.fainted.len(), 2);
+    assert!(team.first().is_none());
+}
+
+#[test]
+fn test_shop_lemur_team() {
+    let mut lemur = Pet::try_from(PetName::Lemur).unwrap();
+    lemur.add_experience(1).unwrap();
+    let mut team = Team::new(
+        &[Some(lemur), Some(Pet::try_from(PetName::Dog).unwrap())],
+        5,
+    )
+    .unwrap();
+    let mut custom_shop = Shop::default();
+    custom_shop
+        .add_item(ShopItem::new(Pet::try_from(PetName::Lemur).unwrap()))
+        .unwrap();
+    team.replace_shop(custom_shop).unwrap().open_shop().unwrap();
+
+    let dog = team.nth(1).unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+
+    // Buy lemur and merge it into the lemur on the team.
+    team.buy(&Position::First, &Entity::Pet, &Position::First)
+        .unwrap();
+    assert_eq!(team.first().unwrap().read().unwrap().lvl, 2);
+
+    // Dog gains (1,1) from lemur levelup.
+    assert_eq!(
+        dog.read().unwrap().stats,
+        dog_start_stats + Statistics::new(1, 1).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_mandrill_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Mandrill).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let dog = team.first().unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+
+    team.open_shop().unwrap().close_shop().unwrap();
+
+    // Friend ahead gains (1,2) as team has four or fewer pets.
+    assert_eq!(
+        dog.read().unwrap().stats,
+        dog_start_stats + Statistics::new(1, 2).unwrap()
+    );
+
+    let mut full_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Mandrill).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let dog = full_team.first().unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+
+    full_team.open_shop().unwrap().close_shop().unwrap();
+
+    // No stats gained with five pets.
+    assert_eq!(dog.read().unwrap().stats, dog_start_stats);
+}
+
+#[test]
+fn test_shop_robin_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Robin).unwrap())], 5).unwrap();
+
+    team.open_shop().unwrap();
+    let egg_query = |team: &Team| {
+        team.shop
+            .foods
+            .iter()
+            .filter(|item| item.name() == EntityName::Food(FoodName::Egg))
+            .count()
+    };
+    assert_eq!(egg_query(&team), 0);
+
+    team.close_shop().unwrap();
+
+    // Robin stocks an egg at end of turn.
+    assert_eq!(egg_query(&team), 1);
+}
+
+#[test]
+fn test_shop_baboon_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Baboon).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.open_shop().unwrap();
+
+    let dog = team.nth(1).unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+    let num_foods = team.shop.foods.len() as isize;
+    assert_ne!(num_foods, 0);
+
+    team.sell(&Position::First).unwrap();
+
+    // Dog gains (1,1) for each food in the shop.
+    assert_eq!(
+        dog.read().unwrap().stats,
+        dog_start_stats + Statistics::new(num_foods, num_foods).unwrap()
+    );
+}
+
+#[test]
+fn test_battle_betta_fish_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::BettaFish).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+    let dog_start_stats = team.first().unwrap().read().unwrap().stats;
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Adjacent dogs gain (0,2).
+    for pos in [0, 2] {
+        assert_eq!(
+            team.nth(pos).unwrap().read().unwrap().stats,
+            dog_start_stats + Statistics::new(0, 2).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_battle_flea_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Flea).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Gorilla).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Healthiest enemy is weakened.
+    let weak_query = |pet: &Arc<std::sync::RwLock<Pet>>| {
+        pet.read()
+            .unwrap()
+            .items
+            .ailment
+            .as_ref()
+            .map(|food| food.name.clone())
+    };
+    assert_eq!(
+        weak_query(&enemy_team.last().unwrap()),
+        Some(FoodName::Weak)
+    );
+    assert_eq!(weak_query(&enemy_team.first().unwrap()), None);
+}
+
+#[test]
+fn test_battle_flying_fish_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::FlyingFish).unwrap())], 5).unwrap();
+    let mut enemy_team = test_gorilla_team();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Flying fish faints and summons a copy of itself.
+    assert_eq!(team.fainted.len(), 1);
+    let summon = team.first().unwrap();
+    assert_eq!(summon.read().unwrap().name, PetName::FlyingFish);
+
+    team.fight(&mut enemy_team).unwrap();
+    assert!(team.first().is_none());
+}
+
+#[test]
+fn test_shop_guineafowl_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    let mut custom_shop = Shop::default();
+    custom_shop
+        .add_item(ShopItem::new(Pet::try_from(PetName::Guineafowl).unwrap()))
+        .unwrap();
+    team.replace_shop(custom_shop).unwrap().open_shop().unwrap();
+
+    let ant = team.first().unwrap();
+    let ant_start_stats = ant.read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Pet, &Position::Last)
+        .unwrap();
+
+    // Ant gains (0,1) from guineafowl being bought.
+    assert_eq!(
+        ant.read().unwrap().stats,
+        ant_start_stats + Statistics::new(0, 1).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_meekrat_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Meekrat).unwrap())], 5).unwrap();
+    team.open_shop().unwrap();
+
+    team.sell(&Position::First).unwrap();
+
+    // A free canned food is stocked.
+    let canned_food = team
+        .shop
+        .foods
+        .iter()
+        .find(|item| item.name() == EntityName::Food(FoodName::CannedFood))
+        .unwrap();
+    assert_eq!(canned_food.cost, 0);
+}
+
+#[test]
+fn test_battle_musk_ox_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::MuskOx).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Friend ahead faints and musk ox gains melon.
+    let musk_ox = team.first().unwrap();
+    assert_eq!(musk_ox.read().unwrap().name, PetName::MuskOx);
+    assert_eq!(
+        musk_ox
+            .read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .map(|food| food.name.clone()),
+        Some(FoodName::Melon)
+    );
+}
+
+#[test]
+fn test_battle_osprey_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Osprey).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+    let dog = team.nth(1).unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Friend behind gains (2,0).
+    assert_eq!(
+        dog.read().unwrap().stats,
+        dog_start_stats + Statistics::new(2, 0).unwrap()
+    );
+}
+
+#[test]
+fn test_battle_royal_flycatcher_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::RoyalFlycatcher).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    for pet in enemy_team.all() {
+        pet.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    }
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Friend ahead attacks so last enemy takes 2 damage.
+    assert_eq!(
+        enemy_team.last().unwrap().read().unwrap().stats,
+        Statistics::new(1, 48).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_surgeon_fish_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::SurgeonFish).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let dog = team.first().unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+
+    team.open_shop().unwrap();
+
+    // Friend ahead gains (1,1) at start of turn.
+    assert_eq!(
+        dog.read().unwrap().stats,
+        dog_start_stats + Statistics::new(1, 1).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_weasel_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Weasel).unwrap())], 5).unwrap();
+    let mut custom_shop = Shop::default();
+    custom_shop
+        .add_item(ShopItem::from(Food::try_from(FoodName::Apple).unwrap()))
+        .unwrap();
+    team.replace_shop(custom_shop).unwrap().open_shop().unwrap();
+
+    let apple_query = |team: &Team| {
+        team.shop
+            .foods
+            .iter()
+            .find(|item| item.name() == EntityName::Food(FoodName::Apple))
+            .map(|item| (item.attack_stat(), item.health_stat()))
+            .unwrap()
+    };
+    assert_eq!(apple_query(&team), (Some(1), Some(1)));
+
+    team.sell(&Position::First).unwrap();
+
+    // Shop apple gains (1,1).
+    assert_eq!(apple_query(&team), (Some(2), Some(2)));
+}
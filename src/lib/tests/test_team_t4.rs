@@ -1,16 +1,21 @@
 use std::sync::Arc;
 
+use itertools::Itertools;
+
 use crate::{
     effects::{
         actions::{Action, CopyType, SummonType},
         effect::Entity,
-        state::{Position, Target},
+        state::{Position, Status, Target},
         stats::Statistics,
         trigger::*,
     },
     foods::names::FoodName,
     pets::names::PetName,
-    teams::{combat::TeamCombat, effects::TeamEffects, team::TeamFightOutcome, viewer::TeamViewer},
+    teams::{
+        combat::TeamCombat, counters::CounterKey, effects::TeamEffects, team::TeamFightOutcome,
+        viewer::TeamViewer,
+    },
     tests::common::{
         count_pets, test_ant_team, test_anteater_team, test_armadillo_team, test_bison_team,
         test_blowfish_team, test_buffalo_team, test_caterpillar_team, test_crow_team,
@@ -21,7 +26,8 @@ use crate::{
         test_platypus_team, test_porcupine_team, test_praying_mantis_team, test_skunk_team,
         test_snake_team, test_squirrel_team, test_turtle_team, test_whale_team,
     },
-    Effect, EntityName, Food, ItemCondition, Pet, ShopItemViewer, ShopViewer, Team, TeamShopping,
+    Effect, EntityName, Food, ItemCondition, Pet, Shop, ShopItem, ShopItemViewer, ShopViewer, Team,
+    TeamShopping,
 };
 
 #[test]
@@ -160,6 +166,7 @@ fn test_shop_parrot_team() {
             ),
             uses: None,
             temp: true,
+            ..Default::default()
         }],
         team.nth(1).unwrap().read().unwrap().effect
     );
@@ -185,6 +192,7 @@ fn test_shop_parrot_team() {
             action: Action::Summon(SummonType::StoredPet(Box::new(zombie_cricket))),
             uses: Some(1),
             temp: false,
+            ..Default::default()
         }],
         team.nth(1).unwrap().read().unwrap().effect
     );
@@ -244,7 +252,7 @@ fn test_battle_turtle_team() {
     let mut team = test_turtle_team();
     let mut enemy_team = test_turtle_team();
 
-    assert_eq!(team.nth(1).unwrap().read().unwrap().item, None);
+    assert_eq!(team.nth(1).unwrap().read().unwrap().items.perk, None);
 
     // Three attacks to kill both lvl.1 turtles.
     for _ in 0..3 {
@@ -256,7 +264,8 @@ fn test_battle_turtle_team() {
         pet_behind_turtle
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -287,6 +296,36 @@ fn test_battle_whale_team() {
     assert_eq!(count_pets(&team.friends, PetName::Cricket), 0);
 }
 
+#[test]
+fn test_battle_whale_lvl_3_keeps_item() {
+    let mut team = test_whale_team();
+    let mut enemy_team = test_hippo_team();
+    team.set_item(
+        &Position::First,
+        Some(Food::try_from(FoodName::Garlic).unwrap()),
+    )
+    .unwrap();
+    let whale = team.nth(1).unwrap();
+    whale.write().unwrap().set_level(3).unwrap();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Cricket released at level 3 with 5 exp worth of stats and its garlic.
+    let whale = whale.read().unwrap();
+    let swallowed_pet = whale.swallowed_pet().unwrap();
+    assert_eq!(swallowed_pet.name, PetName::Cricket);
+    assert_eq!(swallowed_pet.get_level(), 3);
+    assert_eq!(swallowed_pet.get_experience(), 5);
+    assert_eq!(
+        swallowed_pet.stats,
+        Pet::try_from(PetName::Cricket).unwrap().stats + Statistics::new(5, 5).unwrap()
+    );
+    assert_eq!(
+        swallowed_pet.items.perk.as_ref().unwrap().name,
+        FoodName::Garlic
+    );
+}
+
 #[test]
 fn test_battle_front_whale_team() {
     let mut team = Team::new(&[Some(Pet::try_from(PetName::Whale).unwrap())], 5).unwrap();
@@ -361,7 +400,7 @@ fn test_battle_doberman_team() {
         health: 0,
     };
     let start_stats = doberman.read().unwrap().stats;
-    assert_eq!(doberman.read().unwrap().item, None);
+    assert_eq!(doberman.read().unwrap().items.perk, None);
 
     // Doberman is lowest tier.
     assert_eq!(
@@ -378,7 +417,7 @@ fn test_battle_doberman_team() {
 
     // Doberman gets coconut and gets (5,5)
     assert_eq!(
-        doberman.read().unwrap().item.as_ref().unwrap().name,
+        doberman.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Coconut
     );
     assert_eq!(doberman.read().unwrap().stats, start_stats + BUFF);
@@ -390,7 +429,7 @@ fn test_battle_doberman_highest_tier_team() {
     let mut enemy_team = test_hippo_team();
 
     // Doberman has no item.
-    assert_eq!(team.first().unwrap().read().unwrap().item, None);
+    assert_eq!(team.first().unwrap().read().unwrap().items.perk, None);
     assert_eq!(
         team.first().unwrap().read().unwrap().stats,
         Statistics::new(4, 5).unwrap()
@@ -409,7 +448,7 @@ fn test_battle_doberman_highest_tier_team() {
     team.fight(&mut enemy_team).unwrap();
 
     // Doberman doesn't get coconut or stats.
-    assert_eq!(team.first().unwrap().read().unwrap().item, None);
+    assert_eq!(team.first().unwrap().read().unwrap().items.perk, None);
     assert_eq!(
         team.first().unwrap().read().unwrap().stats,
         Statistics::new(4, 1).unwrap()
@@ -528,6 +567,51 @@ fn test_shop_caterpillar_team() {
     assert!(caterpillar.read().unwrap().exp == 1 && caterpillar.read().unwrap().lvl == 1)
 }
 
+#[test]
+fn test_team_caterpillar_transform() {
+    let mut team = test_caterpillar_team();
+    let caterpillar = team.first().unwrap();
+    caterpillar
+        .write()
+        .unwrap()
+        .items
+        .attach(Food::try_from(FoodName::Melon).unwrap());
+    let caterpillar_exp = caterpillar.read().unwrap().exp;
+
+    team.trigger_start_battle_effects(&mut test_hippo_team())
+        .unwrap();
+
+    // Butterfly takes caterpillar's place, item, and experience.
+    let butterfly = team.first().unwrap();
+    assert_eq!(butterfly.read().unwrap().name, PetName::Butterfly);
+    assert_eq!(butterfly.read().unwrap().exp, caterpillar_exp);
+    assert_eq!(
+        butterfly.read().unwrap().items.perk.as_ref().unwrap().name,
+        FoodName::Melon
+    );
+    assert!(caterpillar.read().unwrap().items.is_empty());
+
+    // Transform triggers are emitted instead of summon triggers.
+    let mut team = test_caterpillar_team();
+    let caterpillar = team.first().unwrap();
+    team.triggers.clear();
+    let butterfly = team
+        .transform_pet(&caterpillar, Pet::try_from(PetName::Butterfly).unwrap())
+        .unwrap();
+    assert_eq!(team.triggers.len(), 2);
+    assert!(team.triggers.iter().all(|trigger| {
+        trigger.status == Status::Transformed
+            && Arc::ptr_eq(
+                &trigger.affected_pet.as_ref().unwrap().upgrade().unwrap(),
+                &butterfly,
+            )
+    }));
+    // Original pet is no longer on team.
+    assert!(team
+        .transform_pet(&caterpillar, Pet::try_from(PetName::Butterfly).unwrap())
+        .is_err());
+}
+
 #[test]
 fn test_battle_anteater_team() {
     let mut team = test_anteater_team();
@@ -628,7 +712,7 @@ fn test_battle_pelican_team() {
         let ant = team.nth(1).unwrap();
 
         assert_eq!(ant.read().unwrap().stats, Statistics::new(2, 2).unwrap());
-        let ant_item = &ant.read().unwrap().item;
+        let ant_item = &ant.read().unwrap().items.perk;
         assert_eq!(ant_item.as_ref().unwrap().name, FoodName::Strawberry)
     }
 
@@ -761,7 +845,7 @@ fn test_shop_dragonfly_team() {
 fn test_shop_jerboa_team() {
     let mut team = test_jerboa_team();
 
-    team.set_shop_seed(Some(121)).open_shop().unwrap();
+    team.set_shop_seed(Some(2)).open_shop().unwrap();
 
     let pets = team.all();
     let (duck, jerboa, dog) = (
@@ -1046,3 +1130,237 @@ fn test_shop_orangutan_team() {
         lowest_health_pet_start_stats + ORANGUTAN_BUFF
     );
 }
+
+#[test]
+fn test_battle_cuttlefish() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Cuttlefish).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Gorilla).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let ant = enemy_team.first().unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Enemy with the lowest attack is given ink.
+    assert_eq!(
+        ant.read()
+            .unwrap()
+            .items
+            .ailment
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Ink)
+    );
+}
+
+#[test]
+fn test_battle_egyptian_vulture() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::EgyptianVulture).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let enemy_dog = enemy_team.first().unwrap();
+    enemy_dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Friend faints and vulture damages an enemy.
+    assert!(enemy_dog.read().unwrap().stats.health < 49);
+}
+
+#[test]
+fn test_battle_falcon() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Falcon).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let last_dog = enemy_team.last().unwrap();
+    last_dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Last enemy damaged at start of battle.
+    assert!(last_dog.read().unwrap().stats.health < 50);
+}
+
+#[test]
+fn test_battle_manatee() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Manatee).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let dogs = team.all().into_iter().skip(1).collect_vec();
+    let dog_stats = dogs
+        .iter()
+        .map(|dog| dog.read().unwrap().stats)
+        .collect_vec();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Manatee faints and all friends gain stats.
+    for (dog, stats) in dogs.iter().zip(dog_stats) {
+        let new_stats = dog.read().unwrap().stats;
+        assert!(new_stats.attack > stats.attack && new_stats.health > stats.health);
+    }
+}
+
+#[test]
+fn test_battle_manta_ray() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::MantaRay).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    let enemy_dog = enemy_team.first().unwrap();
+    enemy_dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Manta ray damages an enemy before attacking.
+    assert!(enemy_dog.read().unwrap().stats.health < 49);
+}
+
+#[test]
+fn test_battle_poison_dart_frog() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::PoisonDartFrog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let dog = team.nth(1).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Poison dart frog faints and gives friend behind a peanut.
+    assert_eq!(
+        dog.read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Peanut)
+    );
+}
+
+#[test]
+fn test_battle_saiga_antelope() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::SaigaAntelope).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let saiga = team.nth(1).unwrap();
+    let saiga_stats = saiga.read().unwrap().stats;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Friend faints and saiga antelope gains stats.
+    let new_stats = saiga.read().unwrap().stats;
+    assert!(new_stats.attack > saiga_stats.attack && new_stats.health > saiga_stats.health);
+}
+
+#[test]
+fn test_battle_sealion() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Sealion).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_ant_team();
+    let dog = team.first().unwrap();
+    dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Friend ahead gains stats at start of battle.
+    assert!(dog.read().unwrap().stats.attack > 1);
+}
+
+#[test]
+fn test_battle_secretary_bird() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::SecretaryBird).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(50, 50).unwrap();
+    enemy_team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let last_dog = enemy_team.last().unwrap();
+    last_dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Secretary bird knocks out the first dog and damages the next enemy.
+    assert!(last_dog.read().unwrap().stats.health < 50);
+}
+
+#[test]
+fn test_shop_slug() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Slug).unwrap())], 5).unwrap();
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::from(Food::try_from(FoodName::Apple).unwrap()))
+        .unwrap();
+    team.replace_shop(shop).unwrap();
+    team.open_shop().unwrap();
+
+    let slug = team.first().unwrap();
+    let slug_stats = slug.read().unwrap().stats;
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Slug gains more than the apple's (1, 1).
+    let new_stats = slug.read().unwrap().stats;
+    assert!(new_stats.attack > slug_stats.attack + 1 && new_stats.health > slug_stats.health + 1);
+}
+
+#[test]
+fn test_shop_vaquita() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Vaquita).unwrap())], 5).unwrap();
+    team.open_shop().unwrap();
+
+    // End turn.
+    team.close_shop().unwrap();
+
+    // Vaquita gains a trumpet.
+    assert_eq!(team.counters.get(&CounterKey::Trumpets), Some(1));
+}
@@ -4,7 +4,7 @@ use crate::{
     effects::{state::Position, stats::Statistics, trigger::TRIGGER_START_BATTLE},
     foods::names::FoodName,
     pets::names::PetName,
-    shop::store::ShopItem,
+    shop::{store::ShopItem, trigger::TRIGGER_ANY_FOOD_EATEN},
     teams::{combat::TeamCombat, team::TeamFightOutcome, viewer::TeamViewer},
     tests::common::{
         count_pets, test_cricket_horse_team, test_crocodile_team, test_eagle_team, test_fox_team,
@@ -205,7 +205,7 @@ fn test_shop_scorpion_team() {
     // Scorpion summoned and gains peanuts after purchase.
     let scorpion = team.first().unwrap();
     assert_eq!(
-        scorpion.read().unwrap().item.as_ref().unwrap().name,
+        scorpion.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Peanut
     );
 }
@@ -231,7 +231,8 @@ fn test_battle_scorpion_team() {
         summoned_scorpion
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -405,7 +406,7 @@ fn test_battle_microbe_team() {
         .flatten()
     {
         assert_eq!(
-            pet.read().unwrap().item.as_ref().unwrap().name,
+            pet.read().unwrap().items.ailment.as_ref().unwrap().name,
             FoodName::Weak
         );
     }
@@ -736,13 +737,13 @@ fn test_shop_fox_team() {
     // No gold.
     assert_eq!(team.gold(), 0);
     // Fox has no item.
-    assert_eq!(fox.read().unwrap().item, None);
+    assert_eq!(fox.read().unwrap().items.perk, None);
 
     team.close_shop().unwrap();
 
     // Stole honey from shop.
     assert_eq!(
-        fox.read().unwrap().item.as_ref().unwrap().name,
+        fox.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Honey
     );
 }
@@ -859,7 +860,7 @@ fn test_shop_shoebill_team() {
     // All friends have strawberries.
     assert!(friends
         .iter()
-        .all(|pet| pet.read().unwrap().item.as_ref().unwrap().name == FoodName::Strawberry));
+        .all(|pet| pet.read().unwrap().items.perk.as_ref().unwrap().name == FoodName::Strawberry));
     // End turn.
     team.close_shop().unwrap();
 
@@ -949,3 +950,238 @@ fn test_shop_zebra_team() {
         ant_start_stats + ZEBRA_BUFF + ZEBRA_BUFF
     );
 }
+
+#[test]
+fn test_battle_beluga_whale() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::BelugaWhale).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    let beluga = team.first().unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Beluga whale swallows the friend behind it.
+    assert_eq!(
+        beluga
+            .read()
+            .unwrap()
+            .swallowed_pet()
+            .map(|pet| pet.name.clone()),
+        Some(PetName::Dog)
+    );
+}
+
+#[test]
+fn test_battle_blue_ringed_octopus() {
+    let mut team = Team::new(
+        &[Some(Pet::try_from(PetName::BlueRingedOctopus).unwrap())],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    let octopus = team.first().unwrap();
+    octopus.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Octopus hurt and gains a peanut.
+    assert_eq!(
+        octopus
+            .read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Peanut)
+    );
+}
+
+#[test]
+fn test_shop_macaque() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Macaque).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.open_shop().unwrap();
+    let dog = team.last().unwrap();
+    let dog_stats = dog.read().unwrap().stats;
+
+    // End turn.
+    team.close_shop().unwrap();
+
+    // Last friend gains stats.
+    let new_dog_stats = dog.read().unwrap().stats;
+    assert!(new_dog_stats.attack > dog_stats.attack && new_dog_stats.health > dog_stats.health);
+}
+
+#[test]
+fn test_battle_nurse_shark() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::NurseShark).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let dog = enemy_team.first().unwrap();
+    dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Nurse shark faints and damages an enemy.
+    assert!(dog.read().unwrap().stats.health < 49);
+}
+
+#[test]
+fn test_shop_silver_fox() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::SilverFox).unwrap())], 5).unwrap();
+    let mut shop = Shop::default();
+    shop.add_item(ShopItem::from(Food::try_from(FoodName::Apple).unwrap()))
+        .unwrap();
+    team.replace_shop(shop).unwrap();
+    team.open_shop().unwrap();
+
+    team.buy(&Position::First, &Entity::Food, &Position::First)
+        .unwrap();
+
+    // Apple costs 3 gold but 1 gold is refunded.
+    assert_eq!(team.gold(), 8);
+}
+
+#[test]
+fn test_battle_wolf() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Wolf).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Gorilla).unwrap())], 5).unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let dogs = team.all().into_iter().skip(1).collect_vec();
+    let dog_stats = dogs
+        .iter()
+        .map(|dog| dog.read().unwrap().stats)
+        .collect_vec();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Wolf faints and all friends gain stats.
+    for (dog, stats) in dogs.iter().zip(dog_stats) {
+        let new_stats = dog.read().unwrap().stats;
+        assert!(new_stats.attack > stats.attack && new_stats.health > stats.health);
+    }
+}
+
+#[test]
+fn test_battle_chameleon_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Chameleon).unwrap()),
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_cricket_horse_team();
+
+    team.trigger_start_battle_effects(&mut enemy_team).unwrap();
+
+    // Chameleon copies the ant's faint effect.
+    let chameleon = team.first().unwrap();
+    let ant_effect = team.nth(1).unwrap().read().unwrap().effect[0].clone();
+    assert!(chameleon
+        .read()
+        .unwrap()
+        .effect
+        .iter()
+        .any(|effect| effect.trigger.status == ant_effect.trigger.status
+            && effect.action == ant_effect.action));
+}
+
+#[test]
+fn test_battle_gharial_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Gharial).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    enemy_team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    // Feeding a friend in the shop doesn't require an enemy team.
+    let mut custom_shop = Shop::default();
+    custom_shop
+        .add_item(ShopItem::from(Food::try_from(FoodName::Apple).unwrap()))
+        .unwrap();
+    team.replace_shop(custom_shop).unwrap().open_shop().unwrap();
+    team.buy(&Position::First, &Entity::Food, &Position::Last)
+        .unwrap();
+    team.close_shop().unwrap();
+
+    // Friend eats food in battle. Enemy takes 3 damage.
+    team.trigger_effects(&TRIGGER_ANY_FOOD_EATEN, Some(&mut enemy_team))
+        .unwrap();
+    assert_eq!(
+        enemy_team.first().unwrap().read().unwrap().stats,
+        Statistics::new(1, 47).unwrap()
+    );
+}
+
+#[test]
+fn test_battle_tahr_team() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Tahr).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    enemy_team.first().unwrap().write().unwrap().stats = Statistics::new(50, 50).unwrap();
+    let dog = team.nth(1).unwrap();
+    let dog_start_stats = dog.read().unwrap().stats;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Tahr faints and friend behind gains (3,3).
+    assert_eq!(team.fainted.len(), 1);
+    assert_eq!(
+        dog.read().unwrap().stats,
+        dog_start_stats + Statistics::new(3, 3).unwrap()
+    );
+}
+
+#[test]
+fn test_shop_whale_shark_team() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::WhaleShark).unwrap())], 5).unwrap();
+    let whale_shark = team.first().unwrap();
+    let start_stats = whale_shark.read().unwrap().stats;
+
+    // Food in shop. No stats gained.
+    team.open_shop().unwrap().close_shop().unwrap();
+    assert_eq!(whale_shark.read().unwrap().stats, start_stats);
+
+    // No food in shop. Gain (2,2).
+    team.open_shop().unwrap();
+    team.shop.foods.clear();
+    team.close_shop().unwrap();
+    assert_eq!(
+        whale_shark.read().unwrap().stats,
+        start_stats + Statistics::new(2, 2).unwrap()
+    );
+}
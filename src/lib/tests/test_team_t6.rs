@@ -4,6 +4,8 @@ use itertools::Itertools;
 
 use crate::{
     effects::{
+        actions::{Action, RepeatSource, StatChangeType},
+        effect::{ActivationLimit, LimitScope},
         state::{Position, Status},
         stats::Statistics,
         trigger::TRIGGER_START_BATTLE,
@@ -21,17 +23,44 @@ use crate::{
         test_spinosaurus_team, test_stegosaurus_team, test_tapir_team, test_tiger_team,
         test_tyrannosaurus_team, test_velociraptor_team, test_walrus_team, test_white_tiger_team,
     },
-    Entity, Food, ItemCondition, Pet, ShopItem, ShopItemViewer, ShopViewer, Team, TeamEffects,
-    TeamShopping,
+    Entity, Food, ItemCondition, Pet, Shop, ShopItem, ShopItemViewer, ShopViewer, Team,
+    TeamEffects, TeamShopping,
 };
 
-// #[test]
-// fn test_battle_lionfish_team() {
-//     let mut team = test_lionfish_team();
-//     let mut enemy_team = test_mammoth_team();
+#[test]
+fn test_battle_lionfish_team() {
+    let mut team = test_lionfish_team();
+    let mut enemy_team = Team::new(
+        &[Some(Pet::custom(
+            "Dummy",
+            Statistics::new(1, 50).unwrap(),
+            &[],
+        ))],
+        5,
+    )
+    .unwrap();
+    let dog = team.first().unwrap();
+    dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
 
-//     todo!()
-// }
+    let lionfish = team.nth(1).unwrap();
+    let Action::Conditional(_, buff, _) = lionfish.read().unwrap().effect[0].action.clone() else {
+        panic!("Lionfish effect should be conditional.")
+    };
+    let Action::Add(StatChangeType::Static(buff_stats)) = *buff else {
+        panic!("Lionfish effect should add stats.")
+    };
+
+    // Enemy not hurt before first attack so no buff.
+    team.fight(&mut enemy_team).unwrap();
+    assert_eq!(dog.read().unwrap().stats, Statistics::new(1, 49).unwrap());
+
+    // Enemy hurt so dog buffed before attacking.
+    team.fight(&mut enemy_team).unwrap();
+    assert_eq!(
+        dog.read().unwrap().stats,
+        Statistics::new(1, 48).unwrap() + buff_stats
+    );
+}
 
 #[test]
 fn test_battle_boar_team() {
@@ -102,12 +131,20 @@ fn test_battle_gorilla_team() {
     // Gorilla has no items before fight.
     let gorilla = team.first().unwrap();
     let gorilla_original_stats = gorilla.read().unwrap().stats;
-    assert_eq!(gorilla.read().unwrap().item, None);
+    assert_eq!(gorilla.read().unwrap().items.perk, None);
     team.fight(&mut enemy_team).unwrap();
 
     // Gorilla is hurt and gains coconut.
     assert_eq!(
-        gorilla.read().as_ref().unwrap().item.as_ref().unwrap().name,
+        gorilla
+            .read()
+            .as_ref()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .unwrap()
+            .name,
         FoodName::Coconut
     );
     assert_ne!(gorilla.read().unwrap().stats, gorilla_original_stats);
@@ -239,6 +276,34 @@ fn test_battle_tiger_team() {
     assert_eq!(pets.get(1).unwrap().read().unwrap().name, PetName::Tiger);
 }
 
+#[test]
+fn test_battle_custom_repeat_team() {
+    let mut enemy_team = test_scorpion_team();
+    enemy_team
+        .add_pet(Pet::try_from(PetName::Scorpion).unwrap(), 1, None)
+        .unwrap();
+
+    // Ant that repeats effects of the friend ahead like a tiger.
+    let tiger = Pet::try_from(PetName::Tiger).unwrap();
+    assert_eq!(
+        tiger.effect[0].action,
+        Action::Repeat(RepeatSource::FriendAhead)
+    );
+    let mut ant = Pet::try_from(PetName::Ant).unwrap();
+    ant.effect = tiger.effect;
+    let mut team = Team::new(
+        &[Some(Pet::try_from(PetName::Leopard).unwrap()), Some(ant)],
+        5,
+    )
+    .unwrap();
+
+    // Frontline leopard lives because its effect triggers twice.
+    team.fight(&mut enemy_team).unwrap();
+    let pets = team.all();
+    assert_eq!(pets.get(0).unwrap().read().unwrap().name, PetName::Leopard);
+    assert_eq!(pets.get(1).unwrap().read().unwrap().name, PetName::Ant);
+}
+
 #[test]
 fn test_battle_alpaca_team() {
     let mut team = test_alpaca_team();
@@ -251,7 +316,8 @@ fn test_battle_alpaca_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -301,6 +367,29 @@ fn test_battle_tapir_team() {
     );
 }
 
+#[test]
+fn test_battle_tapir_limit() {
+    let mut team = test_tapir_team();
+    let mut enemy_team = test_gorilla_team();
+
+    // Summons are counted per battle.
+    let limit = team.first().unwrap().read().unwrap().effect[0]
+        .limit
+        .unwrap();
+    assert_eq!(limit, ActivationLimit::new(LimitScope::Battle, 1));
+
+    team.fight(&mut enemy_team).unwrap();
+
+    let tapir = team.fainted.first().cloned().flatten().unwrap();
+    assert!(tapir.read().unwrap().effect[0].limit.unwrap().is_reached());
+
+    // Limit is reset at the start of the next battle.
+    team.restore();
+    team.reset_activation_limits(LimitScope::Battle);
+    let tapir = team.first().unwrap();
+    assert_eq!(tapir.read().unwrap().effect[0].limit.unwrap().count, 0);
+}
+
 #[test]
 fn test_battle_walrus_team() {
     let mut team = test_walrus_team();
@@ -315,7 +404,8 @@ fn test_battle_walrus_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -411,17 +501,25 @@ fn test_battle_reindeer_team() {
 
     // No item before fight.
     let reindeer = team.first().unwrap();
-    assert_eq!(reindeer.read().unwrap().item, None);
+    assert_eq!(reindeer.read().unwrap().items.perk, None);
 
     team.fight(&mut enemy_team).unwrap();
 
     // After fight has melon but already used.
     assert_eq!(
-        reindeer.read().unwrap().item.as_ref().unwrap().name,
+        reindeer.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Melon
     );
     assert_eq!(
-        reindeer.read().unwrap().item.as_ref().unwrap().ability.uses,
+        reindeer
+            .read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .unwrap()
+            .ability
+            .uses,
         Some(0)
     );
 }
@@ -515,7 +613,8 @@ fn test_battle_velociraptor_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -530,7 +629,8 @@ fn test_battle_velociraptor_team() {
             .unwrap()
             .read()
             .unwrap()
-            .item
+            .items
+            .perk
             .as_ref()
             .unwrap()
             .name,
@@ -542,7 +642,7 @@ fn test_battle_velociraptor_team() {
 fn test_shop_cat_team() {
     let mut team = test_cat_team();
 
-    team.set_shop_seed(Some(19)).open_shop().unwrap();
+    team.set_shop_seed(Some(2)).open_shop().unwrap();
 
     let (pos, item_type) = (Position::First, Entity::Food);
     let items = team
@@ -566,6 +666,9 @@ fn test_shop_cat_team() {
         health: 2,
     };
 
+    // Cat effect not used yet.
+    assert!(!cat.read().unwrap().effect[0].limit.unwrap().is_reached());
+
     // Buy apple.
     team.buy(&pos, &item_type, &Position::First).unwrap();
 
@@ -574,6 +677,37 @@ fn test_shop_cat_team() {
         cat.read().unwrap().stats,
         cat_start_stats + APPLE_BUFF * CAT_MULTIPLIER
     );
+    // Cat effect used for the turn.
+    assert!(cat.read().unwrap().effect[0].limit.unwrap().is_reached());
+}
+
+#[test]
+fn test_shop_cat_limit() {
+    let mut team = test_cat_team();
+    let mut shop = Shop::default();
+    for _ in 0..2 {
+        shop.add_item(ShopItem::from(Food::try_from(FoodName::Apple).unwrap()))
+            .unwrap();
+    }
+    team.replace_shop(shop).unwrap().open_shop().unwrap();
+
+    let cat = team.first().unwrap();
+    let cat_start_stats = cat.read().unwrap().stats;
+
+    // Only the first apple of the turn is doubled.
+    for _ in 0..2 {
+        team.buy(&Position::First, &Entity::Food, &Position::First)
+            .unwrap();
+    }
+    assert_eq!(
+        cat.read().unwrap().stats,
+        cat_start_stats + Statistics::new(3, 3).unwrap()
+    );
+
+    // Limit is reset next turn.
+    team.close_shop().unwrap().open_shop().unwrap();
+    let cat = team.first().unwrap();
+    assert_eq!(cat.read().unwrap().effect[0].limit.unwrap().count, 0);
 }
 
 #[test]
@@ -855,7 +989,7 @@ fn test_shop_komodo() {
 fn test_shop_ostrich_team() {
     let mut team = test_ostrich_team();
 
-    team.set_shop_seed(Some(12)).open_shop().unwrap();
+    team.set_shop_seed(Some(27)).open_shop().unwrap();
 
     let ostrich = team.first().unwrap();
     let ostrich_start_stats = ostrich.read().unwrap().stats;
@@ -892,3 +1026,171 @@ fn test_shop_ostrich_team() {
         ostrich_start_stats + OSTRICH_BUFF * Statistics::new(num_tier_5_6, num_tier_5_6).unwrap()
     );
 }
+
+#[test]
+fn test_battle_pteranodon() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Pteranodon).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+    for pet in team.all() {
+        pet.write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    }
+
+    // Dog faints first.
+    team.fight(&mut enemy_team).unwrap();
+    assert_eq!(
+        team.first().unwrap().read().unwrap().name,
+        PetName::Pteranodon
+    );
+
+    // Pteranodon faints and summons the fainted dog.
+    team.fight(&mut enemy_team).unwrap();
+    assert_eq!(team.first().unwrap().read().unwrap().name, PetName::Dog);
+}
+
+#[test]
+fn test_battle_wildebeest() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Wildebeest).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    enemy_team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Wildebeest faints and pushes the first enemy to the back.
+    assert_eq!(
+        enemy_team.first().unwrap().read().unwrap().name,
+        PetName::Dog
+    );
+    assert_eq!(
+        enemy_team.last().unwrap().read().unwrap().name,
+        PetName::Ant
+    );
+}
+
+#[test]
+fn test_battle_oyster() {
+    let mut team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Oyster).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let mut enemy_team = test_gorilla_team();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    let dog = team.nth(1).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Oyster faints and gives the friend behind a melon.
+    assert_eq!(
+        dog.read()
+            .unwrap()
+            .items
+            .perk
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Melon)
+    );
+}
+
+#[test]
+fn test_battle_cobra() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Cobra).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    enemy_team.first().unwrap().write().unwrap().stats = Statistics::new(1, 10).unwrap();
+    let healthiest_dog = enemy_team.last().unwrap();
+    healthiest_dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Healthiest enemy damaged at start of battle.
+    assert!(healthiest_dog.read().unwrap().stats.health < 50);
+}
+
+#[test]
+fn test_battle_grizzly_bear() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::GrizzlyBear).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+            Some(Pet::try_from(PetName::Dog).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 1).unwrap();
+    for pet in enemy_team.all() {
+        pet.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    }
+    let last_dog = enemy_team.last().unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Grizzly bear faints and damages all enemies.
+    assert!(last_dog.read().unwrap().stats.health < 50);
+}
+
+#[test]
+fn test_battle_sting_ray() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::StingRay).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(&[Some(Pet::try_from(PetName::Dog).unwrap())], 5).unwrap();
+    team.first().unwrap().write().unwrap().stats = Statistics::new(1, 50).unwrap();
+    let dog = enemy_team.first().unwrap();
+    dog.write().unwrap().stats = Statistics::new(1, 50).unwrap();
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Sting ray hurt and weakens its attacker.
+    assert_eq!(
+        dog.read()
+            .unwrap()
+            .items
+            .ailment
+            .as_ref()
+            .map(|food| &food.name),
+        Some(&FoodName::Weak)
+    );
+}
+
+#[test]
+fn test_battle_mongoose() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Mongoose).unwrap())], 5).unwrap();
+    let mut enemy_team = Team::new(
+        &[
+            Some(Pet::try_from(PetName::Ant).unwrap()),
+            Some(Pet::try_from(PetName::Gorilla).unwrap()),
+        ],
+        5,
+    )
+    .unwrap();
+    let gorilla = enemy_team.last().unwrap();
+    let gorilla_health = gorilla.read().unwrap().stats.health;
+
+    team.fight(&mut enemy_team).unwrap();
+
+    // Highest tier enemy damaged at start of battle.
+    assert!(gorilla.read().unwrap().stats.health < gorilla_health);
+}
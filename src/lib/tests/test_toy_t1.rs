@@ -1,8 +1,16 @@
 use itertools::Itertools;
 
 use crate::{
+    effects::{
+        actions::{Action, ConditionType, LogicType, StatChangeType, ToyFallback, ToyType},
+        effect::{Entity, EntityName},
+        state::{EqualityCondition, Target},
+    },
+    pets::registry::{register_custom_pet, unregister_custom_pet},
+    shop::trigger::TRIGGER_TOY_BREAK,
     toys::{names::ToyName, toy::Toy},
-    Pet, PetName, Statistics, Team, TeamEffects, TeamShopping, TeamViewer,
+    Effect, Pet, PetName, Position, SAPQuery, Statistics, Team, TeamEffects, TeamShopping,
+    TeamToys, TeamViewer, ToyColumn,
 };
 
 use super::common::test_ant_team;
@@ -29,6 +37,129 @@ fn test_toy_balloon() {
     );
 }
 
+#[test]
+fn test_toy_source_pet() {
+    let balloon = Toy::try_from(ToyName::Balloon).unwrap();
+    assert_eq!(balloon.source_pet(), Some(&PetName::Ferret));
+
+    // Ferret only has balloons up to level 3.
+    let team = test_ant_team();
+    let ferret_toy = |lvl: usize, fallback: ToyFallback| {
+        ToyType::QueryOneToy {
+            query: SAPQuery::builder()
+                .set_table(Entity::Toy)
+                .set_param(ToyColumn::Source, vec![PetName::Ferret])
+                .set_param(ToyColumn::Lvl, vec![lvl]),
+            fallback,
+        }
+        .to_toy(&team)
+    };
+    assert_eq!(
+        ferret_toy(2, ToyFallback::Error).unwrap(),
+        Some(Toy::new(ToyName::Balloon, 2).unwrap())
+    );
+    assert!(ferret_toy(4, ToyFallback::Error).is_err());
+    assert_eq!(ferret_toy(4, ToyFallback::NoToy).unwrap(), None);
+    assert_eq!(
+        ferret_toy(4, ToyFallback::LowerLevel).unwrap(),
+        Some(Toy::new(ToyName::Balloon, 3).unwrap())
+    );
+}
+
+#[test]
+fn test_team_toys_lifecycle() {
+    let mut team = test_ant_team();
+    team.add_toy(Toy::new(ToyName::Balloon, 1).unwrap())
+        .add_toy(Toy::try_from(ToyName::TennisBall).unwrap());
+    assert_eq!(team.toys().len(), 2);
+
+    // Level up keeps duration.
+    team.level_up_toy(&ToyName::Balloon).unwrap();
+    let balloon = &team.toys()[0];
+    assert_eq!(balloon.lvl, 2);
+    assert_eq!(balloon.duration, Some(2));
+    assert!(team.level_up_toy(&ToyName::Boomerang).is_err());
+
+    // Remove toy without breaking it.
+    let first_ant = team.first().unwrap();
+    let first_ant_stats = first_ant.read().unwrap().stats;
+    let tennis_ball = team.remove_toy(&ToyName::TennisBall).unwrap();
+    assert_eq!(tennis_ball.name, ToyName::TennisBall);
+    assert_eq!(team.toys().len(), 1);
+    assert!(team.remove_toy(&ToyName::TennisBall).is_err());
+
+    // Break toy immediately.
+    team.break_toy(&ToyName::Balloon).unwrap();
+    assert!(team.toys().is_empty());
+    assert!(first_ant.read().unwrap().stats.attack > first_ant_stats.attack);
+    assert!(team.break_toy(&ToyName::Balloon).is_err());
+
+    // Aging toys queues a break trigger once their duration runs out.
+    team.add_toy(Toy::try_from(ToyName::Balloon).unwrap());
+    team.age_toys();
+    assert!(team.triggers.is_empty());
+    team.age_toys();
+    assert_eq!(team.triggers.len(), 1);
+    assert_eq!(team.triggers[0].status, TRIGGER_TOY_BREAK.status);
+}
+
+#[test]
+fn test_toy_break_condition() {
+    let break_effect = |cond: EqualityCondition| {
+        Effect::builder()
+            .set_trigger(TRIGGER_TOY_BREAK)
+            .set_target(Target::Friend)
+            .set_position(Position::OnSelf)
+            .set_action(Action::Conditional(
+                LogicType::If(ConditionType::Trigger(Entity::Toy, cond)),
+                Box::new(Action::Add(StatChangeType::Static(Statistics {
+                    attack: 1,
+                    health: 1,
+                }))),
+                Box::new(Action::None),
+            ))
+            .set_uses(None)
+            .build()
+    };
+    // Ants that react to a balloon breaking and to a tier 6 toy breaking.
+    // Registered so their effects are restored at the end of the turn.
+    let mut balloon_ant = Pet::custom("BalloonAnt", Statistics::new(2, 2).unwrap(), &[]);
+    balloon_ant.effect = vec![break_effect(EqualityCondition::Name(EntityName::Toy(
+        ToyName::Balloon,
+    )))];
+    let mut tier_6_ant = Pet::custom("TierSixAnt", Statistics::new(2, 2).unwrap(), &[]);
+    tier_6_ant.effect = vec![break_effect(EqualityCondition::Tier(6))];
+    register_custom_pet(&balloon_ant).unwrap();
+    register_custom_pet(&tier_6_ant).unwrap();
+    let mut team = Team::new(&[Some(balloon_ant), Some(tier_6_ant)], 5).unwrap();
+    team.toys.push(Toy::try_from(ToyName::Balloon).unwrap());
+
+    let (balloon_ant, tier_6_ant) = (team.first().unwrap(), team.last().unwrap());
+    let (balloon_ant_stats, tier_6_ant_stats) = (
+        balloon_ant.read().unwrap().stats,
+        tier_6_ant.read().unwrap().stats,
+    );
+
+    // First turn. Then second to break toy.
+    team.open_shop().unwrap().close_shop().unwrap();
+    team.open_shop().unwrap().close_shop().unwrap();
+
+    // Balloon buffs the first ant and the ant's effect also activates.
+    assert_eq!(
+        balloon_ant.read().unwrap().stats,
+        balloon_ant_stats
+            + Statistics {
+                attack: 2,
+                health: 2
+            }
+    );
+    assert_eq!(tier_6_ant.read().unwrap().stats, tier_6_ant_stats);
+
+    for name in ["BalloonAnt", "TierSixAnt"] {
+        assert!(unregister_custom_pet(&PetName::Custom(name.to_string())).is_some());
+    }
+}
+
 #[test]
 fn test_toy_tennis_ball() {
     let mut team = Team::new(
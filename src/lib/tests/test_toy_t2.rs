@@ -45,20 +45,20 @@ fn test_toy_garlic_press() {
     team.toys.push(Toy::new(ToyName::GarlicPress, 1).unwrap());
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
     assert_eq!(
-        first_ant.read().unwrap().item.as_ref().unwrap().name,
+        first_ant.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Garlic
     );
-    assert!(second_ant.read().unwrap().item.is_none());
+    assert!(second_ant.read().unwrap().items.is_empty());
     team.restore();
 
     team.toys.push(Toy::new(ToyName::GarlicPress, 2).unwrap());
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
     assert_eq!(
-        first_ant.read().unwrap().item.as_ref().unwrap().name,
+        first_ant.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Garlic
     );
     assert_eq!(
-        second_ant.read().unwrap().item.as_ref().unwrap().name,
+        second_ant.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Garlic
     );
 }
@@ -28,14 +28,14 @@ fn test_toy_toilet_paper() {
     let enemy_pets = enemy_team.all();
     assert!(enemy_pets
         .iter()
-        .all(|pet| pet.read().unwrap().item.is_none()));
+        .all(|pet| pet.read().unwrap().items.is_empty()));
 
     team.toys.push(Toy::new(ToyName::ToiletPaper, 2).unwrap());
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
 
     for pet in enemy_pets.get(0..2).unwrap() {
         assert_eq!(
-            pet.read().unwrap().item.as_ref().unwrap().name,
+            pet.read().unwrap().items.ailment.as_ref().unwrap().name,
             FoodName::Weak
         )
     }
@@ -16,10 +16,10 @@ fn test_toy_melon_helmet() {
     team.open_shop().unwrap();
 
     assert_eq!(
-        first_ant.read().unwrap().item.as_ref().unwrap().name,
+        first_ant.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Melon
     );
-    assert!(second_ant.read().unwrap().item.is_none());
+    assert!(second_ant.read().unwrap().items.is_empty());
 }
 
 #[test]
@@ -45,7 +45,7 @@ fn test_toy_peanut_jar() {
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
 
     assert_eq!(
-        first_ant.read().unwrap().item.as_ref().unwrap().name,
+        first_ant.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Peanut
     );
 }
@@ -61,7 +61,7 @@ fn test_toy_air_palm_tree() {
     team.trigger_start_battle_effects(&mut enemy_team).unwrap();
 
     assert_eq!(
-        first_ant.read().unwrap().item.as_ref().unwrap().name,
+        first_ant.read().unwrap().items.perk.as_ref().unwrap().name,
         FoodName::Coconut
     );
 }
@@ -4,22 +4,43 @@ use crate::{
     db::record::ToyRecord,
     effects::{
         actions::{
-            Action, ConditionType, GainType, LogicType, RandomizeType, StatChangeType, SummonType,
+            Action, ConditionType, GainType, LogicType, PriceType, RandomizeType, StatChangeType,
+            SummonType,
         },
         state::{FrontToBackCondition, ShopCondition, Target, TeamCondition},
         trigger::Outcomes,
     },
     error::SAPTestError,
-    Effect, FoodName, ItemCondition, PetName, Position, Statistics,
+    Effect, Entity, FoodName, ItemCondition, PetColumn, PetName, Position, SAPQuery, Statistics,
 };
 
 use super::names::ToyName;
 
+/// Feline [`PetName`]s summoned by the [`ToyMouse`](ToyName::ToyMouse).
+const FELINES: [PetName; 11] = [
+    PetName::Cat,
+    PetName::TabbyCat,
+    PetName::Leopard,
+    PetName::Tiger,
+    PetName::Lynx,
+    PetName::Lion,
+    PetName::WhiteTiger,
+    PetName::SabertoothTiger,
+    PetName::Lioness,
+    PetName::Panther,
+    PetName::Puma,
+];
+
 impl TryInto<Vec<Effect>> for ToyRecord {
     type Error = SAPTestError;
 
     fn try_into(self) -> Result<Vec<Effect>, Self::Error> {
-        let outcomes = Outcomes::from_str(&self.effect_trigger.unwrap_or_default())?;
+        let effect_trigger = match self.name {
+            // Losing gold on every roll or buy is a price increase for the turn.
+            ToyName::Dice | ToyName::OpenPiggyBank => Some(String::from("Start of turn")),
+            _ => self.effect_trigger,
+        };
+        let outcomes = Outcomes::from_str(&effect_trigger.unwrap_or_default())?;
         let effect_stats = Statistics::new(self.effect_atk, self.effect_health)?;
         let toy_lvl = TryInto::<isize>::try_into(self.lvl)?;
 
@@ -244,9 +265,15 @@ impl TryInto<Vec<Effect>> for ToyRecord {
                         base_effect.action = Action::Gain(GainType::DefaultItem(FoodName::Coconut))
                     }
                     ToyName::Dice | ToyName::OpenPiggyBank => {
+                        let price_type = if self.name == ToyName::Dice {
+                            PriceType::Roll
+                        } else {
+                            PriceType::Item
+                        };
                         base_effect.target = Target::Shop;
                         base_effect.position = Position::None;
-                        base_effect.action = Action::AlterGold(-1)
+                        base_effect.action = Action::Markup(price_type, 1);
+                        base_effect.uses = None
                     }
                     ToyName::RubberDuck => {
                         base_effect.target = Target::Enemy;
@@ -357,8 +384,13 @@ impl TryInto<Vec<Effect>> for ToyRecord {
                     ToyName::ToyMouse => {
                         base_effect.target = Target::Enemy;
                         base_effect.position = Position::First;
-                        // TODO: Felines should be a field in the db?
-                        base_effect.action = Action::None;
+                        base_effect.action = Action::Summon(SummonType::QueryPet(
+                            SAPQuery::builder()
+                                .set_table(Entity::Pet)
+                                .set_param(PetColumn::Name, FELINES.to_vec())
+                                .set_param(PetColumn::Lvl, vec![1]),
+                            Some(effect_stats),
+                        ));
                     }
                     ToyName::Custom(_) => {}
                 }
@@ -4,5 +4,7 @@
 pub mod effects;
 /// Toy names.
 pub mod names;
+/// [`Toy`](crate::Toy)s for a [`Team`](crate::Team).
+pub mod team_toys;
 /// Toy implementation.
 pub mod toy;
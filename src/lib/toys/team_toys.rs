@@ -0,0 +1,178 @@
+use log::info;
+
+use crate::{
+    error::SAPTestError,
+    shop::{team_shopping::TeamShoppingHelpers, trigger::TRIGGER_TOY_BREAK},
+    Team, Toy, ToyName,
+};
+
+/// Manage the [`Toy`]s of a [`Team`].
+/// ```rust no_run
+/// use saptest::TeamToys;
+/// ```
+pub trait TeamToys {
+    /// Get the [`Toy`]s of a [`Team`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamToys, Toy, ToyName};
+    ///
+    /// let mut team = Team::default();
+    /// assert!(team.toys().is_empty());
+    ///
+    /// team.add_toy(Toy::try_from(ToyName::Balloon).unwrap());
+    /// assert_eq!(team.toys()[0].name, ToyName::Balloon);
+    /// ```
+    fn toys(&self) -> &[Toy];
+
+    /// Add a [`Toy`] to a [`Team`].
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamToys, Toy, ToyName};
+    ///
+    /// let mut team = Team::default();
+    /// team.add_toy(Toy::try_from(ToyName::Balloon).unwrap())
+    ///     .add_toy(Toy::try_from(ToyName::TennisBall).unwrap());
+    /// assert_eq!(team.toys().len(), 2);
+    /// ```
+    fn add_toy(&mut self, toy: Toy) -> &mut Self;
+
+    /// Remove the first [`Toy`] with a given [`ToyName`] from a [`Team`] without breaking it.
+    /// * Errors if no toy has this name.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamToys, Toy, ToyName};
+    ///
+    /// let mut team = Team::default();
+    /// team.add_toy(Toy::try_from(ToyName::Balloon).unwrap());
+    ///
+    /// let toy = team.remove_toy(&ToyName::Balloon).unwrap();
+    /// assert_eq!(toy.name, ToyName::Balloon);
+    /// assert!(team.toys().is_empty());
+    /// assert!(team.remove_toy(&ToyName::Balloon).is_err());
+    /// ```
+    fn remove_toy(&mut self, name: &ToyName) -> Result<Toy, SAPTestError>;
+
+    /// Level up the first [`Toy`] with a given [`ToyName`].
+    /// * The toy's effects are replaced with those at its next level.
+    /// * The toy's duration is unchanged.
+    /// * Errors if no toy has this name or the toy has no next level.
+    /// # Example
+    /// ```
+    /// use saptest::{Team, TeamToys, Toy, ToyName};
+    ///
+    /// let mut team = Team::default();
+    /// team.add_toy(Toy::new(ToyName::Balloon, 1).unwrap());
+    /// team.level_up_toy(&ToyName::Balloon).unwrap();
+    ///
+    /// assert_eq!(team.toys()[0], {
+    ///     let mut lvl_2_balloon = Toy::new(ToyName::Balloon, 2).unwrap();
+    ///     lvl_2_balloon.duration = team.toys()[0].duration;
+    ///     lvl_2_balloon
+    /// });
+    /// ```
+    fn level_up_toy(&mut self, name: &ToyName) -> Result<&mut Self, SAPTestError>;
+
+    /// Break all [`Toy`]s with a given [`ToyName`].
+    /// * Activates effects triggered by the toy breaking and then removes the toys.
+    /// * Errors if no toy has this name.
+    /// # Example
+    /// ```
+    /// use saptest::{Pet, PetName, Team, TeamToys, TeamViewer, Toy, ToyName};
+    ///
+    /// let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    /// team.add_toy(Toy::try_from(ToyName::Balloon).unwrap());
+    ///
+    /// let ant_stats = team.first().unwrap().read().unwrap().stats;
+    /// team.break_toy(&ToyName::Balloon).unwrap();
+    ///
+    /// // Balloon gives the first pet stats when it breaks.
+    /// assert!(team.toys().is_empty());
+    /// assert!(team.first().unwrap().read().unwrap().stats.attack > ant_stats.attack);
+    /// ```
+    fn break_toy(&mut self, name: &ToyName) -> Result<&mut Self, SAPTestError>;
+
+    /// Decrease the duration of all [`Toy`]s by one turn.
+    /// * Toys that run out queue a [`TRIGGER_TOY_BREAK`](crate::shop::trigger::TRIGGER_TOY_BREAK).
+    /// * Toys with the same name break together.
+    /// * Broken toys are removed once the triggers are processed at the start of a shop turn.
+    fn age_toys(&mut self) -> &mut Self;
+}
+
+impl TeamToys for Team {
+    fn toys(&self) -> &[Toy] {
+        &self.toys
+    }
+
+    fn add_toy(&mut self, toy: Toy) -> &mut Self {
+        info!(target: "run", "(\"{}\")\nAdded toy {}.", self.name, toy.name);
+        self.toys.push(toy);
+        self
+    }
+
+    fn remove_toy(&mut self, name: &ToyName) -> Result<Toy, SAPTestError> {
+        let idx = self.toys.iter().position(|toy| &toy.name == name).ok_or(
+            SAPTestError::InvalidTeamAction {
+                subject: "Remove Toy".to_string(),
+                reason: format!("No toy {name} on team."),
+            },
+        )?;
+        Ok(self.toys.remove(idx))
+    }
+
+    fn level_up_toy(&mut self, name: &ToyName) -> Result<&mut Self, SAPTestError> {
+        let toy = self.toys.iter_mut().find(|toy| &toy.name == name).ok_or(
+            SAPTestError::InvalidTeamAction {
+                subject: "Level Up Toy".to_string(),
+                reason: format!("No toy {name} on team."),
+            },
+        )?;
+        let mut leveled_toy = Toy::new(name.clone(), toy.lvl + 1)?;
+        leveled_toy.duration = toy.duration;
+        leveled_toy.hard_mode = toy.hard_mode;
+        *toy = leveled_toy;
+        Ok(self)
+    }
+
+    fn break_toy(&mut self, name: &ToyName) -> Result<&mut Self, SAPTestError> {
+        let mut break_trigger = TRIGGER_TOY_BREAK;
+        for toy in self.toys.iter_mut().filter(|toy| &toy.name == name) {
+            toy.duration = Some(0);
+            break_trigger.set_afflicting_toy(toy);
+        }
+        if break_trigger.afflicting_toy.is_none() {
+            return Err(SAPTestError::InvalidTeamAction {
+                subject: "Break Toy".to_string(),
+                reason: format!("No toy {name} on team."),
+            });
+        }
+        self.triggers.push_back(break_trigger);
+        self.process_shop_triggers()?;
+        self.toys.retain(|toy| toy.duration != Some(0));
+        Ok(self)
+    }
+
+    fn age_toys(&mut self) -> &mut Self {
+        for toy in self.toys.iter_mut() {
+            if let Some(duration) = toy.duration.as_mut() {
+                *duration = duration.saturating_sub(1);
+            }
+        }
+        // Break toys that have run out.
+        // * Toys with the same name break together.
+        let mut broken_toys: Vec<&Toy> = vec![];
+        for toy in self.toys.iter().filter(|toy| toy.duration == Some(0)) {
+            if broken_toys
+                .iter()
+                .all(|broken_toy| broken_toy.name != toy.name)
+            {
+                broken_toys.push(toy)
+            }
+        }
+        for toy in broken_toys {
+            let mut break_trigger = TRIGGER_TOY_BREAK;
+            break_trigger.set_afflicting_toy(toy);
+            self.triggers.push_back(break_trigger);
+        }
+        self
+    }
+}
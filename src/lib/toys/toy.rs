@@ -1,9 +1,11 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     db::record::{SAPRecord, ToyRecord},
     error::SAPTestError,
-    Effect, Entity, SAPQuery, SAPDB,
+    Effect, Entity, PetName, SAPQuery, ToyColumn, SAPDB,
 };
 
 use super::names::ToyName;
@@ -21,6 +23,12 @@ pub struct Toy {
     pub duration: Option<usize>,
     /// Effect of toy.
     pub effect: Vec<Effect>,
+    /// Toy is from hard mode.
+    #[serde(default)]
+    pub hard_mode: bool,
+    /// Pet that grants the toy.
+    #[serde(default)]
+    pub(crate) source: Option<PetName>,
 }
 
 impl Toy {
@@ -35,8 +43,8 @@ impl Toy {
     pub fn new(name: ToyName, lvl: usize) -> Result<Toy, SAPTestError> {
         let query = SAPQuery::builder()
             .set_table(Entity::Toy)
-            .set_param("name", vec![&name])
-            .set_param("lvl", vec![lvl]);
+            .set_param(ToyColumn::Name, vec![&name])
+            .set_param(ToyColumn::Lvl, vec![lvl]);
 
         if let Some(SAPRecord::Toy(record)) = SAPDB
             .execute_query(query)
@@ -50,19 +58,40 @@ impl Toy {
             })
         }
     }
+
+    /// Get the [`PetName`] of the pet that grants this toy, if any.
+    /// * Ex. [`Ferret`](PetName::Ferret) and [`Puppy`](PetName::Puppy).
+    /// * Toys without a source are only obtained from the [`Shop`](crate::Shop) or other effects.
+    pub fn source_pet(&self) -> Option<&PetName> {
+        self.source.as_ref()
+    }
 }
 
 impl TryFrom<ToyRecord> for Toy {
     type Error = SAPTestError;
 
     fn try_from(record: ToyRecord) -> Result<Toy, SAPTestError> {
-        let (name, tier, lvl) = (record.name.clone(), record.tier, record.lvl);
+        let (name, tier, lvl, hard_mode) = (
+            record.name.clone(),
+            record.tier,
+            record.lvl,
+            record.hard_mode,
+        );
+        // Sources without a pet are stored as "None".
+        let source = record
+            .source
+            .as_deref()
+            .filter(|source| !source.is_empty() && *source != "None")
+            .map(PetName::from_str)
+            .transpose()?;
         Ok(Toy {
             name,
             tier,
             lvl,
             duration: Some(2),
             effect: record.try_into()?,
+            hard_mode,
+            source,
         })
     }
 }
@@ -73,7 +102,7 @@ impl TryFrom<ToyName> for Toy {
     fn try_from(name: ToyName) -> Result<Self, Self::Error> {
         let query = SAPQuery::builder()
             .set_table(Entity::Toy)
-            .set_param("name", vec![&name]);
+            .set_param(ToyColumn::Name, vec![&name]);
 
         if let Some(SAPRecord::Toy(record)) = SAPDB
             .execute_query(query)
@@ -0,0 +1,119 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::CONFIG;
+
+/// Default directory of cached wiki pages.
+pub const DEFAULT_CACHE_DIR: &str = "./.saptest_cache";
+
+/// Cached response of a wiki page.
+/// * Pages are keyed by their url which includes the page and its revision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedPage {
+    /// Url of page.
+    pub url: String,
+    /// `ETag` header of the response.
+    pub etag: Option<String>,
+    /// `Last-Modified` header of the response.
+    pub last_modified: Option<String>,
+    /// Body of the response.
+    pub body: String,
+}
+
+impl CachedPage {
+    /// Check if the page is pinned to a revision.
+    /// * Pinned pages never change and can be used without revalidation.
+    pub fn is_pinned(&self) -> bool {
+        self.url.contains("&oldid=")
+    }
+}
+
+/// On-disk cache of wiki pages.
+#[derive(Debug, Clone)]
+pub struct PageCache {
+    dir: PathBuf,
+}
+
+impl Default for PageCache {
+    fn default() -> Self {
+        PageCache::new(
+            CONFIG
+                .database
+                .cache_dir
+                .as_deref()
+                .unwrap_or(DEFAULT_CACHE_DIR),
+        )
+    }
+}
+
+impl PageCache {
+    /// Create a cache in a given directory.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        PageCache {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Path of the cached file for a url.
+    /// * Characters not allowed in filenames are replaced with `_`.
+    fn page_path(&self, url: &str) -> PathBuf {
+        let key = url
+            .trim_start_matches("https://")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        self.dir.join(format!("{key}.json"))
+    }
+
+    /// Get a cached page by its url.
+    /// * Missing or unreadable pages are treated as uncached.
+    pub fn get(&self, url: &str) -> Option<CachedPage> {
+        let contents = fs::read_to_string(self.page_path(url)).ok()?;
+        serde_json::from_str::<CachedPage>(&contents)
+            .ok()
+            .filter(|page| page.url == url)
+    }
+
+    /// Store a page in the cache.
+    /// * Failures to write are logged and otherwise ignored.
+    pub fn insert(&self, page: &CachedPage) {
+        let written = fs::create_dir_all(&self.dir)
+            .map_err(|err| err.to_string())
+            .and_then(|_| serde_json::to_string(page).map_err(|err| err.to_string()))
+            .and_then(|contents| {
+                fs::write(self.page_path(&page.url), contents).map_err(|err| err.to_string())
+            });
+        if let Err(err) = written {
+            warn!(target: "wiki_scraper", "Unable to cache page {}: {err}", page.url);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CachedPage, PageCache};
+
+    #[test]
+    fn test_page_cache_roundtrip() {
+        let cache = PageCache::new(std::env::temp_dir().join("saptest_page_cache_test"));
+        let page = CachedPage {
+            url: "https://superautopets.wiki.gg/wiki/Pets?action=raw&oldid=1".to_string(),
+            etag: Some("\"abc\"".to_string()),
+            last_modified: None,
+            body: "{{Pets}}".to_string(),
+        };
+        assert!(page.is_pinned());
+        cache.insert(&page);
+
+        assert_eq!(cache.get(&page.url), Some(page));
+        assert_eq!(
+            cache.get("https://superautopets.wiki.gg/wiki/Pets?action=raw"),
+            None
+        );
+    }
+}
@@ -1,12 +1,55 @@
-use crate::{error::SAPTestError, regex_patterns::*};
+use crate::{
+    error::SAPTestError,
+    regex_patterns::*,
+    wiki_scraper::cache::{CachedPage, PageCache},
+    CONFIG,
+};
 use itertools::Itertools;
 use log::info;
 
 pub const TABLE_ENTRY_DELIM: &str = "|-";
 
+/// Get the contents of a wiki page.
+/// * Pages are cached on disk by url and reused if unchanged.
+///     * Pages pinned to a revision are never downloaded again.
+///     * Other pages are revalidated with their `ETag` and `Last-Modified` headers.
+/// * Setting `force_refresh` in the config ignores any cached pages.
 pub fn get_page_info(url: &str) -> Result<String, SAPTestError> {
+    let cache = PageCache::default();
+    let cached_page = if CONFIG.database.force_refresh {
+        None
+    } else {
+        cache.get(url)
+    };
+    if let Some(page) = cached_page.as_ref().filter(|page| page.is_pinned()) {
+        info!(target: "wiki_scraper", "Using cached page info for {url}.");
+        return Ok(page.body.clone());
+    }
+
     info!(target: "wiki_scraper", "Retrieving page info for {url}.");
-    Ok(ureq::get(url).call()?.into_string()?)
+    let mut req = ureq::get(url);
+    if let Some(page) = cached_page.as_ref() {
+        if let Some(etag) = page.etag.as_deref() {
+            req = req.set("If-None-Match", etag)
+        }
+        if let Some(last_modified) = page.last_modified.as_deref() {
+            req = req.set("If-Modified-Since", last_modified)
+        }
+    }
+    let resp = req.call()?;
+    if let (304, Some(page)) = (resp.status(), cached_page) {
+        info!(target: "wiki_scraper", "Page info for {url} unchanged.");
+        return Ok(page.body);
+    }
+
+    let page = CachedPage {
+        url: url.to_owned(),
+        etag: resp.header("ETag").map(str::to_owned),
+        last_modified: resp.header("Last-Modified").map(str::to_owned),
+        body: resp.into_string()?,
+    };
+    cache.insert(&page);
+    Ok(page.body)
 }
 
 /// Remove any Fandom icon names from a block of text.
@@ -1,14 +1,33 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    thread,
+};
 
 use lazy_static::lazy_static;
+use log::warn;
 
 use crate::{
-    wiki_scraper::parse_icons::{extract_all_wiki_images, list_page_images},
+    db::{
+        record::{FoodRecord, PetRecord, ScrapeStatusRecord, TeamNameRecord, ToyRecord},
+        snapshot::{DataSnapshot, SNAPSHOT_VERSION},
+    },
+    error::SAPTestError,
+    wiki_scraper::{
+        parse_ailment::parse_ailment_info,
+        parse_food::parse_food_info,
+        parse_hard_mode_toys::parse_hard_mode_toy_info,
+        parse_icons::{extract_all_wiki_images, list_page_images},
+        parse_names::parse_names_info,
+        parse_pet::parse_pet_info,
+        parse_tokens::parse_token_info,
+        parse_toy::parse_toy_info,
+    },
     CONFIG,
 };
 
 use self::parse_icons::WikiImgData;
 
+pub mod cache;
 pub mod common;
 pub mod parse_ailment;
 pub mod parse_food;
@@ -63,3 +82,247 @@ lazy_static! {
         }
     };
 }
+
+const PET_URL: &str = "https://superautopets.wiki.gg/wiki/Pets?action=raw";
+const FOOD_URL: &str = "https://superautopets.wiki.gg/wiki/Food?action=raw";
+const AILMENTS_URL: &str = "https://superautopets.wiki.gg/wiki/Ailments?action=raw";
+const TOKEN_URL: &str = "https://superautopets.wiki.gg/wiki/Tokens?action=raw";
+const TOYS_URL: &str = "https://superautopets.wiki.gg/wiki/Toys?action=raw";
+const TOYS_HARD_MODE_URL: &str = "https://superautopets.wiki.gg/wiki/Hard_Mode_Toys?action=raw";
+const NAMES_URL: &str = "https://superautopets.wiki.gg/wiki/Team_Names?action=raw";
+
+/// Get the url of a wiki page at a given version.
+/// * Leaving the version blank uses the latest version.
+fn versioned_url(url: &str, version: Option<u16>) -> String {
+    version.map_or_else(|| url.to_owned(), |id| format!("{url}&oldid={id}"))
+}
+
+/// Wiki page to scrape.
+pub(crate) struct WikiPage<T> {
+    /// Name of page.
+    name: &'static str,
+    /// Url of page at the configured version.
+    url: String,
+    /// Parser of page into records.
+    parse: fn(&str) -> Result<Vec<T>, SAPTestError>,
+}
+
+impl<T> WikiPage<T> {
+    fn new(
+        name: &'static str,
+        url: &str,
+        version: Option<u16>,
+        parse: fn(&str) -> Result<Vec<T>, SAPTestError>,
+    ) -> Self {
+        WikiPage {
+            name,
+            url: versioned_url(url, version),
+            parse,
+        }
+    }
+}
+
+/// Food and ailment pages.
+pub(crate) fn food_pages() -> [WikiPage<FoodRecord>; 2] {
+    [
+        WikiPage::new(
+            "Food",
+            FOOD_URL,
+            CONFIG.database.foods_version,
+            parse_food_info,
+        ),
+        WikiPage::new(
+            "Ailments",
+            AILMENTS_URL,
+            CONFIG.database.ailments_version,
+            parse_ailment_info,
+        ),
+    ]
+}
+
+/// Pet and token pages.
+pub(crate) fn pet_pages() -> [WikiPage<PetRecord>; 2] {
+    [
+        WikiPage::new(
+            "Pets",
+            PET_URL,
+            CONFIG.database.pets_version,
+            parse_pet_info,
+        ),
+        WikiPage::new(
+            "Tokens",
+            TOKEN_URL,
+            CONFIG.database.tokens_version,
+            parse_token_info,
+        ),
+    ]
+}
+
+/// Toy and hard mode toy pages.
+pub(crate) fn toy_pages() -> [WikiPage<ToyRecord>; 2] {
+    [
+        WikiPage::new(
+            "Toys",
+            TOYS_URL,
+            CONFIG.database.toys_version,
+            parse_toy_info,
+        ),
+        WikiPage::new(
+            "Hard Mode Toys",
+            TOYS_HARD_MODE_URL,
+            CONFIG.database.toys_hard_mode_version,
+            parse_hard_mode_toy_info,
+        ),
+    ]
+}
+
+/// Team name pages.
+pub(crate) fn name_pages() -> [WikiPage<TeamNameRecord>; 1] {
+    [WikiPage::new(
+        "Team Names",
+        NAMES_URL,
+        CONFIG.database.names_version,
+        |url| {
+            Ok(parse_names_info(url)?
+                .into_iter()
+                .map(TeamNameRecord::from)
+                .collect())
+        },
+    )]
+}
+
+/// Scrape wiki pages concurrently.
+/// * Each page is fetched and parsed on its own thread.
+/// * Pages that fail to be scraped or parsed are logged and have no records.
+///     * The status of each page is returned as a [`ScrapeStatusRecord`].
+pub(crate) fn scrape_pages<T: Send>(pages: &[WikiPage<T>]) -> (Vec<T>, Vec<ScrapeStatusRecord>) {
+    thread::scope(|scope| {
+        let handles = pages
+            .iter()
+            .map(|page| scope.spawn(|| (page.parse)(&page.url)))
+            .collect::<Vec<_>>();
+
+        let mut records = vec![];
+        let mut statuses = vec![];
+        for (page, handle) in pages.iter().zip(handles) {
+            let error = match handle.join() {
+                Ok(Ok(page_records)) => {
+                    records.extend(page_records);
+                    None
+                }
+                Ok(Err(err)) => Some(err.to_string()),
+                Err(_) => Some("Thread scraping page panicked.".to_string()),
+            };
+            if let Some(err) = error.as_ref() {
+                warn!(target: "wiki_scraper", "Failed to scrape {} page ({err}). Keeping previous records.", page.name);
+            }
+            statuses.push(ScrapeStatusRecord {
+                page: page.name.to_string(),
+                url: page.url.clone(),
+                error,
+            })
+        }
+        (records, statuses)
+    })
+}
+
+/// Scrape all wiki pages concurrently into a [`DataSnapshot`].
+/// * Foods, pets, toys, and names are scraped with [`scrape_pages`] on their own threads.
+/// * The snapshot only contains records from pages that were scraped successfully.
+pub(crate) fn scrape_snapshot() -> (DataSnapshot, Vec<ScrapeStatusRecord>) {
+    thread::scope(|scope| {
+        let foods = scope.spawn(|| scrape_pages(&food_pages()));
+        let pets = scope.spawn(|| scrape_pages(&pet_pages()));
+        let toys = scope.spawn(|| scrape_pages(&toy_pages()));
+        let (names, mut statuses) = scrape_pages(&name_pages());
+
+        let mut snapshot = DataSnapshot {
+            version: SNAPSHOT_VERSION,
+            names,
+            ..Default::default()
+        };
+        // Scraping threads catch parser failures so joining can only fail on a bug in scrape_pages.
+        let (foods, food_statuses) = foods.join().expect("Food scraping thread panicked.");
+        let (pets, pet_statuses) = pets.join().expect("Pet scraping thread panicked.");
+        let (toys, toy_statuses) = toys.join().expect("Toy scraping thread panicked.");
+        snapshot.foods = foods;
+        snapshot.pets = pets;
+        snapshot.toys = toys;
+        statuses.extend(food_statuses);
+        statuses.extend(pet_statuses);
+        statuses.extend(toy_statuses);
+        (snapshot, statuses)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        food_pages, name_pages, pet_pages, scrape_pages, scrape_snapshot, toy_pages, WikiPage,
+    };
+    use crate::{db::snapshot::SNAPSHOT_VERSION, error::SAPTestError};
+
+    #[test]
+    fn test_scrape_pages() {
+        let pages = [
+            WikiPage::new("Numbers", "numbers", None, |_| Ok(vec![1, 2])),
+            WikiPage::new("Broken", "broken", Some(1), |url| {
+                Err(SAPTestError::ParserFailure {
+                    subject: "Broken Page".to_string(),
+                    reason: format!("Unable to parse {url}."),
+                })
+            }),
+            WikiPage::new("More Numbers", "more_numbers", None, |_| Ok(vec![3])),
+        ];
+        let (records, statuses) = scrape_pages(&pages);
+
+        // Failed pages have no records but don't stop other pages from being scraped.
+        assert_eq!(records, vec![1, 2, 3]);
+        assert_eq!(
+            statuses
+                .iter()
+                .map(|status| (status.page.as_str(), status.succeeded()))
+                .collect::<Vec<_>>(),
+            vec![("Numbers", true), ("Broken", false), ("More Numbers", true)]
+        );
+        assert_eq!(statuses[1].url, "broken&oldid=1");
+        assert!(statuses[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("broken&oldid=1"));
+    }
+
+    #[test]
+    fn test_scrape_snapshot() {
+        let (snapshot, statuses) = scrape_snapshot();
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+
+        // One status for every page.
+        let mut pages = statuses
+            .iter()
+            .map(|status| status.page.clone())
+            .collect::<Vec<_>>();
+        let mut exp_pages = name_pages()
+            .iter()
+            .map(|page| page.name)
+            .chain(food_pages().iter().map(|page| page.name))
+            .chain(pet_pages().iter().map(|page| page.name))
+            .chain(toy_pages().iter().map(|page| page.name))
+            .collect::<Vec<_>>();
+        pages.sort();
+        exp_pages.sort();
+        assert_eq!(pages, exp_pages);
+
+        // Tables only have records if one of their pages was scraped.
+        let scraped = |page_names: &[&str]| {
+            statuses
+                .iter()
+                .any(|status| page_names.contains(&status.page.as_str()) && status.succeeded())
+        };
+        assert!(scraped(&["Food", "Ailments"]) || snapshot.foods.is_empty());
+        assert!(scraped(&["Pets", "Tokens"]) || snapshot.pets.is_empty());
+        assert!(scraped(&["Toys", "Hard Mode Toys"]) || snapshot.toys.is_empty());
+        assert!(scraped(&["Team Names"]) || snapshot.names.is_empty());
+    }
+}
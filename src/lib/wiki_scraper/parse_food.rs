@@ -3,8 +3,8 @@ use log::{error, info};
 use std::{error::Error, str::FromStr};
 
 use crate::regex_patterns::{
-    RGX_ATK, RGX_DMG, RGX_DMG_REDUCE, RGX_END_OF_BATTLE, RGX_END_TURN, RGX_HEALTH, RGX_ONE_USE,
-    RGX_RANDOM, RGX_START_TURN, RGX_SUMMON_ATK, RGX_SUMMON_HEALTH,
+    RGX_ATK, RGX_DMG, RGX_DMG_REDUCE, RGX_END_OF_BATTLE, RGX_END_TURN, RGX_HEALTH, RGX_N_PETS,
+    RGX_ONE_USE, RGX_RANDOM, RGX_START_TURN, RGX_SUMMON_ATK, RGX_SUMMON_HEALTH,
 };
 use crate::shop::store::MAX_SHOP_TIER;
 use crate::FoodName;
@@ -22,6 +22,8 @@ const SINGLE_USE_ITEMS_EXCEPTIONS: [&str; 2] = ["Pepper", "Sleeping Pill"];
 const HOLDABLE_ITEMS_EXCEPTIONS: [&str; 2] = ["Coconut", "Peanut"];
 const ONE_GOLD_ITEMS_EXCEPTIONS: [&str; 1] = ["Sleeping Pill"];
 const DEFAULT_FOOD_COST: usize = 3;
+/// Number of pets on a full team.
+const ALL_PETS_N_TARGETS: usize = 5;
 
 /// Check whether food effect is random and the number of targets it affects.
 pub fn get_random_n_effect(effect: &str) -> Result<(bool, usize), Box<dyn Error>> {
@@ -36,6 +38,16 @@ pub fn get_random_n_effect(effect: &str) -> Result<(bool, usize), Box<dyn Error>
                 _ => n.parse()?,
             },
         ))
+    } else if let Some(Some(n_targets)) = RGX_N_PETS.captures(effect).map(|cap| cap.get(1)) {
+        // Non-random multi-target foods. ex. Donut and Pancakes
+        Ok((
+            false,
+            match n_targets.as_str() {
+                "two" => 2,
+                "three" => 3,
+                _ => ALL_PETS_N_TARGETS,
+            },
+        ))
     } else {
         Ok((false, 1))
     }
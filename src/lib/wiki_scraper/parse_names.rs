@@ -3,6 +3,7 @@ use std::str::FromStr;
 use log::info;
 
 use crate::{
+    db::record::TeamNameRecord,
     error::SAPTestError,
     regex_patterns::{RGX_COL_DESC_CATEG, RGX_COL_WORD, RGX_MULT_TABLE},
 };
@@ -27,6 +28,15 @@ pub(crate) struct TeamWord {
     pub word: String,
 }
 
+impl From<TeamWord> for TeamNameRecord {
+    fn from(word: TeamWord) -> Self {
+        TeamNameRecord {
+            category: word.word_type.to_string(),
+            word: word.word,
+        }
+    }
+}
+
 impl FromStr for WordType {
     type Err = SAPTestError;
 
@@ -50,14 +50,15 @@ pub fn parse_pet_stats(line: &str) -> Result<(usize, usize), SAPTestError> {
 }
 
 /// Parse a block of Fandom wiki source text for a pet's packs.
-/// * Original text: `starpack`
-/// * Regex: `(\w+pack)+`
+/// * Original text: `starpack = yes`
+/// * Regex: `(\w+pack)\s*=\s*yes`
 ///
-/// Each pack is matched to a `Pack` enum:
+/// Only packs flagged with `yes` are included. Each pack is matched to a `Pack` enum:
 /// * `Star`
 /// * `Puppy`
 /// * `Turtle`
 /// * `Weekly`
+/// * `Golden`
 /// * `Unknown`
 ///
 pub fn parse_pet_packs(line: &str) -> Vec<Pack> {
@@ -106,6 +107,28 @@ pub fn parse_pet_effects(line: &str, pet_effect_found: bool) -> Vec<String> {
         .collect_vec()
 }
 
+/// Get the effect description of each pet level.
+/// * Pets with an effect per level use that level's effect.
+/// * Pets with one effect shared by all levels have per-level values written as `1/2/3`.
+///     * Original text: `Deal 1/2/3 damage.`
+///     * Level 2: `Deal 2 damage.`
+/// * Missing levels reuse the effect of the highest level found.
+pub fn parse_pet_lvl_effects(effects: &[String]) -> [Option<String>; 3] {
+    let shared_effect = effects.len() == 1;
+    [0, 1, 2].map(|lvl| {
+        let effect = effects.get(lvl).or_else(|| effects.last())?;
+        if shared_effect {
+            Some(
+                RGX_PET_LVL_VALUES
+                    .replace_all(effect, |cap: &regex::Captures| cap[lvl + 1].to_string())
+                    .into_owned(),
+            )
+        } else {
+            Some(effect.clone())
+        }
+    })
+}
+
 /// Extracts effect information.
 ///
 /// **Note: This only gets raw stats**
@@ -195,13 +218,12 @@ pub fn parse_single_pet(
 
     // Remove icon names in line so regex doesn't give false positive.
     let pet_effect_trigger = parse_pet_effect_trigger(block);
-    let pet_effects = parse_pet_effects(block, pet_effect_trigger.is_some());
+    let pet_effects =
+        parse_pet_lvl_effects(&parse_pet_effects(block, pet_effect_trigger.is_some()));
 
     // Create a new pet record for every level.
     for pack in pet_packs.iter() {
-        for lvl in 0..3 {
-            let pet_lvl_effect = pet_effects.get(lvl).cloned();
-
+        for (lvl, pet_lvl_effect) in pet_effects.iter().cloned().enumerate() {
             let (effect_stats, n_triggers, temp_effect) =
                 extract_pet_effect_info(pet_lvl_effect.as_deref());
             let pet = PetRecord {
@@ -220,6 +242,7 @@ pub fn parse_single_pet(
                 cost: DEFAULT_PET_COST,
                 img_url: url.clone(),
                 is_token: false,
+                summonable: true,
             };
 
             pets.push(pet)
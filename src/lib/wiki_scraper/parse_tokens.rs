@@ -78,6 +78,7 @@ pub fn parse_single_token(block: &str, pets: &mut Vec<PetRecord>) -> Result<(),
                     cost: DEFAULT_TOKEN_COST,
                     img_url: url.clone(),
                     is_token: true,
+                    summonable: false,
                 })
             } else {
                 warn!("Failed to parse stats for {name} from string {stats}.")
@@ -6,8 +6,8 @@ use saptest::{
         trigger::*,
     },
     teams::{combat::TeamCombat, team::TeamFightOutcome},
-    Effect, Food, FoodName, Pet, PetName, SAPQuery, Shop, ShopItem, Statistics, Team, TeamEffects,
-    TeamShopping, SAPDB,
+    Effect, Food, FoodName, Pet, PetName, SAPQuery, Shop, ShopItem, ShopItemViewer, ShopViewer,
+    Statistics, Team, TeamEffects, TeamShopping, SAPDB,
 };
 use std::{str::FromStr, thread};
 
@@ -101,8 +101,8 @@ fn test_create_custom_pet() {
     let front_ant = team.friends[0].as_ref().unwrap();
     let behind_ant = team.friends[2].as_ref().unwrap();
     assert!(
-        front_ant.read().unwrap().item.as_ref().unwrap().name == FoodName::Melon
-            && behind_ant.read().unwrap().item.as_ref().unwrap().name == FoodName::Melon
+        front_ant.read().unwrap().items.perk.as_ref().unwrap().name == FoodName::Melon
+            && behind_ant.read().unwrap().items.perk.as_ref().unwrap().name == FoodName::Melon
     )
 }
 
@@ -264,13 +264,44 @@ fn test_apply_effect() {
         .any(|trigger| trigger.status == Status::Hurt));
 }
 
+#[test]
+fn test_pending_triggers() {
+    let mosquito = Pet::try_from(PetName::Mosquito).unwrap();
+    let mut team = Team::new(&vec![Some(mosquito.clone()); 5], 5).unwrap();
+    let mut enemy_team = Team::new(&vec![Some(mosquito); 5], 5).unwrap();
+    team.set_seed(Some(0));
+
+    // No triggers queued on a new team.
+    assert!(enemy_team.pending_triggers().is_empty());
+
+    let mosquito = team.friends[0].as_ref().unwrap();
+    let mosquito_effect = mosquito.read().unwrap().effect[0].clone();
+    team.apply_effect(&mosquito_effect, Some(&mut enemy_team))
+        .unwrap();
+
+    // Hurt trigger is queued on the enemy team.
+    assert!(enemy_team
+        .pending_triggers()
+        .iter()
+        .any(|trigger| trigger.status == Status::Hurt));
+
+    // Custom triggers can be added to the queue.
+    let num_triggers = enemy_team.pending_triggers().len();
+    enemy_team.add_trigger(TRIGGER_START_BATTLE).unwrap();
+    assert_eq!(enemy_team.pending_triggers().len(), num_triggers + 1);
+    assert_eq!(
+        enemy_team.pending_triggers().back(),
+        Some(&TRIGGER_START_BATTLE)
+    );
+}
+
 #[test]
 fn test_serialize_pet() {
     let mut pet = Pet::try_from(PetName::Ant).unwrap();
     pet.seed = Some(20);
 
     let json_pet = serde_json::to_string(&pet).unwrap();
-    let exp_json = r#"{"id":null,"name":"Ant","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"Faint","affected_team":"Friend","afflicting_team":"None","position":"OnSelf","stat_diff":null},"target":"Friend","position":{"Any":"None"},"action":{"Add":{"Static":{"attack":1,"health":1}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":null,"team":null}"#;
+    let exp_json = r#"{"id":null,"name":"Ant","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"Faint","affected_team":"Friend","afflicting_team":"None","position":"OnSelf","stat_diff":null},"target":"Friend","position":{"Any":"None"},"action":{"Add":{"Static":{"attack":1,"health":1}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":null,"team":null}"#;
     assert_eq!(json_pet, exp_json);
 
     // Restore pet from json string.
@@ -296,7 +327,7 @@ fn test_serialize_team() {
     team.set_seed(Some(seed)).set_name(&name).unwrap();
 
     let json_team: String = (&team).try_into().unwrap();
-    let exp_json = r#"{"seed":20,"name":"The Wavy Monks","friends":[{"id":0,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":0,"team":"The Wavy Monks"},{"id":1,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":1,"team":"The Wavy Monks"},{"id":2,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":2,"team":"The Wavy Monks"},{"id":3,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":3,"team":"The Wavy Monks"}],"fainted":[],"sold":[],"max_size":5,"triggers":[],"stored_friends":[{"id":0,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":0,"team":"The Wavy Monks"},{"id":1,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":1,"team":"The Wavy Monks"},{"id":2,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":2,"team":"The Wavy Monks"},{"id":3,"name":"Mosquito","tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false}],"item":null,"seed":20,"cost":3,"lvl":1,"exp":0,"pos":3,"team":"The Wavy Monks"}],"persistent_effects":[{"trigger":{"status":{"IsTeam":{"NumberPets":{"LessEqual":1}}},"affected_team":"Friend","afflicting_team":"Enemy","position":"None","stat_diff":null},"target":"Friend","position":"First","action":{"Conditional":[{"If":{"Shop":{"InState":"Closed"}}},{"Conditional":[{"IfNot":{"Team":["Friend",{"Counter":["Trumpets",{"Equal":0}]}]}},{"Multiple":[{"Summon":{"CustomPet":["GoldenRetriever",{"TeamCounter":"Trumpets"},1]}},{"AddToCounter":["Trumpets",-50]}]},"None"]},"None"]},"uses":1,"temp":true}],"toys":[],"counters":{"Trumpets":0}}"#;
+    let exp_json = r#"{"seed":20,"name":"The Wavy Monks","friends":[{"id":0,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":0,"team":"The Wavy Monks"},{"id":1,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":1,"team":"The Wavy Monks"},{"id":2,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":2,"team":"The Wavy Monks"},{"id":3,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":3,"team":"The Wavy Monks"}],"fainted":[],"sold":[],"max_size":5,"triggers":[],"shop":{"state":"Closed","tier":1,"seed":null,"coins":10,"pets":[],"foods":[],"toys":[],"toy_slots":0,"packs":["Turtle"],"perm_stats":{"attack":0,"health":0},"temp_stats":[],"free_rolls":0,"roll_markup":0,"item_markup":0,"saved_coins":0,"levelup_offer":true,"auto_tier":true,"hard_mode":false,"economy":{"pet_cost":3,"food_costs":[],"sell_values":[1,2,3],"max_gold":null,"carry_over":0,"interest_per":null,"max_interest":null},"tier_schedule":[1,3,5,7,9,11],"pet_slot_schedule":[3,3,4,4,5,5],"food_slot_schedule":[1,2,2,2,2,2],"pet_tier_odds":null,"food_tier_odds":null,"allowed_items":[],"denied_items":[],"pack_weights":[],"pack_rotation":[],"turn_packs":null},"stored_friends":[{"id":0,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":0,"team":"The Wavy Monks"},{"id":1,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":1,"team":"The Wavy Monks"},{"id":2,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":2,"team":"The Wavy Monks"},{"id":3,"name":"Mosquito","nickname":null,"tier":1,"stats":{"attack":2,"health":2},"effect":[{"trigger":{"status":"StartOfBattle","affected_team":"None","afflicting_team":"None","position":"None","stat_diff":null},"target":"Enemy","position":{"N":{"condition":"None","targets":1,"random":true,"exact_n_targets":false}},"action":{"Remove":{"Static":{"attack":1,"health":0}}},"uses":1,"temp":false,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"items":{"perk":null,"ailment":null},"seed":20,"cost":3,"sell_modifier":0,"lvl":1,"exp":0,"pos":3,"team":"The Wavy Monks"}],"persistent_effects":[{"trigger":{"status":{"IsTeam":{"NumberPets":{"LessEqual":1}}},"affected_team":"Friend","afflicting_team":"Enemy","position":"None","stat_diff":null},"target":"Friend","position":"First","action":{"Conditional":[{"All":[{"Shop":{"InState":"Closed"}},{"Team":["Friend",{"Counter":["Trumpets",{"Greater":0}]}]}]},{"Multiple":[{"Summon":{"CustomPet":["GoldenRetriever",{"TeamCounter":"Trumpets"},1]}},{"AddToCounter":["Trumpets",-50]}]},"None"]},"uses":1,"temp":true,"duration":null,"tags":[],"limit":null,"uses_refresh":null}],"toys":[],"counters":{"Trumpets":{"count":0,"min":0,"max":null}},"auras":[]}"#;
     assert_eq!(exp_json, json_team);
 
     let new_team = Team::from_str(&json_team).unwrap();
@@ -305,6 +336,36 @@ fn test_serialize_team() {
     assert_ne!(new_team, team)
 }
 
+#[test]
+fn test_serialize_team_shop() {
+    let mut team = Team::new(&[Some(Pet::try_from(PetName::Ant).unwrap())], 5).unwrap();
+    team.set_shop_seed(Some(12)).open_shop().unwrap();
+    team.freeze_shop(&Position::First, &Entity::Pet).unwrap();
+    team.roll_shop().unwrap();
+
+    let json_team: String = (&team).try_into().unwrap();
+    let new_team = Team::from_str(&json_team).unwrap();
+
+    // Shop items, frozen state, and gold are restored.
+    let (shop, new_shop) = (team.get_shop(), new_team.get_shop());
+    assert_eq!(new_shop.coins, 9);
+    assert!(new_shop.pets[0].is_frozen());
+    for (item, new_item) in shop
+        .pets
+        .iter()
+        .chain(shop.foods.iter())
+        .zip(new_shop.pets.iter().chain(new_shop.foods.iter()))
+    {
+        assert_eq!(item.name(), new_item.name());
+        assert_eq!(item.cost(), new_item.cost());
+        assert_eq!(item.is_frozen(), new_item.is_frozen());
+    }
+    assert_eq!(
+        shop.len_pets() + shop.len_foods(),
+        new_shop.len_pets() + new_shop.len_foods()
+    );
+}
+
 #[test]
 fn test_battle_to_tsv() {
     let mosquito = Pet::try_from(PetName::Mosquito).unwrap();